@@ -3,13 +3,21 @@
 use anyhow::{anyhow, Result};
 use serde_json::Value;
 
-use super::{todoist::TodoistBackend, Backend};
+use super::{
+    resilient::{ResilienceSettings, ResilientBackend},
+    todoist::TodoistBackend,
+    Backend,
+};
 
-/// Create a backend instance from backend type and credentials.
+/// Create a backend instance from backend type, credentials and settings.
+///
+/// The backend is wrapped in a [`ResilientBackend`] that retries failed requests and
+/// limits the request rate as configured in the settings.
 ///
 /// # Arguments
 /// * `backend_type` - The type of backend (e.g., "todoist")
 /// * `credentials` - JSON-encoded credentials string
+/// * `settings` - JSON-encoded settings string
 ///
 /// # Returns
 /// A boxed Backend trait object
@@ -17,9 +25,15 @@ use super::{todoist::TodoistBackend, Backend};
 /// # Errors
 /// Returns error if:
 /// - Backend type is unknown
-/// - Credentials are invalid JSON
+/// - Credentials or settings are invalid JSON
 /// - Required credentials are missing
-pub fn create_backend(backend_type: &str, credentials: &str) -> Result<Box<dyn Backend>> {
+pub fn create_backend(backend_type: &str, credentials: &str, settings: &str) -> Result<Box<dyn Backend>> {
+    let resilience = ResilienceSettings::from_json(settings)?;
+    let backend = create_inner_backend(backend_type, credentials)?;
+    Ok(Box::new(ResilientBackend::new(backend, resilience)))
+}
+
+fn create_inner_backend(backend_type: &str, credentials: &str) -> Result<Box<dyn Backend>> {
     let creds: Value =
         serde_json::from_str(credentials).map_err(|e| anyhow!("Failed to parse credentials JSON: {}", e))?;
 
@@ -4,9 +4,11 @@
 //! along with common data types and error handling.
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 pub mod factory;
+pub mod resilient;
 pub mod todoist;
 
 /// Common error types for backend operations.
@@ -24,10 +26,43 @@ pub enum BackendError {
     #[error("Invalid data: {0}")]
     InvalidData(String),
 
+    #[error("Rate limited: {message}")]
+    RateLimited {
+        /// Seconds the backend asked to wait before the next request, if it said so
+        retry_after: Option<u64>,
+        message: String,
+    },
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    #[error("Server error (HTTP {status}): {message}")]
+    Server { status: u16, message: String },
+
     #[error("Backend error: {0}")]
     Other(String),
 }
 
+impl BackendError {
+    /// Classify a failed HTTP response by its status code.
+    pub fn from_status(status: u16, message: String, retry_after: Option<u64>) -> Self {
+        match status {
+            401 | 403 => Self::Auth(message),
+            404 => Self::NotFound(message),
+            409 => Self::Conflict(message),
+            429 => Self::RateLimited { retry_after, message },
+            400 | 422 => Self::InvalidData(message),
+            500..=599 => Self::Server { status, message },
+            _ => Self::Other(format!("HTTP {}: {}", status, message)),
+        }
+    }
+
+    /// Whether the same request may succeed when it is sent again later.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Network(_) | Self::RateLimited { .. } | Self::Server { .. })
+    }
+}
+
 /// Backend-agnostic project representation.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BackendProject {
@@ -37,6 +72,8 @@ pub struct BackendProject {
     pub is_inbox: bool,
     pub order_index: i32,
     pub parent_remote_id: Option<String>,
+    /// Backend color name, mapped to a terminal color by the UI
+    pub color: Option<String>,
 }
 
 /// Backend-agnostic task representation.
@@ -53,9 +90,14 @@ pub struct BackendTask {
     pub due_date: Option<String>,
     pub due_datetime: Option<String>,
     pub is_recurring: bool,
+    /// The due date in natural language, the recurrence pattern of recurring tasks
+    pub due_string: Option<String>,
     pub deadline: Option<String>,
     pub duration: Option<String>,
     pub is_completed: bool,
+    pub completed_at: Option<String>,
+    /// When the task was added, if the backend tracks it
+    pub created_at: Option<String>,
     pub labels: Vec<String>,
 }
 
@@ -66,6 +108,8 @@ pub struct BackendLabel {
     pub name: String,
     pub order_index: i32,
     pub is_favorite: bool,
+    /// Backend color name, mapped to a terminal color by the UI
+    pub color: Option<String>,
 }
 
 /// Backend-agnostic section representation.
@@ -77,16 +121,52 @@ pub struct BackendSection {
     pub order_index: i32,
 }
 
+/// Backend-agnostic comment representation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BackendComment {
+    pub remote_id: String,
+    pub task_remote_id: String,
+    pub content: String,
+    pub posted_at: Option<String>,
+}
+
+/// Changes reported by a backend's incremental sync API.
+///
+/// Entities in the upsert lists are new or modified (completed tasks are included with
+/// `is_completed` set); the `removed_*` lists hold remote IDs of entities that were deleted
+/// or archived remotely.
+#[derive(Clone, Debug, Default)]
+pub struct BackendChanges {
+    /// Cursor to pass to the next [`Backend::fetch_changes`] call
+    pub cursor: Option<String>,
+    /// Whether this is the complete remote dataset rather than a delta; local rows missing
+    /// from a full dataset are pruned, so only set this when nothing failed to load
+    pub is_full: bool,
+    pub projects: Vec<BackendProject>,
+    pub tasks: Vec<BackendTask>,
+    pub labels: Vec<BackendLabel>,
+    pub sections: Vec<BackendSection>,
+    pub comments: Vec<BackendComment>,
+    pub removed_project_ids: Vec<String>,
+    pub removed_task_ids: Vec<String>,
+    pub removed_label_ids: Vec<String>,
+    pub removed_section_ids: Vec<String>,
+    pub removed_comment_ids: Vec<String>,
+}
+
 /// Arguments for creating a new project.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CreateProjectArgs {
     pub name: String,
     pub is_favorite: Option<bool>,
     pub parent_remote_id: Option<String>,
+    /// Idempotency key; backends that support it ignore a repeated request with the same key
+    #[serde(default)]
+    pub request_id: Option<String>,
 }
 
 /// Arguments for creating a new task.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CreateTaskArgs {
     pub content: String,
     pub description: Option<String>,
@@ -96,26 +176,63 @@ pub struct CreateTaskArgs {
     pub priority: Option<i32>,
     pub due_date: Option<String>,
     pub due_datetime: Option<String>,
+    /// Natural language due date; backends that understand it use it instead of the
+    /// resolved `due_date`/`due_datetime`, which remain set whenever they could be computed locally
+    #[serde(default)]
+    pub due_string: Option<String>,
+    /// Deadline as `YYYY-MM-DD`
+    #[serde(default)]
+    pub deadline: Option<String>,
     pub duration: Option<String>,
     pub labels: Vec<String>,
+    /// Idempotency key; backends that support it ignore a repeated request with the same key
+    #[serde(default)]
+    pub request_id: Option<String>,
 }
 
 /// Arguments for creating a new label.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CreateLabelArgs {
     pub name: String,
     pub is_favorite: Option<bool>,
+    /// Idempotency key; backends that support it ignore a repeated request with the same key
+    #[serde(default)]
+    pub request_id: Option<String>,
+}
+
+/// Arguments for creating a new section.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CreateSectionArgs {
+    pub name: String,
+    pub project_remote_id: String,
+    pub order_index: Option<i32>,
+    /// Idempotency key; backends that support it ignore a repeated request with the same key
+    #[serde(default)]
+    pub request_id: Option<String>,
+}
+
+/// Arguments for creating a new comment on a task.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CreateCommentArgs {
+    pub task_remote_id: String,
+    pub content: String,
+    /// Idempotency key; backends that support it ignore a repeated request with the same key
+    #[serde(default)]
+    pub request_id: Option<String>,
 }
 
 /// Arguments for updating a project.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct UpdateProjectArgs {
     pub name: Option<String>,
     pub is_favorite: Option<bool>,
 }
 
 /// Arguments for updating a task.
-#[derive(Clone, Debug)]
+///
+/// Unset fields are left unchanged. An empty `description`, `deadline` or `duration`
+/// clears the field, a due date is cleared with the due string "no date".
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct UpdateTaskArgs {
     pub content: Option<String>,
     pub description: Option<String>,
@@ -126,17 +243,62 @@ pub struct UpdateTaskArgs {
     pub due_date: Option<String>,
     pub due_datetime: Option<String>,
     pub due_string: Option<String>,
+    pub deadline: Option<String>,
     pub duration: Option<String>,
     pub labels: Option<Vec<String>>,
 }
 
+/// Destination of a task move.
+///
+/// The task keeps its subtasks. The most specific location wins: a parent decides the
+/// section and project, a section decides the project.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoveTaskArgs {
+    pub project_remote_id: String,
+    pub section_remote_id: Option<String>,
+    pub parent_remote_id: Option<String>,
+}
+
 /// Arguments for updating a label.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct UpdateLabelArgs {
     pub name: Option<String>,
     pub is_favorite: Option<bool>,
 }
 
+/// Arguments for updating a section.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct UpdateSectionArgs {
+    pub name: Option<String>,
+}
+
+/// Arguments for updating a comment.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct UpdateCommentArgs {
+    pub content: Option<String>,
+}
+
+/// New position of a section within its project.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionOrder {
+    pub remote_id: String,
+    pub order_index: i32,
+}
+
+/// New position of a task among the tasks sharing its project, section and parent.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskOrder {
+    pub remote_id: String,
+    pub order_index: i32,
+}
+
+/// New position of a project among the projects sharing its parent.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectOrder {
+    pub remote_id: String,
+    pub order_index: i32,
+}
+
 /// Backend trait that all task management backends must implement.
 ///
 /// This trait defines the common interface for interacting with different
@@ -151,21 +313,63 @@ pub trait Backend: Send + Sync {
     async fn fetch_tasks(&self) -> Result<Vec<BackendTask>, BackendError>;
     async fn fetch_labels(&self) -> Result<Vec<BackendLabel>, BackendError>;
     async fn fetch_sections(&self) -> Result<Vec<BackendSection>, BackendError>;
+    /// Fetch the current state of a single task.
+    async fn fetch_task(&self, remote_id: &str) -> Result<BackendTask, BackendError>;
+
+    /// Fetch everything that changed since `cursor` (`None` requests the full dataset).
+    ///
+    /// Backends without a delta API keep the default, which returns `Ok(None)` so the
+    /// caller falls back to the `fetch_*` methods above.
+    async fn fetch_changes(&self, _cursor: Option<&str>) -> Result<Option<BackendChanges>, BackendError> {
+        Ok(None)
+    }
+
+    /// Fetch the tasks completed between `since` and `until`, most recently completed first.
+    ///
+    /// Neither `fetch_tasks` nor a full `fetch_changes` include completed tasks, so this
+    /// is where the logbook gets them from.
+    async fn fetch_completed_tasks(
+        &self,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<BackendTask>, BackendError>;
 
     // CRUD operations for projects
     async fn create_project(&self, args: CreateProjectArgs) -> Result<BackendProject, BackendError>;
     async fn update_project(&self, remote_id: &str, args: UpdateProjectArgs) -> Result<BackendProject, BackendError>;
     async fn delete_project(&self, remote_id: &str) -> Result<(), BackendError>;
+    /// Set the order of the given projects, which must all share the same parent.
+    async fn reorder_projects(&self, orders: Vec<ProjectOrder>) -> Result<(), BackendError>;
 
     // CRUD operations for tasks
     async fn create_task(&self, args: CreateTaskArgs) -> Result<BackendTask, BackendError>;
     async fn update_task(&self, remote_id: &str, args: UpdateTaskArgs) -> Result<BackendTask, BackendError>;
     async fn delete_task(&self, remote_id: &str) -> Result<(), BackendError>;
+    /// Complete a task; a recurring task moves on to its next occurrence instead.
     async fn complete_task(&self, remote_id: &str) -> Result<(), BackendError>;
     async fn reopen_task(&self, remote_id: &str) -> Result<(), BackendError>;
+    /// Move a task and its subtasks to another project, section or parent task.
+    async fn move_task(&self, remote_id: &str, args: MoveTaskArgs) -> Result<(), BackendError>;
+    /// Set the order of the given tasks, which must all share the same project, section and parent.
+    async fn reorder_tasks(&self, orders: Vec<TaskOrder>) -> Result<(), BackendError>;
 
     // CRUD operations for labels
     async fn create_label(&self, args: CreateLabelArgs) -> Result<BackendLabel, BackendError>;
     async fn update_label(&self, remote_id: &str, args: UpdateLabelArgs) -> Result<BackendLabel, BackendError>;
     async fn delete_label(&self, remote_id: &str) -> Result<(), BackendError>;
+
+    // CRUD operations for sections
+    async fn create_section(&self, args: CreateSectionArgs) -> Result<BackendSection, BackendError>;
+    async fn update_section(&self, remote_id: &str, args: UpdateSectionArgs) -> Result<BackendSection, BackendError>;
+    /// Delete a section together with the tasks in it.
+    async fn delete_section(&self, remote_id: &str) -> Result<(), BackendError>;
+    /// Set the order of the given sections, which must all belong to the same project.
+    async fn reorder_sections(&self, orders: Vec<SectionOrder>) -> Result<(), BackendError>;
+
+    // CRUD operations for comments
+    /// Fetch all comments of a task, oldest first.
+    async fn fetch_comments(&self, task_remote_id: &str) -> Result<Vec<BackendComment>, BackendError>;
+    async fn create_comment(&self, args: CreateCommentArgs) -> Result<BackendComment, BackendError>;
+    async fn update_comment(&self, remote_id: &str, args: UpdateCommentArgs) -> Result<BackendComment, BackendError>;
+    async fn delete_comment(&self, remote_id: &str) -> Result<(), BackendError>;
 }
@@ -0,0 +1,356 @@
+//! Retry, rate limiting and idempotency for any backend.
+//!
+//! [`ResilientBackend`] wraps another [`Backend`] and is applied to every backend by
+//! [`super::factory::create_backend`]. Each request first waits for a token from a
+//! token bucket, so bulk edits are spread out instead of tripping the remote rate
+//! limit. Requests that fail with a retryable [`BackendError`] are sent again with
+//! exponential backoff and jitter, honoring the retry-after delay of rate-limited
+//! responses. Creates get an idempotency key that stays the same across retries, so a
+//! request that reached the backend before the connection dropped does not create a
+//! duplicate.
+//!
+//! Both are configured through the `settings` JSON of the backend:
+//!
+//! ```json
+//! {
+//!     "retry": { "max_attempts": 4, "base_delay_ms": 500, "max_delay_ms": 30000 },
+//!     "rate_limit": { "requests_per_minute": 60, "burst": 10 }
+//! }
+//! ```
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::warn;
+use serde::Deserialize;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use super::{
+    Backend, BackendChanges, BackendComment, BackendError, BackendLabel, BackendProject, BackendSection, BackendTask,
+    CreateCommentArgs, CreateLabelArgs, CreateProjectArgs, CreateSectionArgs, CreateTaskArgs, MoveTaskArgs,
+    ProjectOrder, SectionOrder, TaskOrder, UpdateCommentArgs, UpdateLabelArgs, UpdateProjectArgs, UpdateSectionArgs,
+    UpdateTaskArgs,
+};
+
+/// How failed requests are retried.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct RetrySettings {
+    /// Attempts per request including the first one (1 disables retries)
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for every further one
+    pub base_delay_ms: u64,
+    /// Upper bound for the delay between two attempts
+    pub max_delay_ms: u64,
+}
+
+impl Default for RetrySettings {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay_ms: 500,
+            max_delay_ms: 30_000,
+        }
+    }
+}
+
+impl RetrySettings {
+    /// Delay before retrying after the given failed attempt (starting at 1).
+    ///
+    /// `jitter` in `0.0..=1.0` picks a point in the upper half of the exponential delay,
+    /// so clients that failed together do not retry in lockstep. A retry-after delay
+    /// requested by the backend is never undercut.
+    pub fn delay(&self, attempt: u32, retry_after: Option<u64>, jitter: f64) -> Duration {
+        let exponential = self
+            .base_delay_ms
+            .saturating_mul(2u64.saturating_pow(attempt.saturating_sub(1).min(32)))
+            .min(self.max_delay_ms);
+        let jittered = exponential / 2 + (exponential as f64 / 2.0 * jitter.clamp(0.0, 1.0)) as u64;
+        let requested = retry_after.map_or(0, |seconds| seconds.saturating_mul(1000));
+        Duration::from_millis(jittered.max(requested))
+    }
+}
+
+/// How many requests may be sent to the backend.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    /// Sustained request rate (0 disables the limiter)
+    pub requests_per_minute: u32,
+    /// Requests that may be sent at once after a quiet period
+    pub burst: u32,
+}
+
+impl Default for RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            requests_per_minute: 60,
+            burst: 10,
+        }
+    }
+}
+
+/// Retry and rate limit settings of a backend, read from its `settings` JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct ResilienceSettings {
+    pub retry: RetrySettings,
+    pub rate_limit: RateLimitSettings,
+}
+
+impl ResilienceSettings {
+    /// Parse the settings JSON of a backend; missing keys fall back to the defaults.
+    ///
+    /// # Errors
+    /// Returns an error if the settings are not valid JSON or have values of the wrong type
+    pub fn from_json(settings: &str) -> anyhow::Result<Self> {
+        if settings.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        serde_json::from_str(settings).map_err(|e| anyhow::anyhow!("Invalid backend settings: {}", e))
+    }
+}
+
+/// Token bucket that spaces out requests to the configured rate.
+struct TokenBucket {
+    capacity: f64,
+    tokens_per_second: f64,
+    state: Mutex<(f64, Instant)>,
+}
+
+impl TokenBucket {
+    fn new(settings: &RateLimitSettings) -> Option<Self> {
+        if settings.requests_per_minute == 0 {
+            return None;
+        }
+        let capacity = f64::from(settings.burst.max(1));
+        Some(Self {
+            capacity,
+            tokens_per_second: f64::from(settings.requests_per_minute) / 60.0,
+            state: Mutex::new((capacity, Instant::now())),
+        })
+    }
+
+    /// Wait until a request may be sent and take its token.
+    async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().await;
+                let (tokens, last_refill) = &mut *state;
+                let now = Instant::now();
+                *tokens = (*tokens + now.duration_since(*last_refill).as_secs_f64() * self.tokens_per_second)
+                    .min(self.capacity);
+                *last_refill = now;
+
+                if *tokens >= 1.0 {
+                    *tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - *tokens) / self.tokens_per_second)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Backend decorator adding retries with backoff, client-side rate limiting and
+/// idempotency keys for creates.
+pub struct ResilientBackend {
+    inner: Box<dyn Backend>,
+    retry: RetrySettings,
+    limiter: Option<TokenBucket>,
+}
+
+impl ResilientBackend {
+    pub fn new(inner: Box<dyn Backend>, settings: ResilienceSettings) -> Self {
+        Self {
+            limiter: TokenBucket::new(&settings.rate_limit),
+            retry: settings.retry,
+            inner,
+        }
+    }
+
+    /// Send a request, retrying it while it fails with a retryable error.
+    async fn call<T, F, Fut>(&self, operation: &str, request: F) -> Result<T, BackendError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, BackendError>>,
+    {
+        let mut attempt = 1;
+        loop {
+            if let Some(limiter) = &self.limiter {
+                limiter.acquire().await;
+            }
+
+            let error = match request().await {
+                Err(e) if e.is_retryable() && attempt < self.retry.max_attempts => e,
+                result => return result,
+            };
+
+            let retry_after = match &error {
+                BackendError::RateLimited { retry_after, .. } => *retry_after,
+                _ => None,
+            };
+            // Waiting out a long rate limit here would stall the caller; leave it to the next sync
+            if retry_after.is_some_and(|seconds| seconds.saturating_mul(1000) > self.retry.max_delay_ms) {
+                return Err(error);
+            }
+
+            let delay = self.retry.delay(attempt, retry_after, fastrand::f64());
+            warn!("{operation} failed (attempt {attempt}), retrying in {delay:?}: {error}");
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Idempotency key of a create request, generated once so every retry reuses it.
+///
+/// Outbox creates already carry the key minted when they were enqueued, which is kept.
+fn with_request_id(request_id: Option<String>) -> Option<String> {
+    request_id.or_else(|| Some(Uuid::new_v4().to_string()))
+}
+
+#[async_trait]
+impl Backend for ResilientBackend {
+    fn backend_type(&self) -> &str {
+        self.inner.backend_type()
+    }
+
+    async fn fetch_projects(&self) -> Result<Vec<BackendProject>, BackendError> {
+        self.call("fetch_projects", || self.inner.fetch_projects()).await
+    }
+
+    async fn fetch_tasks(&self) -> Result<Vec<BackendTask>, BackendError> {
+        self.call("fetch_tasks", || self.inner.fetch_tasks()).await
+    }
+
+    async fn fetch_labels(&self) -> Result<Vec<BackendLabel>, BackendError> {
+        self.call("fetch_labels", || self.inner.fetch_labels()).await
+    }
+
+    async fn fetch_sections(&self) -> Result<Vec<BackendSection>, BackendError> {
+        self.call("fetch_sections", || self.inner.fetch_sections()).await
+    }
+
+    async fn fetch_task(&self, remote_id: &str) -> Result<BackendTask, BackendError> {
+        self.call("fetch_task", || self.inner.fetch_task(remote_id)).await
+    }
+
+    async fn fetch_changes(&self, cursor: Option<&str>) -> Result<Option<BackendChanges>, BackendError> {
+        self.call("fetch_changes", || self.inner.fetch_changes(cursor)).await
+    }
+
+    async fn fetch_completed_tasks(
+        &self,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<BackendTask>, BackendError> {
+        self.call("fetch_completed_tasks", || {
+            self.inner.fetch_completed_tasks(since, until)
+        })
+        .await
+    }
+
+    async fn create_project(&self, mut args: CreateProjectArgs) -> Result<BackendProject, BackendError> {
+        args.request_id = with_request_id(args.request_id);
+        self.call("create_project", || self.inner.create_project(args.clone())).await
+    }
+
+    async fn update_project(&self, remote_id: &str, args: UpdateProjectArgs) -> Result<BackendProject, BackendError> {
+        self.call("update_project", || self.inner.update_project(remote_id, args.clone()))
+            .await
+    }
+
+    async fn delete_project(&self, remote_id: &str) -> Result<(), BackendError> {
+        self.call("delete_project", || self.inner.delete_project(remote_id)).await
+    }
+
+    async fn reorder_projects(&self, orders: Vec<ProjectOrder>) -> Result<(), BackendError> {
+        self.call("reorder_projects", || self.inner.reorder_projects(orders.clone()))
+            .await
+    }
+
+    async fn create_task(&self, mut args: CreateTaskArgs) -> Result<BackendTask, BackendError> {
+        args.request_id = with_request_id(args.request_id);
+        self.call("create_task", || self.inner.create_task(args.clone())).await
+    }
+
+    async fn update_task(&self, remote_id: &str, args: UpdateTaskArgs) -> Result<BackendTask, BackendError> {
+        self.call("update_task", || self.inner.update_task(remote_id, args.clone()))
+            .await
+    }
+
+    async fn delete_task(&self, remote_id: &str) -> Result<(), BackendError> {
+        self.call("delete_task", || self.inner.delete_task(remote_id)).await
+    }
+
+    async fn complete_task(&self, remote_id: &str) -> Result<(), BackendError> {
+        self.call("complete_task", || self.inner.complete_task(remote_id)).await
+    }
+
+    async fn reopen_task(&self, remote_id: &str) -> Result<(), BackendError> {
+        self.call("reopen_task", || self.inner.reopen_task(remote_id)).await
+    }
+
+    async fn move_task(&self, remote_id: &str, args: MoveTaskArgs) -> Result<(), BackendError> {
+        self.call("move_task", || self.inner.move_task(remote_id, args.clone())).await
+    }
+
+    async fn reorder_tasks(&self, orders: Vec<TaskOrder>) -> Result<(), BackendError> {
+        self.call("reorder_tasks", || self.inner.reorder_tasks(orders.clone())).await
+    }
+
+    async fn create_label(&self, mut args: CreateLabelArgs) -> Result<BackendLabel, BackendError> {
+        args.request_id = with_request_id(args.request_id);
+        self.call("create_label", || self.inner.create_label(args.clone())).await
+    }
+
+    async fn update_label(&self, remote_id: &str, args: UpdateLabelArgs) -> Result<BackendLabel, BackendError> {
+        self.call("update_label", || self.inner.update_label(remote_id, args.clone()))
+            .await
+    }
+
+    async fn delete_label(&self, remote_id: &str) -> Result<(), BackendError> {
+        self.call("delete_label", || self.inner.delete_label(remote_id)).await
+    }
+
+    async fn create_section(&self, mut args: CreateSectionArgs) -> Result<BackendSection, BackendError> {
+        args.request_id = with_request_id(args.request_id);
+        self.call("create_section", || self.inner.create_section(args.clone())).await
+    }
+
+    async fn update_section(&self, remote_id: &str, args: UpdateSectionArgs) -> Result<BackendSection, BackendError> {
+        self.call("update_section", || self.inner.update_section(remote_id, args.clone()))
+            .await
+    }
+
+    async fn delete_section(&self, remote_id: &str) -> Result<(), BackendError> {
+        self.call("delete_section", || self.inner.delete_section(remote_id)).await
+    }
+
+    async fn reorder_sections(&self, orders: Vec<SectionOrder>) -> Result<(), BackendError> {
+        self.call("reorder_sections", || self.inner.reorder_sections(orders.clone()))
+            .await
+    }
+
+    async fn fetch_comments(&self, task_remote_id: &str) -> Result<Vec<BackendComment>, BackendError> {
+        self.call("fetch_comments", || self.inner.fetch_comments(task_remote_id)).await
+    }
+
+    async fn create_comment(&self, mut args: CreateCommentArgs) -> Result<BackendComment, BackendError> {
+        args.request_id = with_request_id(args.request_id);
+        self.call("create_comment", || self.inner.create_comment(args.clone())).await
+    }
+
+    async fn update_comment(&self, remote_id: &str, args: UpdateCommentArgs) -> Result<BackendComment, BackendError> {
+        self.call("update_comment", || self.inner.update_comment(remote_id, args.clone()))
+            .await
+    }
+
+    async fn delete_comment(&self, remote_id: &str) -> Result<(), BackendError> {
+        self.call("delete_comment", || self.inner.delete_comment(remote_id)).await
+    }
+}
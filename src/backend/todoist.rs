@@ -1,23 +1,282 @@
 //! Todoist backend implementation.
 
 use super::{
-    Backend, BackendError, BackendLabel, BackendProject, BackendSection, BackendTask, CreateLabelArgs,
-    CreateProjectArgs, CreateTaskArgs, UpdateLabelArgs, UpdateProjectArgs, UpdateTaskArgs,
+    Backend, BackendChanges, BackendComment, BackendError, BackendLabel, BackendProject, BackendSection, BackendTask,
+    CreateCommentArgs, CreateLabelArgs, CreateProjectArgs, CreateSectionArgs, CreateTaskArgs, MoveTaskArgs,
+    ProjectOrder, SectionOrder, TaskOrder, UpdateCommentArgs, UpdateLabelArgs, UpdateProjectArgs, UpdateSectionArgs,
+    UpdateTaskArgs,
 };
 use crate::todoist::TodoistWrapper;
 use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Base URL of the Todoist REST API, used for creates that carry an idempotency key.
+const TODOIST_API_URL: &str = "https://api.todoist.com/api/v1";
+
+/// Endpoint of the Todoist Sync API, used for incremental syncs.
+const TODOIST_SYNC_URL: &str = "https://api.todoist.com/api/v1/sync";
+
+/// Resource types requested from the Sync API.
+const SYNC_RESOURCE_TYPES: &str = r#"["projects","items","labels","sections","notes"]"#;
+
+/// Response of the Sync API (only the resources terminalist stores).
+#[derive(Debug, Deserialize)]
+struct SyncResponse {
+    sync_token: String,
+    #[serde(default)]
+    full_sync: bool,
+    #[serde(default)]
+    projects: Vec<SyncProject>,
+    #[serde(default)]
+    items: Vec<SyncItem>,
+    #[serde(default)]
+    labels: Vec<SyncLabel>,
+    #[serde(default)]
+    sections: Vec<SyncSection>,
+    #[serde(default)]
+    notes: Vec<SyncNote>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SyncProject {
+    id: String,
+    name: String,
+    #[serde(default)]
+    is_favorite: bool,
+    #[serde(default, alias = "inbox_project")]
+    is_inbox_project: bool,
+    parent_id: Option<String>,
+    color: Option<String>,
+    #[serde(default)]
+    is_deleted: bool,
+    #[serde(default)]
+    is_archived: bool,
+    #[serde(default)]
+    child_order: i32,
+}
+
+#[derive(Debug, Deserialize)]
+struct SyncItem {
+    id: String,
+    content: String,
+    #[serde(default)]
+    description: String,
+    project_id: String,
+    section_id: Option<String>,
+    parent_id: Option<String>,
+    #[serde(default = "default_priority")]
+    priority: i32,
+    due: Option<SyncDue>,
+    deadline: Option<crate::todoist::Deadline>,
+    duration: Option<crate::todoist::Duration>,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    checked: bool,
+    completed_at: Option<String>,
+    added_at: Option<String>,
+    #[serde(default)]
+    child_order: i32,
+    #[serde(default)]
+    is_deleted: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct SyncDue {
+    date: String,
+    datetime: Option<String>,
+    #[serde(default)]
+    is_recurring: bool,
+    string: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SyncLabel {
+    id: String,
+    name: String,
+    #[serde(default)]
+    item_order: i32,
+    #[serde(default)]
+    is_favorite: bool,
+    color: Option<String>,
+    #[serde(default)]
+    is_deleted: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct SyncSection {
+    id: String,
+    name: String,
+    project_id: String,
+    #[serde(default)]
+    section_order: i32,
+    #[serde(default)]
+    is_deleted: bool,
+    #[serde(default)]
+    is_archived: bool,
+}
+
+/// A task comment, called note by the Sync API.
+#[derive(Debug, Deserialize)]
+struct SyncNote {
+    id: String,
+    item_id: String,
+    #[serde(default)]
+    content: String,
+    posted_at: Option<String>,
+    #[serde(default)]
+    is_deleted: bool,
+}
+
+/// A page of completed tasks, which this endpoint lists under `items` rather than `results`.
+#[derive(Debug, Deserialize)]
+struct CompletedTasksResponse {
+    items: Vec<crate::todoist::Task>,
+    next_cursor: Option<String>,
+}
+
+/// Response of the Sync API to a batch of commands.
+#[derive(Debug, Deserialize)]
+struct SyncCommandResponse {
+    /// "ok" or an error object for every command UUID
+    #[serde(default)]
+    sync_status: HashMap<String, serde_json::Value>,
+}
+
+fn default_priority() -> i32 {
+    1
+}
+
+/// Split a duration like "30 minute" into the amount and unit Todoist expects
+fn duration_parts(duration: &str) -> Option<(i32, String)> {
+    let (amount, unit) = duration.split_once(' ')?;
+    Some((amount.parse().ok()?, unit.to_string()))
+}
+
+impl From<crate::todoist::TodoistError> for BackendError {
+    fn from(error: crate::todoist::TodoistError) -> Self {
+        use crate::todoist::TodoistError;
+
+        match error {
+            TodoistError::RateLimited { retry_after, message } => Self::RateLimited { retry_after, message },
+            TodoistError::AuthenticationError { message } | TodoistError::AuthorizationError { message } => {
+                Self::Auth(message)
+            }
+            TodoistError::NotFound { message, .. } => Self::NotFound(message),
+            TodoistError::ValidationError { message, .. } => Self::InvalidData(message),
+            TodoistError::ServerError { status_code, message } => Self::Server {
+                status: status_code,
+                message,
+            },
+            TodoistError::NetworkError { message } => Self::Network(message),
+            TodoistError::ParseError { message } | TodoistError::EmptyResponse { message, .. } => {
+                Self::InvalidData(message)
+            }
+            TodoistError::Generic {
+                status_code: Some(status),
+                message,
+            } => Self::from_status(status, message, None),
+            TodoistError::Generic {
+                status_code: None,
+                message,
+            } => Self::Other(message),
+        }
+    }
+}
 
 /// Todoist backend implementation.
 pub struct TodoistBackend {
     wrapper: TodoistWrapper,
+    client: reqwest::Client,
+    api_token: String,
 }
 
 impl TodoistBackend {
     /// Create a new Todoist backend with the provided API token.
     pub fn new(api_token: String) -> Self {
         Self {
-            wrapper: TodoistWrapper::new(api_token),
+            wrapper: TodoistWrapper::new(api_token.clone()),
+            client: reqwest::Client::new(),
+            api_token,
+        }
+    }
+
+    /// Send a create request, tagged with `X-Request-Id` when an idempotency key is given.
+    ///
+    /// Todoist ignores a repeated request with the same ID, so retrying a create whose
+    /// response got lost does not create a duplicate. The API wrapper cannot set headers,
+    /// hence the direct request.
+    async fn create<A, T>(&self, endpoint: &str, args: &A, request_id: Option<&str>) -> Result<T, BackendError>
+    where
+        A: Serialize,
+        T: DeserializeOwned,
+    {
+        let mut request = self
+            .client
+            .post(format!("{}/{}", TODOIST_API_URL, endpoint))
+            .bearer_auth(&self.api_token)
+            .json(args);
+        if let Some(request_id) = request_id {
+            request = request.header("X-Request-Id", request_id);
         }
+
+        let response = request.send().await.map_err(|e| BackendError::Network(e.to_string()))?;
+        Self::parse_response(response).await
+    }
+
+    /// Run a single Sync API command, for writes the REST API does not offer.
+    async fn sync_command(&self, command_type: &str, args: serde_json::Value) -> Result<(), BackendError> {
+        let command_uuid = uuid::Uuid::new_v4().to_string();
+        let commands = serde_json::json!([{ "type": command_type, "uuid": command_uuid, "args": args }]);
+
+        let response = self
+            .client
+            .post(TODOIST_SYNC_URL)
+            .bearer_auth(&self.api_token)
+            .form(&[("commands", commands.to_string())])
+            .send()
+            .await
+            .map_err(|e| BackendError::Network(e.to_string()))?;
+        let response: SyncCommandResponse = Self::parse_response(response).await?;
+
+        match response.sync_status.get(&command_uuid) {
+            Some(serde_json::Value::String(status)) if status == "ok" => Ok(()),
+            Some(error) => {
+                let message = error
+                    .get("error")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or("command failed")
+                    .to_string();
+                let status = error
+                    .get("http_code")
+                    .and_then(serde_json::Value::as_u64)
+                    .and_then(|code| u16::try_from(code).ok())
+                    .unwrap_or(400);
+                Err(BackendError::from_status(status, message, None))
+            }
+            None => Err(BackendError::InvalidData(format!(
+                "No status for {} command",
+                command_type
+            ))),
+        }
+    }
+
+    /// Turn an HTTP response into its JSON body or a classified error.
+    async fn parse_response<T: DeserializeOwned>(response: reqwest::Response) -> Result<T, BackendError> {
+        let status = response.status();
+        if !status.is_success() {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok());
+            let body = response.text().await.unwrap_or_default();
+            return Err(BackendError::from_status(status.as_u16(), body, retry_after));
+        }
+
+        response.json().await.map_err(|e| BackendError::InvalidData(e.to_string()))
     }
 
     // Helper: Transform Todoist API project → Backend project
@@ -27,8 +286,9 @@ impl TodoistBackend {
             name: api_project.name.clone(),
             is_favorite: api_project.is_favorite,
             is_inbox: api_project.inbox_project,
-            order_index: 0, // order field removed from API v1
+            order_index: api_project.child_order,
             parent_remote_id: api_project.parent_id.clone(),
+            color: Some(api_project.color.clone()),
         }
     }
 
@@ -42,17 +302,123 @@ impl TodoistBackend {
             section_remote_id: api_task.section_id.clone(),
             parent_remote_id: api_task.parent_id.clone(),
             priority: api_task.priority,
-            order_index: 0, // order field removed from API v1
+            order_index: api_task.child_order,
             due_date: api_task.due.as_ref().map(|d| d.date.clone()),
             due_datetime: api_task.due.as_ref().and_then(|d| d.datetime.clone()),
             is_recurring: api_task.due.as_ref().map(|d| d.is_recurring).unwrap_or(false),
-            deadline: None, // Todoist doesn't have deadline
+            due_string: api_task.due.as_ref().map(|d| d.string.clone()),
+            deadline: api_task.deadline.as_ref().map(|d| d.date.clone()),
             duration: api_task.duration.as_ref().map(|d| format!("{} {}", d.amount, d.unit)),
-            is_completed: false, // Fetch operations don't include completed tasks
+            is_completed: api_task.checked,
+            completed_at: api_task.completed_at.clone(),
+            created_at: Some(api_task.added_at.clone()),
             labels: api_task.labels.clone(),
         }
     }
 
+    // Helper: Transform Sync API response → Backend changes
+    fn sync_response_to_changes(response: SyncResponse) -> BackendChanges {
+        let mut changes = BackendChanges {
+            cursor: Some(response.sync_token),
+            is_full: response.full_sync,
+            ..Default::default()
+        };
+
+        for project in response.projects {
+            if project.is_deleted || project.is_archived {
+                changes.removed_project_ids.push(project.id);
+            } else {
+                changes.projects.push(BackendProject {
+                    remote_id: project.id,
+                    name: project.name,
+                    is_favorite: project.is_favorite,
+                    is_inbox: project.is_inbox_project,
+                    order_index: project.child_order,
+                    parent_remote_id: project.parent_id,
+                    color: project.color,
+                });
+            }
+        }
+
+        for item in response.items {
+            if item.is_deleted {
+                changes.removed_task_ids.push(item.id);
+                continue;
+            }
+            // The Sync API puts the time into `date` for timed tasks
+            let (due_date, due_datetime, is_recurring, due_string) = match item.due {
+                Some(due) => {
+                    let datetime = due.datetime.or_else(|| due.date.contains('T').then(|| due.date.clone()));
+                    let date = due.date.get(..10).unwrap_or(&due.date).to_string();
+                    (Some(date), datetime, due.is_recurring, due.string)
+                }
+                None => (None, None, false, None),
+            };
+            changes.tasks.push(BackendTask {
+                remote_id: item.id,
+                content: item.content,
+                description: Some(item.description),
+                project_remote_id: item.project_id,
+                section_remote_id: item.section_id,
+                parent_remote_id: item.parent_id,
+                priority: item.priority,
+                order_index: item.child_order,
+                due_date,
+                due_datetime,
+                is_recurring,
+                due_string,
+                deadline: item.deadline.map(|d| d.date),
+                duration: item.duration.map(|d| format!("{} {}", d.amount, d.unit)),
+                is_completed: item.checked,
+                completed_at: item.completed_at,
+                created_at: item.added_at,
+                labels: item.labels,
+            });
+        }
+
+        for label in response.labels {
+            if label.is_deleted {
+                changes.removed_label_ids.push(label.id);
+            } else {
+                changes.labels.push(BackendLabel {
+                    remote_id: label.id,
+                    name: label.name,
+                    order_index: label.item_order,
+                    is_favorite: label.is_favorite,
+                    color: label.color,
+                });
+            }
+        }
+
+        for section in response.sections {
+            if section.is_deleted || section.is_archived {
+                changes.removed_section_ids.push(section.id);
+            } else {
+                changes.sections.push(BackendSection {
+                    remote_id: section.id,
+                    name: section.name,
+                    project_remote_id: section.project_id,
+                    order_index: section.section_order,
+                });
+            }
+        }
+
+        for note in response.notes {
+            if note.is_deleted {
+                changes.removed_comment_ids.push(note.id);
+            } else {
+                changes.comments.push(BackendComment {
+                    remote_id: note.id,
+                    task_remote_id: note.item_id,
+                    content: note.content,
+                    posted_at: note.posted_at,
+                });
+            }
+        }
+
+        changes
+    }
+
     // Helper: Transform Todoist API label → Backend label
     fn label_to_backend(api_label: &crate::todoist::Label) -> BackendLabel {
         BackendLabel {
@@ -60,6 +426,7 @@ impl TodoistBackend {
             name: api_label.name.clone(),
             order_index: api_label.order.unwrap_or(0),
             is_favorite: api_label.is_favorite,
+            color: Some(api_label.color.clone()),
         }
     }
 
@@ -72,6 +439,17 @@ impl TodoistBackend {
             order_index: api_section.section_order,
         }
     }
+
+    // Helper: Transform Todoist API comment → Backend comment
+    fn comment_to_backend(api_comment: &crate::todoist::Comment, task_remote_id: &str) -> BackendComment {
+        BackendComment {
+            remote_id: api_comment.id.clone(),
+            // Responses only carry the task ID when the request had one
+            task_remote_id: api_comment.task_id.clone().unwrap_or_else(|| task_remote_id.to_string()),
+            content: api_comment.content.clone(),
+            posted_at: api_comment.posted_at.clone(),
+        }
+    }
 }
 
 #[async_trait]
@@ -90,7 +468,7 @@ impl Backend for TodoistBackend {
                 .wrapper
                 .get_projects(Some(200), cursor.clone())
                 .await
-                .map_err(|e| BackendError::Network(e.to_string()))?;
+                .map_err(BackendError::from)?;
 
             all_projects.extend(response.results.iter().map(Self::project_to_backend));
 
@@ -114,7 +492,7 @@ impl Backend for TodoistBackend {
                 .wrapper
                 .get_tasks(Some(200), cursor.clone())
                 .await
-                .map_err(|e| BackendError::Network(e.to_string()))?;
+                .map_err(BackendError::from)?;
 
             all_tasks.extend(response.results.iter().map(Self::task_to_backend));
 
@@ -138,7 +516,7 @@ impl Backend for TodoistBackend {
                 .wrapper
                 .get_labels(Some(200), cursor.clone())
                 .await
-                .map_err(|e| BackendError::Network(e.to_string()))?;
+                .map_err(BackendError::from)?;
 
             all_labels.extend(response.results.iter().map(Self::label_to_backend));
 
@@ -152,6 +530,11 @@ impl Backend for TodoistBackend {
         Ok(all_labels)
     }
 
+    async fn fetch_task(&self, remote_id: &str) -> Result<BackendTask, BackendError> {
+        let task = self.wrapper.get_task(remote_id).await.map_err(BackendError::from)?;
+        Ok(Self::task_to_backend(&task))
+    }
+
     async fn fetch_sections(&self) -> Result<Vec<BackendSection>, BackendError> {
         let mut all_sections = Vec::new();
         let mut cursor: Option<String> = None;
@@ -162,7 +545,7 @@ impl Backend for TodoistBackend {
                 .wrapper
                 .get_sections(Some(200), cursor.clone())
                 .await
-                .map_err(|e| BackendError::Network(e.to_string()))?;
+                .map_err(BackendError::from)?;
 
             all_sections.extend(response.results.iter().map(Self::section_to_backend));
 
@@ -176,6 +559,68 @@ impl Backend for TodoistBackend {
         Ok(all_sections)
     }
 
+    async fn fetch_changes(&self, cursor: Option<&str>) -> Result<Option<BackendChanges>, BackendError> {
+        // "*" asks the Sync API for a full sync
+        let sync_token = cursor.unwrap_or("*");
+
+        let response = self
+            .client
+            .post(TODOIST_SYNC_URL)
+            .bearer_auth(&self.api_token)
+            .form(&[("sync_token", sync_token), ("resource_types", SYNC_RESOURCE_TYPES)])
+            .send()
+            .await
+            .map_err(|e| BackendError::Network(e.to_string()))?;
+
+        let response: SyncResponse = Self::parse_response(response).await?;
+
+        Ok(Some(Self::sync_response_to_changes(response)))
+    }
+
+    async fn fetch_completed_tasks(
+        &self,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<BackendTask>, BackendError> {
+        let mut all_tasks = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        // The wrapper expects `results`, but this endpoint returns its page as `items`
+        loop {
+            let mut query = vec![
+                ("since", since.to_rfc3339_opts(SecondsFormat::Secs, true)),
+                ("until", until.to_rfc3339_opts(SecondsFormat::Secs, true)),
+                ("limit", "200".to_string()),
+            ];
+            if let Some(cursor) = &cursor {
+                query.push(("cursor", cursor.clone()));
+            }
+            let response = self
+                .client
+                .get(format!("{}/tasks/completed/by_completion_date", TODOIST_API_URL))
+                .bearer_auth(&self.api_token)
+                .query(&query)
+                .send()
+                .await
+                .map_err(|e| BackendError::Network(e.to_string()))?;
+            let response: CompletedTasksResponse = Self::parse_response(response).await?;
+
+            all_tasks.extend(response.items.iter().map(|api_task| BackendTask {
+                is_completed: true,
+                ..Self::task_to_backend(api_task)
+            }));
+
+            // Check if there are more pages
+            if response.next_cursor.is_none() {
+                break;
+            }
+            cursor = response.next_cursor;
+        }
+
+        all_tasks.sort_by(|a, b| b.completed_at.cmp(&a.completed_at));
+        Ok(all_tasks)
+    }
+
     async fn create_project(&self, args: CreateProjectArgs) -> Result<BackendProject, BackendError> {
         let todoist_args = crate::todoist::CreateProjectArgs {
             name: args.name,
@@ -185,11 +630,8 @@ impl Backend for TodoistBackend {
             view_style: None,
         };
 
-        let project = self
-            .wrapper
-            .create_project(&todoist_args)
-            .await
-            .map_err(|e| BackendError::Network(e.to_string()))?;
+        let project: crate::todoist::Project =
+            self.create("projects", &todoist_args, args.request_id.as_deref()).await?;
         Ok(Self::project_to_backend(&project))
     }
 
@@ -205,18 +647,29 @@ impl Backend for TodoistBackend {
             .wrapper
             .update_project(remote_id, &todoist_args)
             .await
-            .map_err(|e| BackendError::Network(e.to_string()))?;
+            .map_err(BackendError::from)?;
         Ok(Self::project_to_backend(&project))
     }
 
     async fn delete_project(&self, remote_id: &str) -> Result<(), BackendError> {
-        self.wrapper
-            .delete_project(remote_id)
+        self.wrapper.delete_project(remote_id).await.map_err(BackendError::from)
+    }
+
+    async fn reorder_projects(&self, orders: Vec<ProjectOrder>) -> Result<(), BackendError> {
+        let projects: Vec<serde_json::Value> = orders
+            .into_iter()
+            .map(|order| serde_json::json!({ "id": order.remote_id, "child_order": order.order_index }))
+            .collect();
+        self.sync_command("project_reorder", serde_json::json!({ "projects": projects }))
             .await
-            .map_err(|e| BackendError::Network(e.to_string()))
     }
 
     async fn create_task(&self, args: CreateTaskArgs) -> Result<BackendTask, BackendError> {
+        // Todoist accepts only one form of due date and parses the natural language one itself
+        let (due_date, due_datetime) = match args.due_string {
+            Some(_) => (None, None),
+            None => (args.due_date, args.due_datetime),
+        };
         let todoist_args = crate::todoist::CreateTaskArgs {
             content: args.content,
             description: args.description,
@@ -224,30 +677,42 @@ impl Backend for TodoistBackend {
             section_id: args.section_remote_id,
             parent_id: args.parent_remote_id,
             priority: args.priority,
-            due_date: args.due_date,
-            due_datetime: args.due_datetime,
+            due_string: args.due_string,
+            due_date,
+            due_datetime,
+            deadline_date: args.deadline,
             labels: Some(args.labels),
-            duration: args.duration.as_ref().and_then(|d| {
-                // CreateTaskArgs.duration is Option<i32> (just the amount)
-                let parts: Vec<&str> = d.split_whitespace().collect();
-                if !parts.is_empty() {
-                    parts[0].parse().ok()
-                } else {
-                    None
-                }
-            }),
+            duration: args.duration.as_deref().and_then(duration_parts).map(|(amount, _)| amount),
+            duration_unit: args.duration.as_deref().and_then(duration_parts).map(|(_, unit)| unit),
             ..Default::default()
         };
 
-        let task = self
-            .wrapper
-            .create_task(&todoist_args)
-            .await
-            .map_err(|e| BackendError::Network(e.to_string()))?;
+        let task: crate::todoist::Task = self.create("tasks", &todoist_args, args.request_id.as_deref()).await?;
         Ok(Self::task_to_backend(&task))
     }
 
     async fn update_task(&self, remote_id: &str, args: UpdateTaskArgs) -> Result<BackendTask, BackendError> {
+        // The update endpoint ignores the location, Todoist moves tasks with a dedicated command
+        let destination = match (&args.parent_remote_id, &args.section_remote_id, &args.project_remote_id) {
+            (Some(parent_id), _, _) => Some(("parent_id", parent_id)),
+            (None, Some(section_id), _) => Some(("section_id", section_id)),
+            (None, None, Some(project_id)) => Some(("project_id", project_id)),
+            (None, None, None) => None,
+        };
+        if let Some((key, id)) = destination {
+            self.sync_command("item_move", serde_json::json!({ "id": remote_id, key: id }))
+                .await?;
+        }
+
+        // Clearing a field needs an explicit null, which the update endpoint cannot send
+        for (field, value) in [("deadline", &args.deadline), ("duration", &args.duration)] {
+            if value.as_deref() == Some("") {
+                self.sync_command("item_update", serde_json::json!({ "id": remote_id, field: null }))
+                    .await?;
+            }
+        }
+
+        let (duration, duration_unit) = args.duration.as_deref().and_then(duration_parts).unzip();
         let todoist_args = crate::todoist::UpdateTaskArgs {
             content: args.content,
             description: args.description,
@@ -255,46 +720,51 @@ impl Backend for TodoistBackend {
             due_date: args.due_date,
             due_datetime: args.due_datetime,
             due_string: args.due_string,
+            deadline_date: args.deadline.filter(|deadline| !deadline.is_empty()),
             labels: args.labels,
-            duration: args.duration.as_ref().and_then(|d| {
-                // UpdateTaskArgs.duration is Option<i32> (just the amount)
-                let parts: Vec<&str> = d.split_whitespace().collect();
-                if !parts.is_empty() {
-                    parts[0].parse().ok()
-                } else {
-                    None
-                }
-            }),
+            duration,
+            duration_unit,
             ..Default::default()
         };
 
-        let task = self
-            .wrapper
-            .update_task(remote_id, &todoist_args)
-            .await
-            .map_err(|e| BackendError::Network(e.to_string()))?;
+        let task = if todoist_args.has_updates() {
+            self.wrapper.update_task(remote_id, &todoist_args).await
+        } else {
+            self.wrapper.get_task(remote_id).await
+        }
+        .map_err(BackendError::from)?;
         Ok(Self::task_to_backend(&task))
     }
 
     async fn delete_task(&self, remote_id: &str) -> Result<(), BackendError> {
-        self.wrapper
-            .delete_task(remote_id)
-            .await
-            .map_err(|e| BackendError::Network(e.to_string()))
+        self.wrapper.delete_task(remote_id).await.map_err(BackendError::from)
     }
 
     async fn complete_task(&self, remote_id: &str) -> Result<(), BackendError> {
-        self.wrapper
-            .complete_task(remote_id)
-            .await
-            .map_err(|e| BackendError::Network(e.to_string()))
+        self.wrapper.complete_task(remote_id).await.map_err(BackendError::from)
     }
 
     async fn reopen_task(&self, remote_id: &str) -> Result<(), BackendError> {
-        self.wrapper
-            .reopen_task(remote_id)
+        self.wrapper.reopen_task(remote_id).await.map_err(BackendError::from)
+    }
+
+    async fn move_task(&self, remote_id: &str, args: MoveTaskArgs) -> Result<(), BackendError> {
+        // item_move takes exactly one destination, the subtasks follow the task
+        let (key, id) = match (&args.parent_remote_id, &args.section_remote_id) {
+            (Some(parent_id), _) => ("parent_id", parent_id),
+            (None, Some(section_id)) => ("section_id", section_id),
+            (None, None) => ("project_id", &args.project_remote_id),
+        };
+        self.sync_command("item_move", serde_json::json!({ "id": remote_id, key: id }))
             .await
-            .map_err(|e| BackendError::Network(e.to_string()))
+    }
+
+    async fn reorder_tasks(&self, orders: Vec<TaskOrder>) -> Result<(), BackendError> {
+        let items: Vec<serde_json::Value> = orders
+            .into_iter()
+            .map(|order| serde_json::json!({ "id": order.remote_id, "child_order": order.order_index }))
+            .collect();
+        self.sync_command("item_reorder", serde_json::json!({ "items": items })).await
     }
 
     async fn create_label(&self, args: CreateLabelArgs) -> Result<BackendLabel, BackendError> {
@@ -305,11 +775,7 @@ impl Backend for TodoistBackend {
             ..Default::default()
         };
 
-        let label = self
-            .wrapper
-            .create_label(&todoist_args)
-            .await
-            .map_err(|e| BackendError::Network(e.to_string()))?;
+        let label: crate::todoist::Label = self.create("labels", &todoist_args, args.request_id.as_deref()).await?;
         Ok(Self::label_to_backend(&label))
     }
 
@@ -325,14 +791,118 @@ impl Backend for TodoistBackend {
             .wrapper
             .update_label(remote_id, &todoist_args)
             .await
-            .map_err(|e| BackendError::Network(e.to_string()))?;
+            .map_err(BackendError::from)?;
         Ok(Self::label_to_backend(&label))
     }
 
     async fn delete_label(&self, remote_id: &str) -> Result<(), BackendError> {
-        self.wrapper
-            .delete_label(remote_id)
+        self.wrapper.delete_label(remote_id).await.map_err(BackendError::from)
+    }
+
+    async fn create_section(&self, args: CreateSectionArgs) -> Result<BackendSection, BackendError> {
+        let todoist_args = crate::todoist::CreateSectionArgs {
+            name: args.name,
+            project_id: args.project_remote_id,
+            order: args.order_index,
+        };
+
+        let section: crate::todoist::Section =
+            self.create("sections", &todoist_args, args.request_id.as_deref()).await?;
+        Ok(Self::section_to_backend(&section))
+    }
+
+    async fn update_section(&self, remote_id: &str, args: UpdateSectionArgs) -> Result<BackendSection, BackendError> {
+        // The name is the only field Todoist lets us update
+        let section = match args.name {
+            Some(name) => self
+                .wrapper
+                .update_section(remote_id, &crate::todoist::UpdateSectionArgs { name })
+                .await
+                .map_err(BackendError::from)?,
+            None => self.wrapper.get_section(remote_id).await.map_err(BackendError::from)?,
+        };
+        Ok(Self::section_to_backend(&section))
+    }
+
+    async fn delete_section(&self, remote_id: &str) -> Result<(), BackendError> {
+        self.wrapper.delete_section(remote_id).await.map_err(BackendError::from)
+    }
+
+    async fn reorder_sections(&self, orders: Vec<SectionOrder>) -> Result<(), BackendError> {
+        let sections: Vec<serde_json::Value> = orders
+            .into_iter()
+            .map(|order| serde_json::json!({ "id": order.remote_id, "section_order": order.order_index }))
+            .collect();
+        self.sync_command("section_reorder", serde_json::json!({ "sections": sections }))
             .await
-            .map_err(|e| BackendError::Network(e.to_string()))
+    }
+
+    async fn fetch_comments(&self, task_remote_id: &str) -> Result<Vec<BackendComment>, BackendError> {
+        let mut all_comments = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        // The wrapper expects a plain list, but the endpoint is paginated like the others
+        loop {
+            let mut query = vec![("task_id", task_remote_id.to_string()), ("limit", "200".to_string())];
+            if let Some(cursor) = &cursor {
+                query.push(("cursor", cursor.clone()));
+            }
+            let response = self
+                .client
+                .get(format!("{}/comments", TODOIST_API_URL))
+                .bearer_auth(&self.api_token)
+                .query(&query)
+                .send()
+                .await
+                .map_err(|e| BackendError::Network(e.to_string()))?;
+            let response: crate::todoist::PaginatedResponse<crate::todoist::Comment> =
+                Self::parse_response(response).await?;
+
+            all_comments.extend(
+                response
+                    .results
+                    .iter()
+                    .filter(|comment| !comment.is_deleted)
+                    .map(|comment| Self::comment_to_backend(comment, task_remote_id)),
+            );
+
+            // Check if there are more pages
+            if response.next_cursor.is_none() {
+                break;
+            }
+            cursor = response.next_cursor;
+        }
+
+        all_comments.sort_by(|a, b| a.posted_at.cmp(&b.posted_at));
+        Ok(all_comments)
+    }
+
+    async fn create_comment(&self, args: CreateCommentArgs) -> Result<BackendComment, BackendError> {
+        let todoist_args = crate::todoist::CreateCommentArgs {
+            content: args.content,
+            task_id: Some(args.task_remote_id.clone()),
+            ..Default::default()
+        };
+
+        let comment: crate::todoist::Comment =
+            self.create("comments", &todoist_args, args.request_id.as_deref()).await?;
+        Ok(Self::comment_to_backend(&comment, &args.task_remote_id))
+    }
+
+    async fn update_comment(&self, remote_id: &str, args: UpdateCommentArgs) -> Result<BackendComment, BackendError> {
+        // The content is the only field Todoist lets us update
+        let comment = match args.content {
+            Some(content) => self
+                .wrapper
+                .update_comment(remote_id, &crate::todoist::UpdateCommentArgs { content })
+                .await
+                .map_err(BackendError::from)?,
+            None => self.wrapper.get_comment(remote_id).await.map_err(BackendError::from)?,
+        };
+        Ok(Self::comment_to_backend(&comment, ""))
+    }
+
+    async fn delete_comment(&self, remote_id: &str) -> Result<(), BackendError> {
+        self.wrapper.delete_comment(remote_id).await.map_err(BackendError::from)
     }
 }
@@ -156,7 +156,7 @@ impl BackendRegistry {
         settings: String,
     ) -> Result<Uuid> {
         // Validate by creating instance first
-        let backend_instance = factory::create_backend(&backend_type, &credentials)?;
+        let backend_instance = factory::create_backend(&backend_type, &credentials, &settings)?;
 
         let uuid = Uuid::new_v4();
 
@@ -167,6 +167,7 @@ impl BackendRegistry {
             is_enabled: ActiveValue::Set(true),
             credentials: ActiveValue::Set(credentials),
             settings: ActiveValue::Set(settings),
+            sync_cursor: ActiveValue::Set(None),
         };
 
         let storage = self.storage.lock().await;
@@ -204,26 +205,36 @@ impl BackendRegistry {
             .ok_or_else(|| anyhow::anyhow!("Backend not found: {}", uuid))?;
 
         let backend_type = backend_model.backend_type.clone();
+        let current_credentials = backend_model.credentials.clone();
+        let current_settings = backend_model.settings.clone();
         let mut active_model = backend_model.into_active_model();
 
         if let Some(name) = name {
             active_model.name = ActiveValue::Set(name);
         }
 
-        // If credentials changed, validate and recreate instance
-        if let Some(ref new_credentials) = credentials {
-            let backend_instance = factory::create_backend(&backend_type, new_credentials)?;
-            active_model.credentials = ActiveValue::Set(new_credentials.clone());
+        // If credentials or settings changed, validate and recreate instance
+        if credentials.is_some() || settings.is_some() {
+            let backend_instance = factory::create_backend(
+                &backend_type,
+                credentials.as_deref().unwrap_or(&current_credentials),
+                settings.as_deref().unwrap_or(&current_settings),
+            )?;
+
+            if let Some(new_credentials) = credentials {
+                active_model.credentials = ActiveValue::Set(new_credentials);
+                // New credentials may point to another account, so the next sync must start over
+                active_model.sync_cursor = ActiveValue::Set(None);
+            }
+            if let Some(settings) = settings {
+                active_model.settings = ActiveValue::Set(settings);
+            }
 
             // Update in-memory cache
             let mut backends = self.backends.lock().await;
             backends.insert(*uuid, Arc::new(backend_instance));
         }
 
-        if let Some(settings) = settings {
-            active_model.settings = ActiveValue::Set(settings);
-        }
-
         BackendRepository::update(&storage.conn, active_model).await?;
 
         info!("✅ Updated backend: {}", uuid);
@@ -300,7 +311,7 @@ impl BackendRegistry {
     /// # Errors
     /// Returns error if backend creation fails
     fn create_backend_instance(model: &backend::Model) -> Result<Box<dyn Backend>> {
-        factory::create_backend(&model.backend_type, &model.credentials)
+        factory::create_backend(&model.backend_type, &model.credentials, &model.settings)
     }
 
     /// Get the storage instance (for creating SyncService instances).
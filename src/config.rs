@@ -4,6 +4,7 @@
 
 use crate::constants::{CONFIG_GENERATED, SIDEBAR_DEFAULT_WIDTH, SIDEBAR_MAX_WIDTH, SIDEBAR_MIN_WIDTH};
 use crate::utils::datetime;
+use crate::utils::filter_query::parse_filter_query;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
@@ -16,6 +17,9 @@ pub struct Config {
     pub sync: SyncConfig,
     pub display: DisplayConfig,
     pub logging: LoggingConfig,
+    /// Saved filters shown in the sidebar, as `[[filters]]` tables
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub filters: Vec<FilterConfig>,
 }
 
 /// UI configuration
@@ -23,7 +27,7 @@ pub struct Config {
 #[serde(default)]
 pub struct UiConfig {
     /// Default project to open on startup
-    /// Options: "inbox", "today", "tomorrow", "upcoming", project ID, or project name
+    /// Options: "inbox", "today", "tomorrow", "upcoming", "calendar", "schedule", "logbook", project ID, or project name
     pub default_project: String,
     /// Enable mouse support
     pub mouse_enabled: bool,
@@ -53,7 +57,7 @@ pub struct DisplayConfig {
     pub show_durations: bool,
     /// Show task labels
     pub show_labels: bool,
-    /// Show project colors
+    /// Show project and label colors
     pub show_project_colors: bool,
 }
 
@@ -65,6 +69,15 @@ pub struct LoggingConfig {
     pub enabled: bool,
 }
 
+/// A saved filter query
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilterConfig {
+    /// Name shown in the sidebar
+    pub name: String,
+    /// Filter query, such as "(p1 | p2) & @work" or "overdue | today"
+    pub query: String,
+}
+
 impl Default for UiConfig {
     fn default() -> Self {
         Self {
@@ -152,7 +165,7 @@ impl Config {
         }
 
         // Validate default project
-        let valid_projects = ["inbox", "today", "tomorrow", "upcoming"];
+        let valid_projects = ["inbox", "today", "tomorrow", "upcoming", "calendar", "schedule", "logbook"];
         if !valid_projects.contains(&self.ui.default_project.as_str()) {
             // If it's not a predefined value, assume it's a project ID
             // We could add more validation here if needed
@@ -172,6 +185,16 @@ impl Config {
             anyhow::bail!("Invalid time_format '{}': {}", self.display.time_format, e);
         }
 
+        // Validate saved filters
+        for filter in &self.filters {
+            if filter.name.trim().is_empty() {
+                anyhow::bail!("Filter with query '{}' needs a name", filter.query);
+            }
+            if let Err(e) = parse_filter_query(&filter.query) {
+                anyhow::bail!("Invalid query for filter '{}': {}", filter.name, e);
+            }
+        }
+
         Ok(())
     }
 
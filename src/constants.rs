@@ -7,6 +7,13 @@
 pub const HEADER_OVERDUE: &str = "⏰ Overdue";
 pub const HEADER_TODAY: &str = "📅 Today";
 pub const HEADER_TOMORROW: &str = "📅 Tomorrow";
+pub const HEADER_COMPLETED_TODAY: &str = "✅ Today";
+pub const HEADER_COMPLETED_YESTERDAY: &str = "✅ Yesterday";
+pub const HEADER_COMPLETED_UNKNOWN: &str = "✅ Unknown date";
+pub const HEADER_UNSCHEDULED: &str = "📋 No time set";
+pub const HEADER_NO_SECTION: &str = "📂 No section";
+pub const HEADER_NO_DATE: &str = "📅 No date";
+pub const HEADER_NO_LABELS: &str = "🏷️ No labels";
 
 // Success Messages
 pub const SUCCESS_TASK_COMPLETED: &str = "✅ Task completed";
@@ -18,17 +25,33 @@ pub const SUCCESS_TASK_DUE_TODAY: &str = "✅ Task due date set to today";
 pub const SUCCESS_TASK_DUE_TOMORROW: &str = "✅ Task due date set to tomorrow";
 pub const SUCCESS_TASK_DUE_MONDAY: &str = "✅ Task due date set to next Monday";
 pub const SUCCESS_TASK_DUE_SATURDAY: &str = "✅ Task due date set to next Saturday";
+pub const SUCCESS_TASK_RESCHEDULED: &str = "✅ Task moved to";
 pub const SUCCESS_TASK_DUE_STRING_SET: &str = "✅ Task due date updated";
+pub const SUCCESS_TASK_DEADLINE_SET: &str = "✅ Task deadline updated";
+pub const SUCCESS_TASK_DURATION_SET: &str = "✅ Task duration updated";
 pub const SUCCESS_PROJECT_CREATED_PARENT: &str = "✅ Project created with parent";
 pub const SUCCESS_PROJECT_CREATED_ROOT: &str = "✅ Root project created";
 pub const SUCCESS_PROJECT_DELETED: &str = "✅ Project deleted";
 pub const SUCCESS_PROJECT_UPDATED: &str = "✅ Project updated";
+pub const SUCCESS_PROJECT_REORDERED: &str = "✅ Project moved";
 pub const SUCCESS_LABEL_CREATED: &str = "✅ Label created";
 pub const SUCCESS_LABEL_DELETED: &str = "✅ Label deleted";
 pub const SUCCESS_LABEL_UPDATED: &str = "✅ Label updated";
+pub const SUCCESS_SECTION_CREATED: &str = "✅ Section created";
+pub const SUCCESS_SECTION_DELETED: &str = "✅ Section deleted";
+pub const SUCCESS_SECTION_UPDATED: &str = "✅ Section updated";
+pub const SUCCESS_SECTION_MOVED: &str = "✅ Section moved";
+pub const SUCCESS_COMMENT_CREATED: &str = "✅ Comment posted";
 pub const SUCCESS_TASK_PRIORITY_UPDATED: &str = "✅ Task priority updated to P";
 pub const SUCCESS_TASK_RESTORED: &str = "✅ Task restored";
+pub const SUCCESS_TASK_LABELS_SET: &str = "✅ Task labels updated";
+pub const SUCCESS_TASK_MOVED: &str = "✅ Task moved";
+pub const SUCCESS_TASK_COLLAPSED_SET: &str = "✅ Subtasks shown or hidden";
+pub const SUCCESS_TASK_REORDERED: &str = "✅ Task moved";
+pub const SUCCESS_VIEW_MODE_SET: &str = "✅ View sorting and grouping saved";
 pub const SUCCESS_SYNC_COMPLETED: &str = "Sync completed successfully";
+pub const SUCCESS_CONFLICT_RESOLVED: &str = "✅ Sync conflict resolved";
+pub const SUCCESS_API_TOKEN_UPDATED: &str = "✅ API token updated";
 
 // Error Messages
 pub const ERROR_TASK_COMPLETION_FAILED: &str = "❌ Failed to complete task";
@@ -36,22 +59,46 @@ pub const ERROR_TASK_DELETE_FAILED: &str = "❌ Failed to delete task";
 pub const ERROR_TASK_UPDATE_FAILED: &str = "❌ Failed to update task";
 pub const ERROR_TASK_CREATE_FAILED: &str = "❌ Failed to create task";
 pub const ERROR_TASK_DUE_DATE_FAILED: &str = "❌ Failed to set task due date";
+pub const ERROR_TASK_DEADLINE_FAILED: &str = "❌ Failed to set task deadline";
+pub const ERROR_TASK_DURATION_FAILED: &str = "❌ Failed to set task duration";
 pub const ERROR_TASK_PRIORITY_FAILED: &str = "❌ Failed to update task priority";
 pub const ERROR_PROJECT_CREATE_FAILED: &str = "❌ Failed to create project";
 pub const ERROR_PROJECT_DELETE_FAILED: &str = "❌ Failed to delete project";
 pub const ERROR_PROJECT_UPDATE_FAILED: &str = "❌ Failed to update project";
+pub const ERROR_PROJECT_REORDER_FAILED: &str = "❌ Failed to move project";
 pub const ERROR_LABEL_CREATE_FAILED: &str = "❌ Failed to create label";
 pub const ERROR_LABEL_DELETE_FAILED: &str = "❌ Failed to delete label";
 pub const ERROR_LABEL_UPDATE_FAILED: &str = "❌ Failed to update label";
+pub const ERROR_SECTION_CREATE_FAILED: &str = "❌ Failed to create section";
+pub const ERROR_SECTION_DELETE_FAILED: &str = "❌ Failed to delete section";
+pub const ERROR_SECTION_UPDATE_FAILED: &str = "❌ Failed to update section";
+pub const ERROR_SECTION_MOVE_FAILED: &str = "❌ Failed to move section";
+pub const ERROR_COMMENT_CREATE_FAILED: &str = "❌ Failed to post comment";
 pub const ERROR_TASK_RESTORE_FAILED: &str = "❌ Failed to restore task";
+pub const ERROR_TASK_LABELS_FAILED: &str = "❌ Failed to update task labels";
+pub const ERROR_TASK_MOVE_FAILED: &str = "❌ Failed to move task";
+pub const ERROR_TASK_COLLAPSE_FAILED: &str = "❌ Failed to show or hide subtasks";
+pub const ERROR_TASK_REORDER_FAILED: &str = "❌ Failed to move task";
+pub const ERROR_CONFLICT_RESOLVE_FAILED: &str = "❌ Failed to resolve sync conflict";
+pub const ERROR_VIEW_MODE_FAILED: &str = "❌ Failed to save view sorting and grouping";
 
 // Validation Error Messages
 pub const ERROR_INVALID_PRIORITY_FORMAT: &str = "❌ Invalid priority value format";
 pub const ERROR_INVALID_PRIORITY_INFO: &str = "❌ Invalid task priority info format";
 pub const ERROR_INVALID_DATE_FORMAT: &str = "❌ Invalid task info format for setting due date";
+pub const ERROR_INVALID_PLANNING_FORMAT: &str = "❌ Invalid task info format for setting deadline or duration";
+pub const ERROR_INVALID_NEW_TASK_FORMAT: &str = "❌ Invalid new task format";
 pub const ERROR_INVALID_TASK_EDIT_FORMAT: &str = "❌ Invalid task edit format";
+pub const ERROR_INVALID_TASK_LABELS_FORMAT: &str = "❌ Invalid task labels format";
+pub const ERROR_INVALID_TASK_MOVE_FORMAT: &str = "❌ Invalid task move format";
+pub const ERROR_INVALID_TASK_COLLAPSE_FORMAT: &str = "❌ Invalid task collapse format";
+pub const ERROR_INVALID_REORDER_FORMAT: &str = "❌ Invalid reorder format";
 pub const ERROR_INVALID_PROJECT_EDIT_FORMAT: &str = "❌ Invalid project edit format";
 pub const ERROR_INVALID_LABEL_EDIT_FORMAT: &str = "❌ Invalid label edit format";
+pub const ERROR_INVALID_SECTION_FORMAT: &str = "❌ Invalid section info format";
+pub const ERROR_INVALID_COMMENT_FORMAT: &str = "❌ Invalid comment info format";
+pub const ERROR_INVALID_CONFLICT_FORMAT: &str = "❌ Invalid conflict resolution format";
+pub const ERROR_INVALID_VIEW_MODE_FORMAT: &str = "❌ Invalid view mode format";
 pub const ERROR_UNKNOWN_OPERATION: &str = "❌ Unknown operation";
 
 // Log Messages
@@ -77,10 +124,30 @@ pub const CONFIG_GENERATED: &str = "✅ Generated default configuration file";
 pub const ERROR_NO_API_TOKEN: &str = "❌ Error: TODOIST_API_TOKEN environment variable not set";
 pub const DIALOG_TITLE_DEBUG_LOGS: &str = "🔍 Debug Logs - Press 'Esc', 'G' or 'q' to close";
 pub const UI_CANNOT_DELETE_TODAY_VIEW: &str = "Cannot delete the Today view";
+pub const UI_FILTERS_IN_CONFIG: &str =
+    "Filters are added, edited and removed in the [[filters]] section of the config file";
+pub const UI_BOARD_PROJECTS_ONLY: &str = "Only projects can be shown as a board of their sections";
+pub const UI_VIEW_MODE_FIXED: &str = "The Calendar, Schedule and Logbook views keep their own order";
+pub const UI_REORDER_NEEDS_MANUAL_SORT: &str =
+    "Tasks can only be moved while the view is in manual order (v to change)";
 pub const UI_NO_TASK_SELECTED_DUE_DATE: &str = "No task selected to set due date";
+pub const UI_NO_TASK_SELECTED_DEADLINE: &str = "No task selected to set deadline";
+pub const UI_NO_TASK_SELECTED_DURATION: &str = "No task selected to set duration";
 pub const UI_LOADING_DATA: &str = "Loading data";
 pub const UI_SYNCING_WITH_TODOIST: &str = "Syncing with Todoist";
 pub const UI_LOADING_DATA_FROM_STORAGE: &str = "Loading data from storage";
+pub const UI_OFFLINE_MODE: &str = "Offline - showing cached data (r to retry)";
+pub const UI_UNSYNCED_CHANGES: &str = "unsynced changes";
+pub const UI_NEXT_SYNC: &str = "Next sync";
+pub const UI_SYNC_CONFLICTS: &str = "conflicts (C to resolve)";
+pub const UI_NO_SYNC_CONFLICTS: &str = "No sync conflicts to resolve";
+pub const UI_RATE_LIMITED: &str = "Rate limited by Todoist - retrying later";
+
+// Auto-sync backoff: retry delay doubles per consecutive failure, up to this many minutes
+pub const AUTO_SYNC_MAX_BACKOFF_MINUTES: u64 = 60;
+
+// Pushing a local change pulls remote changes first only if the last pull is older than this
+pub const OUTBOX_PULL_MIN_INTERVAL_SECONDS: u64 = 60;
 
 // Date header format for upcoming view
 pub const UPCOMING_DATE_FORMAT: &str = "📊 {} - {}";
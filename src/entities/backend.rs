@@ -11,8 +11,9 @@ pub struct Model {
     pub backend_type: String,
     pub name: String,
     pub is_enabled: bool,
-    pub credentials: String, // JSON-encoded credentials (to be encrypted in future)
-    pub settings: String,    // JSON-encoded backend-specific settings
+    pub credentials: String,         // JSON-encoded credentials (to be encrypted in future)
+    pub settings: String,            // JSON-encoded backend-specific settings
+    pub sync_cursor: Option<String>, // Incremental sync cursor (e.g. Todoist sync_token); None forces a full sync
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -0,0 +1,48 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "comments")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub uuid: Uuid,
+    pub backend_uuid: Uuid,
+    pub remote_id: String,
+    pub task_uuid: Uuid,
+    pub content: String,
+    /// RFC 3339 timestamp of when the comment was posted
+    pub posted_at: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::task::Entity",
+        from = "Column::TaskUuid",
+        to = "super::task::Column::Uuid",
+        on_delete = "Cascade"
+    )]
+    Task,
+    #[sea_orm(
+        belongs_to = "super::backend::Entity",
+        from = "Column::BackendUuid",
+        to = "super::backend::Column::Uuid",
+        on_delete = "Cascade"
+    )]
+    Backend,
+}
+
+impl Related<super::task::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Task.def()
+    }
+}
+
+impl Related<super::backend::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Backend.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -12,6 +12,10 @@ pub struct Model {
     pub name: String,
     pub order_index: i32,
     pub is_favorite: bool,
+    /// Backend color name, e.g. Todoist's "berry_red"
+    pub color: Option<String>,
+    /// JSON of the field values last seen on the backend, the base for merging local edits
+    pub remote_snapshot: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
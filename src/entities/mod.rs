@@ -1,13 +1,21 @@
 pub mod backend;
+pub mod comment;
 pub mod label;
+pub mod pending_operation;
 pub mod project;
 pub mod section;
+pub mod sync_conflict;
 pub mod task;
 pub mod task_label;
+pub mod view_setting;
 
 pub use backend::Entity as Backend;
+pub use comment::Entity as Comment;
 pub use label::Entity as Label;
+pub use pending_operation::Entity as PendingOperation;
 pub use project::Entity as Project;
 pub use section::Entity as Section;
+pub use sync_conflict::Entity as SyncConflict;
 pub use task::Entity as Task;
 pub use task_label::Entity as TaskLabel;
+pub use view_setting::Entity as ViewSetting;
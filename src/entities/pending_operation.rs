@@ -0,0 +1,43 @@
+//! Pending operation entity for the offline outbox.
+//!
+//! Every local mutation is recorded here and replayed against the backend in
+//! `sequence` order once it is reachable.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "pending_operations")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub uuid: Uuid,
+    pub backend_uuid: Uuid,
+    pub sequence: i64,
+    pub operation: String, // Operation name, e.g. "create_task" (for logs and inspection)
+    pub entity_uuid: Uuid, // Local UUID of the task, project or label being changed
+    pub remote_id: String, // Remote ID the operation targets (may be a temporary ID)
+    pub payload: String,   // JSON-encoded operation including its arguments
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::backend::Entity",
+        from = "Column::BackendUuid",
+        to = "super::backend::Column::Uuid",
+        on_delete = "Cascade"
+    )]
+    Backend,
+}
+
+impl Related<super::backend::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Backend.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
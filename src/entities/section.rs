@@ -12,6 +12,8 @@ pub struct Model {
     pub name: String,
     pub project_uuid: Uuid,
     pub order_index: i32,
+    /// JSON of the field values last seen on the backend, the base for merging local edits
+    pub remote_snapshot: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -0,0 +1,43 @@
+//! Sync conflict entity.
+//!
+//! A conflict is recorded per field when the same field of an entity was changed both
+//! locally and remotely since the last sync. Pending operations of a conflicted entity
+//! are held back until the user picks a side.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sync_conflicts")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub uuid: Uuid,
+    pub backend_uuid: Uuid,
+    pub entity_type: String, // "task", "project" or "label"
+    pub entity_uuid: Uuid,
+    pub entity_name: String, // Task content or project/label name, for display
+    pub field: String,
+    pub local_value: String,  // JSON-encoded local value
+    pub remote_value: String, // JSON-encoded remote value
+    pub created_at: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::backend::Entity",
+        from = "Column::BackendUuid",
+        to = "super::backend::Column::Uuid",
+        on_delete = "Cascade"
+    )]
+    Backend,
+}
+
+impl Related<super::backend::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Backend.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
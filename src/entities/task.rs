@@ -20,10 +20,20 @@ pub struct Model {
     pub due_date: Option<String>,
     pub due_datetime: Option<String>,
     pub is_recurring: bool,
+    /// The due date as typed, such as "every monday"; the recurrence pattern of recurring tasks
+    pub due_string: Option<String>,
     pub deadline: Option<String>,
     pub duration: Option<String>,
     pub is_completed: bool,
+    /// RFC 3339 timestamp of when the task was completed
+    pub completed_at: Option<String>,
     pub is_deleted: bool,
+    /// JSON of the field values last seen on the backend, the base for merging local edits
+    pub remote_snapshot: Option<String>,
+    /// Whether the subtasks are hidden in the task list; local only, never synced
+    pub is_collapsed: bool,
+    /// RFC 3339 timestamp of when the task was added
+    pub created_at: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -49,6 +59,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Parent,
+    #[sea_orm(has_many = "super::comment::Entity")]
+    Comments,
     #[sea_orm(
         belongs_to = "super::backend::Entity",
         from = "Column::BackendUuid",
@@ -79,6 +91,12 @@ impl Related<super::label::Entity> for Entity {
     }
 }
 
+impl Related<super::comment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Comments.def()
+    }
+}
+
 impl Related<super::backend::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Backend.def()
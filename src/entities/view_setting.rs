@@ -0,0 +1,24 @@
+//! View setting entity.
+//!
+//! Stores the sort and grouping mode and the layout picked for a sidebar view, keyed by the view.
+//! View settings are local only and never synced.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "view_settings")]
+pub struct Model {
+    /// "today", "project:<uuid>", "label:<uuid>", "filter:<name>" and so on
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub view_key: String,
+    pub sort_mode: String,
+    pub group_mode: String,
+    /// "list" or "board"
+    pub layout: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
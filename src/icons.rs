@@ -296,4 +296,40 @@ impl IconService {
             IconTheme::Ascii => ">",
         }
     }
+
+    #[must_use]
+    pub fn calendar(&self) -> &'static str {
+        match self.current_theme {
+            IconTheme::Emoji => "📆",
+            IconTheme::Unicode => "▦",
+            IconTheme::Ascii => "#",
+        }
+    }
+
+    #[must_use]
+    pub fn schedule(&self) -> &'static str {
+        match self.current_theme {
+            IconTheme::Emoji => "🕒",
+            IconTheme::Unicode => "◷",
+            IconTheme::Ascii => "@",
+        }
+    }
+
+    #[must_use]
+    pub fn filter(&self) -> &'static str {
+        match self.current_theme {
+            IconTheme::Emoji => "🔍",
+            IconTheme::Unicode => "⧩",
+            IconTheme::Ascii => "?",
+        }
+    }
+
+    #[must_use]
+    pub fn logbook(&self) -> &'static str {
+        match self.current_theme {
+            IconTheme::Emoji => "✅",
+            IconTheme::Unicode => "✓",
+            IconTheme::Ascii => "x",
+        }
+    }
 }
@@ -8,16 +8,17 @@
 //!
 //! * `-h, --help` - Show help message
 //! * `-V, --version` - Show version information
-//! * `-d, --debug` - Use file-backed SQLite database for debugging
+//! * `-d, --debug` - Skip the initial sync and work from the local database
 //! * `--generate-config` - Generate a default configuration file
 //!
 //! # Environment Variables
 //!
-//! * `TODOIST_API_TOKEN` - Your Todoist API token (required)
+//! * `TODOIST_API_TOKEN` - Your Todoist API token (required until it has been stored locally)
 
 use anyhow::{Context, Result};
 use std::env;
 use std::sync::Arc;
+use terminalist::constants::ERROR_NO_API_TOKEN;
 use terminalist::{backend_registry, config, logger, storage, sync, ui};
 use tokio::sync::Mutex;
 
@@ -26,7 +27,7 @@ use tokio::sync::Mutex;
 /// This function:
 /// 1. Parses command-line arguments
 /// 2. Loads configuration
-/// 3. Validates the Todoist API token
+/// 3. Opens the local database and resolves the Todoist backend
 /// 4. Initializes the sync service
 /// 5. Launches the TUI application
 ///
@@ -34,7 +35,6 @@ use tokio::sync::Mutex;
 ///
 /// Returns an error if:
 /// * Configuration cannot be loaded
-/// * API token is not set
 /// * Sync service fails to initialize
 /// * UI fails to run
 #[tokio::main]
@@ -60,11 +60,11 @@ async fn main() -> Result<()> {
         println!("OPTIONS:");
         println!("    -h, --help           Show this help message");
         println!("    -V, --version        Show version information");
-        println!("    -d, --debug          Debug mode: keep database file and skip initial sync");
+        println!("    -d, --debug          Debug mode: skip initial sync and use the local database as-is");
         println!("    --generate-config    Generate a default configuration file");
         println!();
         println!("ENVIRONMENT VARIABLES:");
-        println!("    TODOIST_API_TOKEN    Your Todoist API token (required)");
+        println!("    TODOIST_API_TOKEN    Your Todoist API token (required on first run)");
         println!();
         return Ok(());
     }
@@ -90,35 +90,65 @@ async fn main() -> Result<()> {
     // Initialize logger
     logger::init_logger(config.logging.enabled)?;
 
-    // Check if API token is set
-    if std::env::var("TODOIST_API_TOKEN").is_err() {
-        eprintln!("❌ Error: TODOIST_API_TOKEN environment variable not set");
-        eprintln!("\n💡 To use this app:");
-        eprintln!("1. Get your API token from https://todoist.com/prefs/integrations");
-        eprintln!("2. Set it as environment variable: export TODOIST_API_TOKEN=your_token_here");
-        eprintln!("3. Run the app again to see your actual data!");
-        eprintln!("\n💡 Use --help for more options");
-        return Ok(());
-    }
-
-    // Initialize storage
-    let local_storage = Arc::new(Mutex::new(storage::LocalStorage::new(debug_mode).await?));
+    // Initialize storage (persistent across restarts, migrated to the current schema)
+    let local_storage = match storage::LocalStorage::new().await {
+        Ok(local_storage) => Arc::new(Mutex::new(local_storage)),
+        Err(e) => {
+            eprintln!("❌ Error: could not open the local database");
+            eprintln!("   {:#}", e);
+            if let Ok(db_path) = storage::LocalStorage::get_db_path() {
+                eprintln!("\n💡 Database location: {}", db_path.display());
+            }
+            std::process::exit(1);
+        }
+    };
 
     // Initialize backend registry
     let backend_registry = Arc::new(backend_registry::BackendRegistry::new(local_storage.clone()));
-
-    // Create initial Todoist backend (DB is always fresh at startup)
-    let api_token = std::env::var("TODOIST_API_TOKEN")?;
-    let credentials = serde_json::json!({ "api_token": api_token }).to_string();
-
-    let backend_uuid = backend_registry
-        .add_backend(
-            "todoist".to_string(),
-            "My Todoist".to_string(),
-            credentials,
-            "{}".to_string(),
-        )
-        .await?;
+    backend_registry.load_backends().await?;
+
+    // Reuse the stored Todoist backend so cached data stays attached to it
+    let existing = backend_registry
+        .list_backends()
+        .await?
+        .into_iter()
+        .find(|b| b.backend_type == "todoist");
+
+    let api_token = std::env::var("TODOIST_API_TOKEN").ok();
+
+    let backend_uuid = match (existing, api_token) {
+        (Some(existing), Some(api_token)) => {
+            let credentials = serde_json::json!({ "api_token": api_token }).to_string();
+            if existing.credentials != credentials {
+                backend_registry
+                    .update_backend(&existing.uuid, None, Some(credentials), None)
+                    .await?;
+            }
+            existing.uuid
+        }
+        // No token in the environment: start from the cached data and stored credentials
+        (Some(existing), None) => existing.uuid,
+        (None, Some(api_token)) => {
+            let credentials = serde_json::json!({ "api_token": api_token }).to_string();
+            backend_registry
+                .add_backend(
+                    "todoist".to_string(),
+                    "My Todoist".to_string(),
+                    credentials,
+                    "{}".to_string(),
+                )
+                .await?
+        }
+        (None, None) => {
+            eprintln!("{}", ERROR_NO_API_TOKEN);
+            eprintln!("\n💡 To use this app:");
+            eprintln!("1. Get your API token from https://todoist.com/prefs/integrations");
+            eprintln!("2. Set it as environment variable: export TODOIST_API_TOKEN=your_token_here");
+            eprintln!("3. Run the app again to see your actual data!");
+            eprintln!("\n💡 Use --help for more options");
+            return Ok(());
+        }
+    };
 
     // Create sync service with timeout
     let timeout = tokio::time::Duration::from_secs(10);
@@ -1,6 +1,7 @@
 //! Backend repository for database operations.
 
 use anyhow::Result;
+use sea_orm::sea_query::Expr;
 use sea_orm::{ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
 use uuid::Uuid;
 
@@ -64,6 +65,19 @@ impl BackendRepository {
         Ok(backend.update(conn).await?)
     }
 
+    /// Store the incremental sync cursor of a backend (None forces the next sync to be a full one).
+    pub async fn set_sync_cursor<C>(conn: &C, uuid: &Uuid, cursor: Option<&str>) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        backend::Entity::update_many()
+            .col_expr(backend::Column::SyncCursor, Expr::value(cursor.map(str::to_string)))
+            .filter(backend::Column::Uuid.eq(*uuid))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
     /// Delete a backend by UUID.
     pub async fn delete<C>(conn: &C, uuid: &Uuid) -> Result<()>
     where
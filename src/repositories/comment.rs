@@ -0,0 +1,66 @@
+//! Comment repository for database operations.
+
+use anyhow::Result;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use uuid::Uuid;
+
+use crate::entities::comment;
+
+/// Repository for comment-related database operations.
+pub struct CommentRepository;
+
+impl CommentRepository {
+    /// Get the comments of a task, oldest first.
+    pub async fn get_for_task<C>(conn: &C, task_uuid: &Uuid) -> Result<Vec<comment::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(comment::Entity::find()
+            .filter(comment::Column::TaskUuid.eq(*task_uuid))
+            .order_by_asc(comment::Column::PostedAt)
+            .all(conn)
+            .await?)
+    }
+
+    /// Get a single comment by UUID.
+    pub async fn get_by_id<C>(conn: &C, uuid: &Uuid) -> Result<Option<comment::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(comment::Entity::find()
+            .filter(comment::Column::Uuid.eq(*uuid))
+            .one(conn)
+            .await?)
+    }
+
+    /// Get a single comment by remote_id and backend_uuid.
+    pub async fn get_by_remote_id<C>(conn: &C, backend_uuid: &Uuid, remote_id: &str) -> Result<Option<comment::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(comment::Entity::find()
+            .filter(comment::Column::BackendUuid.eq(*backend_uuid))
+            .filter(comment::Column::RemoteId.eq(remote_id))
+            .one(conn)
+            .await?)
+    }
+
+    /// Update a comment in the database.
+    pub async fn update<C>(conn: &C, comment: comment::ActiveModel) -> Result<comment::Model>
+    where
+        C: ConnectionTrait,
+    {
+        use sea_orm::ActiveModelTrait;
+        Ok(comment.update(conn).await?)
+    }
+
+    /// Delete a comment from the database.
+    pub async fn delete<C>(conn: &C, comment: comment::Model) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        use sea_orm::ModelTrait;
+        comment.delete(conn).await?;
+        Ok(())
+    }
+}
@@ -1,10 +1,10 @@
 //! Label repository for database operations.
 
 use anyhow::Result;
-use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait};
 use uuid::Uuid;
 
-use crate::entities::label;
+use crate::entities::{label, task_label};
 
 /// Repository for label-related database operations.
 pub struct LabelRepository;
@@ -31,6 +31,26 @@ impl LabelRepository {
         Ok(label::Entity::find().order_by_asc(label::Column::OrderIndex).all(conn).await?)
     }
 
+    /// Get the labels of a task ordered by order index.
+    pub async fn get_for_task<C>(conn: &C, task_uuid: &Uuid) -> Result<Vec<label::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(label::Entity::find()
+            .filter(
+                label::Column::Uuid.in_subquery(
+                    task_label::Entity::find()
+                        .filter(task_label::Column::TaskUuid.eq(*task_uuid))
+                        .select_only()
+                        .column(task_label::Column::LabelUuid)
+                        .into_query(),
+                ),
+            )
+            .order_by_asc(label::Column::OrderIndex)
+            .all(conn)
+            .await?)
+    }
+
     /// Get a single label by UUID.
     pub async fn get_by_id<C>(conn: &C, uuid: &Uuid) -> Result<Option<label::Model>>
     where
@@ -39,6 +59,18 @@ impl LabelRepository {
         Ok(label::Entity::find().filter(label::Column::Uuid.eq(*uuid)).one(conn).await?)
     }
 
+    /// Get a single label by remote_id and backend_uuid.
+    pub async fn get_by_remote_id<C>(conn: &C, backend_uuid: &Uuid, remote_id: &str) -> Result<Option<label::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(label::Entity::find()
+            .filter(label::Column::BackendUuid.eq(*backend_uuid))
+            .filter(label::Column::RemoteId.eq(remote_id))
+            .one(conn)
+            .await?)
+    }
+
     /// Get a single label by name.
     pub async fn get_by_name<C>(conn: &C, name: &str) -> Result<Option<label::Model>>
     where
@@ -55,4 +87,14 @@ impl LabelRepository {
         use sea_orm::ActiveModelTrait;
         Ok(label.update(conn).await?)
     }
+
+    /// Delete a label from the database.
+    pub async fn delete<C>(conn: &C, label: label::Model) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        use sea_orm::ModelTrait;
+        label.delete(conn).await?;
+        Ok(())
+    }
 }
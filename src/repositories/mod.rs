@@ -6,13 +6,21 @@
 //! database access methods.
 
 pub mod backend;
+pub mod comment;
 pub mod label;
+pub mod pending_operation;
 pub mod project;
 pub mod section;
+pub mod sync_conflict;
 pub mod task;
+pub mod view_setting;
 
 pub use backend::BackendRepository;
+pub use comment::CommentRepository;
 pub use label::LabelRepository;
+pub use pending_operation::PendingOperationRepository;
 pub use project::ProjectRepository;
 pub use section::SectionRepository;
+pub use sync_conflict::SyncConflictRepository;
 pub use task::TaskRepository;
+pub use view_setting::ViewSettingRepository;
@@ -0,0 +1,201 @@
+//! Pending operation repository for the offline outbox.
+
+use anyhow::Result;
+use sea_orm::sea_query::{Expr, Func};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+};
+use uuid::Uuid;
+
+use crate::entities::pending_operation;
+
+/// Repository for outbox-related database operations.
+pub struct PendingOperationRepository;
+
+impl PendingOperationRepository {
+    /// Append an operation to the end of a backend's outbox.
+    pub async fn enqueue<C>(
+        conn: &C,
+        backend_uuid: &Uuid,
+        operation: &str,
+        entity_uuid: &Uuid,
+        remote_id: &str,
+        payload: String,
+    ) -> Result<pending_operation::Model>
+    where
+        C: ConnectionTrait,
+    {
+        let last_sequence: Option<i64> = pending_operation::Entity::find()
+            .select_only()
+            .column_as(pending_operation::Column::Sequence.max(), "max_sequence")
+            .filter(pending_operation::Column::BackendUuid.eq(*backend_uuid))
+            .into_tuple::<Option<i64>>()
+            .one(conn)
+            .await?
+            .flatten();
+
+        let model = pending_operation::ActiveModel {
+            uuid: ActiveValue::Set(Uuid::new_v4()),
+            backend_uuid: ActiveValue::Set(*backend_uuid),
+            sequence: ActiveValue::Set(last_sequence.unwrap_or(0) + 1),
+            operation: ActiveValue::Set(operation.to_string()),
+            entity_uuid: ActiveValue::Set(*entity_uuid),
+            remote_id: ActiveValue::Set(remote_id.to_string()),
+            payload: ActiveValue::Set(payload),
+            attempts: ActiveValue::Set(0),
+            last_error: ActiveValue::Set(None),
+            created_at: ActiveValue::Set(chrono::Utc::now().to_rfc3339()),
+        };
+
+        Ok(model.insert(conn).await?)
+    }
+
+    /// Get all pending operations for a backend in replay order.
+    pub async fn get_for_backend<C>(conn: &C, backend_uuid: &Uuid) -> Result<Vec<pending_operation::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(pending_operation::Entity::find()
+            .filter(pending_operation::Column::BackendUuid.eq(*backend_uuid))
+            .order_by_asc(pending_operation::Column::Sequence)
+            .all(conn)
+            .await?)
+    }
+
+    /// Get the oldest pending operation of a backend, if any.
+    pub async fn get_next<C>(conn: &C, backend_uuid: &Uuid) -> Result<Option<pending_operation::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(pending_operation::Entity::find()
+            .filter(pending_operation::Column::BackendUuid.eq(*backend_uuid))
+            .order_by_asc(pending_operation::Column::Sequence)
+            .one(conn)
+            .await?)
+    }
+
+    /// Get the oldest pending operation of a backend that does not belong to one of the given entities.
+    pub async fn get_next_excluding<C>(
+        conn: &C,
+        backend_uuid: &Uuid,
+        excluded_entities: &[Uuid],
+    ) -> Result<Option<pending_operation::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(pending_operation::Entity::find()
+            .filter(pending_operation::Column::BackendUuid.eq(*backend_uuid))
+            .filter(pending_operation::Column::EntityUuid.is_not_in(excluded_entities.iter().copied()))
+            .order_by_asc(pending_operation::Column::Sequence)
+            .one(conn)
+            .await?)
+    }
+
+    /// Get the pending operations of a single entity in replay order.
+    pub async fn get_for_entity<C>(conn: &C, entity_uuid: &Uuid) -> Result<Vec<pending_operation::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(pending_operation::Entity::find()
+            .filter(pending_operation::Column::EntityUuid.eq(*entity_uuid))
+            .order_by_asc(pending_operation::Column::Sequence)
+            .all(conn)
+            .await?)
+    }
+
+    /// Count pending operations for a backend.
+    pub async fn count_for_backend<C>(conn: &C, backend_uuid: &Uuid) -> Result<u64>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(pending_operation::Entity::find()
+            .filter(pending_operation::Column::BackendUuid.eq(*backend_uuid))
+            .count(conn)
+            .await?)
+    }
+
+    /// Get the UUIDs of all entities that have pending operations for a backend.
+    pub async fn get_entity_uuids<C>(conn: &C, backend_uuid: &Uuid) -> Result<Vec<Uuid>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(pending_operation::Entity::find()
+            .select_only()
+            .column(pending_operation::Column::EntityUuid)
+            .distinct()
+            .filter(pending_operation::Column::BackendUuid.eq(*backend_uuid))
+            .into_tuple::<Uuid>()
+            .all(conn)
+            .await?)
+    }
+
+    /// Remove an operation once it has been replayed.
+    pub async fn delete<C>(conn: &C, uuid: &Uuid) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        pending_operation::Entity::delete_by_id(*uuid).exec(conn).await?;
+        Ok(())
+    }
+
+    /// Replace the payload of a pending operation.
+    pub async fn update_payload<C>(conn: &C, uuid: &Uuid, payload: String) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        pending_operation::Entity::update_many()
+            .col_expr(pending_operation::Column::Payload, Expr::value(payload))
+            .filter(pending_operation::Column::Uuid.eq(*uuid))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Record a failed replay attempt.
+    pub async fn record_failure<C>(conn: &C, uuid: &Uuid, error: &str) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        pending_operation::Entity::update_many()
+            .col_expr(
+                pending_operation::Column::Attempts,
+                Expr::col(pending_operation::Column::Attempts).add(1),
+            )
+            .col_expr(pending_operation::Column::LastError, Expr::value(error))
+            .filter(pending_operation::Column::Uuid.eq(*uuid))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Replace a temporary remote ID with the real one in all pending operations of a backend.
+    pub async fn rewrite_remote_id<C>(conn: &C, backend_uuid: &Uuid, temp_id: &str, real_id: &str) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        pending_operation::Entity::update_many()
+            .col_expr(pending_operation::Column::RemoteId, Expr::value(real_id))
+            .filter(pending_operation::Column::BackendUuid.eq(*backend_uuid))
+            .filter(pending_operation::Column::RemoteId.eq(temp_id))
+            .exec(conn)
+            .await?;
+
+        // Temporary IDs are UUID-based, so a plain text replacement inside the JSON payload is unambiguous
+        pending_operation::Entity::update_many()
+            .col_expr(
+                pending_operation::Column::Payload,
+                Func::cust("REPLACE")
+                    .arg(Expr::col(pending_operation::Column::Payload))
+                    .arg(temp_id)
+                    .arg(real_id)
+                    .into(),
+            )
+            .filter(pending_operation::Column::BackendUuid.eq(*backend_uuid))
+            .filter(pending_operation::Column::Payload.contains(temp_id))
+            .exec(conn)
+            .await?;
+
+        Ok(())
+    }
+}
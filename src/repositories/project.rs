@@ -34,6 +34,21 @@ impl ProjectRepository {
             .await?)
     }
 
+    /// Get the projects sharing a parent (`None` for top-level projects), in their manual order.
+    pub async fn get_siblings<C>(conn: &C, parent_uuid: Option<Uuid>) -> Result<Vec<project::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(project::Entity::find()
+            .filter(match parent_uuid {
+                Some(parent_uuid) => project::Column::ParentUuid.eq(parent_uuid),
+                None => project::Column::ParentUuid.is_null(),
+            })
+            .order_by_asc(project::Column::OrderIndex)
+            .all(conn)
+            .await?)
+    }
+
     /// Get a single project by UUID.
     pub async fn get_by_id<C>(conn: &C, uuid: &Uuid) -> Result<Option<project::Model>>
     where
@@ -57,6 +72,18 @@ impl ProjectRepository {
             .await?)
     }
 
+    /// Get the inbox project of a backend.
+    pub async fn get_inbox<C>(conn: &C, backend_uuid: &Uuid) -> Result<Option<project::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(project::Entity::find()
+            .filter(project::Column::BackendUuid.eq(*backend_uuid))
+            .filter(project::Column::IsInboxProject.eq(true))
+            .one(conn)
+            .await?)
+    }
+
     /// Update a project in the database.
     pub async fn update<C>(conn: &C, project: project::ActiveModel) -> Result<project::Model>
     where
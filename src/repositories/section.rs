@@ -67,4 +67,36 @@ impl SectionRepository {
             .await?
             .map(|s| s.remote_id))
     }
+
+    /// Get the highest order index among the sections of a project.
+    pub async fn get_max_order_index<C>(conn: &C, project_uuid: &Uuid) -> Result<Option<i32>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(section::Entity::find()
+            .filter(section::Column::ProjectUuid.eq(*project_uuid))
+            .order_by_desc(section::Column::OrderIndex)
+            .one(conn)
+            .await?
+            .map(|s| s.order_index))
+    }
+
+    /// Update a section in the database.
+    pub async fn update<C>(conn: &C, section: section::ActiveModel) -> Result<section::Model>
+    where
+        C: ConnectionTrait,
+    {
+        use sea_orm::ActiveModelTrait;
+        Ok(section.update(conn).await?)
+    }
+
+    /// Delete a section from the database.
+    pub async fn delete<C>(conn: &C, section: section::Model) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        use sea_orm::ModelTrait;
+        section.delete(conn).await?;
+        Ok(())
+    }
 }
@@ -0,0 +1,90 @@
+//! Sync conflict repository.
+
+use anyhow::Result;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use uuid::Uuid;
+
+use crate::entities::sync_conflict;
+
+/// Repository for sync-conflict-related database operations.
+pub struct SyncConflictRepository;
+
+impl SyncConflictRepository {
+    /// Get all unresolved conflicts of a backend, oldest first.
+    pub async fn get_for_backend<C>(conn: &C, backend_uuid: &Uuid) -> Result<Vec<sync_conflict::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(sync_conflict::Entity::find()
+            .filter(sync_conflict::Column::BackendUuid.eq(*backend_uuid))
+            .order_by_asc(sync_conflict::Column::CreatedAt)
+            .order_by_asc(sync_conflict::Column::EntityUuid)
+            .order_by_asc(sync_conflict::Column::Field)
+            .all(conn)
+            .await?)
+    }
+
+    /// Get a conflict by UUID.
+    pub async fn get_by_id<C>(conn: &C, uuid: &Uuid) -> Result<Option<sync_conflict::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(sync_conflict::Entity::find_by_id(*uuid).one(conn).await?)
+    }
+
+    /// Get the unresolved conflicts of a single entity.
+    pub async fn get_for_entity<C>(conn: &C, entity_uuid: &Uuid) -> Result<Vec<sync_conflict::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(sync_conflict::Entity::find()
+            .filter(sync_conflict::Column::EntityUuid.eq(*entity_uuid))
+            .all(conn)
+            .await?)
+    }
+
+    /// Get the UUIDs of all entities with unresolved conflicts for a backend.
+    pub async fn get_entity_uuids<C>(conn: &C, backend_uuid: &Uuid) -> Result<Vec<Uuid>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(sync_conflict::Entity::find()
+            .select_only()
+            .column(sync_conflict::Column::EntityUuid)
+            .distinct()
+            .filter(sync_conflict::Column::BackendUuid.eq(*backend_uuid))
+            .into_tuple::<Uuid>()
+            .all(conn)
+            .await?)
+    }
+
+    /// Record a conflict, replacing the values of an existing one for the same entity field.
+    pub async fn upsert<C>(conn: &C, conflict: sync_conflict::ActiveModel) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        sync_conflict::Entity::insert(conflict)
+            .on_conflict(
+                OnConflict::columns([sync_conflict::Column::EntityUuid, sync_conflict::Column::Field])
+                    .update_columns([
+                        sync_conflict::Column::EntityName,
+                        sync_conflict::Column::LocalValue,
+                        sync_conflict::Column::RemoteValue,
+                    ])
+                    .to_owned(),
+            )
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Remove a conflict once it is resolved.
+    pub async fn delete<C>(conn: &C, uuid: &Uuid) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        sync_conflict::Entity::delete_by_id(*uuid).exec(conn).await?;
+        Ok(())
+    }
+}
@@ -1,10 +1,15 @@
 //! Task repository for database operations.
 
 use anyhow::Result;
-use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait};
+use chrono::{Duration, NaiveDate};
+use sea_orm::sea_query::LikeExpr;
+use sea_orm::{ColumnTrait, Condition, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait};
 use uuid::Uuid;
 
-use crate::entities::{task, task_label};
+use crate::entities::{label, project, section, task, task_label};
+use crate::utils::datetime::{format_ymd, parse_deadline};
+use crate::utils::filter_query::{FilterExpr, FilterTerm};
+use crate::utils::quick_add::matches_name;
 
 /// Repository for task-related database operations.
 pub struct TaskRepository;
@@ -110,6 +115,99 @@ impl TaskRepository {
             .await?)
     }
 
+    /// Get the tasks matching a filter query, evaluated with `today` as the current date.
+    ///
+    /// Project, section and label names are resolved against local data; names that
+    /// match nothing match no tasks.
+    pub async fn get_for_filter<C>(conn: &C, filter: &FilterExpr, today: NaiveDate) -> Result<Vec<task::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        let context = FilterContext {
+            projects: project::Entity::find().all(conn).await?,
+            sections: section::Entity::find().all(conn).await?,
+            labels: label::Entity::find().all(conn).await?,
+            today,
+        };
+        Ok(task::Entity::find()
+            .filter(task::Column::IsDeleted.eq(false))
+            .filter(context.condition(filter))
+            .order_by_asc(task::Column::IsCompleted)
+            .order_by_asc(task::Column::OrderIndex)
+            .all(conn)
+            .await?)
+    }
+
+    /// Get the links between tasks and their labels.
+    pub async fn get_label_links<C>(conn: &C) -> Result<Vec<task_label::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(task_label::Entity::find().all(conn).await?)
+    }
+
+    /// Get the direct subtasks of a task.
+    pub async fn get_children<C>(conn: &C, parent_uuid: &Uuid) -> Result<Vec<task::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(task::Entity::find()
+            .filter(task::Column::ParentUuid.eq(*parent_uuid))
+            .order_by_asc(task::Column::OrderIndex)
+            .all(conn)
+            .await?)
+    }
+
+    /// Get the open tasks sharing a project, section and parent task, in their manual order.
+    pub async fn get_siblings<C>(
+        conn: &C,
+        project_uuid: &Uuid,
+        section_uuid: Option<Uuid>,
+        parent_uuid: Option<Uuid>,
+    ) -> Result<Vec<task::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(task::Entity::find()
+            .filter(task::Column::ProjectUuid.eq(*project_uuid))
+            .filter(match section_uuid {
+                Some(section_uuid) => task::Column::SectionUuid.eq(section_uuid),
+                None => task::Column::SectionUuid.is_null(),
+            })
+            .filter(match parent_uuid {
+                Some(parent_uuid) => task::Column::ParentUuid.eq(parent_uuid),
+                None => task::Column::ParentUuid.is_null(),
+            })
+            .filter(task::Column::IsDeleted.eq(false))
+            .filter(task::Column::IsCompleted.eq(false))
+            .order_by_asc(task::Column::OrderIndex)
+            .all(conn)
+            .await?)
+    }
+
+    /// Replace the labels of a task.
+    pub async fn set_labels<C>(conn: &C, task_uuid: &Uuid, label_uuids: &[Uuid]) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        use sea_orm::ActiveValue;
+
+        task_label::Entity::delete_many()
+            .filter(task_label::Column::TaskUuid.eq(*task_uuid))
+            .exec(conn)
+            .await?;
+        if label_uuids.is_empty() {
+            return Ok(());
+        }
+        task_label::Entity::insert_many(label_uuids.iter().map(|label_uuid| task_label::ActiveModel {
+            task_uuid: ActiveValue::Set(*task_uuid),
+            label_uuid: ActiveValue::Set(*label_uuid),
+        }))
+        .exec(conn)
+        .await?;
+        Ok(())
+    }
+
     /// Get tasks for the "Today" view (overdue + today).
     pub async fn get_for_today<C>(conn: &C, today: &str) -> Result<Vec<task::Model>>
     where
@@ -158,6 +256,27 @@ impl TaskRepository {
         Ok(result)
     }
 
+    /// Get tasks due from `start` up to, but not including, `end`, for the calendar view.
+    pub async fn get_due_between<C>(conn: &C, start: &str, end: &str) -> Result<Vec<task::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(task::Entity::due_between(start, end).all(conn).await?)
+    }
+
+    /// Get completed tasks for the logbook, most recently completed first.
+    pub async fn get_completed<C>(conn: &C) -> Result<Vec<task::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(task::Entity::find()
+            .filter(task::Column::IsCompleted.eq(true))
+            .filter(task::Column::IsDeleted.eq(false))
+            .order_by_desc(task::Column::CompletedAt)
+            .all(conn)
+            .await?)
+    }
+
     /// Update a task in the database.
     pub async fn update<C>(conn: &C, task: task::ActiveModel) -> Result<task::Model>
     where
@@ -177,3 +296,132 @@ impl TaskRepository {
         Ok(())
     }
 }
+
+/// Local data that filter query names and dates are resolved against
+struct FilterContext {
+    projects: Vec<project::Model>,
+    sections: Vec<section::Model>,
+    labels: Vec<label::Model>,
+    today: NaiveDate,
+}
+
+impl FilterContext {
+    fn condition(&self, filter: &FilterExpr) -> Condition {
+        match filter {
+            FilterExpr::And(left, right) => Condition::all().add(self.condition(left)).add(self.condition(right)),
+            FilterExpr::Or(left, right) => Condition::any().add(self.condition(left)).add(self.condition(right)),
+            FilterExpr::Not(inner) => self.condition(inner).not(),
+            FilterExpr::Term(term) => self.term_condition(term),
+        }
+    }
+
+    /// Nullable columns are checked for NULL first, so negated terms still match tasks
+    /// without a due date, section or description.
+    fn term_condition(&self, term: &FilterTerm) -> Condition {
+        let due_from = |date: NaiveDate| task::Column::DueDate.gte(format_ymd(date));
+        let due_before = |date: NaiveDate| task::Column::DueDate.lt(format_ymd(date));
+        let has_due_date = task::Column::DueDate.is_not_null();
+        let nothing = || Condition::all().add(task::Column::Uuid.is_in(Vec::<Uuid>::new()));
+
+        match term {
+            FilterTerm::Priority(priority) => Condition::all().add(task::Column::Priority.eq(*priority)),
+            FilterTerm::Label(name) => {
+                let label_uuids: Vec<Uuid> = self
+                    .labels
+                    .iter()
+                    .filter(|label| matches_name(&label.name, name))
+                    .map(|label| label.uuid)
+                    .collect();
+                Condition::all().add(
+                    task::Column::Uuid.in_subquery(
+                        task_label::Entity::find()
+                            .filter(task_label::Column::LabelUuid.is_in(label_uuids))
+                            .select_only()
+                            .column(task_label::Column::TaskUuid)
+                            .into_query(),
+                    ),
+                )
+            }
+            FilterTerm::NoLabels => Condition::all().add(
+                task::Column::Uuid.not_in_subquery(
+                    task_label::Entity::find()
+                        .select_only()
+                        .column(task_label::Column::TaskUuid)
+                        .into_query(),
+                ),
+            ),
+            FilterTerm::Project(name) => {
+                Condition::all().add(task::Column::ProjectUuid.is_in(self.project_uuids(name, false)))
+            }
+            FilterTerm::ProjectTree(name) => {
+                Condition::all().add(task::Column::ProjectUuid.is_in(self.project_uuids(name, true)))
+            }
+            FilterTerm::Section(name) => {
+                let section_uuids: Vec<Uuid> = self
+                    .sections
+                    .iter()
+                    .filter(|section| matches_name(&section.name, name))
+                    .map(|section| section.uuid)
+                    .collect();
+                Condition::all()
+                    .add(task::Column::SectionUuid.is_not_null())
+                    .add(task::Column::SectionUuid.is_in(section_uuids))
+            }
+            FilterTerm::Today => Condition::all()
+                .add(has_due_date)
+                .add(task::Column::DueDate.eq(format_ymd(self.today))),
+            FilterTerm::Tomorrow => Condition::all()
+                .add(has_due_date)
+                .add(task::Column::DueDate.eq(format_ymd(self.today + Duration::days(1)))),
+            FilterTerm::Overdue => Condition::all().add(has_due_date).add(due_before(self.today)),
+            FilterTerm::NoDate => Condition::all().add(task::Column::DueDate.is_null()),
+            FilterTerm::NextDays(days) => Condition::all()
+                .add(has_due_date)
+                .add(due_from(self.today))
+                .add(due_before(self.today + Duration::days(*days))),
+            FilterTerm::DueBefore(phrase) => match parse_deadline(phrase, self.today) {
+                Some(date) => Condition::all().add(has_due_date).add(due_before(date)),
+                None => nothing(),
+            },
+            FilterTerm::DueAfter(phrase) => match parse_deadline(phrase, self.today) {
+                Some(date) => Condition::all().add(has_due_date).add(due_from(date + Duration::days(1))),
+                None => nothing(),
+            },
+            FilterTerm::Recurring => Condition::all().add(task::Column::IsRecurring.eq(true)),
+            FilterTerm::Search(text) => {
+                // The text is matched literally, LIKE wildcards in it are escaped
+                let escaped = text.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+                let pattern = LikeExpr::new(format!("%{}%", escaped)).escape('\\');
+                Condition::any().add(task::Column::Content.like(pattern.clone())).add(
+                    Condition::all()
+                        .add(task::Column::Description.is_not_null())
+                        .add(task::Column::Description.like(pattern)),
+                )
+            }
+            FilterTerm::All => Condition::all(),
+        }
+    }
+
+    /// UUIDs of the projects with a matching name, and of their subprojects if requested
+    fn project_uuids(&self, name: &str, with_subprojects: bool) -> Vec<Uuid> {
+        let mut uuids: Vec<Uuid> = self
+            .projects
+            .iter()
+            .filter(|project| matches_name(&project.name, name))
+            .map(|project| project.uuid)
+            .collect();
+        if with_subprojects {
+            let mut index = 0;
+            while index < uuids.len() {
+                let parent = uuids[index];
+                for project in &self.projects {
+                    if project.parent_uuid == Some(parent) && !uuids.contains(&project.uuid) {
+                        uuids.push(project.uuid);
+                    }
+                }
+                index += 1;
+            }
+        }
+        uuids
+    }
+}
@@ -0,0 +1,46 @@
+//! View setting repository.
+
+use anyhow::Result;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ActiveValue, ConnectionTrait, EntityTrait};
+
+use crate::entities::view_setting;
+
+/// Repository for view-setting-related database operations.
+pub struct ViewSettingRepository;
+
+impl ViewSettingRepository {
+    /// Get the settings of every view that has been customized.
+    pub async fn get_all<C>(conn: &C) -> Result<Vec<view_setting::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(view_setting::Entity::find().all(conn).await?)
+    }
+
+    /// Store the sort and grouping mode and the layout of a view, replacing earlier ones.
+    pub async fn upsert<C>(conn: &C, view_key: &str, sort_mode: &str, group_mode: &str, layout: &str) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        let setting = view_setting::ActiveModel {
+            view_key: ActiveValue::Set(view_key.to_string()),
+            sort_mode: ActiveValue::Set(sort_mode.to_string()),
+            group_mode: ActiveValue::Set(group_mode.to_string()),
+            layout: ActiveValue::Set(layout.to_string()),
+        };
+        view_setting::Entity::insert(setting)
+            .on_conflict(
+                OnConflict::column(view_setting::Column::ViewKey)
+                    .update_columns([
+                        view_setting::Column::SortMode,
+                        view_setting::Column::GroupMode,
+                        view_setting::Column::Layout,
+                    ])
+                    .to_owned(),
+            )
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,208 @@
+//! Versioned schema migrations for the local SQLite database.
+//!
+//! The database is kept across restarts, so schema changes are applied as an ordered
+//! list of migrations. The highest applied version is recorded in the `schema_version`
+//! table. Each migration runs inside its own transaction, so a failing step leaves the
+//! database at the previous version instead of half-migrated.
+//!
+//! Migrations are plain SQL rather than `create_table_from_entity` so that a migration
+//! keeps producing the same schema after the entity structs evolve. To change the schema,
+//! append a new [`Migration`] to [`MIGRATIONS`]; never edit one that has already shipped.
+
+use chrono::Utc;
+use log::info;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, DbErr, Statement, TransactionTrait};
+
+/// Errors raised while bringing the database schema up to date.
+#[derive(Debug, thiserror::Error)]
+pub enum MigrationError {
+    #[error(
+        "Database schema version {found} is newer than the newest version this build supports ({supported}). \
+         Please upgrade terminalist or remove the local database."
+    )]
+    TooNew { found: i64, supported: i64 },
+
+    #[error("Failed to apply database migration {version} ({name}): {source}")]
+    Failed {
+        version: i64,
+        name: &'static str,
+        #[source]
+        source: DbErr,
+    },
+
+    #[error("Failed to read database schema version: {0}")]
+    Version(#[source] DbErr),
+}
+
+/// A single, ordered schema change.
+pub struct Migration {
+    pub version: i64,
+    pub name: &'static str,
+    pub statements: &'static [&'static str],
+}
+
+/// All known migrations, in the order they must be applied.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "initial_schema",
+        // `IF NOT EXISTS` lets databases created before versioning was introduced adopt this schema.
+        statements: &[
+            r#"CREATE TABLE IF NOT EXISTS "backends" ( "uuid" uuid_text NOT NULL PRIMARY KEY, "backend_type" varchar NOT NULL, "name" varchar NOT NULL, "is_enabled" boolean NOT NULL, "credentials" varchar NOT NULL, "settings" varchar NOT NULL )"#,
+            r#"CREATE TABLE IF NOT EXISTS "projects" ( "uuid" uuid_text NOT NULL PRIMARY KEY, "backend_uuid" uuid_text NOT NULL, "remote_id" varchar NOT NULL, "name" varchar NOT NULL, "is_favorite" boolean NOT NULL, "is_inbox_project" boolean NOT NULL, "order_index" integer NOT NULL, "parent_uuid" uuid_text, FOREIGN KEY ("parent_uuid") REFERENCES "projects" ("uuid"), FOREIGN KEY ("backend_uuid") REFERENCES "backends" ("uuid") ON DELETE CASCADE )"#,
+            r#"CREATE TABLE IF NOT EXISTS "sections" ( "uuid" uuid_text NOT NULL PRIMARY KEY, "backend_uuid" uuid_text NOT NULL, "remote_id" varchar NOT NULL, "name" varchar NOT NULL, "project_uuid" uuid_text NOT NULL, "order_index" integer NOT NULL, FOREIGN KEY ("project_uuid") REFERENCES "projects" ("uuid") ON DELETE CASCADE, FOREIGN KEY ("backend_uuid") REFERENCES "backends" ("uuid") ON DELETE CASCADE )"#,
+            r#"CREATE TABLE IF NOT EXISTS "labels" ( "uuid" uuid_text NOT NULL PRIMARY KEY, "backend_uuid" uuid_text NOT NULL, "remote_id" varchar NOT NULL, "name" varchar NOT NULL, "order_index" integer NOT NULL, "is_favorite" boolean NOT NULL, FOREIGN KEY ("backend_uuid") REFERENCES "backends" ("uuid") ON DELETE CASCADE )"#,
+            r#"CREATE TABLE IF NOT EXISTS "tasks" ( "uuid" uuid_text NOT NULL PRIMARY KEY, "backend_uuid" uuid_text NOT NULL, "remote_id" varchar NOT NULL, "content" varchar NOT NULL, "description" varchar, "project_uuid" uuid_text NOT NULL, "section_uuid" uuid_text, "parent_uuid" uuid_text, "priority" integer NOT NULL, "order_index" integer NOT NULL, "due_date" varchar, "due_datetime" varchar, "is_recurring" boolean NOT NULL, "deadline" varchar, "duration" varchar, "is_completed" boolean NOT NULL, "is_deleted" boolean NOT NULL, FOREIGN KEY ("project_uuid") REFERENCES "projects" ("uuid") ON DELETE CASCADE, FOREIGN KEY ("section_uuid") REFERENCES "sections" ("uuid") ON DELETE SET NULL, FOREIGN KEY ("parent_uuid") REFERENCES "tasks" ("uuid") ON DELETE CASCADE, FOREIGN KEY ("backend_uuid") REFERENCES "backends" ("uuid") ON DELETE CASCADE )"#,
+            r#"CREATE TABLE IF NOT EXISTS "task_labels" ( "task_uuid" uuid_text NOT NULL, "label_uuid" uuid_text NOT NULL, CONSTRAINT "pk-task_labels" PRIMARY KEY ("task_uuid", "label_uuid"), FOREIGN KEY ("task_uuid") REFERENCES "tasks" ("uuid") ON DELETE CASCADE, FOREIGN KEY ("label_uuid") REFERENCES "labels" ("uuid") ON DELETE CASCADE )"#,
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_projects_backend_remote ON projects(backend_uuid, remote_id)",
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_sections_backend_remote ON sections(backend_uuid, remote_id)",
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_labels_backend_remote ON labels(backend_uuid, remote_id)",
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_tasks_backend_remote ON tasks(backend_uuid, remote_id)",
+        ],
+    },
+    Migration {
+        version: 2,
+        name: "pending_operations",
+        statements: &[
+            r#"CREATE TABLE "pending_operations" ( "uuid" uuid_text NOT NULL PRIMARY KEY, "backend_uuid" uuid_text NOT NULL, "sequence" bigint NOT NULL, "operation" varchar NOT NULL, "entity_uuid" uuid_text NOT NULL, "remote_id" varchar NOT NULL, "payload" varchar NOT NULL, "attempts" integer NOT NULL, "last_error" varchar, "created_at" varchar NOT NULL, FOREIGN KEY ("backend_uuid") REFERENCES "backends" ("uuid") ON DELETE CASCADE )"#,
+            "CREATE UNIQUE INDEX idx_pending_operations_backend_sequence ON pending_operations(backend_uuid, sequence)",
+        ],
+    },
+    Migration {
+        version: 3,
+        name: "backend_sync_cursor",
+        statements: &[r#"ALTER TABLE "backends" ADD COLUMN "sync_cursor" varchar"#],
+    },
+    Migration {
+        version: 4,
+        name: "sync_conflicts",
+        statements: &[
+            r#"ALTER TABLE "projects" ADD COLUMN "remote_snapshot" varchar"#,
+            r#"ALTER TABLE "sections" ADD COLUMN "remote_snapshot" varchar"#,
+            r#"ALTER TABLE "labels" ADD COLUMN "remote_snapshot" varchar"#,
+            r#"ALTER TABLE "tasks" ADD COLUMN "remote_snapshot" varchar"#,
+            r#"CREATE TABLE "sync_conflicts" ( "uuid" uuid_text NOT NULL PRIMARY KEY, "backend_uuid" uuid_text NOT NULL, "entity_type" varchar NOT NULL, "entity_uuid" uuid_text NOT NULL, "entity_name" varchar NOT NULL, "field" varchar NOT NULL, "local_value" varchar NOT NULL, "remote_value" varchar NOT NULL, "created_at" varchar NOT NULL, FOREIGN KEY ("backend_uuid") REFERENCES "backends" ("uuid") ON DELETE CASCADE )"#,
+            "CREATE UNIQUE INDEX idx_sync_conflicts_entity_field ON sync_conflicts(entity_uuid, field)",
+        ],
+    },
+    Migration {
+        version: 5,
+        name: "comments",
+        statements: &[
+            r#"CREATE TABLE "comments" ( "uuid" uuid_text NOT NULL PRIMARY KEY, "backend_uuid" uuid_text NOT NULL, "remote_id" varchar NOT NULL, "task_uuid" uuid_text NOT NULL, "content" varchar NOT NULL, "posted_at" varchar, FOREIGN KEY ("task_uuid") REFERENCES "tasks" ("uuid") ON DELETE CASCADE, FOREIGN KEY ("backend_uuid") REFERENCES "backends" ("uuid") ON DELETE CASCADE )"#,
+            "CREATE UNIQUE INDEX idx_comments_backend_remote ON comments(backend_uuid, remote_id)",
+            "CREATE INDEX idx_comments_task ON comments(task_uuid)",
+        ],
+    },
+    Migration {
+        version: 6,
+        name: "task_completed_at",
+        statements: &[
+            r#"ALTER TABLE "tasks" ADD COLUMN "completed_at" varchar"#,
+            "CREATE INDEX idx_tasks_completed_at ON tasks(completed_at)",
+        ],
+    },
+    Migration {
+        version: 7,
+        name: "project_label_colors",
+        statements: &[
+            r#"ALTER TABLE "projects" ADD COLUMN "color" varchar"#,
+            r#"ALTER TABLE "labels" ADD COLUMN "color" varchar"#,
+        ],
+    },
+    Migration {
+        version: 8,
+        name: "task_collapsed",
+        statements: &[r#"ALTER TABLE "tasks" ADD COLUMN "is_collapsed" boolean NOT NULL DEFAULT false"#],
+    },
+    Migration {
+        version: 9,
+        name: "task_due_string",
+        statements: &[r#"ALTER TABLE "tasks" ADD COLUMN "due_string" varchar"#],
+    },
+    Migration {
+        version: 10,
+        name: "task_created_at_and_view_settings",
+        statements: &[
+            r#"ALTER TABLE "tasks" ADD COLUMN "created_at" varchar"#,
+            r#"CREATE TABLE "view_settings" ( "view_key" varchar NOT NULL PRIMARY KEY, "sort_mode" varchar NOT NULL, "group_mode" varchar NOT NULL )"#,
+        ],
+    },
+    Migration {
+        version: 11,
+        name: "view_setting_layout",
+        statements: &[r#"ALTER TABLE "view_settings" ADD COLUMN "layout" varchar NOT NULL DEFAULT 'list'"#],
+    },
+];
+
+/// The schema version produced by applying every migration.
+pub fn latest_version() -> i64 {
+    MIGRATIONS.last().map_or(0, |m| m.version)
+}
+
+/// Read the current schema version (0 for a database that has never been migrated).
+pub async fn current_version<C: ConnectionTrait>(conn: &C) -> Result<i64, MigrationError> {
+    conn.execute(stmt(
+        "CREATE TABLE IF NOT EXISTS schema_version (version INTEGER NOT NULL PRIMARY KEY, name TEXT NOT NULL, applied_at TEXT NOT NULL)",
+    ))
+    .await
+    .map_err(MigrationError::Version)?;
+
+    let row = conn
+        .query_one(stmt("SELECT COALESCE(MAX(version), 0) AS version FROM schema_version"))
+        .await
+        .map_err(MigrationError::Version)?;
+
+    match row {
+        Some(row) => row.try_get::<i64>("", "version").map_err(MigrationError::Version),
+        None => Ok(0),
+    }
+}
+
+/// Apply all pending migrations in order.
+///
+/// Returns the schema version the database is at afterwards.
+pub async fn run(conn: &DatabaseConnection) -> Result<i64, MigrationError> {
+    let current = current_version(conn).await?;
+    let latest = latest_version();
+
+    if current > latest {
+        return Err(MigrationError::TooNew {
+            found: current,
+            supported: latest,
+        });
+    }
+
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current) {
+        apply(conn, migration).await.map_err(|source| MigrationError::Failed {
+            version: migration.version,
+            name: migration.name,
+            source,
+        })?;
+        info!("Applied database migration {} ({})", migration.version, migration.name);
+    }
+
+    Ok(latest)
+}
+
+/// Apply a single migration and record it, atomically.
+async fn apply(conn: &DatabaseConnection, migration: &Migration) -> Result<(), DbErr> {
+    let txn = conn.begin().await?;
+
+    for sql in migration.statements {
+        txn.execute(stmt(sql)).await?;
+    }
+
+    txn.execute(Statement::from_sql_and_values(
+        DbBackend::Sqlite,
+        "INSERT INTO schema_version (version, name, applied_at) VALUES (?, ?, ?)",
+        [migration.version.into(), migration.name.into(), Utc::now().to_rfc3339().into()],
+    ))
+    .await?;
+
+    txn.commit().await
+}
+
+fn stmt(sql: &str) -> Statement {
+    Statement::from_string(DbBackend::Sqlite, sql.to_owned())
+}
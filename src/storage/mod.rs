@@ -0,0 +1,65 @@
+use anyhow::{Context, Result};
+use sea_orm::{ConnectOptions, ConnectionTrait, Database, DatabaseConnection, DbBackend, Statement};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub mod migrations;
+
+pub use migrations::MigrationError;
+
+/// Local storage manager for Todoist data
+pub struct LocalStorage {
+    pub conn: DatabaseConnection,
+}
+
+impl LocalStorage {
+    /// Get the database file path using XDG directories
+    pub fn get_db_path() -> Result<PathBuf> {
+        // Always use XDG data directory
+        let data_dir = dirs::data_dir().context("Failed to get XDG data directory")?;
+        let app_data_dir = data_dir.join("terminalist");
+
+        // Create directory if it doesn't exist
+        std::fs::create_dir_all(&app_data_dir).context("Failed to create application data directory")?;
+
+        Ok(app_data_dir.join("terminalist.db"))
+    }
+
+    /// Open the local storage at the default XDG location, applying pending migrations
+    pub async fn new() -> Result<Self> {
+        let db_path = Self::get_db_path()?;
+        Self::open(&db_path).await
+    }
+
+    /// Open (or create) the SQLite database at `db_path` and bring its schema up to date
+    pub async fn open(db_path: &Path) -> Result<Self> {
+        let database_url = format!("sqlite:{}?mode=rwc", db_path.display());
+
+        let mut opt = ConnectOptions::new(database_url);
+        opt.max_connections(4)
+            .min_connections(1)
+            .connect_timeout(Duration::from_secs(8))
+            .idle_timeout(Duration::from_secs(3600))
+            .sqlx_logging(false);
+
+        let conn = Database::connect(opt)
+            .await
+            .with_context(|| format!("Failed to open database: {}", db_path.display()))?;
+
+        // Enable foreign keys for SQLite
+        conn.execute(Statement::from_string(
+            DbBackend::Sqlite,
+            "PRAGMA foreign_keys = ON;".to_owned(),
+        ))
+        .await?;
+
+        migrations::run(&conn).await?;
+
+        Ok(LocalStorage { conn })
+    }
+
+    /// Current schema version of the open database
+    pub async fn schema_version(&self) -> Result<i64> {
+        Ok(migrations::current_version(&self.conn).await?)
+    }
+}
@@ -0,0 +1,171 @@
+use crate::backend::{CreateCommentArgs, UpdateCommentArgs};
+use crate::entities::comment;
+use crate::repositories::{CommentRepository, TaskRepository};
+use crate::sync::outbox::{is_temp_remote_id, new_request_id, new_temp_remote_id, OutboxOperation};
+use crate::sync::SyncService;
+use anyhow::Result;
+use chrono::{SecondsFormat, Utc};
+use log::info;
+use sea_orm::{ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter, TransactionTrait};
+use uuid::Uuid;
+
+impl SyncService {
+    /// Get the comments of a task from local storage (fast), oldest first
+    pub async fn get_comments_for_task(&self, task_uuid: &Uuid) -> Result<Vec<comment::Model>> {
+        let storage = self.storage.lock().await;
+        CommentRepository::get_for_task(&storage.conn, task_uuid).await
+    }
+
+    /// Fetches the comments of a task from the remote backend and stores them locally.
+    ///
+    /// Regular syncs only bring in comments when the backend has a delta API, so the
+    /// comment thread refreshes its task when it is opened. Comments missing remotely are
+    /// removed, unless they have local changes that have not been pushed yet.
+    ///
+    /// # Errors
+    /// Returns an error if the backend cannot be reached or local storage update fails
+    pub async fn refresh_comments(&self, task_uuid: &Uuid) -> Result<()> {
+        let task_remote_id = {
+            let storage = self.storage.lock().await;
+            TaskRepository::get_remote_id(&storage.conn, task_uuid).await?
+        };
+        // The backend does not know the task yet, so it has no comments there either
+        if is_temp_remote_id(&task_remote_id) {
+            return Ok(());
+        }
+
+        let backend = self.get_backend().await?;
+        let comments = backend.fetch_comments(&task_remote_id).await?;
+
+        let storage = self.storage.lock().await;
+        self.store_comments_batch(&storage, &comments).await?;
+
+        let txn = storage.conn.begin().await?;
+        let protected = self.protected_entities(&txn).await?;
+        let stale: Vec<Uuid> = CommentRepository::get_for_task(&txn, task_uuid)
+            .await?
+            .into_iter()
+            .filter(|local| {
+                !protected.contains(&local.uuid)
+                    && !is_temp_remote_id(&local.remote_id)
+                    && !comments.iter().any(|remote| remote.remote_id == local.remote_id)
+            })
+            .map(|local| local.uuid)
+            .collect();
+        comment::Entity::delete_many()
+            .filter(comment::Column::Uuid.is_in(stale))
+            .exec(&txn)
+            .await?;
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    /// Posts a new comment on a task and queues it for creation on the remote backend.
+    ///
+    /// The comment is stored immediately with a temporary remote ID so it shows up in
+    /// the thread right away, even while offline.
+    ///
+    /// # Arguments
+    /// * `task_uuid` - The task to comment on
+    /// * `content` - The text of the comment
+    ///
+    /// # Errors
+    /// Returns an error if the task does not exist or local storage update fails
+    pub async fn create_comment(&self, task_uuid: &Uuid, content: &str) -> Result<()> {
+        info!("Storage: Adding comment to task {}", task_uuid);
+
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+
+        let task_remote_id = TaskRepository::get_remote_id(&txn, task_uuid).await?;
+        let comment_uuid = Uuid::new_v4();
+        let remote_id = new_temp_remote_id();
+
+        let local_comment = comment::ActiveModel {
+            uuid: ActiveValue::Set(comment_uuid),
+            backend_uuid: ActiveValue::Set(self.backend_uuid),
+            remote_id: ActiveValue::Set(remote_id.clone()),
+            task_uuid: ActiveValue::Set(*task_uuid),
+            content: ActiveValue::Set(content.to_string()),
+            // Same format as the timestamps reported by Todoist, so the thread sorts correctly
+            posted_at: ActiveValue::Set(Some(Utc::now().to_rfc3339_opts(SecondsFormat::Micros, true))),
+        };
+        comment::Entity::insert(local_comment).exec(&txn).await?;
+
+        let comment_args = CreateCommentArgs {
+            task_remote_id,
+            content: content.to_string(),
+            request_id: Some(new_request_id()),
+        };
+        self.enqueue_operation(
+            &txn,
+            &comment_uuid,
+            &remote_id,
+            &OutboxOperation::CreateComment(comment_args),
+        )
+        .await?;
+
+        txn.commit().await?;
+        drop(storage);
+
+        self.try_flush_outbox().await;
+        Ok(())
+    }
+
+    /// Update the text of a comment
+    pub async fn update_comment_content(&self, comment_uuid: &Uuid, content: &str) -> Result<()> {
+        info!("Storage: Updating local comment content for UUID {}", comment_uuid);
+
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+
+        let comment = CommentRepository::get_by_id(&txn, comment_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Comment not found: {}", comment_uuid))?;
+        let remote_id = comment.remote_id.clone();
+
+        let mut active_model: comment::ActiveModel = comment.into_active_model();
+        active_model.content = ActiveValue::Set(content.to_string());
+        CommentRepository::update(&txn, active_model).await?;
+
+        let comment_args = UpdateCommentArgs {
+            content: Some(content.to_string()),
+        };
+        self.enqueue_operation(
+            &txn,
+            comment_uuid,
+            &remote_id,
+            &OutboxOperation::UpdateComment(comment_args),
+        )
+        .await?;
+
+        txn.commit().await?;
+        drop(storage);
+
+        self.try_flush_outbox().await;
+        Ok(())
+    }
+
+    /// Delete a comment
+    pub async fn delete_comment(&self, comment_uuid: &Uuid) -> Result<()> {
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+
+        let comment = CommentRepository::get_by_id(&txn, comment_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Comment not found: {}", comment_uuid))?;
+        let remote_id = comment.remote_id.clone();
+
+        CommentRepository::delete(&txn, comment).await?;
+
+        self.enqueue_operation(&txn, comment_uuid, &remote_id, &OutboxOperation::DeleteComment)
+            .await?;
+
+        txn.commit().await?;
+        drop(storage);
+
+        self.try_flush_outbox().await;
+        Ok(())
+    }
+}
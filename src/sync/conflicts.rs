@@ -0,0 +1,712 @@
+//! Detection and resolution of conflicts between local edits and remote changes.
+//!
+//! Every synced entity keeps a snapshot of the field values last seen on the backend
+//! (`remote_snapshot`). When an entity with queued local changes comes back from the
+//! backend, its local and remote values are merged field by field against that snapshot:
+//! a field changed on one side only takes that side's value, while a field changed on
+//! both sides to different values is recorded as a conflict. Pending operations of a
+//! conflicted entity are held back until the user has picked a side for each field.
+
+use anyhow::Result;
+use chrono::Utc;
+use log::info;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ActiveValue, ColumnTrait, ConnectionTrait, EntityTrait, IntoActiveModel, QueryFilter, TransactionTrait};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::backend::{
+    BackendLabel, BackendProject, BackendSection, BackendTask, CreateLabelArgs, CreateProjectArgs, UpdateLabelArgs,
+    UpdateProjectArgs,
+};
+use crate::entities::{label, project, section, sync_conflict, task};
+use crate::repositories::{
+    LabelRepository, PendingOperationRepository, ProjectRepository, SectionRepository, SyncConflictRepository,
+    TaskRepository,
+};
+use crate::sync::outbox::OutboxOperation;
+use crate::sync::SyncService;
+
+/// Field values of an entity keyed by field name, as stored in `remote_snapshot`.
+pub type Fields = Map<String, Value>;
+
+pub const FIELD_CONTENT: &str = "content";
+pub const FIELD_DESCRIPTION: &str = "description";
+pub const FIELD_PRIORITY: &str = "priority";
+pub const FIELD_DUE: &str = "due";
+pub const FIELD_COMPLETED: &str = "is_completed";
+pub const FIELD_DEADLINE: &str = "deadline";
+pub const FIELD_DURATION: &str = "duration";
+pub const FIELD_NAME: &str = "name";
+pub const FIELD_FAVORITE: &str = "is_favorite";
+
+/// Kind of entity a conflict belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EntityKind {
+    Task,
+    Project,
+    Label,
+    Section,
+}
+
+impl EntityKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Task => "task",
+            Self::Project => "project",
+            Self::Label => "label",
+            Self::Section => "section",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "task" => Some(Self::Task),
+            "project" => Some(Self::Project),
+            "label" => Some(Self::Label),
+            "section" => Some(Self::Section),
+            _ => None,
+        }
+    }
+}
+
+/// A local entity with pending changes whose remote values are being merged.
+pub(super) enum LocalEntity<'a> {
+    Task(&'a task::Model),
+    Project(&'a project::Model),
+    Label(&'a label::Model),
+    Section(&'a section::Model),
+}
+
+/// Which side wins when a conflict is resolved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConflictResolution {
+    KeepLocal,
+    UseRemote,
+}
+
+impl ConflictResolution {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::KeepLocal => "local",
+            Self::UseRemote => "remote",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "local" => Some(Self::KeepLocal),
+            "remote" => Some(Self::UseRemote),
+            _ => None,
+        }
+    }
+}
+
+/// A field that was changed both locally and remotely.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldConflict {
+    pub field: String,
+    pub local: Value,
+    pub remote: Value,
+}
+
+/// Result of merging local and remote field values.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MergeOutcome {
+    /// Merged values; conflicting fields keep the local value until resolved
+    pub merged: Fields,
+    pub conflicts: Vec<FieldConflict>,
+}
+
+/// Three-way merge of local and remote field values against the last-seen remote values.
+pub fn merge_fields(base: &Fields, local: &Fields, remote: &Fields) -> MergeOutcome {
+    let mut outcome = MergeOutcome::default();
+
+    for (field, local_value) in local {
+        let remote_value = remote.get(field).unwrap_or(&Value::Null);
+        let base_value = base.get(field).unwrap_or(remote_value);
+
+        let merged = if local_value == remote_value || remote_value == base_value {
+            local_value
+        } else if local_value == base_value {
+            remote_value
+        } else {
+            outcome.conflicts.push(FieldConflict {
+                field: field.clone(),
+                local: local_value.clone(),
+                remote: remote_value.clone(),
+            });
+            local_value
+        };
+        outcome.merged.insert(field.clone(), merged.clone());
+    }
+
+    outcome
+}
+
+/// Human-readable form of a JSON-encoded field value.
+pub fn display_value(json: &str) -> String {
+    match serde_json::from_str::<Value>(json).unwrap_or(Value::Null) {
+        Value::Null => "(none)".to_string(),
+        Value::String(text) if text.is_empty() => "(empty)".to_string(),
+        Value::String(text) => text,
+        Value::Bool(true) => "yes".to_string(),
+        Value::Bool(false) => "no".to_string(),
+        Value::Object(due) => due
+            .get("datetime")
+            .and_then(Value::as_str)
+            .or_else(|| due.get("date").and_then(Value::as_str))
+            .unwrap_or("(none)")
+            .to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn string_value(value: Option<&str>) -> Value {
+    value
+        .filter(|s| !s.is_empty())
+        .map_or(Value::Null, |s| Value::String(s.to_string()))
+}
+
+fn due_value(date: Option<&str>, datetime: Option<&str>) -> Value {
+    if date.is_none() && datetime.is_none() {
+        return Value::Null;
+    }
+    let mut due = Map::new();
+    due.insert("date".to_string(), string_value(date));
+    due.insert("datetime".to_string(), string_value(datetime));
+    Value::Object(due)
+}
+
+fn due_parts(value: &Value) -> (Option<String>, Option<String>) {
+    let part = |key: &str| value.get(key).and_then(Value::as_str).map(str::to_string);
+    (part("date"), part("datetime"))
+}
+
+fn task_values(content: &str, description: Option<&str>, priority: i32, due: Value, is_completed: bool) -> Fields {
+    let mut fields = Fields::new();
+    fields.insert(FIELD_CONTENT.to_string(), Value::String(content.to_string()));
+    fields.insert(FIELD_DESCRIPTION.to_string(), string_value(description));
+    fields.insert(FIELD_PRIORITY.to_string(), Value::from(priority));
+    fields.insert(FIELD_DUE.to_string(), due);
+    fields.insert(FIELD_COMPLETED.to_string(), Value::Bool(is_completed));
+    fields
+}
+
+fn insert_planning_values(fields: &mut Fields, deadline: Option<&str>, duration: Option<&str>) {
+    fields.insert(FIELD_DEADLINE.to_string(), string_value(deadline));
+    fields.insert(FIELD_DURATION.to_string(), string_value(duration));
+}
+
+pub fn task_fields(task: &BackendTask) -> Fields {
+    let mut fields = task_values(
+        &task.content,
+        task.description.as_deref(),
+        task.priority,
+        due_value(task.due_date.as_deref(), task.due_datetime.as_deref()),
+        task.is_completed,
+    );
+    insert_planning_values(&mut fields, task.deadline.as_deref(), task.duration.as_deref());
+    fields
+}
+
+pub fn task_model_fields(task: &task::Model) -> Fields {
+    let mut fields = task_values(
+        &task.content,
+        task.description.as_deref(),
+        task.priority,
+        due_value(task.due_date.as_deref(), task.due_datetime.as_deref()),
+        task.is_completed,
+    );
+    insert_planning_values(&mut fields, task.deadline.as_deref(), task.duration.as_deref());
+    fields
+}
+
+pub fn apply_task_fields(task: &mut task::ActiveModel, fields: &Fields) {
+    for (field, value) in fields {
+        match field.as_str() {
+            FIELD_CONTENT => task.content = ActiveValue::Set(value.as_str().unwrap_or_default().to_string()),
+            FIELD_DESCRIPTION => task.description = ActiveValue::Set(value.as_str().map(str::to_string)),
+            FIELD_PRIORITY => task.priority = ActiveValue::Set(value.as_i64().unwrap_or(1) as i32),
+            FIELD_DUE => {
+                let (date, datetime) = due_parts(value);
+                task.due_date = ActiveValue::Set(date);
+                task.due_datetime = ActiveValue::Set(datetime);
+            }
+            FIELD_COMPLETED => task.is_completed = ActiveValue::Set(value.as_bool().unwrap_or(false)),
+            FIELD_DEADLINE => task.deadline = ActiveValue::Set(value.as_str().map(str::to_string)),
+            FIELD_DURATION => task.duration = ActiveValue::Set(value.as_str().map(str::to_string)),
+            _ => {}
+        }
+    }
+}
+
+fn name_fields(name: &str, is_favorite: bool) -> Fields {
+    let mut fields = Fields::new();
+    fields.insert(FIELD_NAME.to_string(), Value::String(name.to_string()));
+    fields.insert(FIELD_FAVORITE.to_string(), Value::Bool(is_favorite));
+    fields
+}
+
+pub fn project_fields(project: &BackendProject) -> Fields {
+    name_fields(&project.name, project.is_favorite)
+}
+
+pub fn project_model_fields(project: &project::Model) -> Fields {
+    name_fields(&project.name, project.is_favorite)
+}
+
+pub fn apply_project_fields(project: &mut project::ActiveModel, fields: &Fields) {
+    if let Some(name) = fields.get(FIELD_NAME).and_then(Value::as_str) {
+        project.name = ActiveValue::Set(name.to_string());
+    }
+    if let Some(is_favorite) = fields.get(FIELD_FAVORITE).and_then(Value::as_bool) {
+        project.is_favorite = ActiveValue::Set(is_favorite);
+    }
+}
+
+pub fn label_fields(label: &BackendLabel) -> Fields {
+    name_fields(&label.name, label.is_favorite)
+}
+
+pub fn label_model_fields(label: &label::Model) -> Fields {
+    name_fields(&label.name, label.is_favorite)
+}
+
+pub fn apply_label_fields(label: &mut label::ActiveModel, fields: &Fields) {
+    if let Some(name) = fields.get(FIELD_NAME).and_then(Value::as_str) {
+        label.name = ActiveValue::Set(name.to_string());
+    }
+    if let Some(is_favorite) = fields.get(FIELD_FAVORITE).and_then(Value::as_bool) {
+        label.is_favorite = ActiveValue::Set(is_favorite);
+    }
+}
+
+pub fn section_fields(section: &BackendSection) -> Fields {
+    section_name_fields(&section.name)
+}
+
+pub fn section_model_fields(section: &section::Model) -> Fields {
+    section_name_fields(&section.name)
+}
+
+pub fn apply_section_fields(section: &mut section::ActiveModel, fields: &Fields) {
+    if let Some(name) = fields.get(FIELD_NAME).and_then(Value::as_str) {
+        section.name = ActiveValue::Set(name.to_string());
+    }
+}
+
+fn section_name_fields(name: &str) -> Fields {
+    let mut fields = Fields::new();
+    fields.insert(FIELD_NAME.to_string(), Value::String(name.to_string()));
+    fields
+}
+
+/// Serialize field values for the `remote_snapshot` column.
+pub fn snapshot(fields: &Fields) -> String {
+    Value::Object(fields.clone()).to_string()
+}
+
+fn parse_snapshot(snapshot: Option<&str>) -> Option<Fields> {
+    snapshot.and_then(|json| serde_json::from_str(json).ok())
+}
+
+/// Whether every argument of an update is unset, i.e. the update would not change anything.
+fn is_empty_update<T: serde::Serialize>(args: &T) -> bool {
+    serde_json::to_value(args)
+        .ok()
+        .and_then(|value| value.as_object().map(|fields| fields.values().all(Value::is_null)))
+        .unwrap_or(false)
+}
+
+impl OutboxOperation {
+    /// The kind of entity whose fields this operation changes, if any.
+    fn changed_entity(&self) -> Option<EntityKind> {
+        match self {
+            Self::CreateTask(_) | Self::UpdateTask(_) | Self::CompleteTask | Self::ReopenTask => Some(EntityKind::Task),
+            Self::CreateProject(_) | Self::UpdateProject(_) => Some(EntityKind::Project),
+            Self::CreateLabel(_) | Self::UpdateLabel(_) => Some(EntityKind::Label),
+            Self::CreateSection(_) | Self::UpdateSection(_) => Some(EntityKind::Section),
+            // Task location and manual order are not part of the snapshot, comments are not merged
+            Self::MoveTask(_)
+            | Self::ReorderTasks(_)
+            | Self::DeleteTask
+            | Self::ReorderProjects(_)
+            | Self::DeleteProject
+            | Self::DeleteLabel
+            | Self::DeleteSection
+            | Self::ReorderSections(_)
+            | Self::CreateComment(_)
+            | Self::UpdateComment(_)
+            | Self::DeleteComment => None,
+        }
+    }
+
+    /// Apply the field changes of this operation to a set of field values.
+    fn apply_to_fields(&self, fields: &mut Fields) {
+        match self {
+            Self::CreateTask(args) => {
+                fields.extend(task_values(
+                    &args.content,
+                    args.description.as_deref(),
+                    args.priority.unwrap_or(1),
+                    due_value(args.due_date.as_deref(), args.due_datetime.as_deref()),
+                    false,
+                ));
+                insert_planning_values(fields, args.deadline.as_deref(), args.duration.as_deref());
+            }
+            Self::UpdateTask(args) => {
+                if let Some(content) = &args.content {
+                    fields.insert(FIELD_CONTENT.to_string(), Value::String(content.clone()));
+                }
+                if let Some(description) = &args.description {
+                    fields.insert(FIELD_DESCRIPTION.to_string(), string_value(Some(description)));
+                }
+                if let Some(priority) = args.priority {
+                    fields.insert(FIELD_PRIORITY.to_string(), Value::from(priority));
+                }
+                if args.due_date.is_some() || args.due_datetime.is_some() {
+                    let due = due_value(args.due_date.as_deref(), args.due_datetime.as_deref());
+                    fields.insert(FIELD_DUE.to_string(), due);
+                }
+                if let Some(deadline) = &args.deadline {
+                    fields.insert(FIELD_DEADLINE.to_string(), string_value(Some(deadline)));
+                }
+                if let Some(duration) = &args.duration {
+                    fields.insert(FIELD_DURATION.to_string(), string_value(Some(duration)));
+                }
+            }
+            Self::CompleteTask => {
+                fields.insert(FIELD_COMPLETED.to_string(), Value::Bool(true));
+            }
+            Self::ReopenTask => {
+                fields.insert(FIELD_COMPLETED.to_string(), Value::Bool(false));
+            }
+            Self::CreateProject(CreateProjectArgs { name, is_favorite, .. })
+            | Self::CreateLabel(CreateLabelArgs { name, is_favorite, .. }) => {
+                fields.extend(name_fields(name, is_favorite.unwrap_or(false)));
+            }
+            Self::UpdateProject(UpdateProjectArgs { name, is_favorite })
+            | Self::UpdateLabel(UpdateLabelArgs { name, is_favorite }) => {
+                if let Some(name) = name {
+                    fields.insert(FIELD_NAME.to_string(), Value::String(name.clone()));
+                }
+                if let Some(is_favorite) = is_favorite {
+                    fields.insert(FIELD_FAVORITE.to_string(), Value::Bool(*is_favorite));
+                }
+            }
+            Self::CreateSection(args) => fields.extend(section_name_fields(&args.name)),
+            Self::UpdateSection(args) => {
+                if let Some(name) = &args.name {
+                    fields.insert(FIELD_NAME.to_string(), Value::String(name.clone()));
+                }
+            }
+            Self::MoveTask(_)
+            | Self::ReorderTasks(_)
+            | Self::DeleteTask
+            | Self::ReorderProjects(_)
+            | Self::DeleteProject
+            | Self::DeleteLabel
+            | Self::DeleteSection
+            | Self::ReorderSections(_)
+            | Self::CreateComment(_)
+            | Self::UpdateComment(_)
+            | Self::DeleteComment => {}
+        }
+    }
+
+    /// This operation without its change to `field`, or `None` if nothing would be left to push.
+    pub fn without_field(self, field: &str) -> Option<Self> {
+        match self {
+            Self::UpdateTask(mut args) => {
+                match field {
+                    FIELD_CONTENT => args.content = None,
+                    FIELD_DESCRIPTION => args.description = None,
+                    FIELD_PRIORITY => args.priority = None,
+                    FIELD_DUE => {
+                        args.due_date = None;
+                        args.due_datetime = None;
+                        args.due_string = None;
+                    }
+                    FIELD_DEADLINE => args.deadline = None,
+                    FIELD_DURATION => args.duration = None,
+                    _ => {}
+                }
+                (!is_empty_update(&args)).then_some(Self::UpdateTask(args))
+            }
+            Self::UpdateProject(mut args) => {
+                match field {
+                    FIELD_NAME => args.name = None,
+                    FIELD_FAVORITE => args.is_favorite = None,
+                    _ => {}
+                }
+                (!is_empty_update(&args)).then_some(Self::UpdateProject(args))
+            }
+            Self::UpdateLabel(mut args) => {
+                match field {
+                    FIELD_NAME => args.name = None,
+                    FIELD_FAVORITE => args.is_favorite = None,
+                    _ => {}
+                }
+                (!is_empty_update(&args)).then_some(Self::UpdateLabel(args))
+            }
+            Self::UpdateSection(mut args) => {
+                if field == FIELD_NAME {
+                    args.name = None;
+                }
+                (!is_empty_update(&args)).then_some(Self::UpdateSection(args))
+            }
+            Self::CompleteTask | Self::ReopenTask if field == FIELD_COMPLETED => None,
+            other => Some(other),
+        }
+    }
+}
+
+impl SyncService {
+    /// Returns the unresolved sync conflicts of this backend.
+    pub async fn get_conflicts(&self) -> Result<Vec<sync_conflict::Model>> {
+        let storage = self.storage.lock().await;
+        SyncConflictRepository::get_for_backend(&storage.conn, &self.backend_uuid).await
+    }
+
+    /// Resolves a conflict by keeping either the local or the remote value.
+    ///
+    /// Keeping the local value lets the held-back local change be pushed. Taking the
+    /// remote value writes it to local storage and drops that field from the pending
+    /// operations of the entity, so it is not pushed over the remote change.
+    ///
+    /// # Errors
+    /// Returns an error if the conflict does not exist or local storage update fails
+    pub async fn resolve_conflict(&self, conflict_uuid: &Uuid, resolution: ConflictResolution) -> Result<()> {
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+
+        let conflict = SyncConflictRepository::get_by_id(&txn, conflict_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Conflict not found: {}", conflict_uuid))?;
+
+        if resolution == ConflictResolution::UseRemote {
+            let mut fields = Fields::new();
+            fields.insert(conflict.field.clone(), serde_json::from_str(&conflict.remote_value)?);
+
+            match EntityKind::parse(&conflict.entity_type) {
+                Some(EntityKind::Task) => {
+                    if let Some(local) = TaskRepository::get_by_id(&txn, &conflict.entity_uuid).await? {
+                        let mut active_model = local.into_active_model();
+                        apply_task_fields(&mut active_model, &fields);
+                        TaskRepository::update(&txn, active_model).await?;
+                    }
+                }
+                Some(EntityKind::Project) => {
+                    if let Some(local) = ProjectRepository::get_by_id(&txn, &conflict.entity_uuid).await? {
+                        let mut active_model = local.into_active_model();
+                        apply_project_fields(&mut active_model, &fields);
+                        ProjectRepository::update(&txn, active_model).await?;
+                    }
+                }
+                Some(EntityKind::Label) => {
+                    if let Some(local) = LabelRepository::get_by_id(&txn, &conflict.entity_uuid).await? {
+                        let mut active_model = local.into_active_model();
+                        apply_label_fields(&mut active_model, &fields);
+                        LabelRepository::update(&txn, active_model).await?;
+                    }
+                }
+                Some(EntityKind::Section) => {
+                    if let Some(local) = SectionRepository::get_by_id(&txn, &conflict.entity_uuid).await? {
+                        let mut active_model = local.into_active_model();
+                        apply_section_fields(&mut active_model, &fields);
+                        SectionRepository::update(&txn, active_model).await?;
+                    }
+                }
+                None => {}
+            }
+
+            for pending in PendingOperationRepository::get_for_entity(&txn, &conflict.entity_uuid).await? {
+                let operation: OutboxOperation = serde_json::from_str(&pending.payload)?;
+                match operation.without_field(&conflict.field) {
+                    Some(operation) => {
+                        let payload = serde_json::to_string(&operation)?;
+                        PendingOperationRepository::update_payload(&txn, &pending.uuid, payload).await?;
+                    }
+                    None => PendingOperationRepository::delete(&txn, &pending.uuid).await?,
+                }
+            }
+        }
+
+        SyncConflictRepository::delete(&txn, conflict_uuid).await?;
+        txn.commit().await?;
+        drop(storage);
+
+        info!(
+            "Resolved conflict on {} '{}' ({}) with {:?}",
+            conflict.entity_type, conflict.entity_name, conflict.field, resolution
+        );
+        self.try_flush_outbox().await;
+        Ok(())
+    }
+
+    /// Merge the remote values of an entity with pending local changes and record conflicts.
+    ///
+    /// Returns the merged field values to store locally. Conflicts recorded earlier for the
+    /// entity are dropped once both sides agree again.
+    pub(super) async fn merge_remote_fields<C>(
+        &self,
+        conn: &C,
+        local: LocalEntity<'_>,
+        remote: &Fields,
+    ) -> Result<Fields>
+    where
+        C: ConnectionTrait,
+    {
+        let (kind, entity_uuid, entity_name, base_snapshot, local) = match local {
+            LocalEntity::Task(task) => (
+                EntityKind::Task,
+                task.uuid,
+                &task.content,
+                &task.remote_snapshot,
+                task_model_fields(task),
+            ),
+            LocalEntity::Project(project) => (
+                EntityKind::Project,
+                project.uuid,
+                &project.name,
+                &project.remote_snapshot,
+                project_model_fields(project),
+            ),
+            LocalEntity::Label(label) => (
+                EntityKind::Label,
+                label.uuid,
+                &label.name,
+                &label.remote_snapshot,
+                label_model_fields(label),
+            ),
+            LocalEntity::Section(section) => (
+                EntityKind::Section,
+                section.uuid,
+                &section.name,
+                &section.remote_snapshot,
+                section_model_fields(section),
+            ),
+        };
+
+        // Without a snapshot nothing is known about remote changes, so local values win
+        let base = parse_snapshot(base_snapshot.as_deref()).unwrap_or_else(|| remote.clone());
+        let outcome = merge_fields(&base, &local, remote);
+
+        for existing in SyncConflictRepository::get_for_entity(conn, &entity_uuid).await? {
+            if local.get(&existing.field) == remote.get(&existing.field) {
+                SyncConflictRepository::delete(conn, &existing.uuid).await?;
+            }
+        }
+
+        for conflict in &outcome.conflicts {
+            info!(
+                "Conflict on {} '{}': {} changed locally and remotely",
+                kind.as_str(),
+                entity_name,
+                conflict.field
+            );
+            SyncConflictRepository::upsert(
+                conn,
+                sync_conflict::ActiveModel {
+                    uuid: ActiveValue::Set(Uuid::new_v4()),
+                    backend_uuid: ActiveValue::Set(self.backend_uuid),
+                    entity_type: ActiveValue::Set(kind.as_str().to_string()),
+                    entity_uuid: ActiveValue::Set(entity_uuid),
+                    entity_name: ActiveValue::Set(entity_name.clone()),
+                    field: ActiveValue::Set(conflict.field.clone()),
+                    local_value: ActiveValue::Set(conflict.local.to_string()),
+                    remote_value: ActiveValue::Set(conflict.remote.to_string()),
+                    created_at: ActiveValue::Set(Utc::now().to_rfc3339()),
+                },
+            )
+            .await?;
+        }
+
+        Ok(outcome.merged)
+    }
+
+    /// Move the remote snapshot of an entity forward after one of its operations was replayed.
+    ///
+    /// `pushed` holds field values reported back by the backend, which take precedence over
+    /// the ones derived from the operation (e.g. a due date resolved from a due string).
+    pub(super) async fn advance_snapshot<C>(
+        conn: &C,
+        entity_uuid: &Uuid,
+        operation: &OutboxOperation,
+        pushed: Option<Fields>,
+    ) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        let Some(kind) = operation.changed_entity() else {
+            return Ok(());
+        };
+        let current = match kind {
+            EntityKind::Task => TaskRepository::get_by_id(conn, entity_uuid)
+                .await?
+                .map(|task| task.remote_snapshot),
+            EntityKind::Project => ProjectRepository::get_by_id(conn, entity_uuid)
+                .await?
+                .map(|project| project.remote_snapshot),
+            EntityKind::Label => LabelRepository::get_by_id(conn, entity_uuid)
+                .await?
+                .map(|label| label.remote_snapshot),
+            EntityKind::Section => SectionRepository::get_by_id(conn, entity_uuid)
+                .await?
+                .map(|section| section.remote_snapshot),
+        };
+        let Some(current) = current else {
+            return Ok(());
+        };
+
+        let is_create = matches!(
+            operation,
+            OutboxOperation::CreateTask(_)
+                | OutboxOperation::CreateProject(_)
+                | OutboxOperation::CreateLabel(_)
+                | OutboxOperation::CreateSection(_)
+        );
+        let mut fields = match parse_snapshot(current.as_deref()) {
+            Some(fields) => fields,
+            None if is_create => Fields::new(),
+            // The remote state before this change is unknown, so there is nothing to move forward
+            None => return Ok(()),
+        };
+        operation.apply_to_fields(&mut fields);
+        fields.extend(pushed.unwrap_or_default());
+
+        let value = Some(snapshot(&fields));
+        match kind {
+            EntityKind::Task => {
+                task::Entity::update_many()
+                    .col_expr(task::Column::RemoteSnapshot, Expr::value(value.clone()))
+                    .filter(task::Column::Uuid.eq(*entity_uuid))
+                    .exec(conn)
+                    .await?;
+            }
+            EntityKind::Project => {
+                project::Entity::update_many()
+                    .col_expr(project::Column::RemoteSnapshot, Expr::value(value.clone()))
+                    .filter(project::Column::Uuid.eq(*entity_uuid))
+                    .exec(conn)
+                    .await?;
+            }
+            EntityKind::Label => {
+                label::Entity::update_many()
+                    .col_expr(label::Column::RemoteSnapshot, Expr::value(value.clone()))
+                    .filter(label::Column::Uuid.eq(*entity_uuid))
+                    .exec(conn)
+                    .await?;
+            }
+            EntityKind::Section => {
+                section::Entity::update_many()
+                    .col_expr(section::Column::RemoteSnapshot, Expr::value(value.clone()))
+                    .filter(section::Column::Uuid.eq(*entity_uuid))
+                    .exec(conn)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
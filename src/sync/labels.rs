@@ -1,9 +1,10 @@
 use crate::entities::label;
 use crate::repositories::LabelRepository;
+use crate::sync::outbox::{new_request_id, new_temp_remote_id, OutboxOperation};
 use crate::sync::SyncService;
 use anyhow::Result;
 use log::info;
-use sea_orm::{ActiveValue, EntityTrait, IntoActiveModel};
+use sea_orm::{ActiveValue, ConnectionTrait, EntityTrait, IntoActiveModel, TransactionTrait};
 use uuid::Uuid;
 
 impl SyncService {
@@ -13,105 +14,113 @@ impl SyncService {
         LabelRepository::get_all(&storage.conn).await
     }
 
-    /// Creates a new label via the remote backend and stores it locally.
+    /// Creates a new label locally and queues it for creation on the remote backend.
     ///
-    /// This method creates a label remotely and immediately stores it in local storage
-    /// for instant UI updates. The label will be available in the UI without requiring
-    /// a full sync operation.
+    /// The label is stored immediately with a temporary remote ID so it is available in
+    /// the UI right away, even while offline.
     ///
     /// # Arguments
     /// * `name` - The name of the new label
     ///
     /// # Errors
-    /// Returns an error if the backend call fails or local storage update fails
+    /// Returns an error if local storage update fails
     pub async fn create_label(&self, name: &str) -> Result<()> {
-        info!("Backend: Creating label '{}'", name);
+        info!("Storage: Creating label '{}'", name);
 
-        // Create label via backend using the CreateLabelArgs structure
-        let label_args = crate::backend::CreateLabelArgs {
-            name: name.to_string(),
-            is_favorite: None,
-        };
-        let api_label = self
-            .get_backend()
-            .await?
-            .create_label(label_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
-
-        // Store the created label in local database immediately for UI refresh
-        info!("Storage: Storing new label locally with ID {}", api_label.remote_id);
         let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+        self.insert_label(&txn, name).await?;
+        txn.commit().await?;
+        drop(storage);
 
-        let local_label = label::ActiveModel {
-            uuid: ActiveValue::Set(Uuid::new_v4()),
-            backend_uuid: ActiveValue::Set(self.backend_uuid),
-            remote_id: ActiveValue::Set(api_label.remote_id),
-            name: ActiveValue::Set(api_label.name),
-            order_index: ActiveValue::Set(api_label.order_index),
-            is_favorite: ActiveValue::Set(api_label.is_favorite),
-        };
-
-        use sea_orm::sea_query::OnConflict;
-        let mut insert = label::Entity::insert(local_label);
-        insert = insert.on_conflict(
-            OnConflict::columns([label::Column::BackendUuid, label::Column::RemoteId])
-                .update_columns([label::Column::Name, label::Column::OrderIndex, label::Column::IsFavorite])
-                .to_owned(),
-        );
-        insert.exec(&storage.conn).await?;
-
+        self.try_flush_outbox().await;
         Ok(())
     }
 
-    /// Update label content (name only for now)
-    pub async fn update_label_content(&self, label_uuid: &Uuid, name: &str) -> Result<()> {
-        info!("Backend: Updating label name for UUID {} to '{}'", label_uuid, name);
+    /// Store a new label with a temporary remote ID and queue its creation, as part of the
+    /// caller's transaction. Returns the local UUID of the label.
+    pub(super) async fn insert_label<C>(&self, conn: &C, name: &str) -> Result<Uuid>
+    where
+        C: ConnectionTrait,
+    {
+        let label_uuid = Uuid::new_v4();
+        let remote_id = new_temp_remote_id();
 
-        // Look up the label's remote_id for backend call
-        let remote_id = self.get_label_remote_id(label_uuid).await?;
+        let local_label = label::ActiveModel {
+            uuid: ActiveValue::Set(label_uuid),
+            backend_uuid: ActiveValue::Set(self.backend_uuid),
+            remote_id: ActiveValue::Set(remote_id.clone()),
+            name: ActiveValue::Set(name.to_string()),
+            order_index: ActiveValue::Set(0),
+            is_favorite: ActiveValue::Set(false),
+            color: ActiveValue::Set(None),
+            remote_snapshot: ActiveValue::Set(None),
+        };
+        label::Entity::insert(local_label).exec(conn).await?;
 
-        // Update label via backend using the UpdateLabelArgs structure
-        let label_args = crate::backend::UpdateLabelArgs {
-            name: Some(name.to_string()),
+        let label_args = crate::backend::CreateLabelArgs {
+            name: name.to_string(),
             is_favorite: None,
+            request_id: Some(new_request_id()),
         };
-        let _label = self
-            .get_backend()
-            .await?
-            .update_label(&remote_id, label_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+        self.enqueue_operation(conn, &label_uuid, &remote_id, &OutboxOperation::CreateLabel(label_args))
+            .await?;
+        Ok(label_uuid)
+    }
 
-        // Update local storage immediately after successful backend call
+    /// Update label content (name only for now)
+    pub async fn update_label_content(&self, label_uuid: &Uuid, name: &str) -> Result<()> {
         info!(
             "Storage: Updating local label name for UUID {} to '{}'",
             label_uuid, name
         );
+
         let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+
+        let label = LabelRepository::get_by_id(&txn, label_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Label not found: {}", label_uuid))?;
+        let remote_id = label.remote_id.clone();
 
-        if let Some(label) = LabelRepository::get_by_id(&storage.conn, label_uuid).await? {
-            let mut active_model: label::ActiveModel = label.into_active_model();
-            active_model.name = ActiveValue::Set(name.to_string());
-            LabelRepository::update(&storage.conn, active_model).await?;
-        }
+        let mut active_model: label::ActiveModel = label.into_active_model();
+        active_model.name = ActiveValue::Set(name.to_string());
+        LabelRepository::update(&txn, active_model).await?;
 
+        let label_args = crate::backend::UpdateLabelArgs {
+            name: Some(name.to_string()),
+            ..Default::default()
+        };
+        self.enqueue_operation(&txn, label_uuid, &remote_id, &OutboxOperation::UpdateLabel(label_args))
+            .await?;
+
+        txn.commit().await?;
+        drop(storage);
+
+        self.try_flush_outbox().await;
         Ok(())
     }
 
     /// Delete a label
     pub async fn delete_label(&self, label_uuid: &Uuid) -> Result<()> {
-        // Look up the label's remote_id for backend call
-        let remote_id = self.get_label_remote_id(label_uuid).await?;
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
 
-        // Delete label via backend
-        self.get_backend()
+        let label = LabelRepository::get_by_id(&txn, label_uuid)
             .await?
-            .delete_label(&remote_id)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+            .ok_or_else(|| anyhow::anyhow!("Label not found: {}", label_uuid))?;
+        let remote_id = label.remote_id.clone();
+
+        // Task assignments are removed via database CASCADE constraints
+        LabelRepository::delete(&txn, label).await?;
+
+        self.enqueue_operation(&txn, label_uuid, &remote_id, &OutboxOperation::DeleteLabel)
+            .await?;
+
+        txn.commit().await?;
+        drop(storage);
 
-        // Note: Local storage deletion will be handled by the next sync
+        self.try_flush_outbox().await;
         Ok(())
     }
 }
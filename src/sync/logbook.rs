@@ -0,0 +1,70 @@
+use crate::entities::task;
+use crate::repositories::TaskRepository;
+use crate::sync::outbox::is_temp_remote_id;
+use crate::sync::SyncService;
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use log::info;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, TransactionTrait};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// How many days of completed tasks the logbook fetches from the backend
+pub const LOGBOOK_DAYS: i64 = 30;
+
+impl SyncService {
+    /// Get completed tasks from local storage (fast), most recently completed first
+    pub async fn get_completed_tasks(&self) -> Result<Vec<task::Model>> {
+        let storage = self.storage.lock().await;
+        TaskRepository::get_completed(&storage.conn).await
+    }
+
+    /// Fetches the tasks completed in the last [`LOGBOOK_DAYS`] days from the remote backend
+    /// and stores them locally.
+    ///
+    /// Regular syncs only return open tasks, so the logbook refreshes its history when it
+    /// is opened. Completed tasks of that period that the backend no longer lists (reopened
+    /// or deleted) are removed, unless they have local changes that have not been pushed yet.
+    ///
+    /// # Errors
+    /// Returns an error if the backend cannot be reached or local storage update fails
+    pub async fn refresh_completed_tasks(&self) -> Result<()> {
+        let until = Utc::now();
+        let since = until - Duration::days(LOGBOOK_DAYS);
+
+        let backend = self.get_backend().await?;
+        let tasks = backend.fetch_completed_tasks(since, until).await?;
+        info!("Logbook: Fetched {} completed tasks from backend", tasks.len());
+
+        let storage = self.storage.lock().await;
+        self.store_tasks_batch(&storage, &tasks).await?;
+
+        let txn = storage.conn.begin().await?;
+        let protected = self.protected_entities(&txn).await?;
+        let remote_ids: HashSet<&str> = tasks.iter().map(|t| t.remote_id.as_str()).collect();
+        let stale: Vec<Uuid> = TaskRepository::get_completed(&txn)
+            .await?
+            .into_iter()
+            .filter(|local| {
+                let in_period = local
+                    .completed_at
+                    .as_deref()
+                    .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+                    .is_some_and(|at| at >= since);
+                in_period
+                    && local.backend_uuid == self.backend_uuid
+                    && !protected.contains(&local.uuid)
+                    && !is_temp_remote_id(&local.remote_id)
+                    && !remote_ids.contains(local.remote_id.as_str())
+            })
+            .map(|local| local.uuid)
+            .collect();
+        task::Entity::delete_many()
+            .filter(task::Column::Uuid.is_in(stale))
+            .exec(&txn)
+            .await?;
+        txn.commit().await?;
+
+        Ok(())
+    }
+}
@@ -8,21 +8,30 @@
 //! The sync service acts as the main data layer for the application, offering:
 //! - Fast local data access for UI operations
 //! - Background synchronization with remote backends (Todoist, etc.)
-//! - CRUD operations for tasks, projects, and labels
-//! - Business logic for special views (Today, Tomorrow, Upcoming)
+//! - CRUD operations for tasks, projects, labels, sections, and comments
+//! - Business logic for special views (Today, Tomorrow, Upcoming, Logbook)
 
+pub mod comments;
+pub mod conflicts;
 pub mod labels;
+pub mod logbook;
+pub mod outbox;
 pub mod projects;
 pub mod sections;
 pub mod storage;
 pub mod tasks;
+pub mod views;
 
 use anyhow::Result;
 use log::{error, info};
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
+use crate::backend::{BackendChanges, BackendError};
+use crate::constants::OUTBOX_PULL_MIN_INTERVAL_SECONDS;
+use crate::repositories::BackendRepository;
 use crate::storage::LocalStorage;
 
 /// Service that manages data synchronization between remote backends and local storage.
@@ -39,8 +48,8 @@ use crate::storage::LocalStorage;
 /// - Backend-agnostic architecture via trait abstraction
 /// - Thread-safe operations using Arc<Mutex<>>
 /// - Prevents concurrent sync operations
-/// - Provides immediate UI updates after create/update operations
-/// - Handles business logic for special views (Today, Tomorrow, Upcoming)
+/// - Applies create/update operations locally first and queues them in a persisted outbox
+/// - Handles business logic for special views (Today, Tomorrow, Upcoming, Logbook)
 /// - Optional logging support for debugging and monitoring
 ///
 /// # Example
@@ -52,7 +61,7 @@ use crate::storage::LocalStorage;
 /// use tokio::sync::Mutex;
 ///
 /// # async fn example() -> anyhow::Result<()> {
-/// let storage = Arc::new(Mutex::new(LocalStorage::new(false).await?));
+/// let storage = Arc::new(Mutex::new(LocalStorage::new().await?));
 /// let backend_registry = Arc::new(BackendRegistry::new(storage));
 /// // ... initialize and load backends ...
 /// # let backend_uuid = uuid::Uuid::new_v4();
@@ -72,6 +81,8 @@ pub struct SyncService {
     backend_uuid: Uuid,
     storage: Arc<Mutex<LocalStorage>>,
     sync_in_progress: Arc<Mutex<bool>>,
+    outbox_lock: Arc<Mutex<()>>,
+    last_pull: Arc<Mutex<Option<Instant>>>,
     debug_mode: bool,
 }
 
@@ -92,6 +103,52 @@ pub enum SyncStatus {
         /// Human-readable error message describing what went wrong
         message: String,
     },
+    /// The backend rejected the stored credentials; a new API token is needed
+    AuthFailed {
+        /// Human-readable error message describing what went wrong
+        message: String,
+    },
+    /// The backend asked to slow down before the next request
+    RateLimited {
+        /// Seconds to wait before retrying, if the backend said so
+        retry_after: Option<u64>,
+        /// Human-readable error message describing what went wrong
+        message: String,
+    },
+}
+
+impl SyncStatus {
+    /// Status of a sync that failed with the given error.
+    ///
+    /// Backend errors are classified so the UI can react to them: rejected credentials
+    /// and rate limiting get their own status, everything else is reported as an error.
+    pub fn failed(context: &str, error: &anyhow::Error) -> Self {
+        let message = format!("{context}: {error}");
+        match error.downcast_ref::<BackendError>() {
+            Some(BackendError::Auth(_)) => Self::AuthFailed { message },
+            Some(BackendError::RateLimited { retry_after, .. }) => Self::RateLimited {
+                retry_after: *retry_after,
+                message,
+            },
+            _ => Self::Error { message },
+        }
+    }
+
+    /// Whether the sync did not complete.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Self::Error { .. } | Self::AuthFailed { .. } | Self::RateLimited { .. }
+        )
+    }
+
+    /// The error message of a failed sync.
+    pub fn error_message(&self) -> Option<&str> {
+        match self {
+            Self::Error { message } | Self::AuthFailed { message } | Self::RateLimited { message, .. } => Some(message),
+            _ => None,
+        }
+    }
 }
 
 impl SyncService {
@@ -125,6 +182,8 @@ impl SyncService {
             backend_uuid,
             storage,
             sync_in_progress: Arc::new(Mutex::new(false)),
+            outbox_lock: Arc::new(Mutex::new(())),
+            last_pull: Arc::new(Mutex::new(None)),
             debug_mode,
         })
     }
@@ -134,6 +193,17 @@ impl SyncService {
         self.backend_registry.get_backend(&self.backend_uuid).await
     }
 
+    /// Replaces the API token of this backend, e.g. after the backend rejected the old one.
+    ///
+    /// # Errors
+    /// Returns an error if the backend cannot be recreated with the new token
+    pub async fn update_api_token(&self, api_token: &str) -> Result<()> {
+        let credentials = serde_json::json!({ "api_token": api_token }).to_string();
+        self.backend_registry
+            .update_backend(&self.backend_uuid, None, Some(credentials), None)
+            .await
+    }
+
     /// Returns whether debug mode is enabled.
     ///
     /// This is used to enable debug-only features like local data refresh.
@@ -159,10 +229,13 @@ impl SyncService {
     /// at a time to prevent data corruption and resource conflicts.
     ///
     /// The sync process includes:
-    /// 1. Fetching projects, tasks, labels, and sections from the remote backend
-    /// 2. Storing all data in local storage with proper ordering
-    /// 3. Handling backend errors gracefully with detailed error messages
-    /// 4. Providing progress logging for debugging and monitoring
+    /// 1. Fetching what changed since the last sync (or projects, tasks, labels, and sections in full
+    ///    when the backend has no delta API) from the remote backend
+    /// 2. Storing all data in local storage with proper ordering, merging remote changes into
+    ///    entities with queued local changes and recording conflicting fields
+    /// 3. Replaying local changes queued in the outbox
+    /// 4. Handling backend errors gracefully with detailed error messages
+    /// 5. Providing progress logging for debugging and monitoring
     ///
     /// # Returns
     /// A `SyncStatus` indicating the result of the sync operation
@@ -170,131 +243,262 @@ impl SyncService {
     /// # Errors
     /// Returns `SyncStatus::Error` if any part of the sync process fails
     pub async fn sync(&self) -> Result<SyncStatus> {
-        // Check if sync is already in progress and acquire lock
-        let mut sync_guard = self.sync_in_progress.lock().await;
-        if *sync_guard {
+        if !self.begin_sync().await {
             return Ok(SyncStatus::InProgress);
         }
-        *sync_guard = true;
-
-        // Release the lock before performing sync to avoid holding it during the long operation
-        drop(sync_guard);
 
         let result = self.perform_sync().await;
 
-        // Release sync lock
-        {
-            let mut sync_guard = self.sync_in_progress.lock().await;
-            *sync_guard = false;
+        self.end_sync().await;
+        result
+    }
+
+    /// Mark a sync as running, returning `false` if one already is.
+    ///
+    /// The flag is released right away so it is not held during the long operation;
+    /// [`Self::end_sync`] clears it again.
+    pub(super) async fn begin_sync(&self) -> bool {
+        let mut sync_guard = self.sync_in_progress.lock().await;
+        if *sync_guard {
+            return false;
         }
+        *sync_guard = true;
+        true
+    }
 
-        result
+    /// Mark the running sync as finished.
+    pub(super) async fn end_sync(&self) {
+        *self.sync_in_progress.lock().await = false;
     }
 
     /// Internal sync implementation
     async fn perform_sync(&self) -> Result<SyncStatus> {
         info!("🔄 Starting sync process...");
 
+        // Pull before pushing, so remote edits are merged with queued local changes
+        // instead of being overwritten by them
+        let changes = match self.fetch_remote_changes().await {
+            Ok(changes) => changes,
+            Err(status) => return Ok(status),
+        };
+        let status = self.store_changes(changes).await?;
+        if status.is_failure() {
+            return Ok(status);
+        }
+        *self.last_pull.lock().await = Some(Instant::now());
+
+        if let Err(e) = self.flush_outbox().await {
+            error!("❌ Failed to push local changes: {e}");
+            return Ok(SyncStatus::failed("Failed to push local changes", &e));
+        }
+
+        Ok(status)
+    }
+
+    /// Fetch what changed since the last sync, or everything when the backend has no delta API.
+    async fn fetch_remote_changes(&self) -> std::result::Result<BackendChanges, SyncStatus> {
+        let cursor = self
+            .sync_cursor()
+            .await
+            .map_err(|e| SyncStatus::failed("Failed to read sync cursor", &e))?;
+        let backend = self
+            .get_backend()
+            .await
+            .map_err(|e| SyncStatus::failed("Failed to load backend", &e))?;
+
+        match backend.fetch_changes(cursor.as_deref()).await {
+            Ok(Some(changes)) => {
+                info!(
+                    "✅ Fetched {} {} projects, {} tasks, {} labels, {} sections, {} comments",
+                    if changes.is_full { "full sync:" } else { "changes:" },
+                    changes.projects.len(),
+                    changes.tasks.len(),
+                    changes.labels.len(),
+                    changes.sections.len(),
+                    changes.comments.len()
+                );
+                Ok(changes)
+            }
+            Ok(None) => self.fetch_all().await,
+            Err(e) => {
+                error!("❌ Failed to fetch changes: {e}");
+                Err(SyncStatus::failed("Failed to fetch changes", &e.into()))
+            }
+        }
+    }
+
+    /// Pull and store the backend's delta since the last sync, if it has a delta API.
+    ///
+    /// Does nothing before the first sync or for backends that only support full fetches;
+    /// their remote changes are merged on the next regular sync.
+    pub(super) async fn pull_changes(&self) -> Result<()> {
+        let Some(cursor) = self.sync_cursor().await? else {
+            return Ok(());
+        };
+        let backend = self.get_backend().await?;
+        let Some(changes) = backend.fetch_changes(Some(&cursor)).await? else {
+            return Ok(());
+        };
+
+        match self.store_changes(changes).await?.error_message() {
+            Some(message) => Err(anyhow::anyhow!(message.to_string())),
+            None => {
+                *self.last_pull.lock().await = Some(Instant::now());
+                Ok(())
+            }
+        }
+    }
+
+    /// Whether the last pull is old enough that local changes should not be pushed before
+    /// pulling again.
+    pub(super) async fn pull_is_stale(&self) -> bool {
+        let max_age = std::time::Duration::from_secs(OUTBOX_PULL_MIN_INTERVAL_SECONDS);
+        self.last_pull
+            .lock()
+            .await
+            .map_or(true, |pulled_at| pulled_at.elapsed() >= max_age)
+    }
+
+    /// The delta cursor stored for this backend, if any.
+    async fn sync_cursor(&self) -> Result<Option<String>> {
+        let storage = self.storage.lock().await;
+        Ok(BackendRepository::get_by_uuid(&storage.conn, &self.backend_uuid)
+            .await?
+            .and_then(|backend| backend.sync_cursor))
+    }
+
+    /// Fetch the complete remote dataset for backends without a delta API.
+    async fn fetch_all(&self) -> std::result::Result<BackendChanges, SyncStatus> {
+        let backend = self
+            .get_backend()
+            .await
+            .map_err(|e| SyncStatus::failed("Failed to load backend", &e))?;
+
         // Fetch projects from backend
-        let projects = match self.get_backend().await?.fetch_projects().await {
+        let projects = match backend.fetch_projects().await {
             Ok(projects) => {
                 info!("✅ Fetched {} projects from backend", projects.len());
                 projects
             }
             Err(e) => {
                 error!("❌ Failed to fetch projects: {e}");
-                return Ok(SyncStatus::Error {
-                    message: format!("Failed to fetch projects: {e}"),
-                });
+                return Err(SyncStatus::failed("Failed to fetch projects", &e.into()));
             }
         };
 
         // Fetch all tasks from backend
-        let tasks = match self.get_backend().await?.fetch_tasks().await {
+        let tasks = match backend.fetch_tasks().await {
             Ok(tasks) => {
                 info!("✅ Fetched {} tasks from backend", tasks.len());
                 tasks
             }
             Err(e) => {
                 error!("❌ Failed to fetch tasks: {e}");
-                return Ok(SyncStatus::Error {
-                    message: format!("Failed to fetch tasks: {e}"),
-                });
+                return Err(SyncStatus::failed("Failed to fetch tasks", &e.into()));
             }
         };
 
         // Fetch all labels from backend
-        let labels = match self.get_backend().await?.fetch_labels().await {
+        let labels = match backend.fetch_labels().await {
             Ok(labels) => {
                 info!("✅ Fetched {} labels from backend", labels.len());
                 labels
             }
             Err(e) => {
                 error!("❌ Failed to fetch labels: {e}");
-                return Ok(SyncStatus::Error {
-                    message: format!("Failed to fetch labels: {e}"),
-                });
+                return Err(SyncStatus::failed("Failed to fetch labels", &e.into()));
             }
         };
 
         // Fetch all sections from backend
-        let sections = match self.get_backend().await?.fetch_sections().await {
+        let (sections, is_full) = match backend.fetch_sections().await {
             Ok(sections) => {
                 info!("✅ Fetched {} sections from backend", sections.len());
-                sections
+                (sections, true)
             }
             Err(e) => {
                 error!("❌ Failed to fetch sections: {e}");
                 info!("⚠️  Skipping sections sync due to backend compatibility issue");
-                // For now, skip sections sync and continue with other data
-                Vec::new()
+                // For now, skip sections sync and continue with other data. The snapshot is
+                // incomplete then, so it must not be used to prune local rows.
+                (Vec::new(), false)
             }
         };
 
-        // Store in local database
-        {
-            let storage = self.storage.lock().await;
-            info!("💾 Storing data in local database...");
+        Ok(BackendChanges {
+            cursor: None,
+            is_full,
+            projects,
+            tasks,
+            labels,
+            sections,
+            ..Default::default()
+        })
+    }
 
-            // Store projects
-            if let Err(e) = self.store_projects_batch(&storage, &projects).await {
-                error!("❌ Failed to store projects: {e}");
-                return Ok(SyncStatus::Error {
-                    message: format!("Failed to store projects: {e}"),
-                });
-            }
-            info!("✅ Stored projects in database");
+    /// Store fetched changes in the local database and remember the new sync cursor.
+    ///
+    /// Rows removed remotely are pruned afterwards, see [`Self::remove_batch`].
+    pub async fn store_changes(&self, changes: BackendChanges) -> Result<SyncStatus> {
+        let storage = self.storage.lock().await;
+        info!("💾 Storing data in local database...");
 
-            // Store labels BEFORE tasks so task-label relationships can be created
-            if let Err(e) = self.store_labels_batch(&storage, &labels).await {
-                error!("❌ Failed to store labels: {e}");
-                return Ok(SyncStatus::Error {
-                    message: format!("Failed to store labels: {e}"),
-                });
-            }
-            info!("✅ Stored labels in database");
-
-            // Store sections BEFORE tasks since tasks have foreign key references to sections
-            if !sections.is_empty() {
-                if let Err(e) = self.store_sections_batch(&storage, &sections).await {
-                    error!("❌ Failed to store sections: {e}");
-                    return Ok(SyncStatus::Error {
-                        message: format!("Failed to store sections: {e}"),
-                    });
-                }
-                info!("✅ Stored sections in database");
-            } else {
-                info!("⚠️  No sections to store (skipped due to backend issue)");
-            }
+        // Store projects
+        if let Err(e) = self.store_projects_batch(&storage, &changes.projects).await {
+            error!("❌ Failed to store projects: {e}");
+            return Ok(SyncStatus::Error {
+                message: format!("Failed to store projects: {e}"),
+            });
+        }
+        info!("✅ Stored projects in database");
+
+        // Store labels BEFORE tasks so task-label relationships can be created
+        if let Err(e) = self.store_labels_batch(&storage, &changes.labels).await {
+            error!("❌ Failed to store labels: {e}");
+            return Ok(SyncStatus::Error {
+                message: format!("Failed to store labels: {e}"),
+            });
+        }
+        info!("✅ Stored labels in database");
 
-            if let Err(e) = self.store_tasks_batch(&storage, &tasks).await {
-                error!("❌ Failed to store tasks: {e}");
+        // Store sections BEFORE tasks since tasks have foreign key references to sections
+        if !changes.sections.is_empty() {
+            if let Err(e) = self.store_sections_batch(&storage, &changes.sections).await {
+                error!("❌ Failed to store sections: {e}");
                 return Ok(SyncStatus::Error {
-                    message: format!("Failed to store tasks: {e}"),
+                    message: format!("Failed to store sections: {e}"),
                 });
             }
-            info!("✅ Stored tasks in database");
+            info!("✅ Stored sections in database");
+        } else {
+            info!("⚠️  No sections to store");
+        }
+
+        if let Err(e) = self.store_tasks_batch(&storage, &changes.tasks).await {
+            error!("❌ Failed to store tasks: {e}");
+            return Ok(SyncStatus::Error {
+                message: format!("Failed to store tasks: {e}"),
+            });
         }
+        info!("✅ Stored tasks in database");
+
+        // Store comments AFTER tasks since comments reference their task
+        if let Err(e) = self.store_comments_batch(&storage, &changes.comments).await {
+            error!("❌ Failed to store comments: {e}");
+            return Ok(SyncStatus::Error {
+                message: format!("Failed to store comments: {e}"),
+            });
+        }
+
+        if let Err(e) = self.remove_batch(&storage, &changes).await {
+            error!("❌ Failed to remove deleted items: {e}");
+            return Ok(SyncStatus::Error {
+                message: format!("Failed to remove deleted items: {e}"),
+            });
+        }
+
+        // Only advance the cursor once everything it covers has been stored
+        BackendRepository::set_sync_cursor(&storage.conn, &self.backend_uuid, changes.cursor.as_deref()).await?;
 
         Ok(SyncStatus::Success)
     }
@@ -0,0 +1,439 @@
+//! Outbox of local mutations waiting to be pushed to the remote backend.
+//!
+//! Mutations are applied to local storage right away and recorded as pending operations
+//! in the same transaction. The outbox is then replayed in order against the backend
+//! whenever it is reachable. Entities created while offline get a temporary remote ID,
+//! which is swapped for the real one once the backend has created them.
+
+use anyhow::Result;
+use log::{info, warn};
+use sea_orm::{ActiveValue, ConnectionTrait, IntoActiveModel, TransactionTrait};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::backend::{
+    BackendError, BackendTask, CreateCommentArgs, CreateLabelArgs, CreateProjectArgs, CreateSectionArgs,
+    CreateTaskArgs, MoveTaskArgs, ProjectOrder, SectionOrder, TaskOrder, UpdateCommentArgs, UpdateLabelArgs,
+    UpdateProjectArgs, UpdateSectionArgs, UpdateTaskArgs,
+};
+use crate::entities::{comment, label, pending_operation, project, section, task};
+use crate::repositories::{
+    CommentRepository, LabelRepository, PendingOperationRepository, ProjectRepository, SectionRepository,
+    SyncConflictRepository, TaskRepository,
+};
+use crate::sync::conflicts::task_fields;
+use crate::sync::SyncService;
+
+/// Prefix of remote IDs assigned locally to entities the backend has not created yet.
+pub const TEMP_REMOTE_ID_PREFIX: &str = "tmp-";
+
+/// Generate a temporary remote ID for an entity created locally.
+pub fn new_temp_remote_id() -> String {
+    format!("{TEMP_REMOTE_ID_PREFIX}{}", Uuid::new_v4())
+}
+
+/// Generate the idempotency key of a create operation.
+///
+/// The key is minted when the operation is enqueued and stored in its payload, so every
+/// replay sends the same key and a create the backend already applied is not applied twice.
+pub fn new_request_id() -> String {
+    Uuid::new_v4().to_string()
+}
+
+/// Whether a remote ID is a temporary one that has not been replaced yet.
+pub fn is_temp_remote_id(remote_id: &str) -> bool {
+    remote_id.starts_with(TEMP_REMOTE_ID_PREFIX)
+}
+
+/// A mutation recorded in the outbox, serialized as the operation's payload.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "op", content = "args", rename_all = "snake_case")]
+pub enum OutboxOperation {
+    CreateTask(CreateTaskArgs),
+    UpdateTask(UpdateTaskArgs),
+    MoveTask(MoveTaskArgs),
+    ReorderTasks(Vec<TaskOrder>),
+    CompleteTask,
+    ReopenTask,
+    DeleteTask,
+    CreateProject(CreateProjectArgs),
+    UpdateProject(UpdateProjectArgs),
+    DeleteProject,
+    ReorderProjects(Vec<ProjectOrder>),
+    CreateLabel(CreateLabelArgs),
+    UpdateLabel(UpdateLabelArgs),
+    DeleteLabel,
+    CreateSection(CreateSectionArgs),
+    UpdateSection(UpdateSectionArgs),
+    DeleteSection,
+    ReorderSections(Vec<SectionOrder>),
+    CreateComment(CreateCommentArgs),
+    UpdateComment(UpdateCommentArgs),
+    DeleteComment,
+}
+
+impl OutboxOperation {
+    /// Short name of the operation, stored alongside the payload for logs and inspection.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CreateTask(_) => "create_task",
+            Self::UpdateTask(_) => "update_task",
+            Self::MoveTask(_) => "move_task",
+            Self::ReorderTasks(_) => "reorder_tasks",
+            Self::CompleteTask => "complete_task",
+            Self::ReopenTask => "reopen_task",
+            Self::DeleteTask => "delete_task",
+            Self::CreateProject(_) => "create_project",
+            Self::UpdateProject(_) => "update_project",
+            Self::DeleteProject => "delete_project",
+            Self::ReorderProjects(_) => "reorder_projects",
+            Self::CreateLabel(_) => "create_label",
+            Self::UpdateLabel(_) => "update_label",
+            Self::DeleteLabel => "delete_label",
+            Self::CreateSection(_) => "create_section",
+            Self::UpdateSection(_) => "update_section",
+            Self::DeleteSection => "delete_section",
+            Self::ReorderSections(_) => "reorder_sections",
+            Self::CreateComment(_) => "create_comment",
+            Self::UpdateComment(_) => "update_comment",
+            Self::DeleteComment => "delete_comment",
+        }
+    }
+}
+
+/// What a successful replay needs to write back to local storage.
+enum ReplayOutcome {
+    TaskCreated(BackendTask),
+    TaskDueResolved(BackendTask),
+    ProjectCreated(String),
+    LabelCreated(String),
+    SectionCreated(String),
+    CommentCreated(String),
+    Done,
+}
+
+impl SyncService {
+    /// Record a mutation in the outbox.
+    ///
+    /// Call this with the same transaction that applies the mutation locally, so the
+    /// local change and its pending operation are committed together.
+    pub(super) async fn enqueue_operation<C>(
+        &self,
+        conn: &C,
+        entity_uuid: &Uuid,
+        remote_id: &str,
+        operation: &OutboxOperation,
+    ) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        let payload = serde_json::to_string(operation)?;
+        PendingOperationRepository::enqueue(
+            conn,
+            &self.backend_uuid,
+            operation.name(),
+            entity_uuid,
+            remote_id,
+            payload,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Returns the number of local changes that have not been pushed to the backend yet.
+    pub async fn pending_changes_count(&self) -> Result<u64> {
+        let storage = self.storage.lock().await;
+        PendingOperationRepository::count_for_backend(&storage.conn, &self.backend_uuid).await
+    }
+
+    /// Replays all pending operations against the backend, oldest first.
+    ///
+    /// Replay stops at the first failure so later operations never overtake earlier ones;
+    /// the failed operation stays queued and is retried on the next flush. Operations on
+    /// entities the backend no longer knows are dropped instead. Operations of
+    /// entities with unresolved sync conflicts are held back until the conflicts are resolved.
+    ///
+    /// # Errors
+    /// Returns an error if an operation could not be replayed
+    pub async fn flush_outbox(&self) -> Result<()> {
+        // Only one flush at a time, otherwise an operation could be replayed twice
+        let _flush_guard = self.outbox_lock.lock().await;
+
+        let mut replayed = 0;
+        loop {
+            // Fetch one operation at a time, replaying one may rewrite temporary IDs in the next
+            let next = {
+                let storage = self.storage.lock().await;
+                let conflicted = SyncConflictRepository::get_entity_uuids(&storage.conn, &self.backend_uuid).await?;
+                PendingOperationRepository::get_next_excluding(&storage.conn, &self.backend_uuid, &conflicted).await?
+            };
+            let Some(pending) = next else { break };
+
+            if let Err(e) = self.replay_operation(&pending).await {
+                // The entity is gone remotely, so the change can never be applied there; the
+                // next sync prunes it locally
+                let is_create = pending.operation.starts_with("create_");
+                if !is_create && matches!(e.downcast_ref::<BackendError>(), Some(BackendError::NotFound(_))) {
+                    warn!(
+                        "Dropping {} for {}: no longer exists remotely",
+                        pending.operation, pending.remote_id
+                    );
+                    let storage = self.storage.lock().await;
+                    PendingOperationRepository::delete(&storage.conn, &pending.uuid).await?;
+                    continue;
+                }
+                warn!(
+                    "Failed to replay {} for {} (attempt {}): {}",
+                    pending.operation,
+                    pending.remote_id,
+                    pending.attempts + 1,
+                    e
+                );
+                let storage = self.storage.lock().await;
+                PendingOperationRepository::record_failure(&storage.conn, &pending.uuid, &e.to_string()).await?;
+                return Err(e);
+            }
+            replayed += 1;
+        }
+
+        if replayed > 0 {
+            info!("✅ Replayed {replayed} pending operation(s)");
+        }
+        Ok(())
+    }
+
+    /// Push local changes in the background of a mutation.
+    ///
+    /// Nothing is done while a sync is running, it replays the outbox itself; otherwise the
+    /// push holds the sync flag so a sync cannot start halfway through. Remote changes are
+    /// pulled first when the backend has a delta API and the last pull is older than
+    /// [`crate::constants::OUTBOX_PULL_MIN_INTERVAL_SECONDS`], so edits made elsewhere are
+    /// merged instead of overwritten. Failures are only logged: the change is already stored
+    /// locally and stays queued until the next successful flush.
+    pub(super) async fn try_flush_outbox(&self) {
+        if !self.begin_sync().await {
+            info!("Sync in progress, it pushes the local changes");
+            return;
+        }
+
+        let result = async {
+            if self.pull_is_stale().await {
+                self.pull_changes().await?;
+            }
+            self.flush_outbox().await
+        }
+        .await;
+        if let Err(e) = result {
+            warn!("Local changes kept in outbox: {e}");
+        }
+
+        self.end_sync().await;
+    }
+
+    /// Replay a single pending operation and write its result back to local storage.
+    async fn replay_operation(&self, pending: &pending_operation::Model) -> Result<()> {
+        let operation: OutboxOperation = serde_json::from_str(&pending.payload)?;
+        let backend = self.get_backend().await?;
+        let remote_id = pending.remote_id.as_str();
+
+        let outcome = match operation.clone() {
+            OutboxOperation::CreateTask(args) => ReplayOutcome::TaskCreated(backend.create_task(args).await?),
+            OutboxOperation::UpdateTask(args) => {
+                let resolves_due = args.due_string.is_some();
+                let updated = backend.update_task(remote_id, args).await?;
+                if resolves_due {
+                    ReplayOutcome::TaskDueResolved(updated)
+                } else {
+                    ReplayOutcome::Done
+                }
+            }
+            OutboxOperation::MoveTask(args) => {
+                backend.move_task(remote_id, args).await?;
+                ReplayOutcome::Done
+            }
+            OutboxOperation::ReorderTasks(orders) => {
+                backend.reorder_tasks(orders).await?;
+                ReplayOutcome::Done
+            }
+            OutboxOperation::CompleteTask => {
+                backend.complete_task(remote_id).await?;
+                let is_recurring = {
+                    let storage = self.storage.lock().await;
+                    TaskRepository::get_by_id(&storage.conn, &pending.entity_uuid)
+                        .await?
+                        .is_some_and(|task| task.is_recurring)
+                };
+                // A recurring task moved on to its next occurrence; the completion is already
+                // done, so failing to fetch the new due date only leaves it to the next sync
+                if is_recurring {
+                    match backend.fetch_task(remote_id).await {
+                        Ok(task) => ReplayOutcome::TaskDueResolved(task),
+                        Err(e) => {
+                            warn!("Failed to fetch the next occurrence of {}: {}", remote_id, e);
+                            ReplayOutcome::Done
+                        }
+                    }
+                } else {
+                    ReplayOutcome::Done
+                }
+            }
+            OutboxOperation::ReopenTask => {
+                backend.reopen_task(remote_id).await?;
+                ReplayOutcome::Done
+            }
+            OutboxOperation::DeleteTask => {
+                backend.delete_task(remote_id).await?;
+                ReplayOutcome::Done
+            }
+            OutboxOperation::CreateProject(args) => {
+                let created = backend.create_project(args).await?;
+                ReplayOutcome::ProjectCreated(created.remote_id)
+            }
+            OutboxOperation::UpdateProject(args) => {
+                backend.update_project(remote_id, args).await?;
+                ReplayOutcome::Done
+            }
+            OutboxOperation::DeleteProject => {
+                backend.delete_project(remote_id).await?;
+                ReplayOutcome::Done
+            }
+            OutboxOperation::ReorderProjects(orders) => {
+                backend.reorder_projects(orders).await?;
+                ReplayOutcome::Done
+            }
+            OutboxOperation::CreateLabel(args) => {
+                let created = backend.create_label(args).await?;
+                ReplayOutcome::LabelCreated(created.remote_id)
+            }
+            OutboxOperation::UpdateLabel(args) => {
+                backend.update_label(remote_id, args).await?;
+                ReplayOutcome::Done
+            }
+            OutboxOperation::DeleteLabel => {
+                backend.delete_label(remote_id).await?;
+                ReplayOutcome::Done
+            }
+            OutboxOperation::CreateSection(args) => {
+                let created = backend.create_section(args).await?;
+                ReplayOutcome::SectionCreated(created.remote_id)
+            }
+            OutboxOperation::UpdateSection(args) => {
+                backend.update_section(remote_id, args).await?;
+                ReplayOutcome::Done
+            }
+            OutboxOperation::DeleteSection => {
+                backend.delete_section(remote_id).await?;
+                ReplayOutcome::Done
+            }
+            OutboxOperation::ReorderSections(orders) => {
+                backend.reorder_sections(orders).await?;
+                ReplayOutcome::Done
+            }
+            OutboxOperation::CreateComment(args) => {
+                let created = backend.create_comment(args).await?;
+                ReplayOutcome::CommentCreated(created.remote_id)
+            }
+            OutboxOperation::UpdateComment(args) => {
+                backend.update_comment(remote_id, args).await?;
+                ReplayOutcome::Done
+            }
+            OutboxOperation::DeleteComment => {
+                backend.delete_comment(remote_id).await?;
+                ReplayOutcome::Done
+            }
+        };
+
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+
+        // The backend now holds the pushed values, they are the base for merging later edits
+        let pushed = match &outcome {
+            ReplayOutcome::TaskCreated(task) | ReplayOutcome::TaskDueResolved(task) => Some(task_fields(task)),
+            _ => None,
+        };
+        Self::advance_snapshot(&txn, &pending.entity_uuid, &operation, pushed).await?;
+
+        // A created entity only takes the real ID if it still carries the temporary one;
+        // restoring a deleted task assigns it a fresh temporary ID for its re-creation.
+        match outcome {
+            ReplayOutcome::TaskCreated(created) => {
+                if let Some(local) = TaskRepository::get_by_id(&txn, &pending.entity_uuid)
+                    .await?
+                    .filter(|local| local.remote_id == remote_id)
+                {
+                    let mut active_model: task::ActiveModel = local.into_active_model();
+                    active_model.remote_id = ActiveValue::Set(created.remote_id.clone());
+                    active_model.order_index = ActiveValue::Set(created.order_index);
+                    active_model.due_date = ActiveValue::Set(created.due_date);
+                    active_model.due_datetime = ActiveValue::Set(created.due_datetime);
+                    active_model.is_recurring = ActiveValue::Set(created.is_recurring);
+                    active_model.due_string = ActiveValue::Set(created.due_string);
+                    TaskRepository::update(&txn, active_model).await?;
+                }
+                PendingOperationRepository::rewrite_remote_id(&txn, &self.backend_uuid, remote_id, &created.remote_id)
+                    .await?;
+            }
+            ReplayOutcome::TaskDueResolved(updated) => {
+                if let Some(local) = TaskRepository::get_by_id(&txn, &pending.entity_uuid).await? {
+                    let mut active_model: task::ActiveModel = local.into_active_model();
+                    active_model.due_date = ActiveValue::Set(updated.due_date);
+                    active_model.due_datetime = ActiveValue::Set(updated.due_datetime);
+                    active_model.is_recurring = ActiveValue::Set(updated.is_recurring);
+                    active_model.due_string = ActiveValue::Set(updated.due_string);
+                    active_model.deadline = ActiveValue::Set(updated.deadline);
+                    TaskRepository::update(&txn, active_model).await?;
+                }
+            }
+            ReplayOutcome::ProjectCreated(real_id) => {
+                if let Some(local) = ProjectRepository::get_by_id(&txn, &pending.entity_uuid)
+                    .await?
+                    .filter(|local| local.remote_id == remote_id)
+                {
+                    let mut active_model: project::ActiveModel = local.into_active_model();
+                    active_model.remote_id = ActiveValue::Set(real_id.clone());
+                    ProjectRepository::update(&txn, active_model).await?;
+                }
+                PendingOperationRepository::rewrite_remote_id(&txn, &self.backend_uuid, remote_id, &real_id).await?;
+            }
+            ReplayOutcome::LabelCreated(real_id) => {
+                if let Some(local) = LabelRepository::get_by_id(&txn, &pending.entity_uuid)
+                    .await?
+                    .filter(|local| local.remote_id == remote_id)
+                {
+                    let mut active_model: label::ActiveModel = local.into_active_model();
+                    active_model.remote_id = ActiveValue::Set(real_id.clone());
+                    LabelRepository::update(&txn, active_model).await?;
+                }
+                PendingOperationRepository::rewrite_remote_id(&txn, &self.backend_uuid, remote_id, &real_id).await?;
+            }
+            ReplayOutcome::SectionCreated(real_id) => {
+                if let Some(local) = SectionRepository::get_by_id(&txn, &pending.entity_uuid)
+                    .await?
+                    .filter(|local| local.remote_id == remote_id)
+                {
+                    let mut active_model: section::ActiveModel = local.into_active_model();
+                    active_model.remote_id = ActiveValue::Set(real_id.clone());
+                    SectionRepository::update(&txn, active_model).await?;
+                }
+                PendingOperationRepository::rewrite_remote_id(&txn, &self.backend_uuid, remote_id, &real_id).await?;
+            }
+            ReplayOutcome::CommentCreated(real_id) => {
+                if let Some(local) = CommentRepository::get_by_id(&txn, &pending.entity_uuid)
+                    .await?
+                    .filter(|local| local.remote_id == remote_id)
+                {
+                    let mut active_model: comment::ActiveModel = local.into_active_model();
+                    active_model.remote_id = ActiveValue::Set(real_id.clone());
+                    CommentRepository::update(&txn, active_model).await?;
+                }
+                PendingOperationRepository::rewrite_remote_id(&txn, &self.backend_uuid, remote_id, &real_id).await?;
+            }
+            ReplayOutcome::Done => {}
+        }
+
+        PendingOperationRepository::delete(&txn, &pending.uuid).await?;
+        txn.commit().await?;
+
+        Ok(())
+    }
+}
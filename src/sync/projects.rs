@@ -1,9 +1,11 @@
+use crate::backend::ProjectOrder;
 use crate::entities::project;
 use crate::repositories::ProjectRepository;
+use crate::sync::outbox::{new_request_id, new_temp_remote_id, OutboxOperation};
 use crate::sync::SyncService;
 use anyhow::Result;
-use log::warn;
-use sea_orm::{ActiveValue, EntityTrait, IntoActiveModel};
+use log::info;
+use sea_orm::{ActiveValue, EntityTrait, IntoActiveModel, TransactionTrait};
 use uuid::Uuid;
 
 impl SyncService {
@@ -26,11 +28,10 @@ impl SyncService {
         ProjectRepository::get_all(&storage.conn).await
     }
 
-    /// Creates a new project via the remote backend and stores it locally.
+    /// Creates a new project locally and queues it for creation on the remote backend.
     ///
-    /// This method creates a project remotely and immediately stores it in local storage
-    /// for instant UI updates. The project will be available in the UI without requiring
-    /// a full sync operation.
+    /// The project is stored immediately with a temporary remote ID so it is available in
+    /// the UI right away, even while offline.
     ///
     /// # Arguments
     /// * `name` - The name of the new project
@@ -41,118 +42,168 @@ impl SyncService {
     /// but the GET /projects backend endpoint will only return the first 5 projects for free users.
     ///
     /// # Errors
-    /// Returns an error if the backend call fails or local storage update fails
+    /// Returns an error if the parent project does not exist or local storage update fails
     pub async fn create_project(&self, name: &str, parent_uuid: Option<Uuid>) -> Result<()> {
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+
         // Look up remote_id for parent project if provided
         let remote_parent_id = if let Some(uuid) = parent_uuid {
-            Some(self.get_project_remote_id(&uuid).await?)
+            Some(ProjectRepository::get_remote_id(&txn, &uuid).await?)
         } else {
             None
         };
 
-        // Create project via backend using backend CreateProjectArgs
-        let project_args = crate::backend::CreateProjectArgs {
-            name: name.to_string(),
-            parent_remote_id: remote_parent_id,
-            is_favorite: None,
-        };
-        let backend_project = self
-            .get_backend()
-            .await?
-            .create_project(project_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
-
-        // Store the created project in local database immediately for UI refresh
-        let storage = self.storage.lock().await;
+        let project_uuid = Uuid::new_v4();
+        let remote_id = new_temp_remote_id();
 
-        // Upsert the project
         let local_project = project::ActiveModel {
-            uuid: ActiveValue::Set(Uuid::new_v4()),
+            uuid: ActiveValue::Set(project_uuid),
             backend_uuid: ActiveValue::Set(self.backend_uuid),
-            remote_id: ActiveValue::Set(backend_project.remote_id),
-            name: ActiveValue::Set(backend_project.name),
-            is_favorite: ActiveValue::Set(backend_project.is_favorite),
-            is_inbox_project: ActiveValue::Set(backend_project.is_inbox),
-            order_index: ActiveValue::Set(backend_project.order_index),
+            remote_id: ActiveValue::Set(remote_id.clone()),
+            name: ActiveValue::Set(name.to_string()),
+            is_favorite: ActiveValue::Set(false),
+            is_inbox_project: ActiveValue::Set(false),
+            order_index: ActiveValue::Set(0),
             parent_uuid: ActiveValue::Set(parent_uuid),
+            color: ActiveValue::Set(None),
+            remote_snapshot: ActiveValue::Set(None),
         };
+        project::Entity::insert(local_project).exec(&txn).await?;
 
-        use sea_orm::sea_query::OnConflict;
-        let mut insert = project::Entity::insert(local_project);
-        insert = insert.on_conflict(
-            OnConflict::columns([project::Column::BackendUuid, project::Column::RemoteId])
-                .update_columns([
-                    project::Column::Name,
-                    project::Column::IsFavorite,
-                    project::Column::IsInboxProject,
-                    project::Column::OrderIndex,
-                ])
-                .to_owned(),
-        );
-        insert.exec(&storage.conn).await?;
-
+        let project_args = crate::backend::CreateProjectArgs {
+            name: name.to_string(),
+            parent_remote_id: remote_parent_id,
+            is_favorite: None,
+            request_id: Some(new_request_id()),
+        };
+        self.enqueue_operation(
+            &txn,
+            &project_uuid,
+            &remote_id,
+            &OutboxOperation::CreateProject(project_args),
+        )
+        .await?;
+
+        txn.commit().await?;
+        drop(storage);
+
+        self.try_flush_outbox().await;
         Ok(())
     }
 
     /// Update project content (name only for now)
     pub async fn update_project_content(&self, project_uuid: &Uuid, name: &str) -> Result<()> {
-        // Look up the project's remote_id for backend call
-        let remote_id = self.get_project_remote_id(project_uuid).await?;
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
 
-        // Update project via backend using the UpdateProjectArgs structure
-        let project_args = crate::backend::UpdateProjectArgs {
-            name: Some(name.to_string()),
-            is_favorite: None,
-        };
-        let _project = self
-            .get_backend()
+        let project = ProjectRepository::get_by_id(&txn, project_uuid)
             .await?
-            .update_project(&remote_id, project_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+            .ok_or_else(|| anyhow::anyhow!("Project not found: {}", project_uuid))?;
+        let remote_id = project.remote_id.clone();
 
-        // Update local storage immediately after successful backend call
-        let storage = self.storage.lock().await;
-
-        if let Some(project) = ProjectRepository::get_by_id(&storage.conn, project_uuid).await? {
-            let mut active_model: project::ActiveModel = project.into_active_model();
-            active_model.name = ActiveValue::Set(name.to_string());
-            ProjectRepository::update(&storage.conn, active_model).await?;
-        } else {
-            warn!(
-                "Local project with UUID {} not found after successful backend update.",
-                project_uuid
-            );
-        }
+        let mut active_model: project::ActiveModel = project.into_active_model();
+        active_model.name = ActiveValue::Set(name.to_string());
+        ProjectRepository::update(&txn, active_model).await?;
 
+        let project_args = crate::backend::UpdateProjectArgs {
+            name: Some(name.to_string()),
+            ..Default::default()
+        };
+        self.enqueue_operation(
+            &txn,
+            project_uuid,
+            &remote_id,
+            &OutboxOperation::UpdateProject(project_args),
+        )
+        .await?;
+
+        txn.commit().await?;
+        drop(storage);
+
+        self.try_flush_outbox().await;
         Ok(())
     }
 
     /// Delete a project
     pub async fn delete_project(&self, project_uuid: &Uuid) -> Result<()> {
-        // Look up the project's remote_id for backend call
-        let remote_id = self.get_project_remote_id(project_uuid).await?;
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
 
-        // Delete project via backend
-        self.get_backend()
+        let project = ProjectRepository::get_by_id(&txn, project_uuid)
             .await?
-            .delete_project(&remote_id)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+            .ok_or_else(|| anyhow::anyhow!("Project not found: {}", project_uuid))?;
+        let remote_id = project.remote_id.clone();
+
+        // Tasks and sections of the project are removed via database CASCADE constraints
+        ProjectRepository::delete(&txn, project).await?;
+
+        self.enqueue_operation(&txn, project_uuid, &remote_id, &OutboxOperation::DeleteProject)
+            .await?;
+
+        txn.commit().await?;
+        drop(storage);
+
+        self.try_flush_outbox().await;
+        Ok(())
+    }
 
-        // Remove from local storage
+    /// Move a project up (negative offset) or down (positive offset) among the projects
+    /// sharing its parent.
+    ///
+    /// The siblings are renumbered in their new order and the whole order is queued as a
+    /// single reorder. Moving past the first or last position is a no-op.
+    ///
+    /// # Errors
+    /// Returns an error if the project does not exist or local storage update fails
+    pub async fn reorder_project(&self, project_uuid: &Uuid, offset: i32) -> Result<()> {
         let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
 
-        if let Some(project) = ProjectRepository::get_by_id(&storage.conn, project_uuid).await? {
-            ProjectRepository::delete(&storage.conn, project).await?;
-        } else {
-            warn!(
-                "Local project with UUID {} not found after successful backend deletion.",
-                project_uuid
-            );
+        let project = ProjectRepository::get_by_id(&txn, project_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Project not found: {}", project_uuid))?;
+
+        let mut siblings = ProjectRepository::get_siblings(&txn, project.parent_uuid).await?;
+        let Some(current) = siblings.iter().position(|p| p.uuid == *project_uuid) else {
+            return Ok(());
+        };
+        let target = current
+            .saturating_add_signed(offset as isize)
+            .min(siblings.len().saturating_sub(1));
+        if target == current {
+            return Ok(());
+        }
+        let moved = siblings.remove(current);
+        siblings.insert(target, moved);
+
+        let mut orders = Vec::with_capacity(siblings.len());
+        for (position, sibling) in siblings.into_iter().enumerate() {
+            let order_index = position as i32 + 1;
+            orders.push(ProjectOrder {
+                remote_id: sibling.remote_id.clone(),
+                order_index,
+            });
+            if sibling.order_index != order_index {
+                let mut active_model: project::ActiveModel = sibling.into_active_model();
+                active_model.order_index = ActiveValue::Set(order_index);
+                ProjectRepository::update(&txn, active_model).await?;
+            }
         }
 
+        info!("Storage: Moving project {} to position {}", project_uuid, target + 1);
+        self.enqueue_operation(
+            &txn,
+            project_uuid,
+            &project.remote_id,
+            &OutboxOperation::ReorderProjects(orders),
+        )
+        .await?;
+
+        txn.commit().await?;
+        drop(storage);
+
+        self.try_flush_outbox().await;
         Ok(())
     }
 }
@@ -1,7 +1,11 @@
-use crate::entities::section;
-use crate::repositories::SectionRepository;
+use crate::backend::{CreateSectionArgs, SectionOrder, UpdateSectionArgs};
+use crate::entities::{section, task};
+use crate::repositories::{ProjectRepository, SectionRepository};
+use crate::sync::outbox::{new_request_id, new_temp_remote_id, OutboxOperation};
 use crate::sync::SyncService;
 use anyhow::Result;
+use log::info;
+use sea_orm::{ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter, TransactionTrait};
 use uuid::Uuid;
 
 impl SyncService {
@@ -16,4 +20,185 @@ impl SyncService {
         let storage = self.storage.lock().await;
         SectionRepository::get_for_project(&storage.conn, project_uuid).await
     }
+
+    /// Creates a new section at the end of a project and queues it for creation on the remote backend.
+    ///
+    /// The section is stored immediately with a temporary remote ID so it is available in
+    /// the UI right away, even while offline.
+    ///
+    /// # Arguments
+    /// * `project_uuid` - The project the section belongs to
+    /// * `name` - The name of the new section
+    ///
+    /// # Errors
+    /// Returns an error if the project does not exist or local storage update fails
+    pub async fn create_section(&self, project_uuid: &Uuid, name: &str) -> Result<()> {
+        info!("Storage: Creating section '{}' in project {}", name, project_uuid);
+
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+
+        let project_remote_id = ProjectRepository::get_remote_id(&txn, project_uuid).await?;
+        let order_index = SectionRepository::get_max_order_index(&txn, project_uuid)
+            .await?
+            .map_or(1, |max| max + 1);
+
+        let section_uuid = Uuid::new_v4();
+        let remote_id = new_temp_remote_id();
+
+        let local_section = section::ActiveModel {
+            uuid: ActiveValue::Set(section_uuid),
+            backend_uuid: ActiveValue::Set(self.backend_uuid),
+            remote_id: ActiveValue::Set(remote_id.clone()),
+            name: ActiveValue::Set(name.to_string()),
+            project_uuid: ActiveValue::Set(*project_uuid),
+            order_index: ActiveValue::Set(order_index),
+            remote_snapshot: ActiveValue::Set(None),
+        };
+        section::Entity::insert(local_section).exec(&txn).await?;
+
+        let section_args = CreateSectionArgs {
+            name: name.to_string(),
+            project_remote_id,
+            order_index: Some(order_index),
+            request_id: Some(new_request_id()),
+        };
+        self.enqueue_operation(
+            &txn,
+            &section_uuid,
+            &remote_id,
+            &OutboxOperation::CreateSection(section_args),
+        )
+        .await?;
+
+        txn.commit().await?;
+        drop(storage);
+
+        self.try_flush_outbox().await;
+        Ok(())
+    }
+
+    /// Update section content (name only for now)
+    pub async fn update_section_content(&self, section_uuid: &Uuid, name: &str) -> Result<()> {
+        info!(
+            "Storage: Updating local section name for UUID {} to '{}'",
+            section_uuid, name
+        );
+
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+
+        let section = SectionRepository::get_by_id(&txn, section_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Section not found: {}", section_uuid))?;
+        let remote_id = section.remote_id.clone();
+
+        let mut active_model: section::ActiveModel = section.into_active_model();
+        active_model.name = ActiveValue::Set(name.to_string());
+        SectionRepository::update(&txn, active_model).await?;
+
+        let section_args = UpdateSectionArgs {
+            name: Some(name.to_string()),
+        };
+        self.enqueue_operation(
+            &txn,
+            section_uuid,
+            &remote_id,
+            &OutboxOperation::UpdateSection(section_args),
+        )
+        .await?;
+
+        txn.commit().await?;
+        drop(storage);
+
+        self.try_flush_outbox().await;
+        Ok(())
+    }
+
+    /// Delete a section together with its tasks, as the backend does
+    pub async fn delete_section(&self, section_uuid: &Uuid) -> Result<()> {
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+
+        let section = SectionRepository::get_by_id(&txn, section_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Section not found: {}", section_uuid))?;
+        let remote_id = section.remote_id.clone();
+
+        // Subtasks and label assignments are removed via database CASCADE constraints; the
+        // section reference itself would only be cleared, so its tasks are deleted explicitly
+        task::Entity::delete_many()
+            .filter(task::Column::SectionUuid.eq(*section_uuid))
+            .exec(&txn)
+            .await?;
+        SectionRepository::delete(&txn, section).await?;
+
+        self.enqueue_operation(&txn, section_uuid, &remote_id, &OutboxOperation::DeleteSection)
+            .await?;
+
+        txn.commit().await?;
+        drop(storage);
+
+        self.try_flush_outbox().await;
+        Ok(())
+    }
+
+    /// Move a section up (negative offset) or down (positive offset) within its project.
+    ///
+    /// The sections of the project are renumbered in their new order and the whole order is
+    /// queued as a single reorder, so the backend ends up with the same order as the UI.
+    /// Moving past the first or last position is a no-op.
+    ///
+    /// # Errors
+    /// Returns an error if the section does not exist or local storage update fails
+    pub async fn move_section(&self, section_uuid: &Uuid, offset: i32) -> Result<()> {
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+
+        let section = SectionRepository::get_by_id(&txn, section_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Section not found: {}", section_uuid))?;
+
+        let mut sections = SectionRepository::get_for_project(&txn, &section.project_uuid).await?;
+        let Some(current) = sections.iter().position(|s| s.uuid == *section_uuid) else {
+            return Ok(());
+        };
+        let target = current
+            .saturating_add_signed(offset as isize)
+            .min(sections.len().saturating_sub(1));
+        if target == current {
+            return Ok(());
+        }
+        let moved = sections.remove(current);
+        sections.insert(target, moved);
+
+        let mut orders = Vec::with_capacity(sections.len());
+        for (position, section) in sections.into_iter().enumerate() {
+            let order_index = position as i32 + 1;
+            orders.push(SectionOrder {
+                remote_id: section.remote_id.clone(),
+                order_index,
+            });
+            if section.order_index != order_index {
+                let mut active_model: section::ActiveModel = section.into_active_model();
+                active_model.order_index = ActiveValue::Set(order_index);
+                SectionRepository::update(&txn, active_model).await?;
+            }
+        }
+
+        info!("Storage: Moving section {} to position {}", section_uuid, target + 1);
+        self.enqueue_operation(
+            &txn,
+            section_uuid,
+            &section.remote_id,
+            &OutboxOperation::ReorderSections(orders),
+        )
+        .await?;
+
+        txn.commit().await?;
+        drop(storage);
+
+        self.try_flush_outbox().await;
+        Ok(())
+    }
 }
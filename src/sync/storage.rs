@@ -1,9 +1,17 @@
-use crate::entities::{label, project, section, task, task_label};
-use crate::repositories::{LabelRepository, ProjectRepository, SectionRepository, TaskRepository};
+use crate::entities::{comment, label, project, section, task, task_label};
+use crate::repositories::{
+    CommentRepository, LabelRepository, PendingOperationRepository, ProjectRepository, SectionRepository,
+    TaskRepository,
+};
 use crate::storage::LocalStorage;
+use crate::sync::conflicts::{self, LocalEntity};
+use crate::sync::outbox::{is_temp_remote_id, OutboxOperation};
+use crate::sync::tasks;
 use crate::sync::SyncService;
 use anyhow::Result;
-use sea_orm::{ActiveValue, ColumnTrait, EntityTrait, QueryFilter, TransactionTrait};
+use sea_orm::sea_query::Expr;
+use sea_orm::{ActiveValue, ColumnTrait, Condition, EntityTrait, QueryFilter, QuerySelect, TransactionTrait};
+use std::collections::HashSet;
 use uuid::Uuid;
 
 impl SyncService {
@@ -62,6 +70,57 @@ impl SyncService {
         }
     }
 
+    /// UUIDs of the entities with local changes that have not been pushed yet.
+    pub(super) async fn protected_entities<C>(&self, conn: &C) -> Result<HashSet<Uuid>>
+    where
+        C: sea_orm::ConnectionTrait,
+    {
+        Ok(PendingOperationRepository::get_entity_uuids(conn, &self.backend_uuid)
+            .await?
+            .into_iter()
+            .collect())
+    }
+
+    /// Apply the positions of queued task and project reorders over freshly pulled rows.
+    ///
+    /// A reorder renumbers every sibling but is queued for the moved entity only, so the
+    /// siblings are not protected and would otherwise fall back to the remote order.
+    async fn reapply_pending_reorders<C>(&self, conn: &C) -> Result<()>
+    where
+        C: sea_orm::ConnectionTrait,
+    {
+        let reorders = PendingOperationRepository::get_for_backend(conn, &self.backend_uuid)
+            .await?
+            .into_iter()
+            .filter(|pending| matches!(pending.operation.as_str(), "reorder_tasks" | "reorder_projects"));
+        for pending in reorders {
+            match serde_json::from_str(&pending.payload)? {
+                OutboxOperation::ReorderTasks(orders) => {
+                    for order in orders {
+                        task::Entity::update_many()
+                            .col_expr(task::Column::OrderIndex, Expr::value(order.order_index))
+                            .filter(task::Column::BackendUuid.eq(self.backend_uuid))
+                            .filter(task::Column::RemoteId.eq(order.remote_id))
+                            .exec(conn)
+                            .await?;
+                    }
+                }
+                OutboxOperation::ReorderProjects(orders) => {
+                    for order in orders {
+                        project::Entity::update_many()
+                            .col_expr(project::Column::OrderIndex, Expr::value(order.order_index))
+                            .filter(project::Column::BackendUuid.eq(self.backend_uuid))
+                            .filter(project::Column::RemoteId.eq(order.remote_id))
+                            .exec(conn)
+                            .await?;
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
     /// Store projects in batch
     pub(super) async fn store_projects_batch(
         &self,
@@ -71,9 +130,16 @@ impl SyncService {
         use sea_orm::sea_query::OnConflict;
 
         let txn = storage.conn.begin().await?;
+        let protected = self.protected_entities(&txn).await?;
 
         // First pass: Upsert all projects without parent_uuid relationships
         for backend_project in projects {
+            let remote_fields = conflicts::project_fields(backend_project);
+            let pending_local =
+                ProjectRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_project.remote_id)
+                    .await?
+                    .filter(|local| protected.contains(&local.uuid));
+
             let local_project = project::ActiveModel {
                 uuid: ActiveValue::Set(Uuid::new_v4()),
                 backend_uuid: ActiveValue::Set(self.backend_uuid),
@@ -83,6 +149,8 @@ impl SyncService {
                 is_inbox_project: ActiveValue::Set(backend_project.is_inbox),
                 order_index: ActiveValue::Set(backend_project.order_index),
                 parent_uuid: ActiveValue::Set(None),
+                color: ActiveValue::Set(backend_project.color.clone()),
+                remote_snapshot: ActiveValue::Set(Some(conflicts::snapshot(&remote_fields))),
             };
 
             let mut insert = project::Entity::insert(local_project);
@@ -94,10 +162,24 @@ impl SyncService {
                         project::Column::IsInboxProject,
                         project::Column::OrderIndex,
                         project::Column::ParentUuid,
+                        project::Column::Color,
+                        project::Column::RemoteSnapshot,
                     ])
                     .to_owned(),
             );
             insert.exec(&txn).await?;
+
+            // Local edits not pushed yet are merged with the remote values instead of overwritten
+            if let Some(local) = pending_local {
+                let merged = self
+                    .merge_remote_fields(&txn, LocalEntity::Project(&local), &remote_fields)
+                    .await?;
+                let mut active_model: project::ActiveModel = local.into();
+                conflicts::apply_project_fields(&mut active_model, &merged);
+                // Colors can't be edited locally, the remote value always wins
+                active_model.color = ActiveValue::Set(backend_project.color.clone());
+                ProjectRepository::update(&txn, active_model).await?;
+            }
         }
 
         // Second pass: Update parent_uuid references to use local UUIDs
@@ -118,6 +200,8 @@ impl SyncService {
             }
         }
 
+        self.reapply_pending_reorders(&txn).await?;
+
         txn.commit().await?;
         Ok(())
     }
@@ -131,8 +215,14 @@ impl SyncService {
         use sea_orm::sea_query::OnConflict;
 
         let txn = storage.conn.begin().await?;
+        let protected = self.protected_entities(&txn).await?;
 
         for backend_label in labels {
+            let remote_fields = conflicts::label_fields(backend_label);
+            let pending_local = LabelRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_label.remote_id)
+                .await?
+                .filter(|local| protected.contains(&local.uuid));
+
             let local_label = label::ActiveModel {
                 uuid: ActiveValue::Set(Uuid::new_v4()),
                 backend_uuid: ActiveValue::Set(self.backend_uuid),
@@ -140,15 +230,33 @@ impl SyncService {
                 name: ActiveValue::Set(backend_label.name.clone()),
                 order_index: ActiveValue::Set(backend_label.order_index),
                 is_favorite: ActiveValue::Set(backend_label.is_favorite),
+                color: ActiveValue::Set(backend_label.color.clone()),
+                remote_snapshot: ActiveValue::Set(Some(conflicts::snapshot(&remote_fields))),
             };
 
             let mut insert = label::Entity::insert(local_label);
             insert = insert.on_conflict(
                 OnConflict::columns([label::Column::BackendUuid, label::Column::RemoteId])
-                    .update_columns([label::Column::Name, label::Column::OrderIndex, label::Column::IsFavorite])
+                    .update_columns([
+                        label::Column::Name,
+                        label::Column::OrderIndex,
+                        label::Column::IsFavorite,
+                        label::Column::Color,
+                        label::Column::RemoteSnapshot,
+                    ])
                     .to_owned(),
             );
             insert.exec(&txn).await?;
+
+            if let Some(local) = pending_local {
+                let merged = self
+                    .merge_remote_fields(&txn, LocalEntity::Label(&local), &remote_fields)
+                    .await?;
+                let mut active_model: label::ActiveModel = local.into();
+                conflicts::apply_label_fields(&mut active_model, &merged);
+                active_model.color = ActiveValue::Set(backend_label.color.clone());
+                LabelRepository::update(&txn, active_model).await?;
+            }
         }
 
         txn.commit().await?;
@@ -164,6 +272,7 @@ impl SyncService {
         use sea_orm::sea_query::OnConflict;
 
         let txn = storage.conn.begin().await?;
+        let protected = self.protected_entities(&txn).await?;
 
         // Track task labels for later processing
         let mut task_labels_map: Vec<(Uuid, Vec<String>)> = Vec::new();
@@ -192,6 +301,11 @@ impl SyncService {
             let section_uuid =
                 Self::lookup_section_uuid(&txn, &self.backend_uuid, backend_task.section_remote_id.as_ref()).await?;
 
+            let remote_fields = conflicts::task_fields(backend_task);
+            let pending_local = TaskRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_task.remote_id)
+                .await?
+                .filter(|local| protected.contains(&local.uuid));
+
             let local_task = task::ActiveModel {
                 uuid: ActiveValue::Set(Uuid::new_v4()),
                 backend_uuid: ActiveValue::Set(self.backend_uuid),
@@ -206,40 +320,79 @@ impl SyncService {
                 due_date: ActiveValue::Set(backend_task.due_date.clone()),
                 due_datetime: ActiveValue::Set(backend_task.due_datetime.clone()),
                 is_recurring: ActiveValue::Set(backend_task.is_recurring),
+                due_string: ActiveValue::Set(backend_task.due_string.clone()),
                 deadline: ActiveValue::Set(backend_task.deadline.clone()),
                 duration: ActiveValue::Set(backend_task.duration.clone()),
                 is_completed: ActiveValue::Set(backend_task.is_completed),
+                completed_at: ActiveValue::Set(backend_task.completed_at.clone()),
                 is_deleted: ActiveValue::Set(false),
+                remote_snapshot: ActiveValue::Set(Some(conflicts::snapshot(&remote_fields))),
+                // Local only, left alone when the task is updated from the backend
+                is_collapsed: ActiveValue::NotSet,
+                created_at: ActiveValue::Set(backend_task.created_at.clone()),
             };
 
+            let mut update_columns = vec![
+                task::Column::Content,
+                task::Column::Description,
+                task::Column::ProjectUuid,
+                task::Column::SectionUuid,
+                task::Column::ParentUuid,
+                task::Column::Priority,
+                task::Column::OrderIndex,
+                task::Column::DueDate,
+                task::Column::DueDatetime,
+                task::Column::IsRecurring,
+                task::Column::DueString,
+                task::Column::Deadline,
+                task::Column::Duration,
+                task::Column::IsCompleted,
+                task::Column::CompletedAt,
+                task::Column::IsDeleted,
+                task::Column::RemoteSnapshot,
+            ];
+            // Keep the local creation time of tasks added here when the backend does not report one
+            if backend_task.created_at.is_some() {
+                update_columns.push(task::Column::CreatedAt);
+            }
             let mut insert = task::Entity::insert(local_task);
             insert = insert.on_conflict(
                 OnConflict::columns([task::Column::BackendUuid, task::Column::RemoteId])
-                    .update_columns([
-                        task::Column::Content,
-                        task::Column::Description,
-                        task::Column::ProjectUuid,
-                        task::Column::SectionUuid,
-                        task::Column::ParentUuid,
-                        task::Column::Priority,
-                        task::Column::OrderIndex,
-                        task::Column::DueDate,
-                        task::Column::DueDatetime,
-                        task::Column::IsRecurring,
-                        task::Column::Deadline,
-                        task::Column::Duration,
-                        task::Column::IsCompleted,
-                        task::Column::IsDeleted,
-                    ])
+                    .update_columns(update_columns)
                     .to_owned(),
             );
             insert.exec(&txn).await?;
 
-            // Get the uuid of the task we just inserted/updated
+            if let Some(local) = pending_local {
+                let merged = self
+                    .merge_remote_fields(&txn, LocalEntity::Task(&local), &remote_fields)
+                    .await?;
+                // Only the merged fields, the local location and a pending local deletion override
+                // the fresh remote row
+                let is_deleted = local.is_deleted;
+                let completed_at = local.completed_at.clone();
+                let (project_uuid, section_uuid, parent_uuid) =
+                    (local.project_uuid, local.section_uuid, local.parent_uuid);
+                let mut active_model: task::ActiveModel = local.into();
+                conflicts::apply_task_fields(&mut active_model, &merged);
+                active_model.is_deleted = ActiveValue::Set(is_deleted);
+                active_model.project_uuid = ActiveValue::Set(project_uuid);
+                active_model.section_uuid = ActiveValue::Set(section_uuid);
+                active_model.parent_uuid = ActiveValue::Set(parent_uuid);
+                // The backend has no completion time yet for a completion that is still queued
+                if backend_task.completed_at.is_none() {
+                    active_model.completed_at = ActiveValue::Set(completed_at);
+                }
+                TaskRepository::update(&txn, active_model).await?;
+            }
+
+            // Get the uuid of the task we just inserted/updated, pending local label changes are kept
             if let Some(task) =
                 TaskRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_task.remote_id).await?
             {
-                task_labels_map.push((task.uuid, label_names));
+                if !protected.contains(&task.uuid) {
+                    task_labels_map.push((task.uuid, label_names));
+                }
             }
         }
 
@@ -250,7 +403,9 @@ impl SyncService {
                     TaskRepository::get_by_remote_id(&txn, &self.backend_uuid, remote_parent_id).await?
                 {
                     if let Some(task) =
-                        TaskRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_task.remote_id).await?
+                        TaskRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_task.remote_id)
+                            .await?
+                            .filter(|task| !protected.contains(&task.uuid))
                     {
                         let mut active_model: task::ActiveModel = task.into();
                         active_model.parent_uuid = ActiveValue::Set(Some(parent.uuid));
@@ -260,17 +415,21 @@ impl SyncService {
             }
         }
 
-        // Delete task-label relationships only for tasks being synced
-        for backend_task in tasks {
-            if let Some(task) =
-                TaskRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_task.remote_id).await?
-            {
-                task_label::Entity::delete_many()
-                    .filter(task_label::Column::TaskUuid.eq(task.uuid))
-                    .exec(&txn)
-                    .await?;
+        // Subtasks always follow their parent, so a pending local move holds back their remote location too
+        for uuid in &protected {
+            if let Some(task) = TaskRepository::get_by_id(&txn, uuid).await? {
+                tasks::move_subtree(&txn, task.uuid, task.project_uuid, task.section_uuid).await?;
             }
         }
+        self.reapply_pending_reorders(&txn).await?;
+
+        // Delete task-label relationships only for tasks being synced
+        for (task_uuid, _) in &task_labels_map {
+            task_label::Entity::delete_many()
+                .filter(task_label::Column::TaskUuid.eq(*task_uuid))
+                .exec(&txn)
+                .await?;
+        }
 
         // Recreate relationships
         for (task_uuid, label_names) in task_labels_map {
@@ -311,8 +470,15 @@ impl SyncService {
         use sea_orm::sea_query::OnConflict;
 
         let txn = storage.conn.begin().await?;
+        let protected = self.protected_entities(&txn).await?;
 
         for backend_section in sections {
+            let remote_fields = conflicts::section_fields(backend_section);
+            let pending_local =
+                SectionRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_section.remote_id)
+                    .await?
+                    .filter(|local| protected.contains(&local.uuid));
+
             // Look up local project UUID from remote project_id
             let project_uuid = Self::lookup_project_uuid(
                 &txn,
@@ -329,63 +495,271 @@ impl SyncService {
                 name: ActiveValue::Set(backend_section.name.clone()),
                 project_uuid: ActiveValue::Set(project_uuid),
                 order_index: ActiveValue::Set(backend_section.order_index),
+                remote_snapshot: ActiveValue::Set(Some(conflicts::snapshot(&remote_fields))),
             };
 
             let mut insert = section::Entity::insert(local_section);
             insert = insert.on_conflict(
                 OnConflict::columns([section::Column::BackendUuid, section::Column::RemoteId])
-                    .update_columns([section::Column::Name, section::Column::ProjectUuid, section::Column::OrderIndex])
+                    .update_columns([
+                        section::Column::Name,
+                        section::Column::ProjectUuid,
+                        section::Column::OrderIndex,
+                        section::Column::RemoteSnapshot,
+                    ])
                     .to_owned(),
             );
             insert.exec(&txn).await?;
+
+            if let Some(local) = pending_local {
+                let merged = self
+                    .merge_remote_fields(&txn, LocalEntity::Section(&local), &remote_fields)
+                    .await?;
+                // A queued reorder has not reached the backend yet, so keep the local position
+                let reorder_pending = PendingOperationRepository::get_for_entity(&txn, &local.uuid)
+                    .await?
+                    .iter()
+                    .any(|pending| pending.operation == "reorder_sections");
+                let order_index = local.order_index;
+                let mut active_model: section::ActiveModel = local.into();
+                conflicts::apply_section_fields(&mut active_model, &merged);
+                if reorder_pending {
+                    active_model.order_index = ActiveValue::Set(order_index);
+                }
+                SectionRepository::update(&txn, active_model).await?;
+            }
         }
 
         txn.commit().await?;
         Ok(())
     }
 
-    /// Look up remote_id from local task UUID (with automatic locking).
-    ///
-    /// # Arguments
-    /// * `task_uuid` - Local task UUID
-    ///
-    /// # Returns
-    /// Remote task ID for remote backend
-    ///
-    /// # Errors
-    /// Returns error if task with given UUID doesn't exist locally
-    pub(super) async fn get_task_remote_id(&self, task_uuid: &Uuid) -> Result<String> {
-        let storage = self.storage.lock().await;
-        TaskRepository::get_remote_id(&storage.conn, task_uuid).await
-    }
+    /// Store comments in batch
+    pub(super) async fn store_comments_batch(
+        &self,
+        storage: &LocalStorage,
+        comments: &[crate::backend::BackendComment],
+    ) -> Result<()> {
+        use sea_orm::sea_query::OnConflict;
 
-    /// Look up remote_id from local project UUID (with automatic locking).
-    ///
-    /// # Arguments
-    /// * `project_uuid` - Local project UUID
-    ///
-    /// # Returns
-    /// Remote project ID for remote backend
-    ///
-    /// # Errors
-    /// Returns error if project with given UUID doesn't exist locally
-    pub(super) async fn get_project_remote_id(&self, project_uuid: &Uuid) -> Result<String> {
-        let storage = self.storage.lock().await;
-        ProjectRepository::get_remote_id(&storage.conn, project_uuid).await
+        let txn = storage.conn.begin().await?;
+        let protected = self.protected_entities(&txn).await?;
+
+        for backend_comment in comments {
+            // Comments are not merged field by field; a local edit that has not been pushed wins
+            if let Some(local) =
+                CommentRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_comment.remote_id).await?
+            {
+                if protected.contains(&local.uuid) {
+                    continue;
+                }
+            }
+
+            // Skip comments on tasks that are not stored locally
+            let Some(task) =
+                TaskRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_comment.task_remote_id).await?
+            else {
+                continue;
+            };
+
+            let local_comment = comment::ActiveModel {
+                uuid: ActiveValue::Set(Uuid::new_v4()),
+                backend_uuid: ActiveValue::Set(self.backend_uuid),
+                remote_id: ActiveValue::Set(backend_comment.remote_id.clone()),
+                task_uuid: ActiveValue::Set(task.uuid),
+                content: ActiveValue::Set(backend_comment.content.clone()),
+                posted_at: ActiveValue::Set(backend_comment.posted_at.clone()),
+            };
+
+            comment::Entity::insert(local_comment)
+                .on_conflict(
+                    OnConflict::columns([comment::Column::BackendUuid, comment::Column::RemoteId])
+                        .update_columns([
+                            comment::Column::TaskUuid,
+                            comment::Column::Content,
+                            comment::Column::PostedAt,
+                        ])
+                        .to_owned(),
+                )
+                .exec(&txn)
+                .await?;
+        }
+
+        txn.commit().await?;
+        Ok(())
     }
 
-    /// Look up remote_id from local label UUID (with automatic locking).
-    ///
-    /// # Arguments
-    /// * `label_uuid` - Local label UUID
+    /// Remove local rows that no longer exist remotely.
     ///
-    /// # Returns
-    /// Remote label ID for remote backend
-    ///
-    /// # Errors
-    /// Returns error if label with given UUID doesn't exist locally
-    pub(super) async fn get_label_remote_id(&self, label_uuid: &Uuid) -> Result<String> {
-        let storage = self.storage.lock().await;
-        LabelRepository::get_remote_id(&storage.conn, label_uuid).await
+    /// Rows the backend reported as deleted or archived are always removed. A full snapshot
+    /// additionally reconciles deletions: every local row of this backend that is missing from
+    /// it is removed as well. Rows with pending outbox operations (including those still
+    /// carrying a temporary remote ID) are left alone so local changes are never lost.
+    pub(super) async fn remove_batch(
+        &self,
+        storage: &LocalStorage,
+        changes: &crate::backend::BackendChanges,
+    ) -> Result<()> {
+        let txn = storage.conn.begin().await?;
+
+        let protected = self.protected_entities(&txn).await?;
+        let select = |remote_ids: Vec<&str>, removed_ids: &[String], local: Vec<(Uuid, String)>| -> Vec<Uuid> {
+            let remote_ids: HashSet<&str> = remote_ids.into_iter().collect();
+            local
+                .into_iter()
+                .filter(|(uuid, remote_id)| {
+                    let gone = removed_ids.contains(remote_id)
+                        || (changes.is_full && !remote_ids.contains(remote_id.as_str()));
+                    gone && !protected.contains(uuid) && !is_temp_remote_id(remote_id)
+                })
+                .map(|(uuid, _)| uuid)
+                .collect()
+        };
+
+        // Snapshots only list open tasks, so completed ones stay for the logbook unless reported removed
+        let completed_remote_ids: Vec<String> = task::Entity::find()
+            .select_only()
+            .column(task::Column::RemoteId)
+            .filter(task::Column::BackendUuid.eq(self.backend_uuid))
+            .filter(task::Column::IsCompleted.eq(true))
+            .into_tuple()
+            .all(&txn)
+            .await?;
+        let task_uuids = select(
+            changes
+                .tasks
+                .iter()
+                .map(|t| t.remote_id.as_str())
+                .chain(completed_remote_ids.iter().map(String::as_str))
+                .collect(),
+            &changes.removed_task_ids,
+            task::Entity::find()
+                .select_only()
+                .columns([task::Column::Uuid, task::Column::RemoteId])
+                .filter(task::Column::BackendUuid.eq(self.backend_uuid))
+                .into_tuple()
+                .all(&txn)
+                .await?,
+        );
+        let section_uuids = select(
+            changes.sections.iter().map(|s| s.remote_id.as_str()).collect(),
+            &changes.removed_section_ids,
+            section::Entity::find()
+                .select_only()
+                .columns([section::Column::Uuid, section::Column::RemoteId])
+                .filter(section::Column::BackendUuid.eq(self.backend_uuid))
+                .into_tuple()
+                .all(&txn)
+                .await?,
+        );
+        let label_uuids = select(
+            changes.labels.iter().map(|l| l.remote_id.as_str()).collect(),
+            &changes.removed_label_ids,
+            label::Entity::find()
+                .select_only()
+                .columns([label::Column::Uuid, label::Column::RemoteId])
+                .filter(label::Column::BackendUuid.eq(self.backend_uuid))
+                .into_tuple()
+                .all(&txn)
+                .await?,
+        );
+        let project_uuids = select(
+            changes.projects.iter().map(|p| p.remote_id.as_str()).collect(),
+            &changes.removed_project_ids,
+            project::Entity::find()
+                .select_only()
+                .columns([project::Column::Uuid, project::Column::RemoteId])
+                .filter(project::Column::BackendUuid.eq(self.backend_uuid))
+                .into_tuple()
+                .all(&txn)
+                .await?,
+        );
+
+        // Backends without a delta API only return comments per task, so a full dataset does
+        // not list all of them; comments are pruned when reported removed or with their task
+        let comment_uuids: Vec<Uuid> = comment::Entity::find()
+            .select_only()
+            .columns([comment::Column::Uuid, comment::Column::RemoteId])
+            .filter(comment::Column::BackendUuid.eq(self.backend_uuid))
+            .filter(comment::Column::RemoteId.is_in(changes.removed_comment_ids.clone()))
+            .into_tuple::<(Uuid, String)>()
+            .all(&txn)
+            .await?
+            .into_iter()
+            .filter(|(uuid, _)| !protected.contains(uuid))
+            .map(|(uuid, _)| uuid)
+            .collect();
+        comment::Entity::delete_many()
+            .filter(comment::Column::Uuid.is_in(comment_uuids))
+            .exec(&txn)
+            .await?;
+
+        // Task-label links are removed explicitly rather than relying on CASCADE constraints
+        task_label::Entity::delete_many()
+            .filter(
+                Condition::any()
+                    .add(task_label::Column::TaskUuid.is_in(task_uuids.clone()))
+                    .add(task_label::Column::LabelUuid.is_in(label_uuids.clone())),
+            )
+            .exec(&txn)
+            .await?;
+        // Subtasks are deleted with their parent, so protected ones are detached from a removed
+        // parent first
+        task::Entity::update_many()
+            .col_expr(task::Column::ParentUuid, Expr::value(Option::<Uuid>::None))
+            .filter(task::Column::ParentUuid.is_in(task_uuids.clone()))
+            .filter(task::Column::Uuid.is_not_in(task_uuids.clone()))
+            .exec(&txn)
+            .await?;
+        task::Entity::delete_many()
+            .filter(task::Column::Uuid.is_in(task_uuids))
+            .exec(&txn)
+            .await?;
+        // Protected tasks may still point at a removed section
+        task::Entity::update_many()
+            .col_expr(task::Column::SectionUuid, Expr::value(Option::<Uuid>::None))
+            .filter(task::Column::SectionUuid.is_in(section_uuids.clone()))
+            .exec(&txn)
+            .await?;
+        section::Entity::delete_many()
+            .filter(section::Column::Uuid.is_in(section_uuids))
+            .exec(&txn)
+            .await?;
+        label::Entity::delete_many()
+            .filter(label::Column::Uuid.is_in(label_uuids))
+            .exec(&txn)
+            .await?;
+
+        // A project still holding protected tasks has to stay until those tasks are pushed;
+        // completed tasks kept for the logbook go with it
+        let kept_projects: HashSet<Uuid> = task::Entity::find()
+            .select_only()
+            .column(task::Column::ProjectUuid)
+            .filter(task::Column::ProjectUuid.is_in(project_uuids.clone()))
+            .filter(
+                Condition::any()
+                    .add(task::Column::IsCompleted.eq(false))
+                    .add(task::Column::Uuid.is_in(protected.iter().copied())),
+            )
+            .into_tuple::<Uuid>()
+            .all(&txn)
+            .await?
+            .into_iter()
+            .collect();
+        let project_uuids: Vec<Uuid> = project_uuids.into_iter().filter(|uuid| !kept_projects.contains(uuid)).collect();
+        // Kept sub-projects of a removed project move to the top level
+        project::Entity::update_many()
+            .col_expr(project::Column::ParentUuid, Expr::value(Option::<Uuid>::None))
+            .filter(project::Column::ParentUuid.is_in(project_uuids.clone()))
+            .filter(project::Column::Uuid.is_not_in(project_uuids.clone()))
+            .exec(&txn)
+            .await?;
+        project::Entity::delete_many()
+            .filter(project::Column::Uuid.is_in(project_uuids))
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+        Ok(())
     }
 }
@@ -1,11 +1,141 @@
-use crate::entities::task;
-use crate::repositories::{ProjectRepository, SectionRepository, TaskRepository};
+use crate::entities::{label, task, task_label};
+use crate::repositories::{LabelRepository, ProjectRepository, SectionRepository, TaskRepository};
+use crate::sync::outbox::{new_request_id, new_temp_remote_id, OutboxOperation};
 use crate::sync::SyncService;
 use crate::utils::datetime;
+use crate::utils::filter_query::FilterExpr;
 use anyhow::Result;
-use sea_orm::{ActiveValue, EntityTrait, IntoActiveModel, TransactionTrait};
+use chrono::{Local, NaiveDate, SecondsFormat, Utc};
+use log::info;
+use sea_orm::{ActiveModelTrait, ActiveValue, ConnectionTrait, EntityTrait, IntoActiveModel, TransactionTrait};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// All editable fields of a task, as submitted by the task editor.
+///
+/// Every field holds the complete new value; [`SyncService::update_task`] works out
+/// which of them actually changed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaskEdit {
+    pub content: String,
+    pub description: String,
+    pub project_uuid: Uuid,
+    pub section_uuid: Option<Uuid>,
+    pub label_uuids: Vec<Uuid>,
+    pub priority: i32,
+    /// Due date (`YYYY-MM-DD`), due datetime or a natural language due string; empty clears it
+    pub due: String,
+    /// Deadline as `YYYY-MM-DD` or a date phrase such as "friday"; empty clears it
+    pub deadline: String,
+    /// Duration such as "30 minute", "1h30" or "2 days"; empty clears it
+    pub duration: String,
+}
+
+impl TaskEdit {
+    /// The current values of a task with the given labels
+    #[must_use]
+    pub fn from_task(task: &task::Model, label_uuids: Vec<Uuid>) -> Self {
+        Self {
+            content: task.content.clone(),
+            description: task.description.clone().unwrap_or_default(),
+            project_uuid: task.project_uuid,
+            section_uuid: task.section_uuid,
+            label_uuids,
+            priority: task.priority,
+            // Recurring tasks are edited by their pattern, a date would end the recurrence
+            due: task
+                .due_string
+                .clone()
+                .filter(|_| task.is_recurring)
+                .or_else(|| task.due_datetime.clone())
+                .or_else(|| task.due_date.clone())
+                .unwrap_or_default(),
+            deadline: task.deadline.clone().unwrap_or_default(),
+            duration: task.duration.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// A task to create, as submitted by the task creation dialog.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct NewTask {
+    pub content: String,
+    pub description: Option<String>,
+    /// Local project UUID; the inbox project is used when neither a project nor a section is set
+    pub project_uuid: Option<Uuid>,
+    /// Local section UUID; the task goes into the project of the section
+    pub section_uuid: Option<Uuid>,
+    /// Local UUID of the parent task; a subtask goes into the project and section of its parent
+    pub parent_uuid: Option<Uuid>,
+    pub label_uuids: Vec<Uuid>,
+    /// Priority from 1 (normal) to 4 (urgent)
+    pub priority: Option<i32>,
+    /// Natural language due string, such as "tomorrow 5pm" or "every monday"
+    pub due_string: Option<String>,
+    /// Deadline as `YYYY-MM-DD` or a date phrase such as "march 30"
+    #[serde(default)]
+    pub deadline: Option<String>,
+    /// Duration such as "45m", "1h30" or "2 days"
+    #[serde(default)]
+    pub duration: Option<String>,
+}
+
+/// Parse a typed deadline to `YYYY-MM-DD`; empty input stays empty and clears the deadline.
+fn parse_deadline_input(input: &str) -> Result<String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(String::new());
+    }
+    datetime::parse_deadline(input, Local::now().date_naive())
+        .map(datetime::format_ymd)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid deadline '{}', expected a date such as 2025-03-30 or friday",
+                input
+            )
+        })
+}
+
+/// Normalize a typed duration; empty input stays empty and clears the duration.
+fn parse_duration_input(input: &str) -> Result<String> {
+    match input.trim() {
+        "" => Ok(String::new()),
+        input => datetime::normalize_duration(input)
+            .ok_or_else(|| anyhow::anyhow!("Invalid duration '{}', expected a duration such as 45m or 1h30", input)),
+    }
+}
+
+/// Move a task and all of its subtasks to another project and section.
+pub(super) async fn move_subtree<C>(
+    conn: &C,
+    task_uuid: Uuid,
+    project_uuid: Uuid,
+    section_uuid: Option<Uuid>,
+) -> Result<()>
+where
+    C: ConnectionTrait,
+{
+    let mut pending = TaskRepository::get_children(conn, &task_uuid).await?;
+    while let Some(child) = pending.pop() {
+        pending.extend(TaskRepository::get_children(conn, &child.uuid).await?);
+        let mut active_model: task::ActiveModel = child.into_active_model();
+        active_model.project_uuid = ActiveValue::Set(project_uuid);
+        active_model.section_uuid = ActiveValue::Set(section_uuid);
+        TaskRepository::update(conn, active_model).await?;
+    }
+    Ok(())
+}
+
+/// Completed tasks stay in the regular views until the end of the day they were completed on,
+/// afterwards they are only listed in the logbook.
+fn without_past_completions(mut tasks: Vec<task::Model>) -> Vec<task::Model> {
+    let today = chrono::Local::now().date_naive();
+    tasks.retain(|task| {
+        !task.is_completed || task.completed_at.as_deref().and_then(datetime::local_date) == Some(today)
+    });
+    tasks
+}
+
 impl SyncService {
     /// Retrieves all tasks for a specific project from local storage.
     ///
@@ -19,7 +149,8 @@ impl SyncService {
     /// Returns an error if local storage access fails
     pub async fn get_tasks_for_project(&self, project_id: &Uuid) -> Result<Vec<task::Model>> {
         let storage = self.storage.lock().await;
-        TaskRepository::get_for_project(&storage.conn, project_id).await
+        let tasks = TaskRepository::get_for_project(&storage.conn, project_id).await?;
+        Ok(without_past_completions(tasks))
     }
 
     /// Retrieves all tasks from local storage across all projects.
@@ -58,7 +189,17 @@ impl SyncService {
     /// Get tasks with a specific label from local storage (fast)
     pub async fn get_tasks_with_label(&self, label_id: Uuid) -> Result<Vec<task::Model>> {
         let storage = self.storage.lock().await;
-        TaskRepository::get_with_label(&storage.conn, label_id).await
+        let tasks = TaskRepository::get_with_label(&storage.conn, label_id).await?;
+        Ok(without_past_completions(tasks))
+    }
+
+    /// Retrieves the tasks matching a saved filter query.
+    ///
+    /// Relative terms such as `today` or `7 days` are evaluated against the current date.
+    pub async fn get_tasks_for_filter(&self, filter: &FilterExpr) -> Result<Vec<task::Model>> {
+        let storage = self.storage.lock().await;
+        let tasks = TaskRepository::get_for_filter(&storage.conn, filter, Local::now().date_naive()).await?;
+        Ok(without_past_completions(tasks))
     }
 
     /// Retrieves tasks for the "Today" view with business logic.
@@ -75,7 +216,8 @@ impl SyncService {
     pub async fn get_tasks_for_today(&self) -> Result<Vec<task::Model>> {
         let storage = self.storage.lock().await;
         let today = datetime::format_today();
-        TaskRepository::get_for_today(&storage.conn, &today).await
+        let tasks = TaskRepository::get_for_today(&storage.conn, &today).await?;
+        Ok(without_past_completions(tasks))
     }
 
     /// Retrieves tasks scheduled for tomorrow.
@@ -91,7 +233,8 @@ impl SyncService {
     pub async fn get_tasks_for_tomorrow(&self) -> Result<Vec<task::Model>> {
         let storage = self.storage.lock().await;
         let tomorrow = datetime::format_date_with_offset(1);
-        TaskRepository::get_for_tomorrow(&storage.conn, &tomorrow).await
+        let tasks = TaskRepository::get_for_tomorrow(&storage.conn, &tomorrow).await?;
+        Ok(without_past_completions(tasks))
     }
 
     /// Retrieves tasks for the "Upcoming" view with business logic.
@@ -109,7 +252,29 @@ impl SyncService {
         let storage = self.storage.lock().await;
         let today = datetime::format_today();
         let three_months_later = datetime::format_date_with_offset(90);
-        TaskRepository::get_for_upcoming(&storage.conn, &today, &three_months_later).await
+        let tasks = TaskRepository::get_for_upcoming(&storage.conn, &today, &three_months_later).await?;
+        Ok(without_past_completions(tasks))
+    }
+
+    /// Get tasks due from `start` up to, but not including, `end` from local storage (fast)
+    pub async fn get_tasks_due_between(&self, start: NaiveDate, end: NaiveDate) -> Result<Vec<task::Model>> {
+        let storage = self.storage.lock().await;
+        let tasks =
+            TaskRepository::get_due_between(&storage.conn, &datetime::format_ymd(start), &datetime::format_ymd(end))
+                .await?;
+        Ok(without_past_completions(tasks))
+    }
+
+    /// Get the labels of a task from local storage (fast)
+    pub async fn get_labels_for_task(&self, task_uuid: &Uuid) -> Result<Vec<label::Model>> {
+        let storage = self.storage.lock().await;
+        LabelRepository::get_for_task(&storage.conn, task_uuid).await
+    }
+
+    /// Get every task-label link from local storage (fast)
+    pub async fn get_task_label_links(&self) -> Result<Vec<task_label::Model>> {
+        let storage = self.storage.lock().await;
+        TaskRepository::get_label_links(&storage.conn).await
     }
 
     /// Get a single task by ID from local storage (fast)
@@ -118,373 +283,672 @@ impl SyncService {
         TaskRepository::get_by_id(&storage.conn, task_id).await
     }
 
-    /// Creates a new task via the remote backend and stores it locally.
+    /// Creates a new task locally and queues it for creation on the remote backend.
     ///
-    /// This method creates a task remotely and immediately stores it in local storage
-    /// for instant UI updates. The task will be available in the UI without requiring
-    /// a full sync operation.
+    /// The task is stored immediately with a temporary remote ID so it shows up in the UI
+    /// right away, even while offline. The temporary ID is replaced with the real one once
+    /// the outbox has been replayed. Due strings that can be resolved locally are stored as
+    /// dates right away; the backend's interpretation replaces them after the replay.
     ///
     /// # Arguments
-    /// * `content` - The content/description of the new task
-    /// * `project_uuid` - Optional local project UUID to assign the task to a specific project
+    /// * `new_task` - The content and attributes of the new task
+    ///
+    /// # Returns
+    /// The local UUID of the created task
     ///
     /// # Errors
-    /// Returns an error if the backend call fails or local storage update fails
-    pub async fn create_task(&self, content: &str, project_uuid: Option<Uuid>) -> Result<()> {
-        // Look up remote_id for project if provided
-        let remote_project_id = {
-            let storage = self.storage.lock().await;
-            if let Some(uuid) = project_uuid {
-                Some(ProjectRepository::get_remote_id(&storage.conn, &uuid).await?)
-            } else {
-                None
-            }
-            // Lock is automatically dropped here when storage goes out of scope
-        };
-
-        // Create task via backend using backend CreateTaskArgs (lock is not held)
-        let task_args = crate::backend::CreateTaskArgs {
-            content: content.to_string(),
-            description: None,
-            project_remote_id: remote_project_id.unwrap_or_default(),
-            section_remote_id: None,
-            parent_remote_id: None,
-            priority: None,
-            due_date: None,
-            due_datetime: None,
-            duration: None,
-            labels: Vec::new(),
-        };
-        let backend_task = self
-            .get_backend()
-            .await?
-            .create_task(task_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+    /// Returns an error if the content is empty, the deadline or duration cannot be parsed, the
+    /// parent, project, section or a label cannot be resolved, or local storage update fails
+    pub async fn create_task(&self, new_task: &NewTask) -> Result<Uuid> {
+        let content = new_task.content.trim();
+        if content.is_empty() {
+            anyhow::bail!("Task content cannot be empty");
+        }
+        let deadline = Some(parse_deadline_input(new_task.deadline.as_deref().unwrap_or_default())?)
+            .filter(|deadline| !deadline.is_empty());
+        let duration = Some(parse_duration_input(new_task.duration.as_deref().unwrap_or_default())?)
+            .filter(|duration| !duration.is_empty());
 
-        // Store the created task in local database immediately for UI refresh
         let storage = self.storage.lock().await;
         let txn = storage.conn.begin().await?;
 
-        // Look up local project UUID from remote project_id
-        let project_uuid = Self::lookup_project_uuid(
-            &txn,
-            &self.backend_uuid,
-            &backend_task.project_remote_id,
-            "task creation",
-        )
-        .await?;
-
-        // Look up local section UUID from remote section_id if present
-        let section_uuid =
-            Self::lookup_section_uuid(&txn, &self.backend_uuid, backend_task.section_remote_id.as_ref()).await?;
-
-        // Look up local parent UUID from remote parent_id if present
-        let parent_uuid = if let Some(remote_parent_id) = &backend_task.parent_remote_id {
-            TaskRepository::get_by_remote_id(&txn, &self.backend_uuid, remote_parent_id)
-                .await?
-                .map(|t| t.uuid)
-        } else {
-            None
+        let parent = match &new_task.parent_uuid {
+            Some(parent_uuid) => Some(
+                TaskRepository::get_by_id(&txn, parent_uuid)
+                    .await?
+                    .filter(|parent| !parent.is_deleted)
+                    .ok_or_else(|| anyhow::anyhow!("Parent task not found: {}", parent_uuid))?,
+            ),
+            None => None,
         };
 
+        // A parent decides the section and a section the project, so a subtask always stays with its parent
+        let section_uuid = parent.as_ref().map_or(new_task.section_uuid, |parent| parent.section_uuid);
+        let section = match &section_uuid {
+            Some(section_uuid) => Some(
+                SectionRepository::get_by_id(&txn, section_uuid)
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("Section not found: {}", section_uuid))?,
+            ),
+            None => None,
+        };
+        let project_uuid = match &parent {
+            Some(parent) => Some(parent.project_uuid),
+            None => section.as_ref().map(|section| section.project_uuid).or(new_task.project_uuid),
+        };
+        let project = match project_uuid {
+            Some(uuid) => ProjectRepository::get_by_id(&txn, &uuid).await?,
+            None => ProjectRepository::get_inbox(&txn, &self.backend_uuid).await?,
+        }
+        .ok_or_else(|| anyhow::anyhow!("Project for new task not found in local storage"))?;
+
+        let mut label_uuids = new_task.label_uuids.clone();
+        label_uuids.sort();
+        label_uuids.dedup();
+        let mut label_names = Vec::with_capacity(label_uuids.len());
+        for label_uuid in &label_uuids {
+            let label = LabelRepository::get_by_id(&txn, label_uuid)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("Label not found: {}", label_uuid))?;
+            label_names.push(label.name);
+        }
+
+        let due_string = new_task
+            .due_string
+            .as_deref()
+            .map(str::trim)
+            .filter(|due| !due.is_empty())
+            .map(datetime::normalize_due_string);
+        let resolved_due = due_string
+            .as_deref()
+            .and_then(|due| datetime::resolve_due_string(due, Local::now().date_naive()));
+        let due_date = resolved_due.map(|(date, _)| datetime::format_ymd(date));
+        let due_datetime = resolved_due
+            .and_then(|(date, time)| time.map(|time| date.and_time(time).format("%Y-%m-%dT%H:%M:%S").to_string()));
+        let is_recurring = due_string.as_deref().is_some_and(|due| due.to_lowercase().starts_with("every"));
+        let priority = new_task.priority.unwrap_or(1).clamp(1, 4);
+        let description = new_task.description.clone().filter(|d| !d.trim().is_empty());
+
+        let task_uuid = Uuid::new_v4();
+        let remote_id = new_temp_remote_id();
+
         let local_task = task::ActiveModel {
-            uuid: ActiveValue::Set(Uuid::new_v4()),
+            uuid: ActiveValue::Set(task_uuid),
             backend_uuid: ActiveValue::Set(self.backend_uuid),
-            remote_id: ActiveValue::Set(backend_task.remote_id),
-            content: ActiveValue::Set(backend_task.content),
-            description: ActiveValue::Set(backend_task.description),
-            project_uuid: ActiveValue::Set(project_uuid),
-            section_uuid: ActiveValue::Set(section_uuid),
-            parent_uuid: ActiveValue::Set(parent_uuid),
-            priority: ActiveValue::Set(backend_task.priority),
-            order_index: ActiveValue::Set(backend_task.order_index),
-            due_date: ActiveValue::Set(backend_task.due_date),
-            due_datetime: ActiveValue::Set(backend_task.due_datetime),
-            is_recurring: ActiveValue::Set(backend_task.is_recurring),
-            deadline: ActiveValue::Set(backend_task.deadline),
-            duration: ActiveValue::Set(backend_task.duration),
-            is_completed: ActiveValue::Set(backend_task.is_completed),
+            remote_id: ActiveValue::Set(remote_id.clone()),
+            content: ActiveValue::Set(content.to_string()),
+            description: ActiveValue::Set(description.clone()),
+            project_uuid: ActiveValue::Set(project.uuid),
+            section_uuid: ActiveValue::Set(section.as_ref().map(|section| section.uuid)),
+            parent_uuid: ActiveValue::Set(parent.as_ref().map(|parent| parent.uuid)),
+            priority: ActiveValue::Set(priority),
+            order_index: ActiveValue::Set(0),
+            due_date: ActiveValue::Set(due_date.clone()),
+            due_datetime: ActiveValue::Set(due_datetime.clone()),
+            is_recurring: ActiveValue::Set(is_recurring),
+            due_string: ActiveValue::Set(due_string.clone()),
+            deadline: ActiveValue::Set(deadline.clone()),
+            duration: ActiveValue::Set(duration.clone()),
+            is_completed: ActiveValue::Set(false),
+            completed_at: ActiveValue::Set(None),
             is_deleted: ActiveValue::Set(false),
+            remote_snapshot: ActiveValue::Set(None),
+            is_collapsed: ActiveValue::Set(false),
+            created_at: ActiveValue::Set(Some(Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true))),
         };
+        task::Entity::insert(local_task).exec(&txn).await?;
+        TaskRepository::set_labels(&txn, &task_uuid, &label_uuids).await?;
 
-        use sea_orm::sea_query::OnConflict;
-        let mut insert = task::Entity::insert(local_task);
-        insert = insert.on_conflict(
-            OnConflict::columns([task::Column::BackendUuid, task::Column::RemoteId])
-                .update_columns([
-                    task::Column::Content,
-                    task::Column::Description,
-                    task::Column::ProjectUuid,
-                    task::Column::SectionUuid,
-                    task::Column::ParentUuid,
-                    task::Column::Priority,
-                    task::Column::OrderIndex,
-                    task::Column::DueDate,
-                    task::Column::DueDatetime,
-                    task::Column::IsRecurring,
-                    task::Column::Deadline,
-                    task::Column::Duration,
-                    task::Column::IsCompleted,
-                    task::Column::IsDeleted,
-                ])
-                .to_owned(),
-        );
-        insert.exec(&txn).await?;
+        let task_args = crate::backend::CreateTaskArgs {
+            content: content.to_string(),
+            description,
+            project_remote_id: project.remote_id,
+            section_remote_id: section.map(|section| section.remote_id),
+            parent_remote_id: parent.map(|parent| parent.remote_id),
+            priority: new_task.priority.map(|_| priority),
+            due_date,
+            due_datetime,
+            due_string,
+            deadline,
+            duration,
+            labels: label_names,
+            request_id: Some(new_request_id()),
+        };
+        self.enqueue_operation(&txn, &task_uuid, &remote_id, &OutboxOperation::CreateTask(task_args))
+            .await?;
 
         txn.commit().await?;
+        drop(storage);
 
-        Ok(())
+        self.try_flush_outbox().await;
+        Ok(task_uuid)
     }
 
-    /// Update task content
-    pub async fn update_task_content(&self, task_uuid: &Uuid, content: &str) -> Result<()> {
-        // Look up the task's remote_id for backend call
-        let remote_id = self.get_task_remote_id(task_uuid).await?;
+    /// Applies the task editor values to a task and queues a single update for the backend.
+    ///
+    /// Only the fields that differ from the stored task are sent. A changed project or section
+    /// moves the task out of its parent and takes its subtasks along. Due strings that are not
+    /// a plain date are resolved by the backend, like [`Self::update_task_due_string`].
+    ///
+    /// # Arguments
+    /// * `task_uuid` - The local UUID of the task to update
+    /// * `edit` - The new values of all editable fields
+    ///
+    /// # Errors
+    /// Returns an error if the content is empty, the deadline or duration cannot be parsed,
+    /// the task or its new location does not exist, or local storage update fails
+    pub async fn update_task(&self, task_uuid: &Uuid, edit: &TaskEdit) -> Result<()> {
+        let content = edit.content.trim();
+        if content.is_empty() {
+            anyhow::bail!("Task content cannot be empty");
+        }
+        let deadline = parse_deadline_input(&edit.deadline)?;
+        let duration = parse_duration_input(&edit.duration)?;
 
-        // Update task via backend using the UpdateTaskArgs structure
-        let task_args = crate::backend::UpdateTaskArgs {
-            content: Some(content.to_string()),
-            description: None,
-            project_remote_id: None,
-            section_remote_id: None,
-            parent_remote_id: None,
-            priority: None,
-            due_date: None,
-            due_datetime: None,
-            due_string: None,
-            duration: None,
-            labels: None,
-        };
-        let _task = self
-            .get_backend()
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+
+        let task = TaskRepository::get_by_id(&txn, task_uuid)
             .await?
-            .update_task(&remote_id, task_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+            .ok_or_else(|| anyhow::anyhow!("Task not found: {}", task_uuid))?;
+        let labels = LabelRepository::get_for_task(&txn, task_uuid).await?;
+        let current = TaskEdit::from_task(&task, labels.iter().map(|l| l.uuid).collect());
+        let remote_id = task.remote_id.clone();
 
-        // Update local storage immediately after successful backend call
-        let storage = self.storage.lock().await;
+        let mut task_args = crate::backend::UpdateTaskArgs::default();
+        let mut active_model: task::ActiveModel = task.into_active_model();
 
-        if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_uuid).await? {
-            let mut active_model: task::ActiveModel = task.into_active_model();
+        if content != current.content {
+            task_args.content = Some(content.to_string());
             active_model.content = ActiveValue::Set(content.to_string());
-            TaskRepository::update(&storage.conn, active_model).await?;
+        }
+        if edit.description != current.description {
+            task_args.description = Some(edit.description.clone());
+            active_model.description = ActiveValue::Set(Some(edit.description.clone()).filter(|d| !d.is_empty()));
+        }
+        if edit.priority != current.priority {
+            task_args.priority = Some(edit.priority);
+            active_model.priority = ActiveValue::Set(edit.priority);
         }
 
+        let due = edit.due.trim();
+        if due != current.due {
+            if due.is_empty() {
+                task_args.due_string = Some("no date".to_string());
+                active_model.due_date = ActiveValue::Set(None);
+                active_model.due_datetime = ActiveValue::Set(None);
+                active_model.is_recurring = ActiveValue::Set(false);
+                active_model.due_string = ActiveValue::Set(None);
+            } else if datetime::parse_date(due).is_ok() {
+                task_args.due_date = Some(due.to_string());
+                active_model.due_date = ActiveValue::Set(Some(due.to_string()));
+                active_model.due_datetime = ActiveValue::Set(None);
+                active_model.is_recurring = ActiveValue::Set(false);
+                active_model.due_string = ActiveValue::Set(None);
+            } else {
+                task_args.due_string = Some(datetime::normalize_due_string(due));
+            }
+        }
+        if deadline != current.deadline {
+            active_model.deadline = ActiveValue::Set(Some(deadline.clone()).filter(|d| !d.is_empty()));
+            task_args.deadline = Some(deadline);
+        }
+        if duration != current.duration {
+            active_model.duration = ActiveValue::Set(Some(duration.clone()).filter(|d| !d.is_empty()));
+            task_args.duration = Some(duration);
+        }
+
+        if (edit.project_uuid, edit.section_uuid) != (current.project_uuid, current.section_uuid) {
+            match &edit.section_uuid {
+                Some(section_uuid) => {
+                    let section = SectionRepository::get_by_id(&txn, section_uuid)
+                        .await?
+                        .filter(|section| section.project_uuid == edit.project_uuid)
+                        .ok_or_else(|| anyhow::anyhow!("Section not found in project: {}", section_uuid))?;
+                    task_args.section_remote_id = Some(section.remote_id);
+                }
+                None => {
+                    task_args.project_remote_id =
+                        Some(ProjectRepository::get_remote_id(&txn, &edit.project_uuid).await?);
+                }
+            }
+            active_model.project_uuid = ActiveValue::Set(edit.project_uuid);
+            active_model.section_uuid = ActiveValue::Set(edit.section_uuid);
+            active_model.parent_uuid = ActiveValue::Set(None);
+            move_subtree(&txn, *task_uuid, edit.project_uuid, edit.section_uuid).await?;
+        }
+
+        let mut label_uuids = edit.label_uuids.clone();
+        label_uuids.sort();
+        label_uuids.dedup();
+        let mut current_label_uuids = current.label_uuids.clone();
+        current_label_uuids.sort();
+        if label_uuids != current_label_uuids {
+            let mut names = Vec::with_capacity(label_uuids.len());
+            for label_uuid in &label_uuids {
+                let label = LabelRepository::get_by_id(&txn, label_uuid)
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("Label not found: {}", label_uuid))?;
+                names.push(label.name);
+            }
+            task_args.labels = Some(names);
+            TaskRepository::set_labels(&txn, task_uuid, &label_uuids).await?;
+        }
+
+        if active_model.is_changed() {
+            TaskRepository::update(&txn, active_model).await?;
+        }
+        let has_updates = serde_json::to_value(&task_args)?
+            .as_object()
+            .is_some_and(|fields| fields.values().any(|value| !value.is_null()));
+        if has_updates {
+            self.enqueue_operation(&txn, task_uuid, &remote_id, &OutboxOperation::UpdateTask(task_args))
+                .await?;
+        }
+
+        txn.commit().await?;
+        drop(storage);
+
+        if has_updates {
+            self.try_flush_outbox().await;
+        }
         Ok(())
     }
 
-    /// Update task due date
-    pub async fn update_task_due_date(&self, task_uuid: &Uuid, due_date: Option<&str>) -> Result<()> {
-        // Look up the task's remote_id for backend call
-        let remote_id = self.get_task_remote_id(task_uuid).await?;
+    /// Replaces the labels of a task and queues the change for the backend.
+    ///
+    /// Names in `new_label_names` that do not exist yet are created as labels first, so the
+    /// label creations are replayed before the task update that refers to them.
+    ///
+    /// # Arguments
+    /// * `task_uuid` - The local UUID of the task
+    /// * `label_uuids` - Existing labels the task should have
+    /// * `new_label_names` - Names of further labels, created when missing
+    ///
+    /// # Errors
+    /// Returns an error if the task or a label does not exist, or local storage update fails
+    pub async fn set_task_labels(
+        &self,
+        task_uuid: &Uuid,
+        label_uuids: &[Uuid],
+        new_label_names: &[String],
+    ) -> Result<()> {
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
 
-        // Update task via backend using the UpdateTaskArgs structure
-        let task_args = crate::backend::UpdateTaskArgs {
-            content: None,
-            description: None,
-            project_remote_id: None,
-            section_remote_id: None,
-            parent_remote_id: None,
-            priority: None,
-            due_date: due_date.map(std::string::ToString::to_string),
-            due_datetime: None,
-            due_string: None,
-            duration: None,
-            labels: None,
-        };
-        let _task = self
-            .get_backend()
+        let task = TaskRepository::get_by_id(&txn, task_uuid)
             .await?
-            .update_task(&remote_id, task_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+            .ok_or_else(|| anyhow::anyhow!("Task not found: {}", task_uuid))?;
+
+        let mut label_uuids = label_uuids.to_vec();
+        for name in new_label_names.iter().map(|name| name.trim()).filter(|name| !name.is_empty()) {
+            let label_uuid = match LabelRepository::get_by_name(&txn, name).await? {
+                Some(label) => label.uuid,
+                None => self.insert_label(&txn, name).await?,
+            };
+            label_uuids.push(label_uuid);
+        }
+        label_uuids.sort();
+        label_uuids.dedup();
 
-        // Then update local storage
+        let mut current_label_uuids: Vec<Uuid> = LabelRepository::get_for_task(&txn, task_uuid)
+            .await?
+            .iter()
+            .map(|label| label.uuid)
+            .collect();
+        current_label_uuids.sort();
+
+        let changed = label_uuids != current_label_uuids;
+        if changed {
+            let mut names = Vec::with_capacity(label_uuids.len());
+            for label_uuid in &label_uuids {
+                let label = LabelRepository::get_by_id(&txn, label_uuid)
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("Label not found: {}", label_uuid))?;
+                names.push(label.name);
+            }
+            TaskRepository::set_labels(&txn, task_uuid, &label_uuids).await?;
+
+            let task_args = crate::backend::UpdateTaskArgs {
+                labels: Some(names),
+                ..Default::default()
+            };
+            self.enqueue_operation(
+                &txn,
+                task_uuid,
+                &task.remote_id,
+                &OutboxOperation::UpdateTask(task_args),
+            )
+            .await?;
+        }
+
+        txn.commit().await?;
+        drop(storage);
+
+        if changed {
+            self.try_flush_outbox().await;
+        }
+        Ok(())
+    }
+
+    /// Moves a task with all of its subtasks to another project, section or parent task.
+    ///
+    /// The most specific destination wins: a parent task decides the section and project,
+    /// a section must belong to `project_uuid`. Moving a task to where it already is does nothing.
+    ///
+    /// # Arguments
+    /// * `task_uuid` - The local UUID of the task to move
+    /// * `project_uuid` - The destination project
+    /// * `section_uuid` - The destination section, `None` for the project without a section
+    /// * `parent_uuid` - The new parent task, `None` to make it a top-level task
+    ///
+    /// # Errors
+    /// Returns an error if the task or destination does not exist, the task would become its
+    /// own subtask, or local storage update fails
+    pub async fn move_task(
+        &self,
+        task_uuid: &Uuid,
+        project_uuid: Uuid,
+        section_uuid: Option<Uuid>,
+        parent_uuid: Option<Uuid>,
+    ) -> Result<()> {
         let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+
+        let task = TaskRepository::get_by_id(&txn, task_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found: {}", task_uuid))?;
+
+        let mut args = crate::backend::MoveTaskArgs::default();
+        let (project_uuid, section_uuid) = match parent_uuid {
+            Some(parent_uuid) => {
+                let parent = TaskRepository::get_by_id(&txn, &parent_uuid)
+                    .await?
+                    .filter(|parent| !parent.is_deleted)
+                    .ok_or_else(|| anyhow::anyhow!("Parent task not found: {}", parent_uuid))?;
+                // Walk up from the new parent, the task itself must not be among its ancestors
+                let mut ancestor = Some(parent.clone());
+                while let Some(current) = ancestor {
+                    if current.uuid == *task_uuid {
+                        anyhow::bail!("A task cannot be moved under itself or one of its subtasks");
+                    }
+                    ancestor = match current.parent_uuid {
+                        Some(uuid) => TaskRepository::get_by_id(&txn, &uuid).await?,
+                        None => None,
+                    };
+                }
+                args.parent_remote_id = Some(parent.remote_id);
+                (parent.project_uuid, parent.section_uuid)
+            }
+            None => {
+                if let Some(section_uuid) = &section_uuid {
+                    let section = SectionRepository::get_by_id(&txn, section_uuid)
+                        .await?
+                        .filter(|section| section.project_uuid == project_uuid)
+                        .ok_or_else(|| anyhow::anyhow!("Section not found in project: {}", section_uuid))?;
+                    args.section_remote_id = Some(section.remote_id);
+                }
+                (project_uuid, section_uuid)
+            }
+        };
+
+        let moved =
+            (task.project_uuid, task.section_uuid, task.parent_uuid) != (project_uuid, section_uuid, parent_uuid);
+        if moved {
+            args.project_remote_id = ProjectRepository::get_remote_id(&txn, &project_uuid).await?;
+            let remote_id = task.remote_id.clone();
 
-        if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_uuid).await? {
             let mut active_model: task::ActiveModel = task.into_active_model();
-            active_model.due_date = ActiveValue::Set(due_date.map(|s| s.to_string()));
-            TaskRepository::update(&storage.conn, active_model).await?;
+            active_model.project_uuid = ActiveValue::Set(project_uuid);
+            active_model.section_uuid = ActiveValue::Set(section_uuid);
+            active_model.parent_uuid = ActiveValue::Set(parent_uuid);
+            TaskRepository::update(&txn, active_model).await?;
+            move_subtree(&txn, *task_uuid, project_uuid, section_uuid).await?;
+
+            self.enqueue_operation(&txn, task_uuid, &remote_id, &OutboxOperation::MoveTask(args))
+                .await?;
         }
 
+        txn.commit().await?;
+        drop(storage);
+
+        if moved {
+            self.try_flush_outbox().await;
+        }
         Ok(())
     }
 
+    /// Move a task up (negative offset) or down (positive offset) among the open tasks sharing
+    /// its project, section and parent.
+    ///
+    /// The siblings are renumbered in their new order and the whole order is queued as a
+    /// single reorder, so the backend ends up with the same order as the UI. Moving past the
+    /// first or last position is a no-op.
+    ///
+    /// # Errors
+    /// Returns an error if the task does not exist or local storage update fails
+    pub async fn reorder_task(&self, task_uuid: &Uuid, offset: i32) -> Result<()> {
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+
+        let task = TaskRepository::get_by_id(&txn, task_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found: {}", task_uuid))?;
+
+        let mut siblings =
+            TaskRepository::get_siblings(&txn, &task.project_uuid, task.section_uuid, task.parent_uuid).await?;
+        let Some(current) = siblings.iter().position(|t| t.uuid == *task_uuid) else {
+            return Ok(());
+        };
+        let target = current
+            .saturating_add_signed(offset as isize)
+            .min(siblings.len().saturating_sub(1));
+        if target == current {
+            return Ok(());
+        }
+        let moved = siblings.remove(current);
+        siblings.insert(target, moved);
+
+        let mut orders = Vec::with_capacity(siblings.len());
+        for (position, sibling) in siblings.into_iter().enumerate() {
+            let order_index = position as i32 + 1;
+            orders.push(crate::backend::TaskOrder {
+                remote_id: sibling.remote_id.clone(),
+                order_index,
+            });
+            if sibling.order_index != order_index {
+                let mut active_model: task::ActiveModel = sibling.into_active_model();
+                active_model.order_index = ActiveValue::Set(order_index);
+                TaskRepository::update(&txn, active_model).await?;
+            }
+        }
+
+        info!("Storage: Moving task {} to position {}", task_uuid, target + 1);
+        self.enqueue_operation(&txn, task_uuid, &task.remote_id, &OutboxOperation::ReorderTasks(orders))
+            .await?;
+
+        txn.commit().await?;
+        drop(storage);
+
+        self.try_flush_outbox().await;
+        Ok(())
+    }
+
+    /// Update task content
+    pub async fn update_task_content(&self, task_uuid: &Uuid, content: &str) -> Result<()> {
+        let task_args = crate::backend::UpdateTaskArgs {
+            content: Some(content.to_string()),
+            ..Default::default()
+        };
+        self.apply_task_change(task_uuid, OutboxOperation::UpdateTask(task_args), |task| {
+            task.content = ActiveValue::Set(content.to_string());
+        })
+        .await
+    }
+
+    /// Update task due date
+    pub async fn update_task_due_date(&self, task_uuid: &Uuid, due_date: Option<&str>) -> Result<()> {
+        let task_args = crate::backend::UpdateTaskArgs {
+            due_date: due_date.map(std::string::ToString::to_string),
+            ..Default::default()
+        };
+        self.apply_task_change(task_uuid, OutboxOperation::UpdateTask(task_args), |task| {
+            task.due_date = ActiveValue::Set(due_date.map(|s| s.to_string()));
+            // A fixed date ends the recurrence
+            task.is_recurring = ActiveValue::Set(false);
+            task.due_string = ActiveValue::Set(None);
+        })
+        .await
+    }
+
     /// Update task due date using a natural language string via Todoist's due_string API.
-    /// The API parses the string and returns the resolved date, which is used to update local storage.
+    /// Only the backend can parse the string, so the resolved date is written to local storage
+    /// when the queued update is replayed.
     pub async fn update_task_due_string(&self, task_uuid: &Uuid, due_string: &str) -> Result<()> {
-        let remote_id = self.get_task_remote_id(task_uuid).await?;
-
         let task_args = crate::backend::UpdateTaskArgs {
-            content: None,
-            description: None,
-            project_remote_id: None,
-            section_remote_id: None,
-            parent_remote_id: None,
-            priority: None,
-            due_date: None,
-            due_datetime: None,
             due_string: Some(due_string.to_string()),
-            duration: None,
-            labels: None,
+            ..Default::default()
         };
-        let backend_task = self
-            .get_backend()
-            .await?
-            .update_task(&remote_id, task_args)
+        self.apply_task_change(task_uuid, OutboxOperation::UpdateTask(task_args), |_| {})
             .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
-
-        // Update local storage from the API response (resolved date fields)
-        let storage = self.storage.lock().await;
+    }
 
-        if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_uuid).await? {
-            let mut active_model: task::ActiveModel = task.into_active_model();
-            active_model.due_date = ActiveValue::Set(backend_task.due_date);
-            active_model.due_datetime = ActiveValue::Set(backend_task.due_datetime);
-            active_model.is_recurring = ActiveValue::Set(backend_task.is_recurring);
-            active_model.deadline = ActiveValue::Set(backend_task.deadline);
-            TaskRepository::update(&storage.conn, active_model).await?;
-        }
+    /// Sets the deadline of a task, or clears it when the input is empty.
+    ///
+    /// # Arguments
+    /// * `task_uuid` - The local UUID of the task
+    /// * `deadline` - A date as `YYYY-MM-DD` or a date phrase such as "friday" or "march 30"
+    ///
+    /// # Errors
+    /// Returns an error if the deadline cannot be parsed, the task does not exist or local
+    /// storage update fails
+    pub async fn update_task_deadline(&self, task_uuid: &Uuid, deadline: &str) -> Result<()> {
+        let deadline = parse_deadline_input(deadline)?;
+        let task_args = crate::backend::UpdateTaskArgs {
+            deadline: Some(deadline.clone()),
+            ..Default::default()
+        };
+        self.apply_task_change(task_uuid, OutboxOperation::UpdateTask(task_args), |task| {
+            task.deadline = ActiveValue::Set(Some(deadline).filter(|d| !d.is_empty()));
+        })
+        .await
+    }
 
-        Ok(())
+    /// Sets the planned duration of a task, or clears it when the input is empty.
+    ///
+    /// # Arguments
+    /// * `task_uuid` - The local UUID of the task
+    /// * `duration` - A duration such as "45m", "1h30", "2 hours" or "1 day"
+    ///
+    /// # Errors
+    /// Returns an error if the duration cannot be parsed, the task does not exist or local
+    /// storage update fails
+    pub async fn update_task_duration(&self, task_uuid: &Uuid, duration: &str) -> Result<()> {
+        let duration = parse_duration_input(duration)?;
+        let task_args = crate::backend::UpdateTaskArgs {
+            duration: Some(duration.clone()),
+            ..Default::default()
+        };
+        self.apply_task_change(task_uuid, OutboxOperation::UpdateTask(task_args), |task| {
+            task.duration = ActiveValue::Set(Some(duration).filter(|d| !d.is_empty()));
+        })
+        .await
     }
 
     /// Update task priority
     pub async fn update_task_priority(&self, task_uuid: &Uuid, priority: i32) -> Result<()> {
-        // Look up the task's remote_id for backend call
-        let remote_id = self.get_task_remote_id(task_uuid).await?;
-
-        // Update task via backend using the UpdateTaskArgs structure
         let task_args = crate::backend::UpdateTaskArgs {
-            content: None,
-            description: None,
-            project_remote_id: None,
-            section_remote_id: None,
-            parent_remote_id: None,
             priority: Some(priority),
-            due_date: None,
-            due_datetime: None,
-            due_string: None,
-            duration: None,
-            labels: None,
+            ..Default::default()
         };
-        let _task = self
-            .get_backend()
-            .await?
-            .update_task(&remote_id, task_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+        self.apply_task_change(task_uuid, OutboxOperation::UpdateTask(task_args), |task| {
+            task.priority = ActiveValue::Set(priority);
+        })
+        .await
+    }
 
-        // Then update local storage
+    /// Shows or hides the subtasks of a task in the task list.
+    ///
+    /// The collapsed state is local only, it is neither queued for the backend nor
+    /// overwritten by a sync.
+    ///
+    /// # Errors
+    /// Returns an error if the task does not exist or local storage update fails
+    pub async fn set_task_collapsed(&self, task_uuid: &Uuid, is_collapsed: bool) -> Result<()> {
         let storage = self.storage.lock().await;
-
-        if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_uuid).await? {
-            let mut active_model: task::ActiveModel = task.into_active_model();
-            active_model.priority = ActiveValue::Set(priority);
-            TaskRepository::update(&storage.conn, active_model).await?;
-        }
-
+        let task = TaskRepository::get_by_id(&storage.conn, task_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found: {}", task_uuid))?;
+        let mut active_model: task::ActiveModel = task.into_active_model();
+        active_model.is_collapsed = ActiveValue::Set(is_collapsed);
+        TaskRepository::update(&storage.conn, active_model).await?;
         Ok(())
     }
 
-    /// Marks a task as completed via the remote backend and removes it from local storage.
+    /// Marks a task as completed locally and queues the completion for the remote backend.
     ///
-    /// This method completes the task remotely (which automatically handles subtasks)
-    /// and removes it from local storage since completed tasks are not displayed in the UI.
-    /// Subtasks are automatically deleted via database CASCADE constraints.
+    /// Completed tasks are kept locally (soft completion) with their completion time, so they
+    /// can be restored and show up in the logbook. The backend completes subtasks automatically.
+    /// Recurring tasks stay open: the backend moves them to their next occurrence, which is
+    /// fetched and stored when the completion is replayed.
     ///
     /// # Arguments
     /// * `task_uuid` - The local UUID of the task to complete
     ///
     /// # Errors
-    /// Returns an error if the backend call fails or local storage update fails
+    /// Returns an error if the task does not exist or local storage update fails
     pub async fn complete_task(&self, task_uuid: &Uuid) -> Result<()> {
-        // Look up the task's remote_id for backend call
-        let remote_id = self.get_task_remote_id(task_uuid).await?;
-
-        // Complete the task via backend using remote_id (this handles subtasks automatically)
-        self.get_backend()
-            .await?
-            .complete_task(&remote_id)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
-
-        // Then mark as completed in local storage (soft completion)
-        let storage = self.storage.lock().await;
-
-        if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_uuid).await? {
-            let mut active_model: task::ActiveModel = task.into_active_model();
-            active_model.is_completed = ActiveValue::Set(true);
-            TaskRepository::update(&storage.conn, active_model).await?;
-        }
-
-        Ok(())
+        self.apply_task_change(task_uuid, OutboxOperation::CompleteTask, |task| {
+            if *task.is_recurring.as_ref() {
+                return;
+            }
+            task.is_completed = ActiveValue::Set(true);
+            // Same format as the timestamps reported by Todoist, so the logbook sorts correctly
+            task.completed_at = ActiveValue::Set(Some(Utc::now().to_rfc3339_opts(SecondsFormat::Micros, true)));
+        })
+        .await
     }
 
-    /// Permanently deletes a task via the remote backend and removes it from local storage.
+    /// Deletes a task locally and queues the deletion for the remote backend.
     ///
-    /// This method performs a hard delete of the task remotely, soft delete locally.
-    /// The task will be permanently removed and cannot be recovered.
+    /// This performs a soft delete locally and a hard delete remotely once replayed.
     ///
     /// # Arguments
     /// * `task_uuid` - The local UUID of the task to delete
     ///
     /// # Errors
-    /// Returns an error if the backend call fails or local storage update fails
+    /// Returns an error if the task does not exist or local storage update fails
     pub async fn delete_task(&self, task_uuid: &Uuid) -> Result<()> {
-        // Look up the task's remote_id for backend call
-        let remote_id = self.get_task_remote_id(task_uuid).await?;
-
-        // Delete the task via backend using remote_id
-        self.get_backend()
-            .await?
-            .delete_task(&remote_id)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
-
-        // Then mark as deleted in local storage (soft deletion)
-        let storage = self.storage.lock().await;
-
-        if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_uuid).await? {
-            let mut active_model: task::ActiveModel = task.into_active_model();
-            active_model.is_deleted = ActiveValue::Set(true);
-            TaskRepository::update(&storage.conn, active_model).await?;
-        }
-
-        Ok(())
+        self.apply_task_change(task_uuid, OutboxOperation::DeleteTask, |task| {
+            task.is_deleted = ActiveValue::Set(true);
+        })
+        .await
     }
 
-    /// Restore a soft-deleted or completed task via the remote backend and locally
-    /// For completed tasks, reopens them. For deleted tasks, recreates them via backend.
+    /// Restore a soft-deleted or completed task locally and queue the matching backend change.
+    /// For completed tasks, reopens them. For deleted tasks, recreates them via the backend.
     pub async fn restore_task(&self, task_id: &Uuid) -> Result<()> {
-        // First, get the task from local storage to check its state
         let storage = self.storage.lock().await;
-        let task = TaskRepository::get_by_id(&storage.conn, task_id)
+        let txn = storage.conn.begin().await?;
+
+        let task = TaskRepository::get_by_id(&txn, task_id)
             .await?
             .ok_or_else(|| anyhow::anyhow!("Task not found in local storage: {}", task_id))?;
 
         if task.is_deleted {
-            // For deleted tasks, we need to recreate them via backend
-            // Look up remote IDs before dropping storage lock
-            let remote_project_id = ProjectRepository::get_remote_id(&storage.conn, &task.project_uuid).await?;
+            // Deleted tasks are gone remotely, so they are recreated under a new remote ID
+            let remote_project_id = ProjectRepository::get_remote_id(&txn, &task.project_uuid).await?;
             let remote_section_id = if let Some(section_uuid) = &task.section_uuid {
-                SectionRepository::get_remote_id(&storage.conn, section_uuid).await?
+                SectionRepository::get_remote_id(&txn, section_uuid).await?
             } else {
                 None
             };
             let remote_parent_id = if let Some(parent_uuid) = &task.parent_uuid {
-                Some(TaskRepository::get_remote_id(&storage.conn, parent_uuid).await?)
+                Some(TaskRepository::get_remote_id(&txn, parent_uuid).await?)
             } else {
                 None
             };
 
-            drop(storage); // Release the lock before API call
-
-            // Create the task again via backend
             let task_args = crate::backend::CreateTaskArgs {
                 content: task.content.clone(),
                 description: task.description.clone().filter(|d| !d.is_empty()),
@@ -494,108 +958,66 @@ impl SyncService {
                 priority: Some(task.priority),
                 due_date: task.due_date.clone(),
                 due_datetime: task.due_datetime.clone(),
+                // The backend takes the pattern over the dates, so a recurring task keeps recurring
+                due_string: task.due_string.clone().filter(|_| task.is_recurring),
+                deadline: task.deadline.clone(),
                 duration: task.duration.clone(),
                 labels: Vec::new(), // Labels will be synced separately
+                request_id: Some(new_request_id()),
             };
 
-            let new_task = self
-                .get_backend()
-                .await?
-                .create_task(task_args)
-                .await
-                .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
-
-            // Update local storage: remove the old soft-deleted task and add the new one
-            let storage = self.storage.lock().await;
+            let remote_id = new_temp_remote_id();
+            let mut active_model: task::ActiveModel = task.into_active_model();
+            active_model.remote_id = ActiveValue::Set(remote_id.clone());
+            active_model.is_deleted = ActiveValue::Set(false);
+            TaskRepository::update(&txn, active_model).await?;
 
-            // Hard delete the old soft-deleted task
-            if let Some(old_task) = TaskRepository::get_by_id(&storage.conn, task_id).await? {
-                TaskRepository::delete(&storage.conn, old_task).await?;
-            }
+            self.enqueue_operation(&txn, task_id, &remote_id, &OutboxOperation::CreateTask(task_args))
+                .await?;
+        } else {
+            // For completed tasks, just reopen them
+            let remote_id = task.remote_id.clone();
+            let mut active_model: task::ActiveModel = task.into_active_model();
+            active_model.is_completed = ActiveValue::Set(false);
+            active_model.completed_at = ActiveValue::Set(None);
+            TaskRepository::update(&txn, active_model).await?;
 
-            // Store the new task (reuse the single task upsert logic)
-            let txn = storage.conn.begin().await?;
+            self.enqueue_operation(&txn, task_id, &remote_id, &OutboxOperation::ReopenTask)
+                .await?;
+        }
 
-            let project_uuid =
-                Self::lookup_project_uuid(&txn, &self.backend_uuid, &new_task.project_remote_id, "task restore")
-                    .await?;
+        txn.commit().await?;
+        drop(storage);
 
-            let section_uuid =
-                Self::lookup_section_uuid(&txn, &self.backend_uuid, new_task.section_remote_id.as_ref()).await?;
+        self.try_flush_outbox().await;
+        Ok(())
+    }
 
-            let parent_uuid = if let Some(remote_parent_id) = &new_task.parent_remote_id {
-                TaskRepository::get_by_remote_id(&txn, &self.backend_uuid, remote_parent_id)
-                    .await?
-                    .map(|t| t.uuid)
-            } else {
-                None
-            };
+    /// Apply a change to a local task and queue the matching backend operation.
+    async fn apply_task_change<F>(&self, task_uuid: &Uuid, operation: OutboxOperation, apply: F) -> Result<()>
+    where
+        F: FnOnce(&mut task::ActiveModel),
+    {
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
 
-            let local_task = task::ActiveModel {
-                uuid: ActiveValue::Set(Uuid::new_v4()),
-                backend_uuid: ActiveValue::Set(self.backend_uuid),
-                remote_id: ActiveValue::Set(new_task.remote_id),
-                content: ActiveValue::Set(new_task.content),
-                description: ActiveValue::Set(new_task.description),
-                project_uuid: ActiveValue::Set(project_uuid),
-                section_uuid: ActiveValue::Set(section_uuid),
-                parent_uuid: ActiveValue::Set(parent_uuid),
-                priority: ActiveValue::Set(new_task.priority),
-                order_index: ActiveValue::Set(new_task.order_index),
-                due_date: ActiveValue::Set(new_task.due_date),
-                due_datetime: ActiveValue::Set(new_task.due_datetime),
-                is_recurring: ActiveValue::Set(new_task.is_recurring),
-                deadline: ActiveValue::Set(new_task.deadline),
-                duration: ActiveValue::Set(new_task.duration),
-                is_completed: ActiveValue::Set(new_task.is_completed),
-                is_deleted: ActiveValue::Set(false),
-            };
+        let task = TaskRepository::get_by_id(&txn, task_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found: {}", task_uuid))?;
+        let remote_id = task.remote_id.clone();
 
-            use sea_orm::sea_query::OnConflict;
-            let mut insert = task::Entity::insert(local_task);
-            insert = insert.on_conflict(
-                OnConflict::columns([task::Column::BackendUuid, task::Column::RemoteId])
-                    .update_columns([
-                        task::Column::Content,
-                        task::Column::Description,
-                        task::Column::ProjectUuid,
-                        task::Column::SectionUuid,
-                        task::Column::ParentUuid,
-                        task::Column::Priority,
-                        task::Column::OrderIndex,
-                        task::Column::DueDate,
-                        task::Column::DueDatetime,
-                        task::Column::IsRecurring,
-                        task::Column::Deadline,
-                        task::Column::Duration,
-                        task::Column::IsCompleted,
-                        task::Column::IsDeleted,
-                    ])
-                    .to_owned(),
-            );
-            insert.exec(&txn).await?;
-
-            txn.commit().await?;
-        } else {
-            // For completed tasks, just reopen them
-            let remote_id = task.remote_id.clone();
-            drop(storage); // Release the lock before API call
-            self.get_backend()
-                .await?
-                .reopen_task(&remote_id)
-                .await
-                .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+        let mut active_model: task::ActiveModel = task.into_active_model();
+        apply(&mut active_model);
+        if active_model.is_changed() {
+            TaskRepository::update(&txn, active_model).await?;
+        }
 
-            // Clear local completion flag
-            let storage = self.storage.lock().await;
+        self.enqueue_operation(&txn, task_uuid, &remote_id, &operation).await?;
 
-            if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_id).await? {
-                let mut active_model: task::ActiveModel = task.into_active_model();
-                active_model.is_completed = ActiveValue::Set(false);
-                TaskRepository::update(&storage.conn, active_model).await?;
-            }
-        }
+        txn.commit().await?;
+        drop(storage);
 
+        self.try_flush_outbox().await;
         Ok(())
     }
 }
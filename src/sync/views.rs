@@ -0,0 +1,29 @@
+use crate::entities::view_setting;
+use crate::repositories::ViewSettingRepository;
+use crate::sync::SyncService;
+use anyhow::Result;
+
+impl SyncService {
+    /// Get the sort and grouping modes and layouts of every customized view from local storage (fast)
+    pub async fn get_view_settings(&self) -> Result<Vec<view_setting::Model>> {
+        let storage = self.storage.lock().await;
+        ViewSettingRepository::get_all(&storage.conn).await
+    }
+
+    /// Remembers the sort and grouping mode and the layout of a view.
+    ///
+    /// View settings are local only, they are neither queued for the backend nor touched by a sync.
+    ///
+    /// # Errors
+    /// Returns an error if local storage update fails
+    pub async fn set_view_setting(
+        &self,
+        view_key: &str,
+        sort_mode: &str,
+        group_mode: &str,
+        layout: &str,
+    ) -> Result<()> {
+        let storage = self.storage.lock().await;
+        ViewSettingRepository::upsert(&storage.conn, view_key, sort_mode, group_mode, layout).await
+    }
+}
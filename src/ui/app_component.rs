@@ -1,22 +1,29 @@
-use crate::config::Config;
+use crate::config::{Config, FilterConfig};
 use crate::constants::*;
-use crate::entities::{label, project, section, task};
-use crate::sync::{SyncService, SyncStatus};
-use crate::ui::components::{DialogComponent, SidebarComponent, TaskListComponent};
+use crate::entities::{label, project, section, sync_conflict, task, task_label};
+use crate::sync::{
+    conflicts::ConflictResolution,
+    tasks::{NewTask, TaskEdit},
+    SyncService, SyncStatus,
+};
+use crate::ui::components::{BoardComponent, CalendarComponent, DialogComponent, SidebarComponent, TaskListComponent};
 use crate::ui::core::SidebarSelection;
 use crate::ui::core::{
     actions::{Action, DialogType},
     event_handler::EventType,
+    sync_scheduler::SyncScheduler,
     task_manager::{TaskId, TaskManager},
+    view_mode::{self, ViewLayout, ViewMode},
     Component,
 };
 use crate::utils::datetime;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use log::info;
+use log::{error, info};
 use ratatui::{
     layout::{Constraint, Layout, Rect},
     Frame,
 };
+use std::collections::HashMap;
 use tokio::sync::mpsc;
 use uuid::Uuid;
 
@@ -32,6 +39,18 @@ pub struct AppState {
     pub error_message: Option<String>,
     pub info_message: Option<String>,
     pub show_help: bool,
+    /// Set when the last sync could not reach the backend; cached data is shown meanwhile
+    pub offline: bool,
+    /// Set when the backend rate limited the last sync
+    pub rate_limited: bool,
+    /// Number of local changes still waiting in the outbox
+    pub pending_changes: u64,
+    /// Fields changed both locally and remotely that wait for the user to pick a side
+    pub conflicts: Vec<sync_conflict::Model>,
+    /// Sort and grouping mode of each view that was switched away from its own layout, by view key
+    pub view_modes: HashMap<String, ViewMode>,
+    /// Label UUIDs of every task
+    pub task_labels: HashMap<Uuid, Vec<Uuid>>,
     /// didnt we just got rid of custom scrolling ?
     pub help_scroll_offset: usize,
 }
@@ -44,11 +63,28 @@ impl AppState {
         labels: Vec<label::Model>,
         sections: Vec<section::Model>,
         tasks: Vec<task::Model>,
+        task_labels: Vec<task_label::Model>,
     ) {
         self.projects = projects;
         self.labels = labels;
         self.sections = sections;
         self.tasks = tasks;
+        self.task_labels.clear();
+        for link in task_labels {
+            self.task_labels.entry(link.task_uuid).or_default().push(link.label_uuid);
+        }
+    }
+
+    /// Key the current view's mode is stored under, `None` for views with a fixed order
+    pub fn current_view_key(&self, filters: &[FilterConfig]) -> Option<String> {
+        view_mode::view_key(&self.sidebar_selection, &self.projects, &self.labels, filters)
+    }
+
+    /// Sort and grouping mode and layout of the current view
+    pub fn current_view_mode(&self, filters: &[FilterConfig]) -> ViewMode {
+        self.current_view_key(filters)
+            .and_then(|view_key| self.view_modes.get(&view_key).copied())
+            .unwrap_or_default()
     }
 
     /// Clear any transient messages
@@ -62,6 +98,8 @@ pub struct AppComponent {
     // Component composition
     sidebar: SidebarComponent,
     task_list: TaskListComponent,
+    calendar: CalendarComponent,
+    board: BoardComponent,
     dialog: DialogComponent,
 
     // Application state
@@ -79,6 +117,8 @@ pub struct AppComponent {
     should_quit: bool,
     active_sync_task: Option<TaskId>,
     is_initial_sync: bool,
+    is_manual_sync: bool,
+    auto_sync: SyncScheduler,
 
     // Layout state
     sidebar_width: u16,
@@ -97,9 +137,18 @@ impl AppComponent {
             ..Default::default()
         };
 
+        // Debug mode never talks to the backend on its own
+        let auto_sync = if sync_service.is_debug_mode() {
+            SyncScheduler::disabled()
+        } else {
+            SyncScheduler::new(config.sync.auto_sync_interval_minutes)
+        };
+
         Self {
             sidebar,
             task_list,
+            calendar: CalendarComponent::new(),
+            board: BoardComponent::new(),
             dialog: DialogComponent::new(),
             state,
             sync_service,
@@ -109,6 +158,8 @@ impl AppComponent {
             should_quit: false,
             active_sync_task: None,
             is_initial_sync: false,
+            is_manual_sync: false,
+            auto_sync,
             sidebar_width: 30, // Default width
             screen_width: 100, // Default width
             screen_height: 50, // Default height
@@ -129,6 +180,41 @@ impl AppComponent {
         self.active_sync_task.is_some()
     }
 
+    /// Get the application state
+    pub fn state(&self) -> &AppState {
+        &self.state
+    }
+
+    /// Check if a dialog is shown on top of the views
+    pub fn is_dialog_visible(&self) -> bool {
+        self.dialog.is_visible()
+    }
+
+    /// Get the dialog shown on top of the views
+    pub fn dialog(&self) -> &DialogComponent {
+        &self.dialog
+    }
+
+    /// Start a background sync if the auto-sync interval has elapsed.
+    ///
+    /// Called on every app loop tick. Returns `true` when a sync was started.
+    pub fn tick_auto_sync(&mut self) -> bool {
+        self.tick_auto_sync_at(std::time::Instant::now())
+    }
+
+    /// [`Self::tick_auto_sync`] at the given time.
+    ///
+    /// Nothing is scheduled while a sync is running, so a due tick never overlaps one.
+    pub fn tick_auto_sync_at(&mut self, now: std::time::Instant) -> bool {
+        if !self.auto_sync.is_due(now) {
+            return false;
+        }
+
+        info!("AutoSync: Interval elapsed, starting background sync");
+        self.start_background_sync();
+        true
+    }
+
     /// Get total number of tasks
     pub fn total_tasks(&self) -> usize {
         self.state.tasks.len()
@@ -139,22 +225,19 @@ impl AppComponent {
         self.state.projects.len()
     }
 
-    /// Trigger initial sync on startup (unless in debug mode)
+    /// Load cached data on startup, then sync in the background (unless in debug mode)
     pub fn trigger_initial_sync(&mut self) {
+        // Render whatever is already in the local database right away
+        info!("AppComponent: Loading cached data from local storage");
+        self.is_initial_sync = true;
+        self.schedule_initial_data_fetch();
+
         if self.sync_service.is_debug_mode() {
             info!("AppComponent: Skipping initial sync (debug mode)");
-            // In debug mode, just load existing data from database
-            self.is_initial_sync = true;
-            self.schedule_initial_data_fetch();
             self.is_initial_sync = false;
-        } else {
-            info!("AppComponent: Starting initial sync");
-            if self.active_sync_task.is_none() {
-                self.is_initial_sync = true;
-                self.start_background_sync();
-                // Data fetch will be triggered automatically when sync completes
-                info!("AppComponent: Initial sync scheduled");
-            }
+        } else if self.active_sync_task.is_none() {
+            info!("AppComponent: Starting initial background sync");
+            self.start_background_sync();
         }
     }
 
@@ -172,6 +255,9 @@ impl AppComponent {
             "today" => SidebarSelection::Today,
             "tomorrow" => SidebarSelection::Tomorrow,
             "upcoming" => SidebarSelection::Upcoming,
+            "calendar" => SidebarSelection::Calendar,
+            "schedule" => SidebarSelection::Schedule,
+            "logbook" => SidebarSelection::Logbook,
             project_id_or_name => {
                 // Try to find project by ID first (parse as UUID), then by name
                 if let Ok(uuid) = Uuid::parse_str(project_id_or_name) {
@@ -204,11 +290,20 @@ impl AppComponent {
     /// Update all components with current data
     fn sync_component_data(&mut self) {
         // Update sidebar
+        self.sidebar.update_display_config(self.config.display.clone());
+        self.sidebar.update_filters(self.config.filters.clone());
         self.sidebar.update_data(self.state.projects.clone(), self.state.labels.clone());
         self.sidebar.selection = self.state.sidebar_selection.clone();
 
         // Update task list
         self.task_list.update_display_config(self.config.display.clone());
+        let view_mode = self.state.current_view_mode(&self.config.filters);
+        self.task_list.update_view_mode(view_mode);
+        self.task_list.update_task_labels(self.state.task_labels.clone());
+        if self.state.sidebar_selection == SidebarSelection::Calendar {
+            self.calendar.update_tasks(self.state.tasks.clone());
+            self.task_list.update_agenda_day(self.calendar.selected_day);
+        }
         self.task_list.update_data(
             self.state.tasks.clone(),
             self.state.sections.clone(),
@@ -217,6 +312,26 @@ impl AppComponent {
             self.state.sidebar_selection.clone(),
         );
 
+        // The board's selected card is the task the task keys act on
+        if self.board_active() {
+            let project = match self.state.sidebar_selection {
+                SidebarSelection::Project(index) => self.state.projects.get(index).cloned(),
+                _ => None,
+            };
+            self.board.update_display_config(self.config.display.clone());
+            self.board.update_data(
+                project,
+                self.state.sections.clone(),
+                self.state.tasks.clone(),
+                self.state.labels.clone(),
+                self.state.task_labels.clone(),
+                view_mode.sort,
+            );
+            if let Some(task_uuid) = self.board.selected_task().map(|task| task.uuid) {
+                self.task_list.select_task(task_uuid);
+            }
+        }
+
         // Update dialog
         self.dialog.update_display_config(self.config.display.clone());
         self.dialog.update_data_with_tasks(
@@ -224,9 +339,16 @@ impl AppComponent {
             self.state.labels.clone(),
             self.state.tasks.clone(),
         );
+        self.dialog.update_sections(self.state.sections.clone());
         self.dialog.set_sync_service(self.sync_service.clone());
     }
 
+    /// Whether the selected project is shown as a board
+    fn board_active(&self) -> bool {
+        matches!(self.state.sidebar_selection, SidebarSelection::Project(_))
+            && self.state.current_view_mode(&self.config.filters).layout == ViewLayout::Board
+    }
+
     /// Handle global keyboard shortcuts that aren't component-specific
     fn handle_global_key(&mut self, key: KeyEvent) -> Action {
         // Handle help panel scrolling when help is open
@@ -258,6 +380,14 @@ impl AppComponent {
                 info!("Global key: 'G' - opening logs dialog");
                 Action::ShowDialog(DialogType::Logs)
             }
+            KeyCode::Char('C') => {
+                if self.state.conflicts.is_empty() {
+                    Action::ShowDialog(DialogType::Info(UI_NO_SYNC_CONFLICTS.to_string()))
+                } else {
+                    info!("Global key: 'C' - opening sync conflict dialog");
+                    Action::ShowDialog(DialogType::ConflictResolution)
+                }
+            }
             KeyCode::Char('A') => {
                 info!("Global key: 'A' - opening project creation dialog");
                 Action::ShowDialog(DialogType::ProjectCreation)
@@ -292,6 +422,22 @@ impl AppComponent {
                         info!("Global key: 'D' - cannot delete Upcoming view");
                         Action::ShowDialog(DialogType::Info("Cannot delete the Upcoming view".to_string()))
                     }
+                    SidebarSelection::Calendar => {
+                        info!("Global key: 'D' - cannot delete Calendar view");
+                        Action::ShowDialog(DialogType::Info("Cannot delete the Calendar view".to_string()))
+                    }
+                    SidebarSelection::Schedule => {
+                        info!("Global key: 'D' - cannot delete Schedule view");
+                        Action::ShowDialog(DialogType::Info("Cannot delete the Schedule view".to_string()))
+                    }
+                    SidebarSelection::Logbook => {
+                        info!("Global key: 'D' - cannot delete Logbook view");
+                        Action::ShowDialog(DialogType::Info("Cannot delete the Logbook view".to_string()))
+                    }
+                    SidebarSelection::Filter(_) => {
+                        info!("Global key: 'D' - filters are removed in the config file");
+                        Action::ShowDialog(DialogType::Info(UI_FILTERS_IN_CONFIG.to_string()))
+                    }
                     SidebarSelection::Label(index) => {
                         if let Some(label) = self.state.labels.get(*index) {
                             info!("Global key: 'D' - deleting label '{}' (ID: {})", label.name, label.uuid);
@@ -336,6 +482,22 @@ impl AppComponent {
                         info!("Global key: 'E' - cannot edit Upcoming view");
                         Action::ShowDialog(DialogType::Info("Cannot edit the Upcoming view".to_string()))
                     }
+                    SidebarSelection::Calendar => {
+                        info!("Global key: 'E' - cannot edit Calendar view");
+                        Action::ShowDialog(DialogType::Info("Cannot edit the Calendar view".to_string()))
+                    }
+                    SidebarSelection::Schedule => {
+                        info!("Global key: 'E' - cannot edit Schedule view");
+                        Action::ShowDialog(DialogType::Info("Cannot edit the Schedule view".to_string()))
+                    }
+                    SidebarSelection::Logbook => {
+                        info!("Global key: 'E' - cannot edit Logbook view");
+                        Action::ShowDialog(DialogType::Info("Cannot edit the Logbook view".to_string()))
+                    }
+                    SidebarSelection::Filter(_) => {
+                        info!("Global key: 'E' - filters are edited in the config file");
+                        Action::ShowDialog(DialogType::Info(UI_FILTERS_IN_CONFIG.to_string()))
+                    }
                     SidebarSelection::Label(index) => {
                         if let Some(label) = self.state.labels.get(*index) {
                             info!("Global key: 'E' - editing label '{}' (ID: {})", label.name, label.uuid);
@@ -350,6 +512,53 @@ impl AppComponent {
                     }
                 }
             }
+            KeyCode::Char('M') if self.state.sidebar_selection == SidebarSelection::Calendar => {
+                // Reschedule the selected task to the day highlighted in the calendar
+                if let Some(task) = self.task_list.get_selected_task() {
+                    info!(
+                        "Global key: 'M' - moving task '{}' to {}",
+                        task.content, self.calendar.cursor
+                    );
+                    Action::SetTaskDueDate(task.uuid, self.calendar.cursor)
+                } else {
+                    info!("Global key: 'M' - no task selected");
+                    Action::ShowDialog(DialogType::Info(UI_NO_TASK_SELECTED_DUE_DATE.to_string()))
+                }
+            }
+            KeyCode::Char('v') | KeyCode::Char('V') => {
+                // 'v' cycles the sort, 'V' the grouping of the current view
+                let Some(view_key) = self.state.current_view_key(&self.config.filters) else {
+                    return Action::ShowDialog(DialogType::Info(UI_VIEW_MODE_FIXED.to_string()));
+                };
+                let mut mode = self.state.view_modes.get(&view_key).copied().unwrap_or_default();
+                if key.code == KeyCode::Char('v') {
+                    mode.sort = mode.sort.next();
+                } else {
+                    mode.group = mode.group.next();
+                }
+                info!(
+                    "Global key: '{}' - {} now sorted by {} and grouped by {}",
+                    key.code,
+                    view_key,
+                    mode.sort.label(),
+                    mode.group.label()
+                );
+                Action::SetViewMode { view_key, mode }
+            }
+            KeyCode::Char('b') => {
+                // Switch the selected project between its list and board layout
+                let view_key = match self.state.sidebar_selection {
+                    SidebarSelection::Project(_) => self.state.current_view_key(&self.config.filters),
+                    _ => None,
+                };
+                let Some(view_key) = view_key else {
+                    return Action::ShowDialog(DialogType::Info(UI_BOARD_PROJECTS_ONLY.to_string()));
+                };
+                let mut mode = self.state.view_modes.get(&view_key).copied().unwrap_or_default();
+                mode.layout = mode.layout.toggled();
+                info!("Global key: 'b' - {} now shown as {}", view_key, mode.layout.as_str());
+                Action::SetViewMode { view_key, mode }
+            }
             KeyCode::Char('r') => {
                 info!("Global key: 'r' - starting manual sync");
                 Action::StartSync
@@ -415,6 +624,30 @@ impl AppComponent {
                     Action::ShowDialog(DialogType::Info(UI_NO_TASK_SELECTED_DUE_DATE.to_string()))
                 }
             }
+            KeyCode::Char('x') => {
+                if let Some(task) = self.task_list.get_selected_task() {
+                    info!("Global key: 'x' - opening deadline input for task '{}'", task.content);
+                    Action::ShowDialog(DialogType::TaskDeadlineInput {
+                        task_uuid: task.uuid,
+                        deadline: task.deadline.clone().unwrap_or_default(),
+                    })
+                } else {
+                    info!("Global key: 'x' - no task selected");
+                    Action::ShowDialog(DialogType::Info(UI_NO_TASK_SELECTED_DEADLINE.to_string()))
+                }
+            }
+            KeyCode::Char('u') => {
+                if let Some(task) = self.task_list.get_selected_task() {
+                    info!("Global key: 'u' - opening duration input for task '{}'", task.content);
+                    Action::ShowDialog(DialogType::TaskDurationInput {
+                        task_uuid: task.uuid,
+                        duration: task.duration.clone().unwrap_or_default(),
+                    })
+                } else {
+                    info!("Global key: 'u' - no task selected");
+                    Action::ShowDialog(DialogType::Info(UI_NO_TASK_SELECTED_DURATION.to_string()))
+                }
+            }
             KeyCode::Esc => {
                 if self.dialog.is_visible() {
                     info!("Global key: Esc - closing dialog");
@@ -438,8 +671,8 @@ impl AppComponent {
             Action::StartSync => {
                 if self.active_sync_task.is_none() {
                     info!("Starting background sync");
-                    self.state.loading = true;
                     self.start_background_sync();
+                    self.is_manual_sync = true;
                 } else {
                     info!("Sync already in progress, ignoring");
                 }
@@ -451,29 +684,84 @@ impl AppComponent {
                 self.schedule_data_fetch();
                 Action::None
             }
+            Action::SyncCompleted(SyncStatus::Error { message }) => {
+                self.mark_sync_failed(message);
+                Action::None
+            }
+            Action::SyncCompleted(SyncStatus::AuthFailed { message }) => {
+                self.mark_sync_failed(message);
+                // A retry cannot succeed with the same token, so ask for a new one
+                if !self.dialog.is_visible() {
+                    self.dialog.update(Action::ShowDialog(DialogType::ApiTokenInput));
+                }
+                Action::None
+            }
+            Action::SyncCompleted(SyncStatus::RateLimited { retry_after, message }) => {
+                info!(
+                    "Sync: Rate limited by backend: {} (retry after {:?}s)",
+                    message, retry_after
+                );
+                let min_delay = std::time::Duration::from_secs(retry_after.unwrap_or(0));
+                self.active_sync_task = None;
+                self.auto_sync.record_failure_after(std::time::Instant::now(), min_delay);
+                self.state.loading = false;
+                self.state.rate_limited = true;
+                self.state.error_message = Some(message);
+                Action::None
+            }
+            Action::UpdateApiToken(api_token) => {
+                // Not passed through spawn_task_operation, which logs its arguments
+                info!("Sync: Updating API token");
+                let sync_service = self.sync_service.clone();
+                self.task_manager.spawn_task_operation(
+                    move || async move {
+                        sync_service.update_api_token(&api_token).await?;
+                        Ok(SUCCESS_API_TOKEN_UPDATED.to_string())
+                    },
+                    "Update API token".to_string(),
+                );
+                Action::None
+            }
             Action::SyncCompleted(status) => {
                 info!("Sync: Completed with status {:?}", status);
                 self.active_sync_task = None;
+                self.auto_sync.record_success(std::time::Instant::now());
                 self.state.loading = false;
+                self.state.offline = false;
+                self.state.rate_limited = false;
+                self.state.error_message = None;
 
                 // Extract data from sync status and update components
                 self.update_data_from_sync(status);
                 self.sync_component_data();
 
                 self.state.info_message = Some(SUCCESS_SYNC_COMPLETED.to_string());
-                info!("Sync: Showing completion info dialog");
-                Action::ShowDialog(DialogType::Info(self.state.info_message.clone().unwrap()))
+                // Only a sync the user asked for is confirmed, and never over a dialog in use;
+                // scheduled syncs just update the status indicator
+                if std::mem::take(&mut self.is_manual_sync) && !self.dialog.is_visible() {
+                    info!("Sync: Showing completion info dialog");
+                    Action::ShowDialog(DialogType::Info(SUCCESS_SYNC_COMPLETED.to_string()))
+                } else {
+                    Action::None
+                }
             }
             Action::SyncFailed(error) => {
-                info!("Sync: Failed with error: {}", error);
-                self.active_sync_task = None;
-                self.state.loading = false;
-                self.is_initial_sync = false; // Reset flag on failure
-                self.state.error_message = Some(error);
-                Action::ShowDialog(DialogType::Error(self.state.error_message.clone().unwrap_or_default()))
+                self.mark_sync_failed(error);
+                Action::None
             }
             Action::ShowDialog(ref dialog_type) => {
                 info!("Dialog: Showing dialog {:?}", dialog_type);
+                match dialog_type {
+                    DialogType::CommentThread { task_uuid } => {
+                        let _task_id =
+                            self.task_manager
+                                .spawn_comments_load(self.sync_service.clone(), *task_uuid, true);
+                    }
+                    DialogType::TaskEdit { task_uuid, .. } | DialogType::LabelPicker { task_uuid } => {
+                        let _task_id = self.task_manager.spawn_task_labels_load(self.sync_service.clone(), *task_uuid);
+                    }
+                    _ => {}
+                }
                 // Dialog component will handle the actual dialog setup
                 action
             }
@@ -488,6 +776,16 @@ impl AppComponent {
                     SidebarSelection::Today => "Today".to_string(),
                     SidebarSelection::Tomorrow => "Tomorrow".to_string(),
                     SidebarSelection::Upcoming => "Upcoming".to_string(),
+                    SidebarSelection::Calendar => "Calendar".to_string(),
+                    SidebarSelection::Schedule => "Schedule".to_string(),
+                    SidebarSelection::Logbook => "Logbook".to_string(),
+                    SidebarSelection::Filter(index) => {
+                        if let Some(filter) = self.config.filters.get(*index) {
+                            format!("Filter({}) '{}'", index, filter.name)
+                        } else {
+                            format!("Filter({}) [unknown]", index)
+                        }
+                    }
                     SidebarSelection::Project(index) => {
                         if let Some(project) = self.state.projects.get(*index) {
                             format!("Project({}) '{}'", index, project.name)
@@ -509,22 +807,28 @@ impl AppComponent {
                 // Reload data for the new selection
                 self.schedule_data_fetch();
                 info!("Navigation: Scheduled data fetch for new selection");
+                if selection == SidebarSelection::Logbook {
+                    // Regular syncs only bring in open tasks, fetch the recent completions too
+                    self.task_manager.spawn_logbook_refresh(self.sync_service.clone());
+                }
                 Action::None
             }
             // Task operations with background execution
-            Action::CreateTask { content, project_uuid } => {
-                let project_desc = match &project_uuid {
-                    Some(uuid) => format!(" in project {}", uuid),
-                    None => " in inbox".to_string(),
+            Action::CreateTask(new_task) => {
+                let project_desc = match (&new_task.section_uuid, &new_task.project_uuid) {
+                    (Some(uuid), _) => format!(" in section {}", uuid),
+                    (None, Some(uuid)) => format!(" in project {}", uuid),
+                    (None, None) => " in inbox".to_string(),
                 };
-                info!("Task: Creating task with content '{}'{}", content, project_desc);
+                info!(
+                    "Task: Creating task with content '{}'{}",
+                    new_task.content, project_desc
+                );
 
-                // Format task info to include both content and project_uuid
-                let task_info = match project_uuid {
-                    Some(pid) => format!("{}|{}", content, pid),
-                    None => content,
-                };
-                self.spawn_task_operation("Create task".to_string(), task_info);
+                match serde_json::to_string(&new_task) {
+                    Ok(task_json) => self.spawn_task_operation("Create task".to_string(), task_json),
+                    Err(e) => error!("Task: Failed to serialize new task: {}", e),
+                }
                 Action::None
             }
             Action::CompleteTask(task_id) => {
@@ -652,9 +956,92 @@ impl AppComponent {
                 );
                 Action::None
             }
-            Action::EditTask { task_uuid, content } => {
-                info!("Task: Editing task UUID {} with new content '{}'", task_uuid, content);
-                self.spawn_task_operation("Edit task".to_string(), format!("{}: {}", task_uuid, content));
+            Action::SetTaskDueDate(task_uuid, date) => {
+                info!("Task: Rescheduling task {} to {}", task_uuid, date);
+                self.spawn_task_operation(
+                    "Set task due date".to_string(),
+                    format!("{}|{}", task_uuid, datetime::format_ymd(date)),
+                );
+                Action::None
+            }
+            Action::SetTaskDeadline(task_uuid, deadline) => {
+                info!("Task: Setting deadline '{}' for task {}", deadline, task_uuid);
+                self.spawn_task_operation("Set task deadline".to_string(), format!("{}|{}", task_uuid, deadline));
+                Action::None
+            }
+            Action::SetTaskDuration(task_uuid, duration) => {
+                info!("Task: Setting duration '{}' for task {}", duration, task_uuid);
+                self.spawn_task_operation("Set task duration".to_string(), format!("{}|{}", task_uuid, duration));
+                Action::None
+            }
+            Action::EditTask { task_uuid, edit } => {
+                info!(
+                    "Task: Editing task UUID {} with new content '{}'",
+                    task_uuid, edit.content
+                );
+                match serde_json::to_string(&edit) {
+                    Ok(edit_json) => {
+                        self.spawn_task_operation("Edit task".to_string(), format!("{}|{}", task_uuid, edit_json))
+                    }
+                    Err(e) => error!("Task: Failed to encode edit of task {}: {}", task_uuid, e),
+                }
+                Action::None
+            }
+            Action::SetTaskLabels {
+                task_uuid,
+                label_uuids,
+                new_label_names,
+            } => {
+                info!(
+                    "Task: Setting {} labels ({} new) on task UUID {}",
+                    label_uuids.len() + new_label_names.len(),
+                    new_label_names.len(),
+                    task_uuid
+                );
+                match serde_json::to_string(&(label_uuids, new_label_names)) {
+                    Ok(labels_json) => self
+                        .spawn_task_operation("Set task labels".to_string(), format!("{}|{}", task_uuid, labels_json)),
+                    Err(e) => error!("Task: Failed to encode labels of task {}: {}", task_uuid, e),
+                }
+                Action::None
+            }
+            Action::MoveTask {
+                task_uuid,
+                project_uuid,
+                section_uuid,
+                parent_uuid,
+            } => {
+                info!("Task: Moving task UUID {} to project UUID {}", task_uuid, project_uuid);
+                let optional = |uuid: Option<Uuid>| uuid.map(|uuid| uuid.to_string()).unwrap_or_default();
+                self.spawn_task_operation(
+                    "Move task".to_string(),
+                    format!(
+                        "{}|{}|{}|{}",
+                        task_uuid,
+                        project_uuid,
+                        optional(section_uuid),
+                        optional(parent_uuid)
+                    ),
+                );
+                Action::None
+            }
+            Action::SetTaskCollapsed {
+                task_uuid,
+                is_collapsed,
+            } => {
+                info!(
+                    "Task: Setting collapsed to {} for task UUID {}",
+                    is_collapsed, task_uuid
+                );
+                self.spawn_task_operation(
+                    "Set task collapsed".to_string(),
+                    format!("{}|{}", task_uuid, is_collapsed),
+                );
+                Action::None
+            }
+            Action::ReorderTask { task_uuid, offset } => {
+                info!("Task: Moving task UUID {} by {}", task_uuid, offset);
+                self.spawn_task_operation("Reorder task".to_string(), format!("{}|{}", task_uuid, offset));
                 Action::None
             }
             Action::RestoreTask(task_id) => {
@@ -662,6 +1049,17 @@ impl AppComponent {
                 self.spawn_task_operation("Restore task".to_string(), task_id);
                 Action::None
             }
+            Action::ResolveConflict {
+                conflict_uuid,
+                resolution,
+            } => {
+                info!("Sync: Resolving conflict {} with {:?}", conflict_uuid, resolution);
+                self.spawn_task_operation(
+                    "Resolve conflict".to_string(),
+                    format!("{}|{}", conflict_uuid, resolution.as_str()),
+                );
+                Action::None
+            }
             Action::CreateProject { name, parent_uuid } => {
                 let parent_desc = match &parent_uuid {
                     Some(uuid) => format!(" with parent {}", uuid),
@@ -688,6 +1086,11 @@ impl AppComponent {
                 self.spawn_task_operation("Delete project".to_string(), project_id.to_string());
                 Action::None
             }
+            Action::ReorderProject { project_uuid, offset } => {
+                info!("Project: Moving project {} by {}", project_uuid, offset);
+                self.spawn_task_operation("Reorder project".to_string(), format!("{}|{}", project_uuid, offset));
+                Action::None
+            }
             Action::DeleteLabel(label_id) => {
                 // Find label name for better logging
                 let label_desc = if let Some(label) = self.state.labels.iter().find(|l| l.uuid == label_id) {
@@ -726,11 +1129,43 @@ impl AppComponent {
                 self.spawn_task_operation("Edit label".to_string(), format!("{}: {}", label_uuid, name));
                 Action::None
             }
+            Action::CreateSection { project_uuid, name } => {
+                info!("Section: Creating section '{}' in project {}", name, project_uuid);
+                self.spawn_task_operation("Create section".to_string(), format!("{}|{}", project_uuid, name));
+                Action::None
+            }
+            Action::EditSection { section_uuid, name } => {
+                // Find section name for better logging
+                let section_desc = if let Some(section) = self.state.sections.iter().find(|s| s.uuid == section_uuid) {
+                    format!("UUID {} '{}' -> '{}'", section_uuid, section.name, name)
+                } else {
+                    format!("UUID {} [unknown] -> '{}'", section_uuid, name)
+                };
+                info!("Section: Editing section {}", section_desc);
+                self.spawn_task_operation("Edit section".to_string(), format!("{}|{}", section_uuid, name));
+                Action::None
+            }
+            Action::DeleteSection(section_uuid) => {
+                info!("Section: Deleting section {}", section_uuid);
+                self.spawn_task_operation("Delete section".to_string(), section_uuid.to_string());
+                Action::None
+            }
+            Action::MoveSection { section_uuid, offset } => {
+                info!("Section: Moving section {} by {}", section_uuid, offset);
+                self.spawn_task_operation("Move section".to_string(), format!("{}|{}", section_uuid, offset));
+                Action::None
+            }
+            Action::CreateComment { task_uuid, content } => {
+                info!("Comment: Adding comment to task {}", task_uuid);
+                self.spawn_task_operation("Create comment".to_string(), format!("{}|{}", task_uuid, content));
+                Action::None
+            }
             Action::InitialDataLoaded {
                 projects,
                 labels,
                 sections,
                 tasks,
+                task_labels,
             } => {
                 info!(
                     "InitialData: Loaded {} projects, {} labels, {} sections, {} tasks",
@@ -740,8 +1175,14 @@ impl AppComponent {
                     tasks.len()
                 );
 
+                // An empty cache (first run) still needs the default selection once sync brings data in
+                if !projects.is_empty() {
+                    self.is_initial_sync = false;
+                }
+
                 // Update app state with loaded data
-                self.state.update_data(projects, labels, sections, tasks);
+                self.state.update_data(projects, labels, sections, tasks, task_labels);
+                self.state.loading = false;
 
                 // Set initial sidebar selection based on config (now we have projects loaded)
                 self.set_initial_sidebar_selection();
@@ -750,6 +1191,9 @@ impl AppComponent {
                 // Fetch data for the newly selected sidebar item
                 self.schedule_data_fetch();
                 info!("AppComponent: Scheduled data fetch for initial sidebar selection");
+                if self.state.sidebar_selection == SidebarSelection::Logbook {
+                    self.task_manager.spawn_logbook_refresh(self.sync_service.clone());
+                }
 
                 self.sync_component_data();
                 info!("InitialData: Updated all component data after initial data load");
@@ -760,6 +1204,7 @@ impl AppComponent {
                 labels,
                 sections,
                 tasks,
+                task_labels,
             } => {
                 info!(
                     "Data: Loaded {} projects, {} labels, {} sections, {} tasks",
@@ -769,12 +1214,73 @@ impl AppComponent {
                     tasks.len()
                 );
 
+                // Projects are listed in their manual order, so a reorder can shift the selected one
+                let selected_project = match self.state.sidebar_selection {
+                    SidebarSelection::Project(index) => self.state.projects.get(index).map(|p| p.uuid),
+                    _ => None,
+                };
+
                 // Update app state with loaded data
-                self.state.update_data(projects, labels, sections, tasks);
+                self.state.update_data(projects, labels, sections, tasks, task_labels);
+
+                let moved_index = selected_project
+                    .and_then(|uuid| self.state.projects.iter().position(|p| p.uuid == uuid))
+                    .filter(|index| self.state.sidebar_selection != SidebarSelection::Project(*index));
+                if let Some(index) = moved_index {
+                    // The tasks were loaded for whatever project now sits at the old index
+                    self.state.sidebar_selection = SidebarSelection::Project(index);
+                    self.schedule_data_fetch();
+                }
+
                 self.sync_component_data();
                 info!("Data: Updated all component data after data load");
                 Action::None
             }
+            Action::SetViewMode { view_key, mode } => {
+                self.state.view_modes.insert(view_key.clone(), mode);
+                self.sync_component_data();
+                self.spawn_task_operation(
+                    "Set view mode".to_string(),
+                    format!(
+                        "{}|{}|{}|{}",
+                        mode.sort.as_str(),
+                        mode.group.as_str(),
+                        mode.layout.as_str(),
+                        view_key
+                    ),
+                );
+                Action::None
+            }
+            Action::ViewModesLoaded(view_settings) => {
+                info!("Data: Loaded {} view modes", view_settings.len());
+                self.state.view_modes = view_settings
+                    .into_iter()
+                    .map(|setting| {
+                        let mode = ViewMode::from_stored(&setting.sort_mode, &setting.group_mode, &setting.layout);
+                        (setting.view_key, mode)
+                    })
+                    .collect();
+                Action::None
+            }
+            Action::PendingChangesUpdated(count) => {
+                self.state.pending_changes = count;
+                Action::None
+            }
+            Action::ConflictsUpdated(conflicts) => {
+                let new_conflicts = conflicts.len() > self.state.conflicts.len();
+                self.state.conflicts = conflicts.clone();
+                self.dialog.update_conflicts(conflicts);
+
+                // Open the resolution dialog when new conflicts show up and close it once all are resolved
+                let dialog_open = matches!(self.dialog.dialog_type, Some(DialogType::ConflictResolution));
+                if dialog_open && self.state.conflicts.is_empty() {
+                    self.dialog.update(Action::HideDialog);
+                } else if new_conflicts && !self.dialog.is_visible() {
+                    info!("Sync: {} conflicts need resolving", self.state.conflicts.len());
+                    self.dialog.update(Action::ShowDialog(DialogType::ConflictResolution));
+                }
+                Action::None
+            }
             Action::SearchTasks(query) => {
                 info!("Search: Starting database search for '{}'", query);
                 let sync_service = self.sync_service.clone();
@@ -787,6 +1293,16 @@ impl AppComponent {
                 self.dialog.update_search_results(&query, results);
                 Action::None
             }
+            Action::CommentsLoaded { task_uuid, comments } => {
+                info!("Comment: Loaded {} comments of task {}", comments.len(), task_uuid);
+                self.dialog.update_comments(&task_uuid, comments);
+                Action::None
+            }
+            Action::TaskLabelsLoaded { task_uuid, label_uuids } => {
+                info!("Task: Loaded {} labels of task {}", label_uuids.len(), task_uuid);
+                self.dialog.update_task_labels(&task_uuid, label_uuids);
+                Action::None
+            }
             Action::NextTask => {
                 info!("Navigation: Next task (j/down)");
                 action
@@ -799,6 +1315,12 @@ impl AppComponent {
                 info!("Data: Refreshing UI data after task operation");
                 // Schedule a data fetch to reload current view with updated data
                 self.schedule_data_fetch();
+                // Show a posted comment in the open thread
+                if let Some(DialogType::CommentThread { task_uuid }) = self.dialog.dialog_type {
+                    let _task_id = self
+                        .task_manager
+                        .spawn_comments_load(self.sync_service.clone(), task_uuid, false);
+                }
                 Action::None
             }
             // Help panel scrolling actions
@@ -839,10 +1361,22 @@ impl AppComponent {
         }
     }
 
+    /// Keep working from the local database instead of interrupting with an error dialog
+    fn mark_sync_failed(&mut self, error: String) {
+        info!("Sync: Failed with error: {} (continuing offline)", error);
+        self.active_sync_task = None;
+        self.auto_sync.record_failure(std::time::Instant::now());
+        self.state.loading = false;
+        self.state.offline = true;
+        self.state.error_message = Some(error);
+    }
+
     fn start_background_sync(&mut self) {
         let sync_service = self.sync_service.clone();
         let task_id = self.task_manager.spawn_sync(sync_service);
         self.active_sync_task = Some(task_id);
+        self.is_manual_sync = false;
+        self.auto_sync.sync_started();
     }
 
     /// Spawn a generic task operation (now with actual API calls and data refresh)
@@ -909,6 +1443,19 @@ impl AppComponent {
                             Err(ERROR_INVALID_DATE_FORMAT.to_string())
                         }
                     }
+                    "Set task due date" => {
+                        // task_info format: "task_id|YYYY-MM-DD"
+                        match task_info.split_once('|').map(|(id, date)| (Uuid::parse_str(id), date)) {
+                            Some((Ok(task_uuid), date)) if datetime::parse_date(date).is_ok() => {
+                                match sync_service.update_task_due_date(&task_uuid, Some(date)).await {
+                                    Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_RESCHEDULED, date)),
+                                    Err(e) => Err(format!("{}: {}", ERROR_TASK_DUE_DATE_FAILED, e)),
+                                }
+                            }
+                            Some((Err(e), _)) => Err(format!("Invalid task UUID: {}", e)),
+                            _ => Err(ERROR_INVALID_DATE_FORMAT.to_string()),
+                        }
+                    }
                     "Set task due tomorrow" => {
                         // task_info format: "task_id|tomorrow"
                         if let Some((task_id_str, _)) = task_info.split_once('|') {
@@ -981,37 +1528,151 @@ impl AppComponent {
                             Err(ERROR_INVALID_DATE_FORMAT.to_string())
                         }
                     }
-                    "Create task" => {
-                        // task_info format: "content|project_id" or just "content" for inbox
-                        if let Some((content, project_id_str)) = task_info.split_once('|') {
-                            // Task has a specific project - parse the UUID
-                            match Uuid::parse_str(project_id_str) {
-                                Ok(project_uuid) => match sync_service.create_task(content, Some(project_uuid)).await {
-                                    Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_CREATED_PROJECT, content)),
-                                    Err(e) => Err(format!("{}: {}", ERROR_TASK_CREATE_FAILED, e)),
+                    "Set task deadline" => {
+                        if let Some((task_id_str, deadline)) = task_info.split_once('|') {
+                            match Uuid::parse_str(task_id_str) {
+                                Ok(task_uuid) => match sync_service.update_task_deadline(&task_uuid, deadline).await {
+                                    Ok(()) => Ok(SUCCESS_TASK_DEADLINE_SET.to_string()),
+                                    Err(e) => Err(format!("{}: {}", ERROR_TASK_DEADLINE_FAILED, e)),
                                 },
-                                Err(e) => Err(format!("Invalid project UUID: {}", e)),
+                                Err(e) => Err(format!("Invalid task UUID: {}", e)),
                             }
                         } else {
-                            // Task goes to inbox (no project_id)
-                            match sync_service.create_task(&task_info, None).await {
-                                Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_CREATED_INBOX, task_info)),
-                                Err(e) => Err(format!("{}: {}", ERROR_TASK_CREATE_FAILED, e)),
-                            }
+                            Err(ERROR_INVALID_PLANNING_FORMAT.to_string())
                         }
                     }
-                    "Edit task" => {
-                        // task_info format: "task_id: new_content"
-                        if let Some((task_id_str, content)) = task_info.split_once(": ") {
+                    "Set task duration" => {
+                        if let Some((task_id_str, duration)) = task_info.split_once('|') {
                             match Uuid::parse_str(task_id_str) {
-                                Ok(task_uuid) => match sync_service.update_task_content(&task_uuid, content).await {
-                                    Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_UPDATED, task_id_str)),
-                                    Err(e) => Err(format!("{}: {}", ERROR_TASK_UPDATE_FAILED, e)),
+                                Ok(task_uuid) => match sync_service.update_task_duration(&task_uuid, duration).await {
+                                    Ok(()) => Ok(SUCCESS_TASK_DURATION_SET.to_string()),
+                                    Err(e) => Err(format!("{}: {}", ERROR_TASK_DURATION_FAILED, e)),
                                 },
                                 Err(e) => Err(format!("Invalid task UUID: {}", e)),
                             }
                         } else {
-                            Err(ERROR_INVALID_TASK_EDIT_FORMAT.to_string())
+                            Err(ERROR_INVALID_PLANNING_FORMAT.to_string())
+                        }
+                    }
+                    // task_info format: the new task as JSON
+                    "Create task" => match serde_json::from_str::<NewTask>(&task_info) {
+                        Ok(new_task) => match sync_service.create_task(&new_task).await {
+                            Ok(_) if new_task.project_uuid.is_some() || new_task.section_uuid.is_some() => {
+                                Ok(format!("{}: {}", SUCCESS_TASK_CREATED_PROJECT, new_task.content))
+                            }
+                            Ok(_) => Ok(format!("{}: {}", SUCCESS_TASK_CREATED_INBOX, new_task.content)),
+                            Err(e) => Err(format!("{}: {}", ERROR_TASK_CREATE_FAILED, e)),
+                        },
+                        Err(_) => Err(ERROR_INVALID_NEW_TASK_FORMAT.to_string()),
+                    },
+                    "Edit task" => {
+                        // task_info format: "task_id|edit_json"
+                        let parsed = task_info.split_once('|').and_then(|(id, edit)| {
+                            Some((Uuid::parse_str(id).ok()?, serde_json::from_str::<TaskEdit>(edit).ok()?))
+                        });
+                        match parsed {
+                            Some((task_uuid, edit)) => match sync_service.update_task(&task_uuid, &edit).await {
+                                Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_UPDATED, edit.content)),
+                                Err(e) => Err(format!("{}: {}", ERROR_TASK_UPDATE_FAILED, e)),
+                            },
+                            None => Err(ERROR_INVALID_TASK_EDIT_FORMAT.to_string()),
+                        }
+                    }
+                    "Set task labels" => {
+                        // task_info format: "task_id|[label_uuids, new_label_names]" with the lists as JSON
+                        let parsed = task_info.split_once('|').and_then(|(id, labels)| {
+                            Some((
+                                Uuid::parse_str(id).ok()?,
+                                serde_json::from_str::<(Vec<Uuid>, Vec<String>)>(labels).ok()?,
+                            ))
+                        });
+                        match parsed {
+                            Some((task_uuid, (label_uuids, new_label_names))) => {
+                                match sync_service.set_task_labels(&task_uuid, &label_uuids, &new_label_names).await {
+                                    Ok(()) => Ok(SUCCESS_TASK_LABELS_SET.to_string()),
+                                    Err(e) => Err(format!("{}: {}", ERROR_TASK_LABELS_FAILED, e)),
+                                }
+                            }
+                            None => Err(ERROR_INVALID_TASK_LABELS_FORMAT.to_string()),
+                        }
+                    }
+                    "Move task" => {
+                        // task_info format: "task_id|project_id|section_id|parent_id", empty for none
+                        let optional = |id: &str| match id {
+                            "" => Ok(None),
+                            id => Uuid::parse_str(id).map(Some),
+                        };
+                        let mut parts = task_info.split('|');
+                        let parsed = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+                            (Some(task), Some(project), Some(section), Some(parent)) => {
+                                match (
+                                    Uuid::parse_str(task),
+                                    Uuid::parse_str(project),
+                                    optional(section),
+                                    optional(parent),
+                                ) {
+                                    (Ok(task_uuid), Ok(project_uuid), Ok(section_uuid), Ok(parent_uuid)) => {
+                                        Some((task_uuid, project_uuid, section_uuid, parent_uuid))
+                                    }
+                                    _ => None,
+                                }
+                            }
+                            _ => None,
+                        };
+                        match parsed {
+                            Some((task_uuid, project_uuid, section_uuid, parent_uuid)) => {
+                                match sync_service
+                                    .move_task(&task_uuid, project_uuid, section_uuid, parent_uuid)
+                                    .await
+                                {
+                                    Ok(()) => Ok(SUCCESS_TASK_MOVED.to_string()),
+                                    Err(e) => Err(format!("{}: {}", ERROR_TASK_MOVE_FAILED, e)),
+                                }
+                            }
+                            None => Err(ERROR_INVALID_TASK_MOVE_FORMAT.to_string()),
+                        }
+                    }
+                    "Set task collapsed" => {
+                        // task_info format: "task_id|is_collapsed"
+                        let parsed = task_info.split_once('|').and_then(|(id, is_collapsed)| {
+                            Some((Uuid::parse_str(id).ok()?, is_collapsed.parse::<bool>().ok()?))
+                        });
+                        match parsed {
+                            Some((task_uuid, is_collapsed)) => {
+                                match sync_service.set_task_collapsed(&task_uuid, is_collapsed).await {
+                                    Ok(()) => Ok(SUCCESS_TASK_COLLAPSED_SET.to_string()),
+                                    Err(e) => Err(format!("{}: {}", ERROR_TASK_COLLAPSE_FAILED, e)),
+                                }
+                            }
+                            None => Err(ERROR_INVALID_TASK_COLLAPSE_FORMAT.to_string()),
+                        }
+                    }
+                    "Reorder task" => {
+                        // task_info format: "task_id|offset"
+                        let parsed = task_info
+                            .split_once('|')
+                            .and_then(|(id, offset)| Some((Uuid::parse_str(id).ok()?, offset.parse::<i32>().ok()?)));
+                        match parsed {
+                            Some((task_uuid, offset)) => match sync_service.reorder_task(&task_uuid, offset).await {
+                                Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_REORDERED, task_uuid)),
+                                Err(e) => Err(format!("{}: {}", ERROR_TASK_REORDER_FAILED, e)),
+                            },
+                            None => Err(ERROR_INVALID_REORDER_FORMAT.to_string()),
+                        }
+                    }
+                    "Reorder project" => {
+                        // task_info format: "project_id|offset"
+                        let parsed = task_info
+                            .split_once('|')
+                            .and_then(|(id, offset)| Some((Uuid::parse_str(id).ok()?, offset.parse::<i32>().ok()?)));
+                        match parsed {
+                            Some((project_uuid, offset)) => {
+                                match sync_service.reorder_project(&project_uuid, offset).await {
+                                    Ok(()) => Ok(format!("{}: {}", SUCCESS_PROJECT_REORDERED, project_uuid)),
+                                    Err(e) => Err(format!("{}: {}", ERROR_PROJECT_REORDER_FAILED, e)),
+                                }
+                            }
+                            None => Err(ERROR_INVALID_REORDER_FORMAT.to_string()),
                         }
                     }
                     "Restore task" => match Uuid::parse_str(&task_info) {
@@ -1021,6 +1682,21 @@ impl AppComponent {
                         },
                         Err(e) => Err(format!("Invalid task UUID: {}", e)),
                     },
+                    "Resolve conflict" => {
+                        // task_info format: "conflict_id|local" or "conflict_id|remote"
+                        let parsed = task_info
+                            .split_once('|')
+                            .and_then(|(id, side)| Some((Uuid::parse_str(id).ok()?, ConflictResolution::parse(side)?)));
+                        match parsed {
+                            Some((conflict_uuid, resolution)) => {
+                                match sync_service.resolve_conflict(&conflict_uuid, resolution).await {
+                                    Ok(()) => Ok(SUCCESS_CONFLICT_RESOLVED.to_string()),
+                                    Err(e) => Err(format!("{}: {}", ERROR_CONFLICT_RESOLVE_FAILED, e)),
+                                }
+                            }
+                            None => Err(ERROR_INVALID_CONFLICT_FORMAT.to_string()),
+                        }
+                    }
                     "Create project" => {
                         // project_info format: "name|parent_id" or just "name" for root project
                         if let Some((name, parent_id_str)) = task_info.split_once('|') {
@@ -1094,6 +1770,79 @@ impl AppComponent {
                             Err(ERROR_INVALID_LABEL_EDIT_FORMAT.to_string())
                         }
                     }
+                    "Create section" => {
+                        // task_info format: "project_id|name"
+                        match task_info.split_once('|').map(|(id, name)| (Uuid::parse_str(id), name)) {
+                            Some((Ok(project_uuid), name)) => {
+                                match sync_service.create_section(&project_uuid, name).await {
+                                    Ok(()) => Ok(format!("{}: {}", SUCCESS_SECTION_CREATED, name)),
+                                    Err(e) => Err(format!("{}: {}", ERROR_SECTION_CREATE_FAILED, e)),
+                                }
+                            }
+                            Some((Err(e), _)) => Err(format!("Invalid project UUID: {}", e)),
+                            None => Err(ERROR_INVALID_SECTION_FORMAT.to_string()),
+                        }
+                    }
+                    "Edit section" => {
+                        // task_info format: "section_id|new_name"
+                        match task_info.split_once('|').map(|(id, name)| (Uuid::parse_str(id), name)) {
+                            Some((Ok(section_uuid), name)) => {
+                                match sync_service.update_section_content(&section_uuid, name).await {
+                                    Ok(()) => Ok(format!("{}: {}", SUCCESS_SECTION_UPDATED, section_uuid)),
+                                    Err(e) => Err(format!("{}: {}", ERROR_SECTION_UPDATE_FAILED, e)),
+                                }
+                            }
+                            Some((Err(e), _)) => Err(format!("Invalid section UUID: {}", e)),
+                            None => Err(ERROR_INVALID_SECTION_FORMAT.to_string()),
+                        }
+                    }
+                    "Delete section" => match Uuid::parse_str(&task_info) {
+                        Ok(section_uuid) => match sync_service.delete_section(&section_uuid).await {
+                            Ok(()) => Ok(format!("{}: {}", SUCCESS_SECTION_DELETED, task_info)),
+                            Err(e) => Err(format!("{}: {}", ERROR_SECTION_DELETE_FAILED, e)),
+                        },
+                        Err(e) => Err(format!("Invalid section UUID: {}", e)),
+                    },
+                    "Move section" => {
+                        // task_info format: "section_id|offset"
+                        let parsed = task_info
+                            .split_once('|')
+                            .and_then(|(id, offset)| Some((Uuid::parse_str(id).ok()?, offset.parse::<i32>().ok()?)));
+                        match parsed {
+                            Some((section_uuid, offset)) => {
+                                match sync_service.move_section(&section_uuid, offset).await {
+                                    Ok(()) => Ok(format!("{}: {}", SUCCESS_SECTION_MOVED, section_uuid)),
+                                    Err(e) => Err(format!("{}: {}", ERROR_SECTION_MOVE_FAILED, e)),
+                                }
+                            }
+                            None => Err(ERROR_INVALID_SECTION_FORMAT.to_string()),
+                        }
+                    }
+                    "Set view mode" => {
+                        // task_info format: "sort_mode|group_mode|layout|view_key"; the key may contain '|'
+                        match task_info.splitn(4, '|').collect::<Vec<_>>().as_slice() {
+                            [sort_mode, group_mode, layout, view_key] => {
+                                match sync_service.set_view_setting(view_key, sort_mode, group_mode, layout).await {
+                                    Ok(()) => Ok(format!("{}: {}", SUCCESS_VIEW_MODE_SET, view_key)),
+                                    Err(e) => Err(format!("{}: {}", ERROR_VIEW_MODE_FAILED, e)),
+                                }
+                            }
+                            _ => Err(ERROR_INVALID_VIEW_MODE_FORMAT.to_string()),
+                        }
+                    }
+                    "Create comment" => {
+                        // task_info format: "task_id|content"
+                        match task_info.split_once('|').map(|(id, content)| (Uuid::parse_str(id), content)) {
+                            Some((Ok(task_uuid), content)) => {
+                                match sync_service.create_comment(&task_uuid, content).await {
+                                    Ok(()) => Ok(format!("{}: {}", SUCCESS_COMMENT_CREATED, task_uuid)),
+                                    Err(e) => Err(format!("{}: {}", ERROR_COMMENT_CREATE_FAILED, e)),
+                                }
+                            }
+                            Some((Err(e), _)) => Err(format!("Invalid task UUID: {}", e)),
+                            None => Err(ERROR_INVALID_COMMENT_FORMAT.to_string()),
+                        }
+                    }
                     _ => Err(format!("{}: {}", ERROR_UNKNOWN_OPERATION, op_name)),
                 };
 
@@ -1119,16 +1868,24 @@ impl AppComponent {
 
     /// Schedule a background task to fetch initial data after sync completion
     fn schedule_initial_data_fetch(&mut self) {
-        let _task_id =
-            self.task_manager
-                .spawn_data_load(self.sync_service.clone(), self.state.sidebar_selection.clone(), true);
+        let _task_id = self.task_manager.spawn_data_load(
+            self.sync_service.clone(),
+            self.state.sidebar_selection.clone(),
+            self.config.filters.clone(),
+            self.calendar.load_range(),
+            true,
+        );
     }
 
     /// Schedule a background task to fetch data after navigation or changes
     fn schedule_data_fetch(&mut self) {
-        let _task_id =
-            self.task_manager
-                .spawn_data_load(self.sync_service.clone(), self.state.sidebar_selection.clone(), false);
+        let _task_id = self.task_manager.spawn_data_load(
+            self.sync_service.clone(),
+            self.state.sidebar_selection.clone(),
+            self.config.filters.clone(),
+            self.calendar.load_range(),
+            false,
+        );
     }
 
     /// Process background actions from task manager
@@ -1169,7 +1926,18 @@ impl AppComponent {
                         // Mouse is in task list area - calculate proper width
                         let task_list_width = self.screen_width.saturating_sub(self.sidebar_width).max(1);
                         let task_list_area = Rect::new(self.sidebar_width, 0, task_list_width, self.screen_height);
-                        self.task_list.handle_mouse(mouse, task_list_area)
+                        if self.state.sidebar_selection == SidebarSelection::Calendar {
+                            let [calendar_area, agenda_area] = calendar_layout(task_list_area);
+                            if mouse.row < agenda_area.y {
+                                self.calendar.handle_mouse(mouse, calendar_area)
+                            } else {
+                                self.task_list.handle_mouse(mouse, agenda_area)
+                            }
+                        } else if self.board_active() {
+                            self.board.handle_mouse(mouse, task_list_area)
+                        } else {
+                            self.task_list.handle_mouse(mouse, task_list_area)
+                        }
                     }
                 } else {
                     Action::None
@@ -1184,8 +1952,19 @@ impl AppComponent {
                     // Try sidebar first (for J/K navigation)
                     let sidebar_action = self.sidebar.handle_key_events(key);
 
+                    // The calendar grid and the board take their navigation keys before the task list
+                    let calendar_action = if self.state.sidebar_selection == SidebarSelection::Calendar {
+                        self.calendar.handle_key_events(key)
+                    } else if self.board_active() {
+                        self.board.handle_key_events(key)
+                    } else {
+                        Action::None
+                    };
+
                     if !matches!(sidebar_action, Action::None) {
                         sidebar_action
+                    } else if !matches!(calendar_action, Action::None) {
+                        calendar_action
                     } else {
                         // Then try task list (for j/k and other task operations)
                         let task_list_action = self.task_list.handle_key_events(key);
@@ -1220,6 +1999,8 @@ impl AppComponent {
         // Process action through component hierarchy
         let action = self.dialog.update(action);
         let action = self.sidebar.update(action);
+        let action = self.calendar.update(action);
+        let action = self.board.update(action);
         let action = self.task_list.update(action);
 
         // Handle app-level actions
@@ -1232,6 +2013,11 @@ impl AppComponent {
     }
 }
 
+/// Split the main area of the Calendar view into the month grid and the agenda below it
+fn calendar_layout(area: Rect) -> [Rect; 2] {
+    Layout::vertical([Constraint::Percentage(60), Constraint::Min(5)]).areas(area)
+}
+
 impl AppComponent {
     /// Calculate sidebar width based on configured columns
     fn calculate_sidebar_width(&self, screen_width: u16) -> u16 {
@@ -1251,6 +2037,8 @@ impl Component for AppComponent {
         // Process through component hierarchy
         let action = self.dialog.update(action);
         let action = self.sidebar.update(action);
+        let action = self.calendar.update(action);
+        let action = self.board.update(action);
 
         // Return for app-level handling
         self.task_list.update(action)
@@ -1267,12 +2055,27 @@ impl Component for AppComponent {
 
         let main_chunks = Layout::horizontal([Constraint::Length(sidebar_width), Constraint::Min(0)]).split(rect);
 
-        // Render components
+        // Render components; the Calendar view puts the month grid above the agenda
         self.sidebar.render(f, main_chunks[0]);
-        self.task_list.render(f, main_chunks[1]);
+        if self.state.sidebar_selection == SidebarSelection::Calendar {
+            let [calendar_area, agenda_area] = calendar_layout(main_chunks[1]);
+            self.calendar.render(f, calendar_area);
+            self.task_list.render(f, agenda_area);
+        } else if self.board_active() {
+            self.board.render(f, main_chunks[1]);
+        } else {
+            self.task_list.render(f, main_chunks[1]);
+        }
 
-        // Render sync status if syncing or loading
-        if self.state.loading || self.is_syncing() {
+        // Render loading popup or background sync / offline / next sync indicator
+        if self.state.loading
+            || self.is_syncing()
+            || self.state.offline
+            || self.state.rate_limited
+            || self.state.pending_changes > 0
+            || !self.state.conflicts.is_empty()
+            || self.auto_sync.next_sync().is_some()
+        {
             AppComponent::render_sync_status_impl(self, f, rect);
         }
 
@@ -1285,6 +2088,11 @@ impl Component for AppComponent {
 
 impl AppComponent {
     /// Render sync status indicator
+    ///
+    /// While no data has been loaded yet a centered popup is shown. Afterwards syncing happens in
+    /// the background, so only a compact indicator is drawn in the bottom-right corner, together
+    /// with the number of changes that have not been pushed to the backend yet and the time of
+    /// the next automatic sync.
     fn render_sync_status_impl(&self, f: &mut Frame, rect: Rect) {
         use ratatui::{
             layout::{Alignment, Constraint, Layout},
@@ -1293,31 +2101,70 @@ impl AppComponent {
             widgets::{Block, Borders, Clear, Paragraph},
         };
 
-        // Calculate centered area for the sync indicator
-        let popup_area = {
-            let popup_layout =
-                Layout::vertical([Constraint::Percentage(40), Constraint::Min(3), Constraint::Percentage(40)])
-                    .split(rect);
-
-            Layout::horizontal([Constraint::Percentage(30), Constraint::Min(30), Constraint::Percentage(30)])
-                .split(popup_layout[1])[1]
-        };
+        if self.state.loading {
+            // Calculate centered area for the loading indicator
+            let popup_area = {
+                let popup_layout =
+                    Layout::vertical([Constraint::Percentage(40), Constraint::Min(3), Constraint::Percentage(40)])
+                        .split(rect);
+
+                Layout::horizontal([Constraint::Percentage(30), Constraint::Min(30), Constraint::Percentage(30)])
+                    .split(popup_layout[1])[1]
+            };
+
+            let spinner = "⟳";
+            let content = Paragraph::new(Line::from(Span::styled(
+                format!("{} {}…", spinner, UI_LOADING_DATA),
+                Style::default().fg(Color::Yellow),
+            )))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).style(Style::default().fg(Color::Yellow)));
+
+            f.render_widget(Clear, popup_area);
+            f.render_widget(content, popup_area);
+            return;
+        }
 
-        let title = if self.state.loading {
-            UI_LOADING_DATA
-        } else {
-            UI_SYNCING_WITH_TODOIST
-        };
+        let mut parts = Vec::new();
+        let mut color = Color::DarkGray;
+        if self.is_syncing() {
+            parts.push(format!("⟳ {}…", UI_SYNCING_WITH_TODOIST));
+            color = Color::Yellow;
+        } else if self.state.offline {
+            parts.push(format!("⚠ {}", UI_OFFLINE_MODE));
+            color = Color::Red;
+        } else if self.state.rate_limited {
+            parts.push(format!("⏳ {}", UI_RATE_LIMITED));
+            color = Color::Yellow;
+        }
+        if self.state.pending_changes > 0 {
+            parts.push(format!("↑ {} {}", self.state.pending_changes, UI_UNSYNCED_CHANGES));
+            if color == Color::DarkGray {
+                color = Color::Yellow;
+            }
+        }
+        if !self.state.conflicts.is_empty() {
+            parts.push(format!("⚠ {} {}", self.state.conflicts.len(), UI_SYNC_CONFLICTS));
+            color = Color::Red;
+        }
+        if let Some(next_sync) = self.auto_sync.next_sync() {
+            let remaining = next_sync.saturating_duration_since(std::time::Instant::now());
+            let at = chrono::Local::now() + chrono::Duration::from_std(remaining).unwrap_or_default();
+            parts.push(format!(
+                "⏱ {} {}",
+                UI_NEXT_SYNC,
+                at.format(&self.config.display.time_format)
+            ));
+        }
+        let text = format!(" {} ", parts.join(" · "));
 
-        let spinner = "⟳";
-        let content = Paragraph::new(Line::from(Span::styled(
-            format!("{} {}…", spinner, title),
-            Style::default().fg(Color::Yellow),
-        )))
-        .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL).style(Style::default().fg(Color::Yellow)));
+        let width = (text.chars().count() as u16).min(rect.width);
+        if width == 0 || rect.height == 0 {
+            return;
+        }
+        let area = Rect::new(rect.x + rect.width - width, rect.y + rect.height - 1, width, 1);
 
-        f.render_widget(Clear, popup_area);
-        f.render_widget(content, popup_area);
+        f.render_widget(Clear, area);
+        f.render_widget(Paragraph::new(Span::styled(text, Style::default().fg(color))), area);
     }
 }
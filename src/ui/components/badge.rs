@@ -1,4 +1,5 @@
 use crate::entities::label;
+use crate::utils::color;
 use ratatui::{
     style::{Color, Modifier, Style},
     text::Span,
@@ -16,10 +17,10 @@ pub fn create_paren_badge(text: &str) -> Span<'static> {
     )
 }
 
-/// Create a label badge with custom color
+/// Create a label badge with custom color, green when the label has none
 #[must_use]
-pub fn create_label_badge(name: &str) -> Span<'static> {
-    let style = Style::default().fg(Color::Green).add_modifier(Modifier::BOLD);
+pub fn create_label_badge(name: &str, color: Option<Color>) -> Span<'static> {
+    let style = Style::default().fg(color.unwrap_or(Color::Green)).add_modifier(Modifier::BOLD);
 
     Span::styled(format!("@{}", name), style)
 }
@@ -31,6 +32,7 @@ pub fn create_task_badges(
     _has_deadline: bool,
     duration: Option<&str>,
     labels: &[label::Model],
+    show_label_colors: bool,
 ) -> Vec<Span<'static>> {
     let mut badges = Vec::new();
 
@@ -43,7 +45,12 @@ pub fn create_task_badges(
     }
 
     for label in labels {
-        badges.push(create_label_badge(&label.name));
+        let label_color = if show_label_colors {
+            color::backend_color(label.color.as_deref())
+        } else {
+            None
+        };
+        badges.push(create_label_badge(&label.name, label_color));
     }
 
     badges
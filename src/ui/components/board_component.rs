@@ -0,0 +1,434 @@
+//! Board layout of a project.
+//!
+//! Every section of the project is a column, preceded by a column for the tasks without a
+//! section. Top-level tasks are cards showing their priority, labels and due date. Cards are
+//! moved to another column with `<`/`>` (or Shift+arrows) or by dragging them with the mouse,
+//! which moves the task to that section.
+
+use std::collections::HashMap;
+
+use crate::config::DisplayConfig;
+use crate::constants::HEADER_NO_SECTION;
+use crate::entities::{label, project, section, task};
+use crate::ui::components::badge::{create_priority_badge, create_task_badges};
+use crate::ui::core::{actions::Action, view_mode, Component, SortMode};
+use crate::utils::datetime::{format_human_date, format_human_datetime};
+use crate::utils::text::truncate;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{block::BorderType, Block, Borders, Paragraph},
+    Frame,
+};
+use uuid::Uuid;
+
+/// Height of a card, its border included
+const CARD_HEIGHT: u16 = 4;
+/// Narrowest column; when the sections do not fit, the board scrolls sideways
+const MIN_COLUMN_WIDTH: u16 = 24;
+
+/// Columns of sections with the project's top-level tasks as cards.
+pub struct BoardComponent {
+    pub project: Option<project::Model>,
+    /// Sections of the project, in their order
+    pub sections: Vec<section::Model>,
+    /// Tasks of the project in the view's sort order, subtasks included
+    pub tasks: Vec<task::Model>,
+    pub labels: Vec<label::Model>,
+    pub task_labels: HashMap<Uuid, Vec<Uuid>>,
+    pub display_config: DisplayConfig,
+    pub sort: SortMode,
+    /// Column of the selection
+    pub column: usize,
+    /// Card of the selection within its column
+    pub row: usize,
+    /// Task of the selected card, followed when it moves to another column
+    selected_task: Option<Uuid>,
+    /// Card picked up with the mouse and the column it is over
+    drag: Option<(Uuid, usize)>,
+    /// First column shown, the board scrolls sideways to the selected column
+    first_column: usize,
+    /// First card shown in each column
+    card_offsets: Vec<usize>,
+}
+
+impl Default for BoardComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BoardComponent {
+    pub fn new() -> Self {
+        Self {
+            project: None,
+            sections: Vec::new(),
+            tasks: Vec::new(),
+            labels: Vec::new(),
+            task_labels: HashMap::new(),
+            display_config: DisplayConfig::default(),
+            sort: SortMode::Manual,
+            column: 0,
+            row: 0,
+            selected_task: None,
+            drag: None,
+            first_column: 0,
+            card_offsets: Vec::new(),
+        }
+    }
+
+    pub fn update_display_config(&mut self, display_config: DisplayConfig) {
+        self.display_config = display_config;
+    }
+
+    pub fn update_data(
+        &mut self,
+        project: Option<project::Model>,
+        sections: Vec<section::Model>,
+        mut tasks: Vec<task::Model>,
+        labels: Vec<label::Model>,
+        task_labels: HashMap<Uuid, Vec<Uuid>>,
+        sort: SortMode,
+    ) {
+        let project_uuid = project.as_ref().map(|project| project.uuid);
+        view_mode::sort_tasks(&mut tasks, sort);
+
+        self.sections = sections
+            .into_iter()
+            .filter(|section| Some(section.project_uuid) == project_uuid)
+            .collect();
+        self.tasks = tasks
+            .into_iter()
+            .filter(|task| Some(task.project_uuid) == project_uuid)
+            .collect();
+        self.project = project;
+        self.labels = labels;
+        self.task_labels = task_labels;
+        self.sort = sort;
+
+        // Keep the selection on its task, wherever the task is now
+        let found = self.selected_task.and_then(|uuid| {
+            (0..self.column_count()).find_map(|column| {
+                let row = self.cards(column).iter().position(|task| task.uuid == uuid)?;
+                Some((column, row))
+            })
+        });
+        if let Some((column, row)) = found {
+            self.column = column;
+            self.row = row;
+        }
+        self.clamp_selection();
+    }
+
+    /// Number of columns: the tasks without a section, then one per section
+    pub fn column_count(&self) -> usize {
+        self.sections.len() + 1
+    }
+
+    /// Section of a column, `None` for the first column
+    pub fn column_section(&self, column: usize) -> Option<&section::Model> {
+        column.checked_sub(1).and_then(|index| self.sections.get(index))
+    }
+
+    /// Cards of a column, the top-level tasks of its section
+    pub fn cards(&self, column: usize) -> Vec<&task::Model> {
+        let section_uuid = self.column_section(column).map(|section| section.uuid);
+        self.tasks
+            .iter()
+            .filter(|task| task.parent_uuid.is_none())
+            .filter(|task| {
+                // Tasks of an unknown section are listed with the tasks without one
+                let known = task
+                    .section_uuid
+                    .filter(|uuid| self.sections.iter().any(|section| &section.uuid == uuid));
+                known == section_uuid
+            })
+            .collect()
+    }
+
+    /// The task of the selected card
+    pub fn selected_task(&self) -> Option<&task::Model> {
+        self.cards(self.column).get(self.row).copied()
+    }
+
+    fn clamp_selection(&mut self) {
+        self.column = self.column.min(self.column_count() - 1);
+        self.row = self.row.min(self.cards(self.column).len().saturating_sub(1));
+        self.selected_task = self.selected_task().map(|task| task.uuid);
+    }
+
+    /// Move a card to the section of another column; the selection follows it once reloaded
+    fn move_card(&mut self, task_uuid: Uuid, column: usize) -> Action {
+        let Some(project) = &self.project else {
+            return Action::None;
+        };
+        let section_uuid = self.column_section(column).map(|section| section.uuid);
+        self.selected_task = Some(task_uuid);
+        Action::MoveTask {
+            task_uuid,
+            project_uuid: project.uuid,
+            section_uuid,
+            parent_uuid: None,
+        }
+    }
+
+    /// Move the selected card to the neighbouring column
+    fn move_selected_card(&mut self, offset: i32) -> Action {
+        let Some(task_uuid) = self.selected_task().map(|task| task.uuid) else {
+            return Action::None;
+        };
+        let column = self.column as i64 + i64::from(offset);
+        if column < 0 || column >= self.column_count() as i64 {
+            return Action::None;
+        }
+        self.move_card(task_uuid, column as usize)
+    }
+
+    fn block(&self) -> Block<'static> {
+        let name = self.project.as_ref().map_or("", |project| project.name.as_str());
+        let title = if self.sort == SortMode::Manual {
+            format!("Board · {}", name)
+        } else {
+            format!("Board · {} · by {}", name, self.sort.label())
+        };
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(title)
+            .title_style(Style::default().fg(Color::White))
+            .border_style(Style::default().fg(Color::DarkGray))
+    }
+
+    /// The visible columns with their areas, shared by rendering and mouse handling
+    fn column_areas(&self, inner: Rect) -> Vec<(usize, Rect)> {
+        let visible = usize::from((inner.width / MIN_COLUMN_WIDTH).max(1)).min(self.column_count());
+        let columns = (self.first_column..self.column_count()).take(visible);
+        let areas = Layout::horizontal(vec![Constraint::Ratio(1, visible as u32); visible]).split(inner);
+        columns.zip(areas.iter().copied()).collect()
+    }
+
+    /// The visible cards of a column with their areas
+    fn card_areas(&self, column: usize, area: Rect) -> Vec<(Uuid, Rect)> {
+        let inner = Block::default().borders(Borders::ALL).inner(area);
+        let offset = self.card_offsets.get(column).copied().unwrap_or(0);
+        let fitting = usize::from((inner.height / CARD_HEIGHT).max(1));
+        self.cards(column)
+            .into_iter()
+            .skip(offset)
+            .take(fitting)
+            .enumerate()
+            .map(|(index, task)| {
+                let y = inner.y + index as u16 * CARD_HEIGHT;
+                let height = CARD_HEIGHT.min(inner.bottom().saturating_sub(y));
+                (task.uuid, Rect { y, height, ..inner })
+            })
+            .collect()
+    }
+
+    /// Scroll so the selected column and card are visible
+    fn scroll_to_selection(&mut self, inner: Rect) {
+        let visible = usize::from((inner.width / MIN_COLUMN_WIDTH).max(1));
+        if self.column < self.first_column {
+            self.first_column = self.column;
+        } else if self.column >= self.first_column + visible {
+            self.first_column = self.column + 1 - visible;
+        }
+        self.first_column = self.first_column.min(self.column_count().saturating_sub(visible));
+
+        let fitting = usize::from((inner.height.saturating_sub(2) / CARD_HEIGHT).max(1));
+        self.card_offsets.resize(self.column_count(), 0);
+        let offset = &mut self.card_offsets[self.column];
+        if self.row < *offset {
+            *offset = self.row;
+        } else if self.row >= *offset + fitting {
+            *offset = self.row + 1 - fitting;
+        }
+    }
+
+    /// Lines of a card: priority and title, then due date and labels
+    fn card_lines(&self, task: &task::Model, width: usize) -> Vec<Line<'static>> {
+        let title_style = if task.is_completed {
+            Style::default().fg(Color::DarkGray).add_modifier(Modifier::CROSSED_OUT)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let mut title = Vec::new();
+        if let Some(priority) = create_priority_badge(task.priority) {
+            title.push(priority);
+            title.push(Span::raw(" "));
+        }
+        let subtasks = self.tasks.iter().filter(|child| child.parent_uuid == Some(task.uuid)).count();
+        let suffix = if subtasks > 0 {
+            format!(" ({})", subtasks)
+        } else {
+            String::new()
+        };
+        let room = width.saturating_sub(2 + suffix.chars().count());
+        title.push(Span::styled(truncate(&task.content, room), title_style));
+        title.push(Span::styled(suffix, Style::default().fg(Color::Gray)));
+
+        let mut details = Vec::new();
+        if let Some(due_date) = &task.due_date {
+            let formatted = match &task.due_datetime {
+                Some(due_datetime) => format_human_datetime(due_datetime),
+                None => format_human_date(due_date),
+            };
+            details.push(Span::styled(formatted, Style::default().fg(Color::Rgb(255, 165, 0))));
+        }
+        let labels: Vec<label::Model> = self
+            .task_labels
+            .get(&task.uuid)
+            .into_iter()
+            .flatten()
+            .filter_map(|uuid| self.labels.iter().find(|label| &label.uuid == uuid).cloned())
+            .collect();
+        for badge in create_task_badges(
+            task.is_recurring,
+            false,
+            None,
+            &labels,
+            self.display_config.show_project_colors,
+        ) {
+            if !details.is_empty() {
+                details.push(Span::raw(" "));
+            }
+            details.push(badge);
+        }
+
+        vec![Line::from(title), Line::from(details)]
+    }
+
+    fn render_column(&self, f: &mut Frame, column: usize, area: Rect) {
+        let title = match self.column_section(column) {
+            Some(section) => section.name.clone(),
+            None => HEADER_NO_SECTION.to_string(),
+        };
+        let cards = self.cards(column);
+        let border_color = match self.drag {
+            Some((_, hovered)) if hovered == column => Color::Cyan,
+            _ if column == self.column => Color::Yellow,
+            _ => Color::DarkGray,
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(format!("{} ({})", title, cards.len()))
+            .title_style(Style::default().fg(Color::White))
+            .border_style(Style::default().fg(border_color));
+        f.render_widget(block, area);
+
+        let selected = self.selected_task().map(|task| task.uuid);
+        for (task_uuid, card_area) in self.card_areas(column, area) {
+            let Some(task) = cards.iter().find(|task| task.uuid == task_uuid) else {
+                continue;
+            };
+            let card_color = if Some(task_uuid) == selected {
+                Color::Yellow
+            } else {
+                Color::Gray
+            };
+            let card = Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(card_color));
+            let width = card.inner(card_area).width as usize;
+            f.render_widget(Paragraph::new(self.card_lines(task, width)).block(card), card_area);
+        }
+    }
+
+    pub fn handle_mouse(&mut self, mouse: MouseEvent, area: Rect) -> Action {
+        let inner = self.block().inner(area);
+        let contains = |rect: &Rect| {
+            rect.x <= mouse.column && mouse.column < rect.right() && rect.y <= mouse.row && mouse.row < rect.bottom()
+        };
+        let column_at = self
+            .column_areas(inner)
+            .into_iter()
+            .find(|(_, column_area)| contains(column_area));
+
+        match mouse.kind {
+            // Pressing on a card selects it and picks it up
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some((column, column_area)) = column_at {
+                    self.column = column;
+                    let card = self
+                        .card_areas(column, column_area)
+                        .into_iter()
+                        .find(|(_, card_area)| contains(card_area));
+                    if let Some((task_uuid, _)) = card {
+                        self.row = self.cards(column).iter().position(|task| task.uuid == task_uuid).unwrap_or(0);
+                        self.drag = Some((task_uuid, column));
+                    }
+                    self.clamp_selection();
+                }
+                Action::None
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                if let (Some(drag), Some((column, _))) = (&mut self.drag, column_at) {
+                    drag.1 = column;
+                }
+                Action::None
+            }
+            // Releasing it over another column moves it there
+            MouseEventKind::Up(MouseButton::Left) => match (self.drag.take(), column_at) {
+                (Some((task_uuid, _)), Some((column, _))) if column != self.column => self.move_card(task_uuid, column),
+                _ => Action::None,
+            },
+            MouseEventKind::ScrollUp => Action::BoardMoveCard(-1),
+            MouseEventKind::ScrollDown => Action::BoardMoveCard(1),
+            _ => Action::None,
+        }
+    }
+}
+
+impl Component for BoardComponent {
+    fn handle_key_events(&mut self, key: KeyEvent) -> Action {
+        // Alt+j/k still reorder the selected card within its column
+        if key.modifiers.contains(KeyModifiers::ALT) {
+            return Action::None;
+        }
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        match key.code {
+            KeyCode::Left if shift => self.move_selected_card(-1),
+            KeyCode::Right if shift => self.move_selected_card(1),
+            KeyCode::Char('<') => self.move_selected_card(-1),
+            KeyCode::Char('>') => self.move_selected_card(1),
+            KeyCode::Left => Action::BoardMoveColumn(-1),
+            KeyCode::Right => Action::BoardMoveColumn(1),
+            KeyCode::Up | KeyCode::Char('k') => Action::BoardMoveCard(-1),
+            KeyCode::Down | KeyCode::Char('j') => Action::BoardMoveCard(1),
+            _ => Action::None,
+        }
+    }
+
+    fn update(&mut self, action: Action) -> Action {
+        match action {
+            Action::BoardMoveColumn(offset) => {
+                let last = self.column_count() as i64 - 1;
+                self.column = (self.column as i64 + i64::from(offset)).clamp(0, last) as usize;
+                self.clamp_selection();
+                Action::None
+            }
+            Action::BoardMoveCard(offset) => {
+                let last = self.cards(self.column).len() as i64 - 1;
+                self.row = (self.row as i64 + i64::from(offset)).clamp(0, last.max(0)) as usize;
+                self.clamp_selection();
+                Action::None
+            }
+            _ => action,
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        let block = self.block();
+        let inner = block.inner(rect);
+        f.render_widget(block, rect);
+
+        self.scroll_to_selection(inner);
+        for (column, area) in self.column_areas(inner) {
+            self.render_column(f, column, area);
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! Month calendar component for the Calendar view.
+//!
+//! The calendar shows six weeks around the month of the highlighted day, with the open tasks
+//! due on each day. Days are highlighted with the arrow keys and opened with `g`, which shows
+//! their agenda in the task list below the grid.
+
+use crate::entities::task;
+use crate::ui::core::{actions::Action, Component};
+use crate::utils::datetime;
+use crate::utils::text::truncate;
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{block::BorderType, Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Number of weeks in the grid, enough for any month
+const GRID_WEEKS: i64 = 6;
+
+/// Month grid with a highlighted day and a selected day whose agenda is listed.
+pub struct CalendarComponent {
+    /// Day under the cursor; the grid shows its month
+    pub cursor: NaiveDate,
+    /// Day whose agenda is shown in the task list
+    pub selected_day: NaiveDate,
+    /// Tasks due within [`Self::load_range`]
+    pub tasks: Vec<task::Model>,
+}
+
+impl Default for CalendarComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CalendarComponent {
+    pub fn new() -> Self {
+        let today = Local::now().date_naive();
+        Self {
+            cursor: today,
+            selected_day: today,
+            tasks: Vec::new(),
+        }
+    }
+
+    pub fn update_tasks(&mut self, tasks: Vec<task::Model>) {
+        self.tasks = tasks;
+    }
+
+    /// First day shown in the grid
+    pub fn grid_start(&self) -> NaiveDate {
+        datetime::month_grid_start(self.cursor)
+    }
+
+    /// The days whose tasks the calendar needs, the end being exclusive.
+    ///
+    /// This is the grid, stretched to the selected day when the cursor moved to another month
+    /// so its agenda stays listed.
+    pub fn load_range(&self) -> (NaiveDate, NaiveDate) {
+        let start = self.grid_start();
+        let end = start + Duration::weeks(GRID_WEEKS);
+        (
+            start.min(self.selected_day),
+            end.max(self.selected_day + Duration::days(1)),
+        )
+    }
+
+    /// Open tasks due on a day
+    pub fn open_tasks_on(&self, day: NaiveDate) -> Vec<&task::Model> {
+        let day = datetime::format_ymd(day);
+        self.tasks
+            .iter()
+            .filter(|task| !task.is_completed && task.due_date.as_deref() == Some(day.as_str()))
+            .collect()
+    }
+
+    /// Move the cursor or select the day under it
+    fn navigate(&mut self, action: &Action) {
+        match action {
+            Action::CalendarMoveCursor(days) => self.cursor += Duration::days(*days),
+            Action::CalendarMoveMonth(months) => self.cursor = datetime::add_months(self.cursor, *months),
+            Action::CalendarToday => self.cursor = Local::now().date_naive(),
+            Action::CalendarSelectDay => self.selected_day = self.cursor,
+            _ => {}
+        }
+    }
+
+    /// The grid cells with their days, shared by rendering and mouse handling
+    fn cell_areas(&self, inner: Rect) -> Vec<(NaiveDate, Rect)> {
+        let [_, grid] = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(inner);
+        let rows = Layout::vertical([Constraint::Ratio(1, GRID_WEEKS as u32); GRID_WEEKS as usize]).split(grid);
+        let start = self.grid_start();
+
+        let mut cells = Vec::new();
+        for (week, row) in rows.iter().enumerate() {
+            let columns = Layout::horizontal([Constraint::Ratio(1, 7); 7]).split(*row);
+            for (weekday, cell) in columns.iter().enumerate() {
+                let day = start + Duration::days(week as i64 * 7 + weekday as i64);
+                cells.push((day, *cell));
+            }
+        }
+        cells
+    }
+
+    fn block(&self) -> Block<'static> {
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(format!("Calendar · {}", self.cursor.format("%B %Y")))
+            .title_style(Style::default().fg(Color::White))
+            .border_style(Style::default().fg(Color::DarkGray))
+    }
+
+    /// Lines of one day: the day number, then as many task titles as fit
+    fn cell_lines(&self, day: NaiveDate, area: Rect, today: NaiveDate) -> Vec<Line<'static>> {
+        let tasks = self.open_tasks_on(day);
+        let in_month = day.month() == self.cursor.month();
+
+        let mut number_style = if day == today {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else if in_month {
+            Style::default().fg(Color::White)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        if day == self.selected_day {
+            number_style = number_style.add_modifier(Modifier::UNDERLINED | Modifier::BOLD);
+        }
+        let task_style = if day < today {
+            Style::default().fg(Color::Red)
+        } else if in_month {
+            Style::default().fg(Color::Gray)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+
+        let width = area.width as usize;
+        let title_lines = (area.height as usize).saturating_sub(1);
+        let mut number = vec![Span::styled(format!("{:>2}", day.day()), number_style)];
+        if !tasks.is_empty() && title_lines == 0 {
+            number.push(Span::styled(format!(" ·{}", tasks.len()), task_style));
+        }
+        let mut lines = vec![Line::from(number)];
+
+        // The last line counts the tasks that do not fit
+        let shown = if tasks.len() > title_lines {
+            title_lines.saturating_sub(1)
+        } else {
+            tasks.len()
+        };
+        for task in &tasks[..shown] {
+            lines.push(Line::from(Span::styled(truncate(&task.content, width), task_style)));
+        }
+        if shown < tasks.len() && title_lines > 0 {
+            let more = format!("+{} more", tasks.len() - shown);
+            lines.push(Line::from(Span::styled(truncate(&more, width), task_style)));
+        }
+        lines
+    }
+
+    pub fn handle_mouse(&mut self, mouse: MouseEvent, area: Rect) -> Action {
+        let inner = self.block().inner(area);
+        match mouse.kind {
+            // Clicking a day opens its agenda
+            MouseEventKind::Down(MouseButton::Left) => {
+                let clicked = self.cell_areas(inner).into_iter().find(|(_, cell)| {
+                    mouse.column >= cell.x
+                        && mouse.column < cell.x + cell.width
+                        && mouse.row >= cell.y
+                        && mouse.row < cell.y + cell.height
+                });
+                match clicked {
+                    Some((day, _)) => {
+                        let range = self.load_range();
+                        self.cursor = day;
+                        self.selected_day = day;
+                        if self.load_range() != range {
+                            Action::RefreshData
+                        } else {
+                            Action::None
+                        }
+                    }
+                    None => Action::None,
+                }
+            }
+            MouseEventKind::ScrollUp => Action::CalendarMoveMonth(-1),
+            MouseEventKind::ScrollDown => Action::CalendarMoveMonth(1),
+            _ => Action::None,
+        }
+    }
+}
+
+impl Component for CalendarComponent {
+    fn handle_key_events(&mut self, key: KeyEvent) -> Action {
+        match key.code {
+            KeyCode::Left => Action::CalendarMoveCursor(-1),
+            KeyCode::Right => Action::CalendarMoveCursor(1),
+            KeyCode::Char('[') => Action::CalendarMoveCursor(-7),
+            KeyCode::Char(']') => Action::CalendarMoveCursor(7),
+            KeyCode::PageUp => Action::CalendarMoveMonth(-1),
+            KeyCode::PageDown => Action::CalendarMoveMonth(1),
+            KeyCode::Home => Action::CalendarToday,
+            KeyCode::Char('g') => Action::CalendarSelectDay,
+            _ => Action::None,
+        }
+    }
+
+    fn update(&mut self, action: Action) -> Action {
+        match action {
+            Action::CalendarMoveCursor(_)
+            | Action::CalendarMoveMonth(_)
+            | Action::CalendarToday
+            | Action::CalendarSelectDay => {
+                let range = self.load_range();
+                self.navigate(&action);
+                // The agenda is taken from the loaded tasks, other days need a reload
+                if self.load_range() != range {
+                    Action::RefreshData
+                } else {
+                    Action::None
+                }
+            }
+            _ => action,
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        let block = self.block();
+        let inner = block.inner(rect);
+        f.render_widget(block, rect);
+
+        let today = Local::now().date_naive();
+        let cells = self.cell_areas(inner);
+
+        // Weekday names above the first week
+        let header = Rect { height: 1, ..inner };
+        let columns = Layout::horizontal([Constraint::Ratio(1, 7); 7]).split(header);
+        for ((day, _), column) in cells.iter().zip(columns.iter()) {
+            let name = Paragraph::new(day.format("%a").to_string()).style(Style::default().fg(Color::DarkGray));
+            f.render_widget(name, *column);
+        }
+
+        for (day, cell) in cells {
+            let mut paragraph = Paragraph::new(self.cell_lines(day, cell, today));
+            if day == self.cursor {
+                paragraph = paragraph.style(Style::default().bg(Color::DarkGray));
+            }
+            f.render_widget(paragraph, cell);
+        }
+    }
+}
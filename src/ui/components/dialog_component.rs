@@ -5,19 +5,65 @@
 //! label management, and system functions like search and debugging.
 
 use crate::config::DisplayConfig;
-use crate::entities::{label, project, task};
+use crate::entities::{comment, label, project, section, sync_conflict, task};
 use crate::icons::IconService;
+use crate::sync::conflicts::ConflictResolution;
+use crate::sync::tasks::{NewTask, TaskEdit};
 use crate::sync::SyncService;
 use crate::ui::components::task_list_item_component::{ListItem as TaskListItem, TaskItem};
 use crate::ui::core::{
     actions::{Action, DialogType},
     Component,
 };
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{layout::Rect, widgets::ScrollbarState, Frame};
 use uuid::Uuid;
 
-use crate::ui::components::dialogs::{label_dialogs, project_dialogs, scroll_behavior, system_dialogs, task_dialogs};
+use crate::ui::components::dialogs::label_dialogs::LabelPickerRow;
+use crate::ui::components::dialogs::task_dialogs::{MoveTarget, QuickAddPreviewField, TaskEditorField, TaskEditorView};
+use crate::ui::components::dialogs::{
+    comment_dialogs, conflict_dialogs, label_dialogs, project_dialogs, scroll_behavior, section_dialogs,
+    system_dialogs, task_dialogs,
+};
+use crate::utils::datetime;
+use crate::utils::fuzzy::{fuzzy_filter, fuzzy_score};
+use crate::utils::quick_add::{self, matches_name};
+
+/// The text of a task editor field that is edited by typing
+fn task_editor_text(edit: &mut TaskEdit, field: TaskEditorField) -> Option<&mut String> {
+    match field {
+        TaskEditorField::Content => Some(&mut edit.content),
+        TaskEditorField::Description => Some(&mut edit.description),
+        TaskEditorField::Due => Some(&mut edit.due),
+        TaskEditorField::Deadline => Some(&mut edit.deadline),
+        TaskEditorField::Duration => Some(&mut edit.duration),
+        TaskEditorField::Location | TaskEditorField::Labels | TaskEditorField::Priority => None,
+    }
+}
+
+/// Apply a text editing key to `text`, with the cursor position counted in characters
+fn edit_text(text: &mut String, cursor_position: &mut usize, code: KeyCode) {
+    let char_count = text.chars().count();
+    let byte_pos = |position: usize| text.char_indices().nth(position).map_or(text.len(), |(i, _)| i);
+    match code {
+        KeyCode::Char(c) => {
+            text.insert(byte_pos(*cursor_position), c);
+            *cursor_position += 1;
+        }
+        KeyCode::Backspace if *cursor_position > 0 => {
+            text.remove(byte_pos(*cursor_position - 1));
+            *cursor_position -= 1;
+        }
+        KeyCode::Delete if *cursor_position < char_count => {
+            text.remove(byte_pos(*cursor_position));
+        }
+        KeyCode::Left => *cursor_position = cursor_position.saturating_sub(1),
+        KeyCode::Right => *cursor_position = (*cursor_position + 1).min(char_count),
+        KeyCode::Home => *cursor_position = 0,
+        KeyCode::End => *cursor_position = char_count,
+        _ => {}
+    }
+}
 
 /// Modal dialog component that handles various user interactions.
 ///
@@ -27,7 +73,10 @@ use crate::ui::components::dialogs::{label_dialogs, project_dialogs, scroll_beha
 /// - **Task dialogs** - Create, edit, and manage tasks
 /// - **Project dialogs** - Create and manage projects
 /// - **Label dialogs** - Create and manage labels
+/// - **Section dialogs** - Create, rename, delete and reorder the sections of a project
+/// - **Comment dialogs** - Read the comment thread of a task and post new comments
 /// - **System dialogs** - Search, logs, help, and confirmation dialogs
+/// - **Conflict dialogs** - Resolve sync conflicts field by field
 ///
 /// # Features
 /// - Input handling with cursor management
@@ -47,6 +96,8 @@ pub struct DialogComponent {
     pub projects: Vec<project::Model>,
     pub labels: Vec<label::Model>,
     pub tasks: Vec<task::Model>,
+    pub sections: Vec<section::Model>,
+    pub selected_section_index: usize,
     pub selected_project_index: usize,
     pub selected_parent_project_index: Option<usize>, // For project creation parent selection
     pub selected_task_project_index: Option<usize>,   // For task creation project selection (None = no project/inbox)
@@ -58,6 +109,20 @@ pub struct DialogComponent {
     pub scrollbar_state: ScrollbarState,
     // Task search state
     pub search_results: Vec<task::Model>,
+    // Comment thread of the task the open comment dialog belongs to
+    pub comments: Vec<comment::Model>,
+    // Sync conflicts awaiting resolution
+    pub conflicts: Vec<sync_conflict::Model>,
+    pub selected_conflict_index: usize,
+    // Task editor state: the edited values, the focused field and the label under the cursor
+    pub task_edit: TaskEdit,
+    pub task_editor_field: TaskEditorField,
+    pub selected_label_index: usize,
+    // Label picker state: the labels the task will have and the labels to create for it
+    pub picked_label_uuids: Vec<Uuid>,
+    pub new_label_names: Vec<String>,
+    // Move picker state: the highlighted destination
+    pub selected_move_index: usize,
     pub sync_service: Option<SyncService>,
     pub display_config: DisplayConfig,
 }
@@ -77,6 +142,8 @@ impl DialogComponent {
             projects: Vec::new(),
             labels: Vec::new(),
             tasks: Vec::new(),
+            sections: Vec::new(),
+            selected_section_index: 0,
             selected_project_index: 0,
             selected_parent_project_index: None,
             selected_task_project_index: None, // Default to "None" for tasks (no project)
@@ -86,6 +153,15 @@ impl DialogComponent {
             scroll_offset: 0,
             scrollbar_state: ScrollbarState::new(0),
             search_results: Vec::new(),
+            comments: Vec::new(),
+            conflicts: Vec::new(),
+            selected_conflict_index: 0,
+            task_edit: TaskEdit::default(),
+            task_editor_field: TaskEditorField::default(),
+            selected_label_index: 0,
+            picked_label_uuids: Vec::new(),
+            new_label_names: Vec::new(),
+            selected_move_index: 0,
             sync_service: None,
             display_config: DisplayConfig::default(),
         }
@@ -111,6 +187,82 @@ impl DialogComponent {
         self.tasks = tasks;
     }
 
+    pub fn update_sections(&mut self, sections: Vec<section::Model>) {
+        self.sections = sections;
+    }
+
+    /// Sections of a project in display order
+    fn get_project_sections(&self, project_uuid: &Uuid) -> Vec<&section::Model> {
+        let mut sections: Vec<&section::Model> =
+            self.sections.iter().filter(|s| &s.project_uuid == project_uuid).collect();
+        sections.sort_by_key(|s| s.order_index);
+        sections
+    }
+
+    /// Handle keys of the section manager, which act on the selected section
+    fn handle_section_manager_key(&mut self, key: KeyEvent, project_uuid: Uuid) -> Action {
+        let sections = self.get_project_sections(&project_uuid);
+        let selected = sections
+            .get(self.selected_section_index.min(sections.len().saturating_sub(1)))
+            .map(|s| (s.uuid, s.name.clone()));
+        let section_count = sections.len();
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => Action::HideDialog,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_section_index = self.selected_section_index.saturating_sub(1);
+                Action::None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if self.selected_section_index + 1 < section_count {
+                    self.selected_section_index += 1;
+                }
+                Action::None
+            }
+            KeyCode::Char('a') => Action::ShowDialog(DialogType::SectionCreation { project_uuid }),
+            KeyCode::Char('e') => match selected {
+                Some((section_uuid, name)) => Action::ShowDialog(DialogType::SectionEdit { section_uuid, name }),
+                None => Action::None,
+            },
+            KeyCode::Char('d') | KeyCode::Delete => match selected {
+                Some((section_uuid, _)) => Action::ShowDialog(DialogType::DeleteConfirmation {
+                    item_type: "section".to_string(),
+                    item_uuid: section_uuid,
+                }),
+                None => Action::None,
+            },
+            KeyCode::Char('K') | KeyCode::Char('J') => {
+                let Some((section_uuid, _)) = selected else {
+                    return Action::None;
+                };
+                let offset = if key.code == KeyCode::Char('K') { -1 } else { 1 };
+                // Keep the moved section selected
+                self.selected_section_index = self
+                    .selected_section_index
+                    .saturating_add_signed(offset as isize)
+                    .min(section_count.saturating_sub(1));
+                Action::MoveSection { section_uuid, offset }
+            }
+            _ => Action::None,
+        }
+    }
+
+    pub fn update_conflicts(&mut self, conflicts: Vec<sync_conflict::Model>) {
+        self.selected_conflict_index = self.selected_conflict_index.min(conflicts.len().saturating_sub(1));
+        self.conflicts = conflicts;
+    }
+
+    /// Resolve the selected conflict by keeping one side
+    fn resolve_selected_conflict(&self, resolution: ConflictResolution) -> Action {
+        match self.conflicts.get(self.selected_conflict_index) {
+            Some(conflict) => Action::ResolveConflict {
+                conflict_uuid: conflict.uuid,
+                resolution,
+            },
+            None => Action::HideDialog,
+        }
+    }
+
     pub fn set_sync_service(&mut self, sync_service: SyncService) {
         self.sync_service = Some(sync_service);
     }
@@ -139,25 +291,461 @@ impl DialogComponent {
         }
     }
 
-    pub fn is_visible(&self) -> bool {
-        self.dialog_type.is_some()
+    /// Update the comment thread, ignoring comments of a task whose thread is no longer open
+    pub fn update_comments(&mut self, task_uuid: &Uuid, comments: Vec<comment::Model>) {
+        if !matches!(&self.dialog_type, Some(DialogType::CommentThread { task_uuid: open }) if open == task_uuid) {
+            return;
+        }
+        // Follow the end of the thread when comments were added
+        if comments.len() > self.comments.len() {
+            self.scroll_to_bottom();
+        }
+        self.comments = comments;
     }
 
-    fn handle_submit(&mut self) -> Action {
+    /// Show the loaded labels of the task in the open editor or label picker
+    pub fn update_task_labels(&mut self, task_uuid: &Uuid, label_uuids: Vec<Uuid>) {
         match &self.dialog_type {
-            Some(DialogType::TaskCreation { default_project_uuid }) => {
-                if !self.input_buffer.is_empty() {
-                    // Determine the project UUID based on whether user explicitly selected via Tab
-                    let project_uuid = if self.task_project_explicitly_selected {
-                        // User pressed Tab - use their selection (could be None for Inbox or Some(uuid) for a project)
-                        self.selected_task_project_uuid
+            Some(DialogType::TaskEdit { task_uuid: open, .. }) if open == task_uuid => {
+                self.task_edit.label_uuids = label_uuids;
+            }
+            Some(DialogType::LabelPicker { task_uuid: open }) if open == task_uuid => {
+                self.picked_label_uuids = label_uuids;
+            }
+            _ => {}
+        }
+    }
+
+    /// Rows of the label picker matching the filter, best match first, followed by the
+    /// labels to create and an offer to create the typed name if no label has it
+    pub fn label_picker_rows(&self) -> Vec<LabelPickerRow> {
+        let query = self.input_buffer.trim();
+        let mut rows: Vec<LabelPickerRow> = fuzzy_filter(self.labels.iter().map(|l| l.name.as_str()), query)
+            .into_iter()
+            .map(|index| {
+                let label = &self.labels[index];
+                LabelPickerRow::Label {
+                    uuid: label.uuid,
+                    name: label.name.clone(),
+                    is_selected: self.picked_label_uuids.contains(&label.uuid),
+                }
+            })
+            .collect();
+        rows.extend(
+            self.new_label_names
+                .iter()
+                .filter(|name| fuzzy_score(name, query).is_some())
+                .map(|name| LabelPickerRow::New(name.clone())),
+        );
+
+        let exists = self
+            .labels
+            .iter()
+            .map(|l| l.name.as_str())
+            .chain(self.new_label_names.iter().map(String::as_str))
+            .any(|name| name.eq_ignore_ascii_case(query));
+        if !query.is_empty() && !exists {
+            rows.push(LabelPickerRow::Create(query.to_string()));
+        }
+        rows
+    }
+
+    /// Toggle a label picker row; creating a label keeps it selected and clears the filter
+    fn toggle_label_picker_row(&mut self, row: LabelPickerRow) {
+        match row {
+            LabelPickerRow::Label { uuid, is_selected, .. } => {
+                if is_selected {
+                    self.picked_label_uuids.retain(|picked| *picked != uuid);
+                } else {
+                    self.picked_label_uuids.push(uuid);
+                }
+            }
+            LabelPickerRow::New(name) => self.new_label_names.retain(|new| *new != name),
+            LabelPickerRow::Create(name) => {
+                self.new_label_names.push(name);
+                self.input_buffer.clear();
+                self.cursor_position = 0;
+                self.selected_label_index = 0;
+            }
+        }
+    }
+
+    /// Handle keys of the label picker; typing filters, Space toggles and Enter saves
+    fn handle_label_picker_key(&mut self, key: KeyEvent) -> Action {
+        let rows = self.label_picker_rows();
+        let selected = rows.get(self.selected_label_index).cloned();
+        match key.code {
+            KeyCode::Esc => Action::HideDialog,
+            KeyCode::Enter => {
+                // A typed name that does not exist yet is created on save
+                if let Some(row @ LabelPickerRow::Create(_)) = selected {
+                    self.toggle_label_picker_row(row);
+                }
+                self.handle_submit()
+            }
+            KeyCode::Up => {
+                self.selected_label_index = self.selected_label_index.saturating_sub(1);
+                Action::None
+            }
+            KeyCode::Down => {
+                if self.selected_label_index + 1 < rows.len() {
+                    self.selected_label_index += 1;
+                }
+                Action::None
+            }
+            KeyCode::Char(' ') => {
+                if let Some(row) = selected {
+                    self.toggle_label_picker_row(row);
+                    let row_count = self.label_picker_rows().len();
+                    self.selected_label_index = self.selected_label_index.min(row_count.saturating_sub(1));
+                }
+                Action::None
+            }
+            code => {
+                let before = self.input_buffer.clone();
+                edit_text(&mut self.input_buffer, &mut self.cursor_position, code);
+                // The best match of the new filter comes first
+                if self.input_buffer != before {
+                    self.selected_label_index = 0;
+                }
+                Action::None
+            }
+        }
+    }
+
+    /// Projects and sections matching the filter of the move picker, best match first
+    pub fn move_targets(&self) -> Vec<MoveTarget> {
+        let Some(DialogType::TaskMove {
+            project_uuid: current_project,
+            section_uuid: current_section,
+            ..
+        }) = &self.dialog_type
+        else {
+            return Vec::new();
+        };
+
+        let targets: Vec<MoveTarget> = self
+            .task_locations()
+            .into_iter()
+            .map(|(project_uuid, section_uuid)| {
+                let project_name = self
+                    .projects
+                    .iter()
+                    .find(|p| p.uuid == project_uuid)
+                    .map_or("Unknown project", |p| p.name.as_str());
+                let name = match section_uuid.and_then(|uuid| self.sections.iter().find(|s| s.uuid == uuid)) {
+                    Some(section) => format!("{} / {}", project_name, section.name),
+                    None => project_name.to_string(),
+                };
+                MoveTarget {
+                    project_uuid,
+                    section_uuid,
+                    name,
+                    is_current: (project_uuid, section_uuid) == (*current_project, *current_section),
+                }
+            })
+            .collect();
+
+        fuzzy_filter(targets.iter().map(|t| t.name.as_str()), self.input_buffer.trim())
+            .into_iter()
+            .map(|index| targets[index].clone())
+            .collect()
+    }
+
+    /// Handle keys of the move picker; typing filters and Enter moves to the highlighted target
+    fn handle_task_move_key(&mut self, key: KeyEvent) -> Action {
+        match key.code {
+            KeyCode::Esc => Action::HideDialog,
+            KeyCode::Enter => self.handle_submit(),
+            KeyCode::Up => {
+                self.selected_move_index = self.selected_move_index.saturating_sub(1);
+                Action::None
+            }
+            KeyCode::Down => {
+                if self.selected_move_index + 1 < self.move_targets().len() {
+                    self.selected_move_index += 1;
+                }
+                Action::None
+            }
+            code => {
+                let before = self.input_buffer.clone();
+                edit_text(&mut self.input_buffer, &mut self.cursor_position, code);
+                // The best match of the new filter comes first
+                if self.input_buffer != before {
+                    self.selected_move_index = 0;
+                }
+                Action::None
+            }
+        }
+    }
+
+    /// Projects and project sections a task can be placed in, in display order
+    fn task_locations(&self) -> Vec<(Uuid, Option<Uuid>)> {
+        let mut locations = Vec::new();
+        for project in &self.projects {
+            locations.push((project.uuid, None));
+            locations.extend(
+                self.get_project_sections(&project.uuid)
+                    .into_iter()
+                    .map(|section| (project.uuid, Some(section.uuid))),
+            );
+        }
+        locations
+    }
+
+    /// Display name of the project and section selected in the task editor
+    fn task_location_name(&self) -> String {
+        let project_name = self
+            .projects
+            .iter()
+            .find(|p| p.uuid == self.task_edit.project_uuid)
+            .map_or("Unknown project", |p| p.name.as_str());
+        let section_name = self
+            .task_edit
+            .section_uuid
+            .and_then(|uuid| self.sections.iter().find(|s| s.uuid == uuid));
+        match section_name {
+            Some(section) => format!("{} / {}", project_name, section.name),
+            None => project_name.to_string(),
+        }
+    }
+
+    /// Handle keys of the task editor; Tab moves between fields, arrows change the pickers
+    fn handle_task_editor_key(&mut self, key: KeyEvent) -> Action {
+        let field = self.task_editor_field;
+        match key.code {
+            KeyCode::Esc => Action::HideDialog,
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => self.handle_submit(),
+            // Enter starts a new line in the description and saves everywhere else
+            KeyCode::Enter if field != TaskEditorField::Description => self.handle_submit(),
+            KeyCode::Tab | KeyCode::BackTab => {
+                self.task_editor_field = if key.code == KeyCode::Tab {
+                    field.next()
+                } else {
+                    field.previous()
+                };
+                self.cursor_position = task_editor_text(&mut self.task_edit, self.task_editor_field)
+                    .map_or(0, |text| text.chars().count());
+                Action::None
+            }
+            KeyCode::Left | KeyCode::Right if !field.is_text() => {
+                let forward = key.code == KeyCode::Right;
+                match field {
+                    TaskEditorField::Location => {
+                        let locations = self.task_locations();
+                        if !locations.is_empty() {
+                            let current = (self.task_edit.project_uuid, self.task_edit.section_uuid);
+                            let index = locations.iter().position(|l| *l == current).unwrap_or(0);
+                            let next = if forward {
+                                (index + 1) % locations.len()
+                            } else {
+                                (index + locations.len() - 1) % locations.len()
+                            };
+                            (self.task_edit.project_uuid, self.task_edit.section_uuid) = locations[next];
+                        }
+                    }
+                    TaskEditorField::Labels => {
+                        self.selected_label_index = if forward {
+                            (self.selected_label_index + 1).min(self.labels.len().saturating_sub(1))
+                        } else {
+                            self.selected_label_index.saturating_sub(1)
+                        };
+                    }
+                    // Priorities are shown as P1 (4) to P4 (1), so moving right lowers the priority
+                    _ => {
+                        let offset = if forward { -1 } else { 1 };
+                        self.task_edit.priority = (self.task_edit.priority + offset).clamp(1, 4);
+                    }
+                }
+                Action::None
+            }
+            KeyCode::Char(' ') if field == TaskEditorField::Labels => {
+                if let Some(label) = self.labels.get(self.selected_label_index) {
+                    let label_uuids = &mut self.task_edit.label_uuids;
+                    match label_uuids.iter().position(|uuid| *uuid == label.uuid) {
+                        Some(index) => {
+                            label_uuids.remove(index);
+                        }
+                        None => label_uuids.push(label.uuid),
+                    }
+                }
+                Action::None
+            }
+            code => {
+                if let Some(text) = task_editor_text(&mut self.task_edit, field) {
+                    let code = if code == KeyCode::Enter {
+                        KeyCode::Char('\n')
                     } else {
-                        // User didn't press Tab - use default project
-                        *default_project_uuid
+                        code
                     };
+                    edit_text(text, &mut self.cursor_position, code);
+                }
+                Action::None
+            }
+        }
+    }
+
+    /// Parse the task creation input and resolve its names against the loaded projects,
+    /// sections and labels. Returns the task to create and a preview of the parsed fields,
+    /// in which names that were not found are flagged; they are left out of the task.
+    pub fn quick_add_task(&self, default_project_uuid: Option<Uuid>) -> (NewTask, Vec<QuickAddPreviewField>) {
+        let parsed = quick_add::parse_quick_add(&self.input_buffer);
+        let mut preview = vec![QuickAddPreviewField {
+            name: "Content",
+            values: vec![(parsed.content.clone(), !parsed.content.is_empty())],
+        }];
+
+        // A subtask goes where its parent is, a typed project or section does not apply
+        let parent_uuid = match &self.dialog_type {
+            Some(DialogType::TaskCreation { parent_uuid, .. }) => *parent_uuid,
+            _ => None,
+        };
+        if let Some(parent_uuid) = parent_uuid {
+            let parent = self.tasks.iter().find(|t| t.uuid == parent_uuid);
+            preview.push(QuickAddPreviewField {
+                name: "Subtask of",
+                values: vec![(parent.map_or_else(String::new, |t| t.content.clone()), parent.is_some())],
+            });
+        }
+
+        // A project typed into the content wins over the one picked with Tab
+        let selected_project_uuid = if self.task_project_explicitly_selected {
+            self.selected_task_project_uuid
+        } else {
+            default_project_uuid
+        };
+        let typed_project = parsed
+            .project
+            .as_ref()
+            .filter(|_| parent_uuid.is_none())
+            .map(|name| (name, self.projects.iter().find(|p| matches_name(&p.name, name))));
+        let project = match typed_project {
+            Some((_, Some(project))) => Some(project),
+            _ => match selected_project_uuid {
+                Some(uuid) => self.projects.iter().find(|p| p.uuid == uuid),
+                None => self.projects.iter().find(|p| p.is_inbox_project),
+            },
+        };
+        if let Some((name, found)) = typed_project {
+            let value = found.map_or_else(|| (name.clone(), false), |project| (project.name.clone(), true));
+            preview.push(QuickAddPreviewField {
+                name: "Project",
+                values: vec![value],
+            });
+        }
+
+        let section = parsed.section.as_ref().filter(|_| parent_uuid.is_none()).map(|name| {
+            let found = project.and_then(|project| {
+                self.get_project_sections(&project.uuid)
+                    .into_iter()
+                    .find(|section| matches_name(&section.name, name))
+            });
+            let value = found.map_or_else(|| (name.clone(), false), |section| (section.name.clone(), true));
+            preview.push(QuickAddPreviewField {
+                name: "Section",
+                values: vec![value],
+            });
+            found
+        });
+
+        let labels: Vec<(&String, Option<&label::Model>)> = parsed
+            .labels
+            .iter()
+            .map(|name| (name, self.labels.iter().find(|l| matches_name(&l.name, name))))
+            .collect();
+        if !labels.is_empty() {
+            preview.push(QuickAddPreviewField {
+                name: "Labels",
+                values: labels
+                    .iter()
+                    .map(|(name, found)| found.map_or_else(|| ((*name).clone(), false), |l| (l.name.clone(), true)))
+                    .collect(),
+            });
+        }
+
+        if let Some(priority) = parsed.priority {
+            preview.push(QuickAddPreviewField {
+                name: "Priority",
+                values: vec![(format!("P{}", 5 - priority), true)],
+            });
+        }
+        if let Some(due) = &parsed.due_string {
+            // Show the date the due string stands for when it can be worked out locally
+            let value = match datetime::resolve_due_string(due, chrono::Local::now().date_naive()) {
+                Some((date, Some(time))) => {
+                    format!(
+                        "{} ({})",
+                        due,
+                        datetime::format_human_datetime(&date.and_time(time).to_string())
+                    )
+                }
+                Some((date, None)) => format!("{} ({})", due, datetime::format_human_date(&datetime::format_ymd(date))),
+                None => due.clone(),
+            };
+            preview.push(QuickAddPreviewField {
+                name: "Due",
+                values: vec![(value, true)],
+            });
+        }
+        if let Some(deadline) = &parsed.deadline {
+            let resolved = datetime::parse_deadline(deadline, chrono::Local::now().date_naive());
+            let value = match resolved {
+                Some(date) => format!(
+                    "{} ({})",
+                    deadline,
+                    datetime::format_human_date(&datetime::format_ymd(date))
+                ),
+                None => deadline.clone(),
+            };
+            preview.push(QuickAddPreviewField {
+                name: "Deadline",
+                values: vec![(value, resolved.is_some())],
+            });
+        }
+        if let Some(duration) = &parsed.duration {
+            preview.push(QuickAddPreviewField {
+                name: "Duration",
+                values: vec![(duration.clone(), true)],
+            });
+        }
+        if let Some(description) = &parsed.description {
+            preview.push(QuickAddPreviewField {
+                name: "Description",
+                values: vec![(description.clone(), true)],
+            });
+        }
 
+        let task = NewTask {
+            content: parsed.content,
+            description: parsed.description,
+            project_uuid: match typed_project {
+                Some((_, Some(project))) => Some(project.uuid),
+                _ => selected_project_uuid,
+            },
+            section_uuid: section.flatten().map(|section| section.uuid),
+            parent_uuid,
+            label_uuids: labels.iter().filter_map(|(_, found)| found.map(|l| l.uuid)).collect(),
+            priority: parsed.priority,
+            due_string: parsed.due_string,
+            deadline: parsed.deadline,
+            duration: parsed.duration,
+        };
+        (task, preview)
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.dialog_type.is_some()
+    }
+
+    fn handle_submit(&mut self) -> Action {
+        match &self.dialog_type {
+            Some(DialogType::TaskCreation {
+                default_project_uuid, ..
+            }) => {
+                let (task, _) = self.quick_add_task(*default_project_uuid);
+                if !task.content.is_empty() {
                     // Debug logging
-                    if let Some(ref pid) = project_uuid {
+                    if let Some(ref pid) = task.project_uuid {
                         let proj_name = self
                             .projects
                             .iter()
@@ -169,21 +757,17 @@ impl DialogComponent {
                         log::info!("Creating task in inbox (no project)");
                     }
 
-                    let action = Action::CreateTask {
-                        content: self.input_buffer.clone(),
-                        project_uuid,
-                    };
                     self.clear_dialog();
-                    action
+                    Action::CreateTask(task)
                 } else {
                     Action::None
                 }
             }
             Some(DialogType::TaskEdit { task_uuid, .. }) => {
-                if !self.input_buffer.is_empty() {
+                if !self.task_edit.content.trim().is_empty() {
                     let action = Action::EditTask {
                         task_uuid: *task_uuid,
-                        content: self.input_buffer.clone(),
+                        edit: self.task_edit.clone(),
                     };
                     self.clear_dialog();
                     action
@@ -191,6 +775,35 @@ impl DialogComponent {
                     Action::None
                 }
             }
+            Some(DialogType::LabelPicker { task_uuid }) => {
+                let action = Action::SetTaskLabels {
+                    task_uuid: *task_uuid,
+                    label_uuids: self.picked_label_uuids.clone(),
+                    new_label_names: self.new_label_names.clone(),
+                };
+                self.clear_dialog();
+                action
+            }
+            Some(DialogType::TaskMove { task_uuid, .. }) => {
+                let task_uuid = *task_uuid;
+                // Moving to the current location or with nothing matching just closes the picker
+                match self.move_targets().into_iter().nth(self.selected_move_index) {
+                    Some(target) if !target.is_current => {
+                        self.clear_dialog();
+                        Action::MoveTask {
+                            task_uuid,
+                            project_uuid: target.project_uuid,
+                            section_uuid: target.section_uuid,
+                            parent_uuid: None,
+                        }
+                    }
+                    Some(_) => {
+                        self.clear_dialog();
+                        Action::HideDialog
+                    }
+                    None => Action::None,
+                }
+            }
             Some(DialogType::ProjectCreation) => {
                 if !self.input_buffer.is_empty() {
                     let parent_uuid = if let Some(parent_index) = self.selected_parent_project_index {
@@ -249,6 +862,53 @@ impl DialogComponent {
                     Action::None
                 }
             }
+            Some(DialogType::SectionCreation { project_uuid }) => {
+                if !self.input_buffer.is_empty() {
+                    let action = Action::CreateSection {
+                        project_uuid: *project_uuid,
+                        name: self.input_buffer.clone(),
+                    };
+                    self.clear_dialog();
+                    action
+                } else {
+                    Action::None
+                }
+            }
+            Some(DialogType::SectionEdit { section_uuid, .. }) => {
+                if !self.input_buffer.is_empty() {
+                    let action = Action::EditSection {
+                        section_uuid: *section_uuid,
+                        name: self.input_buffer.clone(),
+                    };
+                    self.clear_dialog();
+                    action
+                } else {
+                    Action::None
+                }
+            }
+            Some(DialogType::CommentThread { task_uuid }) => {
+                let content = self.input_buffer.trim();
+                if content.is_empty() {
+                    return Action::None;
+                }
+                let action = Action::CreateComment {
+                    task_uuid: *task_uuid,
+                    content: content.to_string(),
+                };
+                // Keep the thread open for the next comment
+                self.input_buffer.clear();
+                self.cursor_position = 0;
+                action
+            }
+            Some(DialogType::ApiTokenInput) => {
+                let api_token = self.input_buffer.trim().to_string();
+                if !api_token.is_empty() {
+                    self.clear_dialog();
+                    Action::UpdateApiToken(api_token)
+                } else {
+                    Action::None
+                }
+            }
             Some(DialogType::TaskDueDateInput { task_uuid }) => {
                 let due_string = if self.input_buffer.trim().is_empty() {
                     "no date".to_string()
@@ -259,6 +919,16 @@ impl DialogComponent {
                 self.clear_dialog();
                 action
             }
+            Some(DialogType::TaskDeadlineInput { task_uuid, .. }) => {
+                let action = Action::SetTaskDeadline(*task_uuid, self.input_buffer.trim().to_string());
+                self.clear_dialog();
+                action
+            }
+            Some(DialogType::TaskDurationInput { task_uuid, .. }) => {
+                let action = Action::SetTaskDuration(*task_uuid, self.input_buffer.trim().to_string());
+                self.clear_dialog();
+                action
+            }
             Some(DialogType::DeleteConfirmation { item_type, item_uuid }) => match item_type.as_str() {
                 "task" => {
                     let action = Action::DeleteTask(item_uuid.to_string());
@@ -275,6 +945,11 @@ impl DialogComponent {
                     self.clear_dialog();
                     action
                 }
+                "section" => {
+                    let action = Action::DeleteSection(*item_uuid);
+                    self.clear_dialog();
+                    action
+                }
                 _ => Action::None,
             },
             _ => Action::None,
@@ -293,6 +968,15 @@ impl DialogComponent {
         self.scroll_offset = 0;
         self.scrollbar_state = ScrollbarState::new(0);
         self.search_results.clear();
+        self.comments.clear();
+        self.selected_conflict_index = 0;
+        self.selected_section_index = 0;
+        self.task_edit = TaskEdit::default();
+        self.task_editor_field = TaskEditorField::default();
+        self.selected_label_index = 0;
+        self.picked_label_uuids.clear();
+        self.new_label_names.clear();
+        self.selected_move_index = 0;
     }
 
     fn scroll_up(&mut self) {
@@ -321,6 +1005,15 @@ impl DialogComponent {
 
     fn render_task_creation_dialog(&self, f: &mut Frame, area: Rect) {
         let task_projects = self.get_task_projects();
+        let default_project_uuid = match &self.dialog_type {
+            Some(DialogType::TaskCreation {
+                default_project_uuid, ..
+            }) => *default_project_uuid,
+            _ => None,
+        };
+        // Only show the preview once the input uses some of the quick-add syntax
+        let (_, preview) = self.quick_add_task(default_project_uuid);
+        let preview = if preview.len() > 1 { preview } else { Vec::new() };
         task_dialogs::render_task_creation_dialog(
             f,
             area,
@@ -329,6 +1022,29 @@ impl DialogComponent {
             self.cursor_position,
             &task_projects,
             self.selected_task_project_index,
+            &preview,
+        );
+    }
+
+    fn render_label_picker_dialog(&self, f: &mut Frame, area: Rect) {
+        label_dialogs::render_label_picker_dialog(
+            f,
+            area,
+            &self.input_buffer,
+            self.cursor_position,
+            &self.label_picker_rows(),
+            self.selected_label_index,
+        );
+    }
+
+    fn render_task_move_dialog(&self, f: &mut Frame, area: Rect) {
+        task_dialogs::render_task_move_dialog(
+            f,
+            area,
+            &self.input_buffer,
+            self.cursor_position,
+            &self.move_targets(),
+            self.selected_move_index,
         );
     }
 
@@ -358,24 +1074,15 @@ impl DialogComponent {
     }
 
     fn render_task_edit_dialog(&self, f: &mut Frame, area: Rect) {
-        let task_projects = self.get_task_projects();
-
-        // Find the current project index for the task being edited
-        let current_project_index = if let Some(DialogType::TaskEdit { project_uuid, .. }) = &self.dialog_type {
-            task_projects.iter().position(|p| p.uuid == *project_uuid)
-        } else {
-            None
+        let view = TaskEditorView {
+            values: &self.task_edit,
+            focused_field: self.task_editor_field,
+            cursor_position: self.cursor_position,
+            location: self.task_location_name(),
+            labels: &self.labels,
+            selected_label_index: self.selected_label_index,
         };
-
-        task_dialogs::render_task_edit_dialog(
-            f,
-            area,
-            &self.icons,
-            &self.input_buffer,
-            self.cursor_position,
-            &task_projects,
-            current_project_index,
-        );
+        task_dialogs::render_task_editor_dialog(f, area, &view);
     }
 
     fn render_delete_confirmation_dialog(&self, f: &mut Frame, area: Rect, item_type: &str) {
@@ -505,9 +1212,66 @@ impl DialogComponent {
         task_dialogs::render_due_date_input_dialog(f, area, &self.input_buffer, self.cursor_position);
     }
 
+    fn render_deadline_input_dialog(&self, f: &mut Frame, area: Rect) {
+        task_dialogs::render_deadline_input_dialog(f, area, &self.input_buffer, self.cursor_position);
+    }
+
+    fn render_duration_input_dialog(&self, f: &mut Frame, area: Rect) {
+        task_dialogs::render_duration_input_dialog(f, area, &self.input_buffer, self.cursor_position);
+    }
+
     fn render_logs_dialog(&mut self, f: &mut Frame, area: Rect) {
         system_dialogs::render_logs_dialog(f, area, self.scroll_offset, &mut self.scrollbar_state);
     }
+
+    fn render_api_token_dialog(&self, f: &mut Frame, area: Rect) {
+        system_dialogs::render_api_token_dialog(f, area, &self.input_buffer, self.cursor_position);
+    }
+
+    fn render_section_manager_dialog(&self, f: &mut Frame, area: Rect, project_uuid: &Uuid) {
+        let project_name = self
+            .projects
+            .iter()
+            .find(|p| &p.uuid == project_uuid)
+            .map(|p| p.name.as_str())
+            .unwrap_or("project");
+        let sections = self.get_project_sections(project_uuid);
+        section_dialogs::render_section_manager_dialog(f, area, project_name, &sections, self.selected_section_index);
+    }
+
+    fn render_section_creation_dialog(&self, f: &mut Frame, area: Rect) {
+        section_dialogs::render_section_creation_dialog(f, area, &self.input_buffer, self.cursor_position);
+    }
+
+    fn render_section_edit_dialog(&self, f: &mut Frame, area: Rect) {
+        section_dialogs::render_section_edit_dialog(f, area, &self.input_buffer, self.cursor_position);
+    }
+
+    fn render_comment_thread_dialog(&mut self, f: &mut Frame, area: Rect, task_uuid: &Uuid) {
+        let task_content = self
+            .tasks
+            .iter()
+            .find(|t| &t.uuid == task_uuid)
+            .map(|t| t.content.as_str())
+            .unwrap_or("task");
+        let view = comment_dialogs::CommentThreadView {
+            task_content,
+            comments: &self.comments,
+            input_buffer: &self.input_buffer,
+            cursor_position: self.cursor_position,
+        };
+        comment_dialogs::render_comment_thread_dialog(
+            f,
+            area,
+            &view,
+            &mut self.scroll_offset,
+            &mut self.scrollbar_state,
+        );
+    }
+
+    fn render_conflict_resolution_dialog(&self, f: &mut Frame, area: Rect) {
+        conflict_dialogs::render_conflict_resolution_dialog(f, area, &self.conflicts, self.selected_conflict_index);
+    }
 }
 
 impl Component for DialogComponent {
@@ -614,6 +1378,46 @@ impl Component for DialogComponent {
                 KeyCode::Enter => self.handle_submit(),
                 _ => Action::None,
             },
+            Some(DialogType::SectionManagement { project_uuid }) => {
+                let project_uuid = *project_uuid;
+                self.handle_section_manager_key(key, project_uuid)
+            }
+            Some(DialogType::TaskEdit { .. }) => self.handle_task_editor_key(key),
+            Some(DialogType::LabelPicker { .. }) => self.handle_label_picker_key(key),
+            Some(DialogType::TaskMove { .. }) => self.handle_task_move_key(key),
+            // Scrolling keys move through the thread, everything else edits the new comment
+            Some(DialogType::CommentThread { .. })
+                if matches!(
+                    key.code,
+                    KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown | KeyCode::Home | KeyCode::End
+                ) =>
+            {
+                match key.code {
+                    KeyCode::Up => self.scroll_up(),
+                    KeyCode::Down => self.scroll_down(),
+                    KeyCode::PageUp => self.page_up(),
+                    KeyCode::PageDown => self.page_down(),
+                    KeyCode::Home => self.scroll_to_top(),
+                    _ => self.scroll_to_bottom(),
+                }
+                Action::None
+            }
+            Some(DialogType::ConflictResolution) => match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => Action::HideDialog,
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.selected_conflict_index = self.selected_conflict_index.saturating_sub(1);
+                    Action::None
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    if self.selected_conflict_index + 1 < self.conflicts.len() {
+                        self.selected_conflict_index += 1;
+                    }
+                    Action::None
+                }
+                KeyCode::Char('l') => self.resolve_selected_conflict(ConflictResolution::KeepLocal),
+                KeyCode::Char('r') => self.resolve_selected_conflict(ConflictResolution::UseRemote),
+                _ => Action::None,
+            },
             Some(DialogType::TaskSearch) => match key.code {
                 KeyCode::Esc => Action::HideDialog,
                 KeyCode::Enter => Action::HideDialog,
@@ -812,19 +1616,41 @@ impl Component for DialogComponent {
 
                 // Pre-populate input for edit dialogs
                 match &dialog_type {
-                    DialogType::TaskEdit { content, .. } => {
-                        self.input_buffer = content.clone();
-                        self.cursor_position = content.chars().count();
+                    DialogType::TaskEdit { values, .. } => {
+                        self.input_buffer.clear();
+                        self.task_edit = values.clone();
+                        self.task_editor_field = TaskEditorField::Content;
+                        self.selected_label_index = 0;
+                        self.cursor_position = values.content.chars().count();
+                    }
+                    DialogType::LabelPicker { .. } => {
+                        self.input_buffer.clear();
+                        self.cursor_position = 0;
+                        self.selected_label_index = 0;
+                        self.picked_label_uuids.clear();
+                        self.new_label_names.clear();
+                    }
+                    DialogType::TaskMove { .. } => {
+                        self.input_buffer.clear();
+                        self.cursor_position = 0;
+                        self.selected_move_index = 0;
                     }
                     DialogType::ProjectEdit { name, .. } => {
                         self.input_buffer = name.clone();
                         self.cursor_position = name.chars().count();
                     }
-                    DialogType::LabelEdit { name, .. } => {
+                    DialogType::LabelEdit { name, .. } | DialogType::SectionEdit { name, .. } => {
                         self.input_buffer = name.clone();
                         self.cursor_position = name.chars().count();
                     }
-                    DialogType::TaskCreation { default_project_uuid } => {
+                    DialogType::TaskDeadlineInput { deadline: value, .. }
+                    | DialogType::TaskDurationInput { duration: value, .. } => {
+                        self.input_buffer = value.clone();
+                        self.cursor_position = value.chars().count();
+                    }
+                    DialogType::TaskCreation {
+                        default_project_uuid, ..
+                    } => {
                         self.input_buffer.clear();
                         self.cursor_position = 0;
                         // Set the selected task project index and UUID if a default project is provided
@@ -850,6 +1676,13 @@ impl Component for DialogComponent {
                         self.cursor_position = 0;
                         self.search_results.clear();
                     }
+                    DialogType::CommentThread { .. } => {
+                        self.input_buffer.clear();
+                        self.cursor_position = 0;
+                        self.comments.clear();
+                        // Start at the latest comments
+                        self.scroll_to_bottom();
+                    }
                     _ => {
                         self.input_buffer.clear();
                         self.cursor_position = 0;
@@ -881,6 +1714,8 @@ impl Component for DialogComponent {
             match dialog_type {
                 DialogType::TaskCreation { .. } => self.render_task_creation_dialog(f, rect),
                 DialogType::TaskEdit { .. } => self.render_task_edit_dialog(f, rect),
+                DialogType::LabelPicker { .. } => self.render_label_picker_dialog(f, rect),
+                DialogType::TaskMove { .. } => self.render_task_move_dialog(f, rect),
                 DialogType::ProjectCreation => {
                     self.render_project_creation_dialog(f, rect);
                 }
@@ -893,6 +1728,18 @@ impl Component for DialogComponent {
                 DialogType::LabelEdit { .. } => {
                     self.render_label_edit_dialog(f, rect);
                 }
+                DialogType::SectionManagement { project_uuid } => {
+                    self.render_section_manager_dialog(f, rect, &project_uuid);
+                }
+                DialogType::SectionCreation { .. } => {
+                    self.render_section_creation_dialog(f, rect);
+                }
+                DialogType::SectionEdit { .. } => {
+                    self.render_section_edit_dialog(f, rect);
+                }
+                DialogType::CommentThread { task_uuid } => {
+                    self.render_comment_thread_dialog(f, rect, &task_uuid);
+                }
                 DialogType::DeleteConfirmation { item_type, .. } => {
                     self.render_delete_confirmation_dialog(f, rect, &item_type);
                 }
@@ -914,6 +1761,18 @@ impl Component for DialogComponent {
                 DialogType::TaskDueDateInput { .. } => {
                     self.render_due_date_input_dialog(f, rect);
                 }
+                DialogType::TaskDeadlineInput { .. } => {
+                    self.render_deadline_input_dialog(f, rect);
+                }
+                DialogType::TaskDurationInput { .. } => {
+                    self.render_duration_input_dialog(f, rect);
+                }
+                DialogType::ConflictResolution => {
+                    self.render_conflict_resolution_dialog(f, rect);
+                }
+                DialogType::ApiTokenInput => {
+                    self.render_api_token_dialog(f, rect);
+                }
             }
         }
     }
@@ -0,0 +1,156 @@
+use super::common::{self, shortcuts};
+use crate::entities::comment;
+use crate::ui::layout::LayoutManager;
+use crate::utils::datetime;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Frame,
+};
+
+/// What the comment thread dialog shows
+pub struct CommentThreadView<'a> {
+    pub task_content: &'a str,
+    pub comments: &'a [comment::Model],
+    pub input_buffer: &'a str,
+    pub cursor_position: usize,
+}
+
+/// Break text into lines of at most `width` characters, preferring to break at spaces
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let line_len = line.chars().count();
+            let word_len = word.chars().count();
+            if line_len > 0 && line_len + 1 + word_len > width {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+            // Words longer than the whole line are split hard
+            while line.chars().count() > width {
+                let rest: String = line.chars().skip(width).collect();
+                lines.push(line.chars().take(width).collect());
+                line = rest;
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Renders the comments of a task, oldest first, with an input to post a new one.
+///
+/// `scroll_offset` is clamped to the end of the thread, so scrolling to the bottom
+/// with a large offset keeps following new comments.
+pub fn render_comment_thread_dialog(
+    f: &mut Frame,
+    area: Rect,
+    view: &CommentThreadView,
+    scroll_offset: &mut usize,
+    scrollbar_state: &mut ScrollbarState,
+) {
+    let dialog_area = LayoutManager::centered_rect(70, 70, area);
+    f.render_widget(Clear, dialog_area);
+
+    let title = format!(" Comments on {} ", view.task_content);
+    let main_block = common::create_dialog_block(&title, Color::Cyan);
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(3),    // Comment thread (borders + content)
+            Constraint::Length(3), // Comment input field (borders + content)
+            Constraint::Length(1), // Spacer
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    f.render_widget(main_block, dialog_area);
+
+    let thread_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(Color::Gray));
+    let thread_area = thread_block.inner(chunks[0]);
+    f.render_widget(thread_block, chunks[0]);
+
+    // Leave a column for the scrollbar
+    let text_width = thread_area.width.saturating_sub(2) as usize;
+    let mut lines: Vec<Line> = Vec::new();
+    for comment in view.comments {
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        let posted = comment
+            .posted_at
+            .as_deref()
+            .map_or_else(|| "Unknown date".to_string(), datetime::format_human_datetime);
+        lines.push(Line::from(Span::styled(
+            posted,
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        )));
+        lines.extend(
+            wrap_text(&comment.content, text_width)
+                .into_iter()
+                .map(|line| Line::from(Span::styled(line, Style::default().fg(Color::White)))),
+        );
+    }
+
+    if lines.is_empty() {
+        let empty = Paragraph::new("No comments yet. Type below to add one.").style(Style::default().fg(Color::Gray));
+        f.render_widget(empty, thread_area);
+    } else {
+        let total_lines = lines.len();
+        let visible_height = thread_area.height as usize;
+        let max_scroll = total_lines.saturating_sub(visible_height);
+        *scroll_offset = (*scroll_offset).min(max_scroll);
+
+        *scrollbar_state = scrollbar_state
+            .content_length(total_lines)
+            .viewport_content_length(visible_height)
+            .position(*scroll_offset);
+
+        let visible_lines: Vec<Line> = lines.into_iter().skip(*scroll_offset).take(visible_height).collect();
+        f.render_widget(Paragraph::new(visible_lines), thread_area);
+
+        if total_lines > visible_height {
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(Some("↑"))
+                .end_symbol(Some("↓"))
+                .track_symbol(Some("│"))
+                .thumb_symbol("▐")
+                .style(Style::default().fg(Color::Gray))
+                .thumb_style(Style::default().fg(Color::White));
+            f.render_stateful_widget(scrollbar, thread_area, scrollbar_state);
+        }
+    }
+
+    let input_paragraph = common::create_input_paragraph(view.input_buffer, view.cursor_position, "New Comment");
+    f.render_widget(input_paragraph, chunks[1]);
+
+    let instructions = [
+        ("Enter", Color::Green, " Post"),
+        shortcuts::SEPARATOR,
+        ("↑/↓", Color::Yellow, " Scroll"),
+        shortcuts::SEPARATOR,
+        shortcuts::ESC_CANCEL,
+    ];
+    f.render_widget(common::create_instructions_paragraph(&instructions), chunks[3]);
+
+    // Set terminal cursor position with safe u16 conversion and overflow protection
+    let base_x = chunks[1].x.saturating_add(1);
+    let cursor_u16 = u16::try_from(view.cursor_position).unwrap_or(u16::MAX.saturating_sub(base_x));
+    let final_x = base_x.saturating_add(cursor_u16);
+    let final_y = chunks[1].y.saturating_add(1);
+    f.set_cursor_position((final_x, final_y));
+}
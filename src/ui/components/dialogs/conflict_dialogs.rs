@@ -0,0 +1,101 @@
+use super::common::{self, shortcuts};
+use crate::entities::sync_conflict;
+use crate::sync::conflicts::display_value;
+use crate::ui::layout::LayoutManager;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Renders the sync conflict dialog with the local and remote values of the selected field side by side
+pub fn render_conflict_resolution_dialog(
+    f: &mut Frame,
+    area: Rect,
+    conflicts: &[sync_conflict::Model],
+    selected_index: usize,
+) {
+    let dialog_area = LayoutManager::centered_rect_lines(80, 15, area);
+    f.render_widget(Clear, dialog_area);
+
+    let main_block = common::create_dialog_block(" Sync Conflicts ", Color::Yellow);
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(2), // Conflict header
+            Constraint::Min(5),    // Local and remote values
+            Constraint::Length(1), // Spacer
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    f.render_widget(main_block, dialog_area);
+
+    let Some(conflict) = conflicts.get(selected_index.min(conflicts.len().saturating_sub(1))) else {
+        let empty = Paragraph::new("No sync conflicts to resolve").style(Style::default().fg(Color::Gray));
+        f.render_widget(empty, chunks[0]);
+        return;
+    };
+
+    let header = vec![
+        Line::from(vec![
+            Span::styled(
+                format!("{} of {} ", selected_index + 1, conflicts.len()),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(
+                format!("{} \"{}\"", conflict.entity_type, conflict.entity_name),
+                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Field: ", Style::default().fg(Color::Gray)),
+            Span::styled(conflict.field.clone(), Style::default().fg(Color::Cyan)),
+        ]),
+    ];
+    f.render_widget(Paragraph::new(header), chunks[0]);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[1]);
+
+    f.render_widget(
+        value_paragraph(&conflict.local_value, " Local ", Color::Green),
+        columns[0],
+    );
+    f.render_widget(
+        value_paragraph(&conflict.remote_value, " Remote ", Color::Blue),
+        columns[1],
+    );
+
+    let instructions = [
+        ("l", Color::Green, " Keep local"),
+        shortcuts::SEPARATOR,
+        ("r", Color::Blue, " Use remote"),
+        shortcuts::SEPARATOR,
+        ("j/k", Color::Cyan, " Next/previous"),
+        shortcuts::SEPARATOR,
+        shortcuts::ESC_CANCEL,
+    ];
+    f.render_widget(common::create_instructions_paragraph(&instructions), chunks[3]);
+}
+
+fn value_paragraph<'a>(json: &str, title: &'a str, color: Color) -> Paragraph<'a> {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .title_style(Style::default().fg(color).add_modifier(Modifier::BOLD))
+        .style(Style::default().fg(Color::Gray));
+
+    Paragraph::new(display_value(json))
+        .block(block)
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: false })
+}
@@ -3,10 +3,12 @@ use crate::icons::IconService;
 use crate::ui::layout::LayoutManager;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::Color,
-    widgets::Clear,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph},
     Frame,
 };
+use uuid::Uuid;
 
 fn render_label_dialog(
     f: &mut Frame,
@@ -78,3 +80,108 @@ pub fn render_label_edit_dialog(
 ) {
     render_label_dialog(f, area, icons, input_buffer, cursor_position, true);
 }
+
+/// A row of the label picker
+#[derive(Clone, Debug, PartialEq)]
+pub enum LabelPickerRow {
+    /// An existing label and whether the task has it
+    Label {
+        uuid: Uuid,
+        name: String,
+        is_selected: bool,
+    },
+    /// A label that is created when the picker is saved
+    New(String),
+    /// Offer to create a label with the typed name
+    Create(String),
+}
+
+/// Renders the label picker: a filter input above the matching labels, with checkboxes
+pub fn render_label_picker_dialog(
+    f: &mut Frame,
+    area: Rect,
+    input_buffer: &str,
+    cursor_position: usize,
+    rows: &[LabelPickerRow],
+    selected_index: usize,
+) {
+    // Room for the rows plus the filter, borders, margins, spacer and instructions
+    let list_height = u16::try_from(rows.len().max(1)).unwrap_or(u16::MAX).min(12);
+    let dialog_area = LayoutManager::centered_rect_lines(65, list_height.saturating_add(11), area);
+    f.render_widget(Clear, dialog_area);
+
+    let main_block = common::create_dialog_block("Labels", Color::Cyan);
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3), // Filter input field (borders + content)
+            Constraint::Min(3),    // Label list (borders + content)
+            Constraint::Length(1), // Spacer
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    f.render_widget(main_block, dialog_area);
+    f.render_widget(
+        common::create_input_paragraph(input_buffer, cursor_position, "Filter"),
+        chunks[0],
+    );
+
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(Color::Gray));
+
+    if rows.is_empty() {
+        let empty = Paragraph::new("No labels yet. Type a name to create one.")
+            .block(list_block)
+            .style(Style::default().fg(Color::Gray));
+        f.render_widget(empty, chunks[1]);
+    } else {
+        let checkbox = |is_selected: bool| if is_selected { "[x] " } else { "[ ] " };
+        let items: Vec<ListItem> = rows
+            .iter()
+            .map(|row| {
+                let line = match row {
+                    LabelPickerRow::Label { name, is_selected, .. } => Line::from(vec![
+                        Span::styled(checkbox(*is_selected), Style::default().fg(Color::Yellow)),
+                        Span::styled(name.clone(), Style::default().fg(Color::White)),
+                    ]),
+                    LabelPickerRow::New(name) => Line::from(vec![
+                        Span::styled(checkbox(true), Style::default().fg(Color::Yellow)),
+                        Span::styled(name.clone(), Style::default().fg(Color::White)),
+                        Span::styled(" (new)", Style::default().fg(Color::Green)),
+                    ]),
+                    LabelPickerRow::Create(name) => Line::from(Span::styled(
+                        format!("+ Create label \"{}\"", name),
+                        Style::default().fg(Color::Green),
+                    )),
+                };
+                ListItem::new(line)
+            })
+            .collect();
+        let list = List::new(items)
+            .block(list_block)
+            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+        let mut list_state = ListState::default();
+        list_state.select(Some(selected_index.min(rows.len() - 1)));
+        f.render_stateful_widget(list, chunks[1], &mut list_state);
+    }
+
+    let instructions = [
+        ("Space", Color::Yellow, " Toggle"),
+        shortcuts::SEPARATOR,
+        ("Enter", Color::Green, " Save"),
+        shortcuts::SEPARATOR,
+        shortcuts::ESC_CANCEL,
+    ];
+    f.render_widget(common::create_instructions_paragraph(&instructions), chunks[3]);
+
+    // Set terminal cursor position with safe u16 conversion and overflow protection
+    let base_x = chunks[0].x.saturating_add(1);
+    let cursor_u16 = u16::try_from(cursor_position).unwrap_or(u16::MAX.saturating_sub(base_x));
+    f.set_cursor_position((base_x.saturating_add(cursor_u16), chunks[0].y.saturating_add(1)));
+}
@@ -1,7 +1,10 @@
 mod common;
 
+pub mod comment_dialogs;
+pub mod conflict_dialogs;
 pub mod label_dialogs;
 pub mod project_dialogs;
 pub mod scroll_behavior;
+pub mod section_dialogs;
 pub mod system_dialogs;
 pub mod task_dialogs;
@@ -0,0 +1,132 @@
+use super::common::{self, shortcuts};
+use crate::entities::section;
+use crate::ui::layout::LayoutManager;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Renders the list of sections of a project with the shortcuts to manage them
+pub fn render_section_manager_dialog(
+    f: &mut Frame,
+    area: Rect,
+    project_name: &str,
+    sections: &[&section::Model],
+    selected_index: usize,
+) {
+    // Room for the sections plus borders, margins, spacer and instructions
+    let list_height = u16::try_from(sections.len().max(1)).unwrap_or(u16::MAX).min(12);
+    let dialog_area = LayoutManager::centered_rect_lines(65, list_height.saturating_add(8), area);
+    f.render_widget(Clear, dialog_area);
+
+    let title = format!(" Sections of {} ", project_name);
+    let main_block = common::create_dialog_block(&title, Color::Magenta);
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(3),    // Section list (borders + content)
+            Constraint::Length(1), // Spacer
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    f.render_widget(main_block, dialog_area);
+
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(Color::Gray));
+
+    if sections.is_empty() {
+        let empty = Paragraph::new("No sections yet. Press 'a' to add one.")
+            .block(list_block)
+            .style(Style::default().fg(Color::Gray));
+        f.render_widget(empty, chunks[0]);
+    } else {
+        let items: Vec<ListItem> = sections
+            .iter()
+            .map(|section| {
+                ListItem::new(Line::from(Span::styled(
+                    section.name.clone(),
+                    Style::default().fg(Color::White),
+                )))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(list_block)
+            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+        let mut list_state = ListState::default();
+        list_state.select(Some(selected_index.min(sections.len() - 1)));
+        f.render_stateful_widget(list, chunks[0], &mut list_state);
+    }
+
+    let instructions = [
+        ("a", Color::Green, " Add"),
+        shortcuts::SEPARATOR,
+        ("e", Color::Cyan, " Rename"),
+        shortcuts::SEPARATOR,
+        ("d", Color::Red, " Delete"),
+        shortcuts::SEPARATOR,
+        ("J/K", Color::Yellow, " Move"),
+        shortcuts::SEPARATOR,
+        shortcuts::ESC_CANCEL,
+    ];
+    f.render_widget(common::create_instructions_paragraph(&instructions), chunks[2]);
+}
+
+fn render_section_dialog(f: &mut Frame, area: Rect, input_buffer: &str, cursor_position: usize, is_editing: bool) {
+    let dialog_area = LayoutManager::centered_rect_lines(65, 9, area);
+    f.render_widget(Clear, dialog_area);
+
+    let title = if is_editing { "Rename Section" } else { "New Section" };
+    let main_block = common::create_dialog_block(title, Color::Magenta);
+
+    // Create layout for content
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3), // Section name input field (borders + content)
+            Constraint::Length(1), // Spacer
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    let input_paragraph = common::create_input_paragraph(input_buffer, cursor_position, "Section Name");
+
+    let action = if is_editing {
+        ("Enter", Color::Green, " Save Section")
+    } else {
+        ("Enter", Color::Green, " Create Section")
+    };
+
+    let instructions = [action, shortcuts::SEPARATOR, shortcuts::ESC_CANCEL];
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions);
+
+    // Render all components
+    f.render_widget(main_block, dialog_area);
+    f.render_widget(input_paragraph, chunks[0]);
+    f.render_widget(instructions_paragraph, chunks[2]);
+
+    // Set terminal cursor position with safe u16 conversion and overflow protection
+    let base_x = chunks[0].x.saturating_add(1);
+    let cursor_u16 = u16::try_from(cursor_position).unwrap_or(u16::MAX.saturating_sub(base_x));
+    let final_x = base_x.saturating_add(cursor_u16);
+    let final_y = chunks[0].y.saturating_add(1);
+    f.set_cursor_position((final_x, final_y));
+}
+
+pub fn render_section_creation_dialog(f: &mut Frame, area: Rect, input_buffer: &str, cursor_position: usize) {
+    render_section_dialog(f, area, input_buffer, cursor_position, false);
+}
+
+pub fn render_section_edit_dialog(f: &mut Frame, area: Rect, input_buffer: &str, cursor_position: usize) {
+    render_section_dialog(f, area, input_buffer, cursor_position, true);
+}
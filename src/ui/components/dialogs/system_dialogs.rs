@@ -1,3 +1,4 @@
+use super::common::{self, shortcuts};
 use crate::icons::IconService;
 use crate::logger;
 use crate::ui::layout::LayoutManager;
@@ -120,7 +121,11 @@ pub fn render_delete_confirmation_dialog(f: &mut Frame, area: Rect, icons: &Icon
         .split(inner_area);
 
     // Confirmation message
-    let message = format!("Are you sure you want to delete this {}?", item_type);
+    let message = match item_type {
+        // The backend removes the tasks of a section along with it
+        "section" => "Are you sure you want to delete this section and all of its tasks?".to_string(),
+        _ => format!("Are you sure you want to delete this {}?", item_type),
+    };
     let message_paragraph = Paragraph::new(message)
         .style(Style::default().fg(Color::White))
         .alignment(Alignment::Center);
@@ -202,23 +207,91 @@ PROJECT & LABEL MANAGEMENT
 A           Create new project
 E           Edit selected item (project or label)
 D           Delete selected item (project or label)
+S           Manage sections of the selected project
+Alt+J/K     Move the selected project down/up among its siblings
 
 TASK MANAGEMENT
 --------------
-Space       Toggle task completion
+Space       Toggle task completion (reopens tasks listed in the Logbook)
 a           Create new task
-e           Edit selected task
+o           Add a subtask to the selected task
+e           Edit selected task in the task editor
+l           Pick the labels of the selected task
+m           Move the selected task to another project or section
+Tab/>       Indent: make the task a subtask of the one above
+S-Tab/<     Outdent: move the subtask up one level
+z           Collapse or expand the subtasks of the selected task
+Alt+j/k     Move the selected task down/up among its siblings (manual order only)
 d           Delete task (with confirmation)
 p           Cycle task priority
+c           Show and post comments on the selected task
 t           Set task due date to today
 T           Set task due date to tomorrow
 w           Set task due date to next week (Monday)
 W           Set task due date to next week end (Saturday)
 s           Set due date (type natural language, e.g. 'march 15')
+x           Set deadline (e.g. '2025-03-30', 'friday')
+u           Set duration (e.g. '45m', '1h30'), shown in the Schedule view
+
+QUICK ADD (new task)
+--------------------
+#project    Put the task into a project
+/section    Put the task into a section
+@label      Add a label
+p1-p4       Set the priority
+date        Set the due date (tomorrow 5pm, every monday, march 15)
+{date}      Set the deadline ({march 30})
+for 45m     Set the duration (for 1h30, for 2 hours)
+//text      Everything after // becomes the description
+
+LABEL PICKER
+------------
+Type        Filter labels (fuzzy)
+↑↓          Select label
+Space       Toggle the selected label, or create the typed one
+Enter       Save labels
+
+MOVE PICKER
+-----------
+Type        Filter projects and sections (fuzzy)
+↑↓          Select destination
+Enter       Move the task with its subtasks
+
+TASK EDITOR
+-----------
+Tab/S-Tab   Next/previous field
+←→          Change project/section, selected label or priority
+Space       Toggle the selected label
+Enter       Save task (new line in the description)
+Ctrl+S      Save task from any field
+
+VIEW
+----
+v           Cycle the sort of the current view (manual, priority, due date, name, date added)
+V           Cycle the grouping (view sections, none, project, section, priority, due day, label)
+
+CALENDAR VIEW
+-------------
+←/→         Highlight the previous/next day
+[/]         Highlight the same day of the previous/next week
+PgUp/PgDn   Highlight the same day of the previous/next month
+Home        Highlight today
+g           Show the agenda of the highlighted day
+M           Move the selected agenda task to the highlighted day
+Mouse       Click a day to show its agenda, scroll to change months
+
+BOARD VIEW
+----------
+b           Switch the selected project between list and board
+←/→         Select the previous/next column
+j/k, ↑/↓    Select the next/previous card
+</>         Move the selected card to the previous/next column (also Shift+←/→)
+Mouse       Click a card to select it, drag it onto another column to move it
 
 SYNC & DATA
 -----------
 r           Force sync with Todoist
+C           Resolve sync conflicts
 Ctrl+C      Quit application
 
 GENERAL CONTROLS
@@ -369,3 +442,46 @@ pub fn render_logs_dialog(f: &mut Frame, area: Rect, scroll_offset: usize, scrol
         f.render_stateful_widget(scrollbar, logs_content_area, scrollbar_state);
     }
 }
+
+/// Prompt for a new API token after the backend rejected the stored one
+pub fn render_api_token_dialog(f: &mut Frame, area: Rect, input_buffer: &str, cursor_position: usize) {
+    let dialog_area = LayoutManager::centered_rect_lines(65, 10, area);
+    f.render_widget(Clear, dialog_area);
+
+    let main_block = common::create_dialog_block("API Token Rejected", Color::Red);
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(1), // Explanation
+            Constraint::Length(1), // Spacer
+            Constraint::Length(3), // Token input field
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    let explanation = Paragraph::new("Todoist rejected the API token. Enter a new one to keep syncing.")
+        .style(Style::default().fg(Color::White));
+
+    // Never show the token itself
+    let masked = "•".repeat(input_buffer.chars().count());
+    let input_paragraph = common::create_input_paragraph(&masked, cursor_position, "API Token");
+
+    let instructions = [
+        ("Enter", Color::Green, " Save Token"),
+        shortcuts::SEPARATOR,
+        shortcuts::ESC_CANCEL,
+    ];
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions);
+
+    f.render_widget(main_block, dialog_area);
+    f.render_widget(explanation, chunks[0]);
+    f.render_widget(input_paragraph, chunks[2]);
+    f.render_widget(instructions_paragraph, chunks[3]);
+
+    let base_x = chunks[2].x.saturating_add(1);
+    let cursor_u16 = u16::try_from(cursor_position).unwrap_or(u16::MAX.saturating_sub(base_x));
+    f.set_cursor_position((base_x.saturating_add(cursor_u16), chunks[2].y.saturating_add(1)));
+}
@@ -1,13 +1,16 @@
 use super::common::{self, shortcuts};
-use crate::entities::project;
+use crate::entities::{label, project};
 use crate::icons::IconService;
+use crate::sync::tasks::TaskEdit;
 use crate::ui::layout::LayoutManager;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::Color,
-    widgets::Clear,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph},
     Frame,
 };
+use uuid::Uuid;
 
 #[allow(clippy::too_many_arguments)]
 pub fn render_task_dialog(
@@ -18,10 +21,11 @@ pub fn render_task_dialog(
     cursor_position: usize,
     task_projects: &[&project::Model],
     selected_project_index: Option<usize>,
+    preview: &[QuickAddPreviewField],
     is_editing: bool,
 ) {
     let title = if is_editing { "Edit Task" } else { "New Task" };
-    let dialog_area = LayoutManager::centered_rect_lines(65, 12, area);
+    let dialog_area = LayoutManager::centered_rect_lines(65, 12 + QUICK_ADD_PREVIEW_HEIGHT, area);
     f.render_widget(Clear, dialog_area);
 
     let main_block = common::create_dialog_block(title, Color::Cyan);
@@ -32,10 +36,11 @@ pub fn render_task_dialog(
         .direction(Direction::Vertical)
         .margin(1)
         .constraints([
-            Constraint::Length(4), // Task content input field (borders + content)
-            Constraint::Length(4), // Project selection field (borders + content)
-            Constraint::Length(1), // Spacer
-            Constraint::Length(1), // Instructions
+            Constraint::Length(4),                        // Task content input field (borders + content)
+            Constraint::Length(4),                        // Project selection field (borders + content)
+            Constraint::Length(QUICK_ADD_PREVIEW_HEIGHT), // Parsed quick-add fields
+            Constraint::Length(1),                        // Spacer
+            Constraint::Length(1),                        // Instructions
         ])
         .split(inner_area);
 
@@ -76,22 +81,68 @@ pub fn render_task_dialog(
     f.render_widget(main_block, dialog_area);
     f.render_widget(input_paragraph, chunks[0]);
     f.render_widget(project_paragraph, chunks[1]);
-    f.render_widget(instructions_paragraph, chunks[3]);
+    f.render_widget(quick_add_preview_paragraph(preview), chunks[2]);
+    f.render_widget(instructions_paragraph, chunks[4]);
 
     // Set terminal cursor position
     f.set_cursor_position((chunks[0].x + 1 + cursor_position as u16, chunks[0].y + 1));
 }
 
-pub fn render_due_date_input_dialog(
+pub fn render_due_date_input_dialog(f: &mut Frame, area: Rect, input_buffer: &str, cursor_position: usize) {
+    render_task_field_input_dialog(
+        f,
+        area,
+        "Set Due Date",
+        "Due Date",
+        " Set Date",
+        " Clear Date",
+        input_buffer,
+        cursor_position,
+    );
+}
+
+pub fn render_deadline_input_dialog(f: &mut Frame, area: Rect, input_buffer: &str, cursor_position: usize) {
+    render_task_field_input_dialog(
+        f,
+        area,
+        "Set Deadline",
+        "Deadline (2025-03-30, friday, march 30)",
+        " Set Deadline",
+        " Clear Deadline",
+        input_buffer,
+        cursor_position,
+    );
+}
+
+pub fn render_duration_input_dialog(f: &mut Frame, area: Rect, input_buffer: &str, cursor_position: usize) {
+    render_task_field_input_dialog(
+        f,
+        area,
+        "Set Duration",
+        "Duration (45m, 1h30, 2 hours, 1 day)",
+        " Set Duration",
+        " Clear Duration",
+        input_buffer,
+        cursor_position,
+    );
+}
+
+/// Single-line input for one field of a task, where an empty input clears the field
+#[allow(clippy::too_many_arguments)]
+fn render_task_field_input_dialog(
     f: &mut Frame,
     area: Rect,
+    title: &str,
+    field_name: &str,
+    set_label: &'static str,
+    clear_label: &'static str,
     input_buffer: &str,
     cursor_position: usize,
 ) {
     let dialog_area = LayoutManager::centered_rect_lines(65, 8, area);
     f.render_widget(Clear, dialog_area);
 
-    let main_block = common::create_dialog_block("Set Due Date", Color::Cyan);
+    let main_block = common::create_dialog_block(title, Color::Cyan);
 
     let inner_area = main_block.inner(dialog_area);
     let chunks = Layout::default()
@@ -103,12 +154,12 @@ pub fn render_due_date_input_dialog(
         ])
         .split(inner_area);
 
-    let input_paragraph = common::create_input_paragraph(input_buffer, cursor_position, "Due Date");
+    let input_paragraph = common::create_input_paragraph(input_buffer, cursor_position, field_name);
 
     let instructions = [
-        ("Enter", Color::Green, " Set Date"),
+        ("Enter", Color::Green, set_label),
         shortcuts::SEPARATOR,
-        ("Empty", Color::Yellow, " Clear Date"),
+        ("Empty", Color::Yellow, clear_label),
         shortcuts::SEPARATOR,
         shortcuts::ESC_CANCEL,
     ];
@@ -122,6 +173,7 @@ pub fn render_due_date_input_dialog(
 }
 
 // Legacy wrapper functions for backward compatibility
+#[allow(clippy::too_many_arguments)]
 pub fn render_task_creation_dialog(
     f: &mut Frame,
     area: Rect,
@@ -130,6 +182,7 @@ pub fn render_task_creation_dialog(
     cursor_position: usize,
     task_projects: &[&project::Model],
     selected_task_project_index: Option<usize>,
+    preview: &[QuickAddPreviewField],
 ) {
     render_task_dialog(
         f,
@@ -139,27 +192,414 @@ pub fn render_task_creation_dialog(
         cursor_position,
         task_projects,
         selected_task_project_index,
+        preview,
         false, // is_editing = false for creation
     );
 }
 
-pub fn render_task_edit_dialog(
+/// A field parsed from the quick-add syntax of the task creation input
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuickAddPreviewField {
+    pub name: &'static str,
+    /// Values of the field and whether each of them was found in the local data
+    pub values: Vec<(String, bool)>,
+}
+
+/// Lines of the quick-add preview, including its borders
+const QUICK_ADD_PREVIEW_HEIGHT: u16 = 9;
+
+/// Preview of the parsed quick-add fields, with names that were not found in red
+fn quick_add_preview_paragraph(preview: &[QuickAddPreviewField]) -> Paragraph<'static> {
+    let lines: Vec<Line> = if preview.is_empty() {
+        vec![Line::from(Span::styled(
+            "#project /section @label p1-p4, a due date and //description",
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        preview
+            .iter()
+            .map(|field| {
+                let mut spans = vec![Span::styled(format!("{:<12}", field.name), Style::default().fg(Color::Gray))];
+                for (index, (value, is_known)) in field.values.iter().enumerate() {
+                    if index > 0 {
+                        spans.push(Span::raw(", "));
+                    }
+                    if *is_known {
+                        spans.push(Span::styled(value.clone(), Style::default().fg(Color::White)));
+                    } else {
+                        spans.push(Span::styled(
+                            format!("{} (not found)", value),
+                            Style::default().fg(Color::Red),
+                        ));
+                    }
+                }
+                Line::from(spans)
+            })
+            .collect()
+    };
+
+    Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(" Preview ")
+            .title_style(Style::default().fg(Color::White))
+            .style(Style::default().fg(Color::Gray)),
+    )
+}
+
+/// Fields of the task editor, in tab order
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TaskEditorField {
+    #[default]
+    Content,
+    Description,
+    Location,
+    Labels,
+    Priority,
+    Due,
+    Deadline,
+    Duration,
+}
+
+impl TaskEditorField {
+    const ORDER: [Self; 8] = [
+        Self::Content,
+        Self::Description,
+        Self::Location,
+        Self::Labels,
+        Self::Priority,
+        Self::Due,
+        Self::Deadline,
+        Self::Duration,
+    ];
+
+    /// The field after this one, wrapping around to the first
+    #[must_use]
+    pub fn next(self) -> Self {
+        let index = Self::ORDER.iter().position(|field| *field == self).unwrap_or(0);
+        Self::ORDER[(index + 1) % Self::ORDER.len()]
+    }
+
+    /// The field before this one, wrapping around to the last
+    #[must_use]
+    pub fn previous(self) -> Self {
+        let index = Self::ORDER.iter().position(|field| *field == self).unwrap_or(0);
+        Self::ORDER[(index + Self::ORDER.len() - 1) % Self::ORDER.len()]
+    }
+
+    /// Whether the field is edited by typing, the others are pickers
+    #[must_use]
+    pub fn is_text(self) -> bool {
+        !matches!(self, Self::Location | Self::Labels | Self::Priority)
+    }
+}
+
+/// Everything the task editor dialog shows
+pub struct TaskEditorView<'a> {
+    pub values: &'a TaskEdit,
+    pub focused_field: TaskEditorField,
+    /// Cursor position in the focused text field, in characters
+    pub cursor_position: usize,
+    /// Display name of the selected project and section
+    pub location: String,
+    pub labels: &'a [label::Model],
+    pub selected_label_index: usize,
+}
+
+/// Visible text lines of the description field
+const DESCRIPTION_LINES: usize = 4;
+
+/// Field block whose border is highlighted while the field has focus
+fn field_block(field_title: &str, is_focused: bool) -> Block<'static> {
+    let border_color = if is_focused { Color::Yellow } else { Color::Gray };
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} ", field_title))
+        .title_style(Style::default().fg(Color::White))
+        .style(Style::default().fg(border_color))
+}
+
+fn text_field<'a>(text: &'a str, field_title: &str, is_focused: bool) -> Paragraph<'a> {
+    Paragraph::new(text)
+        .block(field_block(field_title, is_focused))
+        .style(Style::default().fg(Color::White))
+}
+
+/// Picker field showing arrows around its value while focused
+fn picker_field(value: Line<'static>, field_title: &str, is_focused: bool) -> Paragraph<'static> {
+    let mut spans = value.spans;
+    if is_focused {
+        spans.insert(0, Span::styled("◀ ", Style::default().fg(Color::Yellow)));
+        spans.push(Span::styled(" ▶", Style::default().fg(Color::Yellow)));
+    }
+    Paragraph::new(Line::from(spans))
+        .block(field_block(field_title, is_focused))
+        .style(Style::default().fg(Color::White))
+}
+
+fn priority_line(priority: i32) -> Line<'static> {
+    let color = match priority {
+        4 => Color::Red,
+        3 => Color::Rgb(255, 165, 0),
+        2 => Color::Blue,
+        _ => Color::White,
+    };
+    Line::from(Span::styled(
+        format!("P{}", 5 - priority.clamp(1, 4)),
+        Style::default().fg(color).add_modifier(Modifier::BOLD),
+    ))
+}
+
+/// Label checkboxes, starting late enough that the selected label fits into `width`
+fn labels_line(view: &TaskEditorView, is_focused: bool, width: usize) -> Line<'static> {
+    if view.labels.is_empty() {
+        return Line::from(Span::styled("No labels", Style::default().fg(Color::DarkGray)));
+    }
+    let entries: Vec<(String, bool)> = view
+        .labels
+        .iter()
+        .map(|label| {
+            let is_checked = view.values.label_uuids.contains(&label.uuid);
+            let marker = if is_checked { "[x]" } else { "[ ]" };
+            (format!("{} {}", marker, label.name), is_checked)
+        })
+        .collect();
+
+    let selected = view.selected_label_index.min(entries.len() - 1);
+    let mut start = selected;
+    let mut used = entries[selected].0.chars().count();
+    while start > 0 && used + 2 + entries[start - 1].0.chars().count() <= width {
+        start -= 1;
+        used += 2 + entries[start].0.chars().count();
+    }
+
+    let mut spans = Vec::new();
+    for (index, (text, is_checked)) in entries.into_iter().enumerate().skip(start) {
+        if index > start {
+            spans.push(Span::raw("  "));
+        }
+        let mut style = if is_checked {
+            Style::default().fg(Color::Green)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        if is_focused && index == selected {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+        spans.push(Span::styled(text, style));
+    }
+    Line::from(spans)
+}
+
+/// Line and column of a character position in multiline text
+fn text_position(text: &str, cursor_position: usize) -> (usize, usize) {
+    let before: String = text.chars().take(cursor_position).collect();
+    let row = before.matches('\n').count();
+    let column = before.rsplit('\n').next().map_or(0, |line| line.chars().count());
+    (row, column)
+}
+
+pub fn render_task_editor_dialog(f: &mut Frame, area: Rect, view: &TaskEditorView) {
+    let dialog_area = LayoutManager::centered_rect_lines(70, 27, area);
+    f.render_widget(Clear, dialog_area);
+
+    let main_block = common::create_dialog_block("Edit Task", Color::Cyan);
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3),                            // Content
+            Constraint::Length(DESCRIPTION_LINES as u16 + 2), // Description
+            Constraint::Length(3),                            // Project and section
+            Constraint::Length(3),                            // Labels
+            Constraint::Length(3),                            // Priority and due date
+            Constraint::Length(3),                            // Deadline and duration
+            Constraint::Length(1),                            // Spacer
+            Constraint::Length(1),                            // Instructions
+        ])
+        .split(inner_area);
+    let priority_due = Layout::horizontal([Constraint::Length(12), Constraint::Min(0)]).split(chunks[4]);
+    let deadline_duration =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).split(chunks[5]);
+
+    let values = view.values;
+    let focused = view.focused_field;
+    let (description_row, description_column) = text_position(&values.description, view.cursor_position);
+    let description_scroll = if focused == TaskEditorField::Description {
+        description_row.saturating_sub(DESCRIPTION_LINES - 1)
+    } else {
+        0
+    };
+    let description = text_field(
+        &values.description,
+        "Description",
+        focused == TaskEditorField::Description,
+    )
+    .scroll((description_scroll as u16, 0));
+
+    let location = picker_field(
+        Line::from(view.location.clone()),
+        "Project / Section",
+        focused == TaskEditorField::Location,
+    );
+    let labels_width = chunks[3].width.saturating_sub(2) as usize;
+    let labels = Paragraph::new(labels_line(view, focused == TaskEditorField::Labels, labels_width))
+        .block(field_block("Labels", focused == TaskEditorField::Labels));
+    let priority = picker_field(
+        priority_line(values.priority),
+        "Priority",
+        focused == TaskEditorField::Priority,
+    );
+
+    let instructions = [
+        ("Tab", Color::Cyan, " Next Field"),
+        shortcuts::SEPARATOR,
+        ("←→", Color::Cyan, " Change"),
+        shortcuts::SEPARATOR,
+        ("Space", Color::Cyan, " Toggle Label"),
+        shortcuts::SEPARATOR,
+        ("Ctrl+S", Color::Green, " Save"),
+        shortcuts::SEPARATOR,
+        shortcuts::ESC_CANCEL,
+    ];
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions);
+
+    f.render_widget(main_block, dialog_area);
+    f.render_widget(
+        text_field(&values.content, "Task Content", focused == TaskEditorField::Content),
+        chunks[0],
+    );
+    f.render_widget(description, chunks[1]);
+    f.render_widget(location, chunks[2]);
+    f.render_widget(labels, chunks[3]);
+    f.render_widget(priority, priority_due[0]);
+    f.render_widget(
+        text_field(
+            &values.due,
+            "Due (date or e.g. 'every monday')",
+            focused == TaskEditorField::Due,
+        ),
+        priority_due[1],
+    );
+    f.render_widget(
+        text_field(
+            &values.deadline,
+            "Deadline (YYYY-MM-DD)",
+            focused == TaskEditorField::Deadline,
+        ),
+        deadline_duration[0],
+    );
+    f.render_widget(
+        text_field(
+            &values.duration,
+            "Duration (e.g. 30 min)",
+            focused == TaskEditorField::Duration,
+        ),
+        deadline_duration[1],
+    );
+    f.render_widget(instructions_paragraph, chunks[7]);
+
+    // Set terminal cursor position in the focused text field
+    let cursor_field = match focused {
+        TaskEditorField::Content => Some((chunks[0], 0, view.cursor_position)),
+        TaskEditorField::Description => Some((chunks[1], description_row - description_scroll, description_column)),
+        TaskEditorField::Due => Some((priority_due[1], 0, view.cursor_position)),
+        TaskEditorField::Deadline => Some((deadline_duration[0], 0, view.cursor_position)),
+        TaskEditorField::Duration => Some((deadline_duration[1], 0, view.cursor_position)),
+        TaskEditorField::Location | TaskEditorField::Labels | TaskEditorField::Priority => None,
+    };
+    if let Some((field_area, row, column)) = cursor_field {
+        let max_column = field_area.width.saturating_sub(3) as usize;
+        f.set_cursor_position((
+            field_area.x + 1 + column.min(max_column) as u16,
+            field_area.y + 1 + row as u16,
+        ));
+    }
+}
+
+/// A project or project section a task can be moved to
+#[derive(Clone, Debug, PartialEq)]
+pub struct MoveTarget {
+    pub project_uuid: Uuid,
+    pub section_uuid: Option<Uuid>,
+    /// "Project" or "Project / Section"
+    pub name: String,
+    /// Whether the task is already there
+    pub is_current: bool,
+}
+
+/// Renders the move picker: a filter input above the matching projects and sections
+pub fn render_task_move_dialog(
     f: &mut Frame,
     area: Rect,
-    icons: &IconService,
     input_buffer: &str,
     cursor_position: usize,
-    task_projects: &[&project::Model],
-    selected_task_project_index: Option<usize>,
+    targets: &[MoveTarget],
+    selected_index: usize,
 ) {
-    render_task_dialog(
-        f,
-        area,
-        icons,
-        input_buffer,
-        cursor_position,
-        task_projects,
-        selected_task_project_index,
-        true, // is_editing = true for editing
+    // Room for the targets plus the filter, borders, margins, spacer and instructions
+    let list_height = u16::try_from(targets.len().max(1)).unwrap_or(u16::MAX).min(12);
+    let dialog_area = LayoutManager::centered_rect_lines(65, list_height.saturating_add(11), area);
+    f.render_widget(Clear, dialog_area);
+
+    let main_block = common::create_dialog_block("Move To", Color::Cyan);
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3), // Filter input field (borders + content)
+            Constraint::Min(3),    // Target list (borders + content)
+            Constraint::Length(1), // Spacer
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    f.render_widget(main_block, dialog_area);
+    f.render_widget(
+        common::create_input_paragraph(input_buffer, cursor_position, "Filter"),
+        chunks[0],
     );
+
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(Color::Gray));
+
+    if targets.is_empty() {
+        let empty = Paragraph::new("No matching project or section.")
+            .block(list_block)
+            .style(Style::default().fg(Color::Gray));
+        f.render_widget(empty, chunks[1]);
+    } else {
+        let items: Vec<ListItem> = targets
+            .iter()
+            .map(|target| {
+                let mut spans = vec![Span::styled(target.name.clone(), Style::default().fg(Color::White))];
+                if target.is_current {
+                    spans.push(Span::styled(" (current)", Style::default().fg(Color::Gray)));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(list_block)
+            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+        let mut list_state = ListState::default();
+        list_state.select(Some(selected_index.min(targets.len() - 1)));
+        f.render_stateful_widget(list, chunks[1], &mut list_state);
+    }
+
+    let instructions = [("Enter", Color::Green, " Move"), shortcuts::SEPARATOR, shortcuts::ESC_CANCEL];
+    f.render_widget(common::create_instructions_paragraph(&instructions), chunks[3]);
+
+    // Set terminal cursor position with safe u16 conversion and overflow protection
+    let base_x = chunks[0].x.saturating_add(1);
+    let cursor_u16 = u16::try_from(cursor_position).unwrap_or(u16::MAX.saturating_sub(base_x));
+    f.set_cursor_position((base_x.saturating_add(cursor_u16), chunks[0].y.saturating_add(1)));
 }
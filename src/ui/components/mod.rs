@@ -11,6 +11,8 @@
 //! - [`badge`] - Small status indicators and labels
 //!
 //! ## Interactive Components
+//! - [`board_component`] - Section columns of a project's board layout
+//! - [`calendar_component`] - Month grid of the Calendar view
 //! - [`dialog_component`] - Modal dialog framework
 //! - [`dialogs`] - Specific dialog implementations (create, edit, confirm)
 //! - [`sidebar_component`] - Navigation sidebar with project/view selection
@@ -40,6 +42,8 @@ pub mod badge;
 pub mod scrollbar_helper;
 
 // Core interactive components
+pub mod board_component;
+pub mod calendar_component;
 pub mod dialog_component;
 pub mod dialogs;
 pub mod sidebar_component;
@@ -48,6 +52,8 @@ pub mod task_list_component;
 pub mod task_list_item_component;
 
 // Public exports for external use
+pub use board_component::BoardComponent;
+pub use calendar_component::CalendarComponent;
 pub use dialog_component::DialogComponent;
 pub use sidebar_component::SidebarComponent;
 pub use task_list_component::TaskListComponent;
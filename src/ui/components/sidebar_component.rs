@@ -1,9 +1,11 @@
 //! Sidebar navigation component for the Terminalist application.
 //!
 //! This component provides the main navigation interface, allowing users to switch
-//! between different views (Today, Tomorrow, Upcoming) and browse projects and labels.
+//! between different views (Today, Tomorrow, Upcoming, Calendar, Schedule, Logbook) and saved filters, and browse
+//! projects and labels.
 //! It handles keyboard and mouse navigation with proper visual feedback.
 
+use crate::config::{DisplayConfig, FilterConfig};
 use crate::entities::{label, project};
 use crate::icons::IconService;
 use crate::ui::components::scrollbar_helper::ScrollbarHelper;
@@ -23,7 +25,8 @@ use uuid::Uuid;
 /// Navigation sidebar component for switching between views, projects, and labels.
 ///
 /// The sidebar provides a hierarchical navigation structure:
-/// - Special views (Today, Tomorrow, Upcoming)
+/// - Special views (Today, Tomorrow, Upcoming, Calendar, Schedule, Logbook)
+/// - Saved filters (from the config file)
 /// - Projects (user-created project list)
 /// - Labels (for filtering tasks by label)
 ///
@@ -37,7 +40,9 @@ pub struct SidebarComponent {
     pub selection: SidebarSelection,
     pub projects: Vec<project::Model>,
     pub labels: Vec<label::Model>,
+    pub filters: Vec<FilterConfig>,
     pub icons: IconService,
+    pub display_config: DisplayConfig,
     items: Vec<SidebarItemType>,
     folder_states: HashMap<String, bool>,
     list_state: ListState,
@@ -59,7 +64,9 @@ impl SidebarComponent {
             selection: SidebarSelection::Today,
             projects: Vec::new(),
             labels: Vec::new(),
+            filters: Vec::new(),
             icons: IconService::default(),
+            display_config: DisplayConfig::default(),
             items: Vec::new(),
             folder_states: HashMap::new(),
             list_state,
@@ -68,6 +75,15 @@ impl SidebarComponent {
         }
     }
 
+    pub fn update_display_config(&mut self, display_config: DisplayConfig) {
+        self.display_config = display_config;
+    }
+
+    /// Set the saved filters, which are listed on the next data update
+    pub fn update_filters(&mut self, filters: Vec<FilterConfig>) {
+        self.filters = filters;
+    }
+
     pub fn update_data(&mut self, projects: Vec<project::Model>, labels: Vec<label::Model>) {
         self.projects = projects;
         self.labels = labels;
@@ -95,6 +111,26 @@ impl SidebarComponent {
             name: "Upcoming".to_string(),
             selection: SidebarSelection::Upcoming,
         });
+        self.items.push(SidebarItemType::SpecialView {
+            name: "Calendar".to_string(),
+            selection: SidebarSelection::Calendar,
+        });
+        self.items.push(SidebarItemType::SpecialView {
+            name: "Schedule".to_string(),
+            selection: SidebarSelection::Schedule,
+        });
+        self.items.push(SidebarItemType::SpecialView {
+            name: "Logbook".to_string(),
+            selection: SidebarSelection::Logbook,
+        });
+
+        // Add saved filters
+        for (index, filter) in self.filters.iter().enumerate() {
+            self.items.push(SidebarItemType::Filter {
+                name: filter.name.clone(),
+                original_index: index,
+            });
+        }
 
         // Use placeholder account ID for now
         let account_id = "main".to_string();
@@ -234,18 +270,19 @@ impl SidebarComponent {
     fn get_sorted_projects(&self) -> Vec<(usize, &project::Model)> {
         let mut projects_with_indices: Vec<(usize, &project::Model)> = self.projects.iter().enumerate().collect();
 
-        // Sort projects hierarchically: root → parent → favorites → name
+        // Sort projects hierarchically: root → parent → manual order → name
         projects_with_indices.sort_by(|(_, a_project), (_, b_project)| {
             // First, sort by root project to keep tree structures together
             let a_root_project = self.get_root_project(a_project);
             let b_root_project = self.get_root_project(b_project);
 
-            // Sort root projects: Inbox first, then alphabetically by name
-            let root_cmp = match (a_root_project.is_inbox_project, b_root_project.is_inbox_project) {
-                (true, false) => std::cmp::Ordering::Less,          // Inbox first
-                (false, true) => std::cmp::Ordering::Greater,       // Inbox first
-                _ => a_root_project.name.cmp(&b_root_project.name), // Both inbox or both regular, sort by name
-            };
+            // Sort root projects: Inbox first, then by their manual order
+            let root_cmp = b_root_project
+                .is_inbox_project
+                .cmp(&a_root_project.is_inbox_project)
+                .then(a_root_project.order_index.cmp(&b_root_project.order_index))
+                .then_with(|| a_root_project.name.cmp(&b_root_project.name))
+                .then(a_root_project.uuid.cmp(&b_root_project.uuid));
 
             if root_cmp != std::cmp::Ordering::Equal {
                 return root_cmp;
@@ -259,12 +296,11 @@ impl SidebarComponent {
                 return parent_cmp;
             }
 
-            // Same immediate parent (siblings), sort favorites first, then by name
-            match (a_project.is_favorite, b_project.is_favorite) {
-                (true, false) => std::cmp::Ordering::Less, // a (favorite) comes before b (non-favorite)
-                (false, true) => std::cmp::Ordering::Greater, // a (non-favorite) comes after b (favorite)
-                _ => a_project.name.cmp(&b_project.name),  // Same favorite status, sort by name
-            }
+            // Same immediate parent (siblings), sort by manual order, then by name
+            a_project
+                .order_index
+                .cmp(&b_project.order_index)
+                .then_with(|| a_project.name.cmp(&b_project.name))
         });
         projects_with_indices
     }
@@ -387,6 +423,21 @@ impl Component for SidebarComponent {
         use crossterm::event::KeyModifiers;
 
         match key.code {
+            // Alt moves the selected project among its siblings instead of changing the selection
+            KeyCode::Char('J') | KeyCode::Char('K') if key.modifiers.contains(KeyModifiers::ALT) => {
+                let offset = if key.code == KeyCode::Char('K') { -1 } else { 1 };
+                match &self.selection {
+                    // The inbox always stays on top
+                    SidebarSelection::Project(index) => match self.projects.get(*index) {
+                        Some(project) if !project.is_inbox_project => Action::ReorderProject {
+                            project_uuid: project.uuid,
+                            offset,
+                        },
+                        _ => Action::None,
+                    },
+                    _ => Action::None,
+                }
+            }
             KeyCode::Char('H') => {
                 // H key: collapse/fold folder if cursor is on a folder
                 if let Some(current_index) = self.list_state.selected() {
@@ -484,7 +535,7 @@ impl Component for SidebarComponent {
         let all_items: Vec<ListItem> = self
             .items
             .iter()
-            .map(|item| item.render(&self.icons, &self.selection, false))
+            .map(|item| item.render(&self.icons, &self.selection, false, &self.display_config))
             .collect();
 
         // Calculate areas for list and scrollbar using helper
@@ -3,9 +3,11 @@
 //! This module provides a trait-based abstraction for sidebar items,
 //! enabling foldable account folders and hierarchical display of projects and labels.
 
+use crate::config::DisplayConfig;
 use crate::entities::{label, project};
 use crate::icons::IconService;
 use crate::ui::core::SidebarSelection;
+use crate::utils::color;
 use ratatui::{
     style::{Color, Modifier, Style},
     text::{Line, Span},
@@ -15,8 +17,10 @@ use ratatui::{
 /// Types of items that can appear in the sidebar
 #[derive(Clone, Debug)]
 pub enum SidebarItemType {
-    /// Special views (Today, Tomorrow, Upcoming)
+    /// Special views (Today, Tomorrow, Upcoming, Calendar, Schedule, Logbook)
     SpecialView { name: String, selection: SidebarSelection },
+    /// Saved filter from the config file
+    Filter { name: String, original_index: usize },
     /// Foldable account folder header
     AccountFolder {
         name: String,
@@ -51,6 +55,7 @@ pub trait SidebarItem {
         icons: &'a IconService,
         current_selection: &'a SidebarSelection,
         is_selected: bool,
+        display_config: &DisplayConfig,
     ) -> ListItem<'a>;
 
     /// Whether this item can be selected (navigated to)
@@ -66,12 +71,22 @@ pub trait SidebarItem {
     fn get_selection(&self) -> Option<SidebarSelection>;
 }
 
+/// Text color of an unselected project or label row
+fn item_color(color_name: Option<&str>, display_config: &DisplayConfig) -> Color {
+    if display_config.show_project_colors {
+        color::backend_color(color_name).unwrap_or(Color::White)
+    } else {
+        Color::White
+    }
+}
+
 impl SidebarItem for SidebarItemType {
     fn render<'a>(
         &'a self,
         icons: &'a IconService,
         current_selection: &'a SidebarSelection,
         _is_selected: bool,
+        display_config: &DisplayConfig,
     ) -> ListItem<'a> {
         match self {
             SidebarItemType::SpecialView { name, selection } => {
@@ -86,6 +101,9 @@ impl SidebarItem for SidebarItemType {
                     SidebarSelection::Today => icons.today(),
                     SidebarSelection::Tomorrow => icons.tomorrow(),
                     SidebarSelection::Upcoming => icons.upcoming(),
+                    SidebarSelection::Calendar => icons.calendar(),
+                    SidebarSelection::Schedule => icons.schedule(),
+                    SidebarSelection::Logbook => icons.logbook(),
                     _ => "",
                 };
 
@@ -95,6 +113,23 @@ impl SidebarItem for SidebarItemType {
                 ]))
             }
 
+            SidebarItemType::Filter { name, original_index } => {
+                let is_selected = matches!(
+                    current_selection,
+                    SidebarSelection::Filter(idx) if idx == original_index
+                );
+                let style = if is_selected {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+
+                ListItem::new(Line::from(vec![
+                    Span::styled(icons.filter().to_string(), style),
+                    Span::styled(name.clone(), style),
+                ]))
+            }
+
             SidebarItemType::AccountFolder { name, is_expanded, .. } => {
                 let style = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
                 let arrow = if *is_expanded { "▼" } else { "▶" };
@@ -123,7 +158,7 @@ impl SidebarItem for SidebarItemType {
                 let style = if is_selected {
                     Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
                 } else {
-                    Style::default().fg(Color::White)
+                    Style::default().fg(item_color(project.color.as_deref(), display_config))
                 };
 
                 let tree_prefix = if *depth > 0 {
@@ -169,7 +204,7 @@ impl SidebarItem for SidebarItemType {
                 let style = if is_selected {
                     Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
                 } else {
-                    Style::default().fg(Color::White)
+                    Style::default().fg(item_color(label.color.as_deref(), display_config))
                 };
 
                 ListItem::new(Line::from(vec![
@@ -188,6 +223,7 @@ impl SidebarItem for SidebarItemType {
     fn is_selectable(&self) -> bool {
         match self {
             SidebarItemType::SpecialView { .. } => true,
+            SidebarItemType::Filter { .. } => true,
             SidebarItemType::AccountFolder { .. } => false, // Folders are not selectable, only foldable
             SidebarItemType::Project { .. } => true,
             SidebarItemType::Label { .. } => true,
@@ -198,6 +234,7 @@ impl SidebarItem for SidebarItemType {
     fn indent_level(&self) -> usize {
         match self {
             SidebarItemType::SpecialView { .. } => 0,
+            SidebarItemType::Filter { .. } => 0,
             SidebarItemType::AccountFolder { .. } => 0,
             SidebarItemType::Project { depth, .. } => *depth,
             SidebarItemType::Label { .. } => 0,
@@ -216,6 +253,7 @@ impl SidebarItem for SidebarItemType {
     fn get_selection(&self) -> Option<SidebarSelection> {
         match self {
             SidebarItemType::SpecialView { selection, .. } => Some(selection.clone()),
+            SidebarItemType::Filter { original_index, .. } => Some(SidebarSelection::Filter(*original_index)),
             SidebarItemType::AccountFolder { .. } => None,
             SidebarItemType::Project { original_index, .. } => Some(SidebarSelection::Project(*original_index)),
             SidebarItemType::Label { original_index, .. } => Some(SidebarSelection::Label(*original_index)),
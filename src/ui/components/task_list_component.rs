@@ -1,31 +1,40 @@
 //! Task list component for displaying and managing tasks in the UI.
 //!
 //! This component provides the main interface for viewing and interacting with tasks.
-//! It supports multiple view modes (Today, Tomorrow, Upcoming, Projects, Labels) and
+//! It supports multiple view modes (Today, Tomorrow, Upcoming, Calendar, Schedule, Logbook, Projects, Labels) and
 //! handles task selection, keyboard navigation, and user interactions.
 
 use crate::config::DisplayConfig;
-use crate::constants::{HEADER_OVERDUE, HEADER_TODAY, HEADER_TOMORROW};
+use crate::constants::{
+    HEADER_COMPLETED_TODAY, HEADER_COMPLETED_UNKNOWN, HEADER_COMPLETED_YESTERDAY, HEADER_NO_DATE, HEADER_NO_LABELS,
+    HEADER_NO_SECTION, HEADER_OVERDUE, HEADER_TODAY, HEADER_TOMORROW, HEADER_UNSCHEDULED, UI_REORDER_NEEDS_MANUAL_SORT,
+};
 use crate::entities::{label, project, section, task};
 use crate::icons::IconService;
+use crate::sync::tasks::TaskEdit;
 use crate::ui::components::scrollbar_helper::ScrollbarHelper;
 use crate::ui::components::task_list_item_component::{ListItem, TaskItem, TaskListItemType};
+use crate::ui::core::view_mode::{self, GroupMode, SortMode, ViewMode};
 use crate::ui::core::SidebarSelection;
 use crate::ui::core::{
     actions::{Action, DialogType},
     Component,
 };
-use crate::utils::datetime;
-use chrono::{Duration, Local};
-use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use crate::utils::{datetime, schedule};
+use chrono::{Datelike, Duration, Local, NaiveDate, Timelike};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
     widgets::{block::BorderType, Block, Borders, List, ListItem as RatatuiListItem, ListState},
     Frame,
 };
+use std::collections::HashMap;
 use uuid::Uuid;
 
+/// Sort key of a group when the tasks are grouped by a view mode
+type GroupKey = (usize, usize, String);
+
 /// Main task list component that displays tasks in various view modes.
 ///
 /// This component handles:
@@ -49,6 +58,12 @@ pub struct TaskListComponent {
     // Keep raw task data for building items
     pub tasks: Vec<task::Model>,
     pub display_config: DisplayConfig,
+    /// Sort and grouping mode of the current view
+    pub view_mode: ViewMode,
+    /// Label UUIDs of every task, used when grouping by label
+    pub task_labels: HashMap<Uuid, Vec<Uuid>>,
+    /// Day whose agenda the Calendar view lists
+    pub agenda_day: NaiveDate,
     scrollbar_helper: ScrollbarHelper,
 }
 
@@ -71,6 +86,9 @@ impl TaskListComponent {
             labels: Vec::new(),
             icons: IconService::default(),
             display_config: DisplayConfig::default(),
+            view_mode: ViewMode::default(),
+            task_labels: HashMap::new(),
+            agenda_day: Local::now().date_naive(),
             scrollbar_helper: ScrollbarHelper::new(),
         }
     }
@@ -79,6 +97,20 @@ impl TaskListComponent {
         self.display_config = display_config;
     }
 
+    /// Set the sort and grouping mode, applied by the next [`Self::update_data`]
+    pub fn update_view_mode(&mut self, view_mode: ViewMode) {
+        self.view_mode = view_mode;
+    }
+
+    /// Set the day the Calendar view lists, applied by the next [`Self::update_data`]
+    pub fn update_agenda_day(&mut self, agenda_day: NaiveDate) {
+        self.agenda_day = agenda_day;
+    }
+
+    pub fn update_task_labels(&mut self, task_labels: HashMap<Uuid, Vec<Uuid>>) {
+        self.task_labels = task_labels;
+    }
+
     pub fn update_data(
         &mut self,
         tasks: Vec<task::Model>,
@@ -87,14 +119,27 @@ impl TaskListComponent {
         labels: Vec<label::Model>,
         sidebar_selection: SidebarSelection,
     ) {
+        let selected_task = self.get_selected_task().map(|task| task.uuid);
+
         self.tasks = tasks;
         self.sections = sections;
         self.projects = projects;
         self.labels = labels;
         self.sidebar_selection = sidebar_selection;
+        view_mode::sort_tasks(&mut self.tasks, self.view_mode.sort);
 
         // Build the flat list of items from the hierarchical task data
         self.build_item_list();
+
+        // Keep the cursor on the selected task when it moved, e.g. after a reorder
+        let selected_position = self
+            .items
+            .iter()
+            .filter(|item| item.is_selectable())
+            .position(|item| matches!(item, TaskListItemType::Task(item) if Some(item.task.uuid) == selected_task));
+        if let Some(position) = selected_position {
+            self.selected_index = position;
+        }
         self.update_list_state();
     }
 
@@ -102,7 +147,14 @@ impl TaskListComponent {
     fn build_item_list(&mut self) {
         self.items.clear();
 
-        if self.tasks.is_empty() {
+        // Project views still list their sections when no tasks are loaded
+        if self.tasks.is_empty() && !matches!(self.sidebar_selection, SidebarSelection::Project(_)) {
+            return;
+        }
+
+        // A chosen grouping replaces the view's own sections
+        if self.view_mode.group != GroupMode::View {
+            self.build_grouped_items();
             return;
         }
 
@@ -111,6 +163,10 @@ impl TaskListComponent {
             SidebarSelection::Today => self.build_today_items(),
             SidebarSelection::Tomorrow => self.build_tomorrow_items(),
             SidebarSelection::Upcoming => self.build_upcoming_items(),
+            SidebarSelection::Calendar => self.build_agenda_items(),
+            SidebarSelection::Schedule => self.build_schedule_items(),
+            SidebarSelection::Logbook => self.build_logbook_items(),
+            SidebarSelection::Filter(_) => self.build_filter_items(),
             SidebarSelection::Project(index) => {
                 if let Some(project) = self.projects.get(*index) {
                     let project_id = project.uuid;
@@ -255,18 +311,10 @@ impl TaskListComponent {
                 self.items.push(TaskListItemType::Separator(SeparatorItem::new(0)));
             }
 
-            // Format the date header
-            let date_header = if due_date == today {
-                HEADER_TODAY.to_string()
-            } else if due_date == today + chrono::Duration::days(1) {
-                HEADER_TOMORROW.to_string()
-            } else {
-                let weekday = due_date.format("%A").to_string();
-                let formatted_date = due_date.format("%b %d").to_string();
-                format!("📊 {} - {}", weekday, formatted_date)
-            };
-
-            self.items.push(TaskListItemType::Header(HeaderItem::new(date_header, 0)));
+            self.items.push(TaskListItemType::Header(HeaderItem::new(
+                date_header(due_date, today),
+                0,
+            )));
 
             for task in tasks {
                 self.add_task_and_children_to_items(task, 0);
@@ -274,6 +322,137 @@ impl TaskListComponent {
         }
     }
 
+    /// Build items for Calendar view (the tasks due on the selected day)
+    fn build_agenda_items(&mut self) {
+        use crate::ui::components::task_list_item_component::HeaderItem;
+
+        let agenda_day = datetime::format_ymd(self.agenda_day);
+        let day_tasks: Vec<task::Model> = self
+            .tasks
+            .iter()
+            .filter(|task| task.due_date.as_deref() == Some(agenda_day.as_str()))
+            .cloned()
+            .collect();
+        if day_tasks.is_empty() {
+            return;
+        }
+
+        let header = date_header(self.agenda_day, Local::now().date_naive());
+        self.items.push(TaskListItemType::Header(HeaderItem::new(header, 0)));
+
+        // Subtasks are listed under their parent when it is due the same day
+        for task in day_tasks.iter().filter(|task| {
+            task.parent_uuid.map_or(true, |parent_uuid| {
+                !day_tasks.iter().any(|other| other.uuid == parent_uuid)
+            })
+        }) {
+            self.add_task_and_children_to_items(task.clone(), 0);
+        }
+    }
+
+    /// Build items for Schedule view (today's timed tasks hour by hour, then the tasks without a time)
+    fn build_schedule_items(&mut self) {
+        use crate::ui::components::task_list_item_component::{HeaderItem, SeparatorItem};
+
+        let schedule = schedule::schedule_day(&self.tasks, Local::now().date_naive());
+
+        let overlap_count = schedule.overlap_count();
+        if overlap_count > 0 {
+            let warning = format!("⚠ {} tasks overlap", overlap_count);
+            self.items.push(TaskListItemType::Header(HeaderItem::new(warning, 0)));
+        }
+
+        // The timeline runs from the first start to the last end, including the empty hours between
+        let first_hour = schedule.blocks.first().map(|(_, block)| block.start.hour());
+        let last_hour = schedule
+            .blocks
+            .iter()
+            .map(|(_, block)| {
+                if block.end > block.start {
+                    (block.end - Duration::minutes(1)).hour()
+                } else {
+                    block.start.hour()
+                }
+            })
+            .max();
+        if let (Some(first_hour), Some(last_hour)) = (first_hour, last_hour) {
+            for hour in first_hour..=last_hour {
+                // Blocks that started earlier and still run are named next to the hour
+                let mut header = format!("{:02}:00", hour);
+                for (task, block) in &schedule.blocks {
+                    if block.start.hour() < hour && (block.end - Duration::minutes(1)).hour() >= hour {
+                        header.push_str(&format!(" ┊ {}", task.content));
+                    }
+                }
+                self.items.push(TaskListItemType::Header(HeaderItem::new(header, 0)));
+
+                for (task, block) in schedule.blocks.iter().filter(|(_, block)| block.start.hour() == hour) {
+                    let task_item = TaskItem::new(
+                        task.clone(),
+                        0,
+                        0,
+                        self.icons.clone(),
+                        self.projects.clone(),
+                        Vec::new(),
+                    )
+                    .with_time_block(*block);
+                    self.items.push(TaskListItemType::Task(Box::new(task_item)));
+                }
+            }
+        }
+
+        if !schedule.unscheduled.is_empty() {
+            if !self.items.is_empty() {
+                self.items.push(TaskListItemType::Separator(SeparatorItem::new(0)));
+            }
+            self.items.push(TaskListItemType::Header(HeaderItem::new(
+                HEADER_UNSCHEDULED.to_string(),
+                0,
+            )));
+            for task in schedule.unscheduled {
+                let task_item = TaskItem::new(task, 0, 0, self.icons.clone(), self.projects.clone(), Vec::new());
+                self.items.push(TaskListItemType::Task(Box::new(task_item)));
+            }
+        }
+    }
+
+    /// Build items for Logbook view (completed tasks grouped by completion day, newest first)
+    fn build_logbook_items(&mut self) {
+        use crate::ui::components::task_list_item_component::{HeaderItem, SeparatorItem};
+
+        let today = Local::now().date_naive();
+        let mut days: Vec<(Option<chrono::NaiveDate>, Vec<task::Model>)> = Vec::new();
+
+        // SQL already orders by completion time, so tasks of the same day are adjacent
+        for task in &self.tasks {
+            let day = task.completed_at.as_deref().and_then(datetime::local_date);
+            match days.last_mut() {
+                Some((last_day, tasks)) if *last_day == day => tasks.push(task.clone()),
+                _ => days.push((day, vec![task.clone()])),
+            }
+        }
+
+        for (day, tasks) in days {
+            if !self.items.is_empty() {
+                self.items.push(TaskListItemType::Separator(SeparatorItem::new(0)));
+            }
+
+            let header = match day {
+                Some(day) if day == today => HEADER_COMPLETED_TODAY.to_string(),
+                Some(day) if day == today - Duration::days(1) => HEADER_COMPLETED_YESTERDAY.to_string(),
+                Some(day) => format!("✅ {} - {}", day.format("%A"), day.format("%b %d")),
+                None => HEADER_COMPLETED_UNKNOWN.to_string(),
+            };
+            self.items.push(TaskListItemType::Header(HeaderItem::new(header, 0)));
+
+            // Completed subtasks are listed on their own, their parents may still be open
+            for task in tasks {
+                let task_item = TaskItem::new(task, 0, 0, self.icons.clone(), self.projects.clone(), Vec::new());
+                self.items.push(TaskListItemType::Task(Box::new(task_item)));
+            }
+        }
+    }
+
     /// Build items for Project view (with section headers)
     fn build_project_items(&mut self, project_id: &Uuid) {
         use crate::ui::components::task_list_item_component::{HeaderItem, SeparatorItem};
@@ -302,21 +481,19 @@ impl TaskListComponent {
             }
         }
 
-        // Add sections with their tasks
+        // Add sections with their tasks; empty sections are listed too
         for section in project_sections {
-            if let Some(section_tasks) = tasks_by_section.get(&Some(section.uuid)) {
-                // Add separator before section
-                if !self.items.is_empty() {
-                    self.items.push(TaskListItemType::Separator(SeparatorItem::new(0)));
-                }
+            // Add separator before section
+            if !self.items.is_empty() {
+                self.items.push(TaskListItemType::Separator(SeparatorItem::new(0)));
+            }
 
-                // Add section header
-                self.items
-                    .push(TaskListItemType::Header(HeaderItem::new(section.name.clone(), 0)));
+            // Add section header
+            self.items
+                .push(TaskListItemType::Header(HeaderItem::new(section.name.clone(), 0)));
 
-                for task in section_tasks {
-                    self.add_task_and_children_to_items(task.clone(), 0);
-                }
+            for task in tasks_by_section.get(&Some(section.uuid)).into_iter().flatten() {
+                self.add_task_and_children_to_items(task.clone(), 0);
             }
         }
     }
@@ -336,6 +513,122 @@ impl TaskListComponent {
         }
     }
 
+    /// Build items for a saved filter view
+    fn build_filter_items(&mut self) {
+        for task in self.loaded_root_tasks() {
+            self.add_task_and_children_to_items(task, 0);
+        }
+    }
+
+    /// Build items grouped by the view mode's grouping instead of the view's own sections
+    fn build_grouped_items(&mut self) {
+        use crate::ui::components::task_list_item_component::{HeaderItem, SeparatorItem};
+        use std::collections::BTreeMap;
+
+        let today = Local::now().date_naive();
+
+        // Groups are ordered by their key; a task with several labels is listed under each of them
+        let mut groups: BTreeMap<GroupKey, (Option<String>, Vec<task::Model>)> = BTreeMap::new();
+        for task in self.loaded_root_tasks() {
+            for (key, header) in self.task_groups(&task, today) {
+                groups.entry(key).or_insert_with(|| (header, Vec::new())).1.push(task.clone());
+            }
+        }
+
+        for (header, tasks) in groups.into_values() {
+            if !self.items.is_empty() {
+                self.items.push(TaskListItemType::Separator(SeparatorItem::new(0)));
+            }
+            if let Some(header) = header {
+                self.items.push(TaskListItemType::Header(HeaderItem::new(header, 0)));
+            }
+            for task in tasks {
+                self.add_task_and_children_to_items(task, 0);
+            }
+        }
+    }
+
+    /// The groups a task belongs to, as sort keys with their headers
+    fn task_groups(&self, task: &task::Model, today: NaiveDate) -> Vec<(GroupKey, Option<String>)> {
+        let project_index = self.projects.iter().position(|project| project.uuid == task.project_uuid);
+        let project_name = project_index.map(|index| self.projects[index].name.clone());
+
+        match self.view_mode.group {
+            GroupMode::View | GroupMode::None => vec![((0, 0, String::new()), None)],
+            GroupMode::Project => vec![(
+                (project_index.unwrap_or(usize::MAX), 0, task.project_uuid.to_string()),
+                project_name,
+            )],
+            GroupMode::Section => {
+                let section_index = task
+                    .section_uuid
+                    .and_then(|uuid| self.sections.iter().position(|section| section.uuid == uuid));
+                let section_name = section_index.map(|index| self.sections[index].name.clone());
+                // Within a project the section name is enough, elsewhere it is prefixed with the project
+                let header = if matches!(self.sidebar_selection, SidebarSelection::Project(_)) {
+                    section_name.unwrap_or_else(|| HEADER_NO_SECTION.to_string())
+                } else {
+                    match (project_name, section_name) {
+                        (Some(project), Some(section)) => format!("{} / {}", project, section),
+                        (Some(project), None) => project,
+                        (None, section) => section.unwrap_or_else(|| HEADER_NO_SECTION.to_string()),
+                    }
+                };
+                let key = (
+                    project_index.unwrap_or(usize::MAX),
+                    section_index.map_or(0, |index| index + 1),
+                    task.section_uuid.map(|uuid| uuid.to_string()).unwrap_or_default(),
+                );
+                vec![(key, Some(header))]
+            }
+            GroupMode::Priority => {
+                // Backend priority 4 is shown as p1
+                let level = (5 - task.priority).clamp(1, 4) as usize;
+                vec![((level, 0, String::new()), Some(format!("🚩 Priority {}", level)))]
+            }
+            GroupMode::DueDay => match task.due_date.as_deref().and_then(|due| datetime::parse_date(due).ok()) {
+                Some(due_date) => vec![(
+                    (0, due_date.num_days_from_ce().max(0) as usize, String::new()),
+                    Some(date_header(due_date, today)),
+                )],
+                None => vec![((1, 0, String::new()), Some(HEADER_NO_DATE.to_string()))],
+            },
+            GroupMode::Label => {
+                let mut groups: Vec<_> = self
+                    .task_labels
+                    .get(&task.uuid)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|label_uuid| self.labels.iter().position(|label| &label.uuid == label_uuid))
+                    .map(|index| {
+                        let label = &self.labels[index];
+                        ((0, index, label.uuid.to_string()), Some(format!("🏷️ {}", label.name)))
+                    })
+                    .collect();
+                if groups.is_empty() {
+                    groups.push(((1, 0, String::new()), Some(HEADER_NO_LABELS.to_string())));
+                }
+                groups
+            }
+        }
+    }
+
+    /// Loaded tasks that are listed at the top level.
+    ///
+    /// Subtasks whose parent is not part of the view, such as a matching subtask of a
+    /// filter whose parent does not match, are listed at the top level too.
+    fn loaded_root_tasks(&self) -> Vec<task::Model> {
+        self.tasks
+            .iter()
+            .filter(|task| {
+                task.parent_uuid.map_or(true, |parent_uuid| {
+                    !self.tasks.iter().any(|other| other.uuid == parent_uuid)
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Build simple items (no sectioning)
     fn build_simple_items(&mut self) {
         // SQL already provides proper ordering (completion status -> priority -> order_index)
@@ -367,6 +660,11 @@ impl TaskListComponent {
         );
         self.items.push(TaskListItemType::Task(Box::new(task_item)));
 
+        // The subtasks of a collapsed task stay hidden
+        if task.is_collapsed {
+            return;
+        }
+
         // Find and add children
         let task_id = task.uuid;
         let children: Vec<task::Model> = self
@@ -452,6 +750,68 @@ impl TaskListComponent {
         None
     }
 
+    /// Select a task, e.g. the card selected on a project's board
+    pub fn select_task(&mut self, task_uuid: Uuid) {
+        let position = self
+            .items
+            .iter()
+            .filter(|item| item.is_selectable())
+            .position(|item| matches!(item, TaskListItemType::Task(item) if item.task.uuid == task_uuid));
+        if let Some(position) = position {
+            self.selected_index = position;
+            self.update_list_state();
+        }
+    }
+
+    /// The task listed above the selected one at the same level, under the same parent.
+    ///
+    /// Indenting makes the selected task a subtask of it.
+    fn previous_sibling(&self) -> Option<&task::Model> {
+        let physical_index = self.logical_to_physical_index(self.selected_index)?;
+        let TaskListItemType::Task(selected) = self.items.get(physical_index)? else {
+            return None;
+        };
+        for item in self.items[..physical_index].iter().rev() {
+            // Headers and separators start another group, the task above is not a sibling
+            let TaskListItemType::Task(item) = item else {
+                return None;
+            };
+            // Skip over the subtasks of the sibling
+            if item.depth > selected.depth {
+                continue;
+            }
+            let is_sibling = item.depth == selected.depth
+                && item.task.parent_uuid == selected.task.parent_uuid
+                && item.task.project_uuid == selected.task.project_uuid
+                && item.task.section_uuid == selected.task.section_uuid;
+            return is_sibling.then_some(&item.task);
+        }
+        None
+    }
+
+    /// The task listed below the selected one at the same level, under the same parent.
+    fn next_sibling(&self) -> Option<&task::Model> {
+        let physical_index = self.logical_to_physical_index(self.selected_index)?;
+        let TaskListItemType::Task(selected) = self.items.get(physical_index)? else {
+            return None;
+        };
+        for item in &self.items[physical_index + 1..] {
+            let TaskListItemType::Task(item) = item else {
+                return None;
+            };
+            // Skip over the subtasks of the selected task
+            if item.depth > selected.depth {
+                continue;
+            }
+            let is_sibling = item.depth == selected.depth
+                && item.task.parent_uuid == selected.task.parent_uuid
+                && item.task.project_uuid == selected.task.project_uuid
+                && item.task.section_uuid == selected.task.section_uuid;
+            return is_sibling.then_some(&item.task);
+        }
+        None
+    }
+
     /// Handle mouse events
     pub fn handle_mouse(&mut self, mouse: MouseEvent, area: Rect) -> Action {
         // Check if mouse is within the task list area
@@ -538,6 +898,30 @@ impl TaskListComponent {
 impl Component for TaskListComponent {
     fn handle_key_events(&mut self, key: KeyEvent) -> Action {
         match key.code {
+            // Alt moves the selected task among its siblings instead of moving the cursor
+            KeyCode::Up | KeyCode::Char('k') | KeyCode::Down | KeyCode::Char('j')
+                if key.modifiers.contains(KeyModifiers::ALT) && self.view_mode.sort != SortMode::Manual =>
+            {
+                Action::ShowDialog(DialogType::Info(UI_REORDER_NEEDS_MANUAL_SORT.to_string()))
+            }
+            KeyCode::Up | KeyCode::Char('k') if key.modifiers.contains(KeyModifiers::ALT) => {
+                match (self.get_selected_task(), self.previous_sibling()) {
+                    (Some(task), Some(_)) if !task.is_completed && !task.is_deleted => Action::ReorderTask {
+                        task_uuid: task.uuid,
+                        offset: -1,
+                    },
+                    _ => Action::None,
+                }
+            }
+            KeyCode::Down | KeyCode::Char('j') if key.modifiers.contains(KeyModifiers::ALT) => {
+                match (self.get_selected_task(), self.next_sibling()) {
+                    (Some(task), Some(_)) if !task.is_completed && !task.is_deleted => Action::ReorderTask {
+                        task_uuid: task.uuid,
+                        offset: 1,
+                    },
+                    _ => Action::None,
+                }
+            }
             KeyCode::Up | KeyCode::Char('k') => {
                 self.previous_task();
                 Action::None
@@ -564,14 +948,75 @@ impl Component for TaskListComponent {
                     SidebarSelection::Project(index) => self.projects.get(*index).map(|p| p.uuid),
                     _ => None,
                 };
-                Action::ShowDialog(DialogType::TaskCreation { default_project_uuid })
+                Action::ShowDialog(DialogType::TaskCreation {
+                    default_project_uuid,
+                    parent_uuid: None,
+                })
             }
+            KeyCode::Char('o') => {
+                if let Some(task) = self.get_selected_task() {
+                    Action::ShowDialog(DialogType::TaskCreation {
+                        default_project_uuid: Some(task.project_uuid),
+                        parent_uuid: Some(task.uuid),
+                    })
+                } else {
+                    Action::None
+                }
+            }
+            KeyCode::Tab | KeyCode::Char('>') => match (self.get_selected_task(), self.previous_sibling()) {
+                (Some(task), Some(sibling)) => Action::MoveTask {
+                    task_uuid: task.uuid,
+                    project_uuid: sibling.project_uuid,
+                    section_uuid: sibling.section_uuid,
+                    parent_uuid: Some(sibling.uuid),
+                },
+                _ => Action::None,
+            },
+            KeyCode::BackTab | KeyCode::Char('<') => {
+                let parent = self
+                    .get_selected_task()
+                    .and_then(|task| task.parent_uuid)
+                    .and_then(|parent_uuid| self.tasks.iter().find(|t| t.uuid == parent_uuid));
+                match (self.get_selected_task(), parent) {
+                    (Some(task), Some(parent)) => Action::MoveTask {
+                        task_uuid: task.uuid,
+                        project_uuid: parent.project_uuid,
+                        section_uuid: parent.section_uuid,
+                        parent_uuid: parent.parent_uuid,
+                    },
+                    _ => Action::None,
+                }
+            }
+            KeyCode::Char('z') => match self.get_selected_task() {
+                Some(task) if self.get_child_task_count(&task.uuid) > 0 => Action::SetTaskCollapsed {
+                    task_uuid: task.uuid,
+                    is_collapsed: !task.is_collapsed,
+                },
+                _ => Action::None,
+            },
             KeyCode::Char('e') => {
                 if let Some(task) = self.get_selected_task() {
                     Action::ShowDialog(DialogType::TaskEdit {
                         task_uuid: task.uuid,
-                        content: task.content.clone(),
+                        values: TaskEdit::from_task(task, Vec::new()),
+                    })
+                } else {
+                    Action::None
+                }
+            }
+            KeyCode::Char('l') => {
+                if let Some(task) = self.get_selected_task() {
+                    Action::ShowDialog(DialogType::LabelPicker { task_uuid: task.uuid })
+                } else {
+                    Action::None
+                }
+            }
+            KeyCode::Char('m') => {
+                if let Some(task) = self.get_selected_task() {
+                    Action::ShowDialog(DialogType::TaskMove {
+                        task_uuid: task.uuid,
                         project_uuid: task.project_uuid,
+                        section_uuid: task.section_uuid,
                     })
                 } else {
                     Action::None
@@ -599,6 +1044,23 @@ impl Component for TaskListComponent {
                     Action::None
                 }
             }
+            // Ctrl+C quits, so only a plain 'c' opens the comments
+            KeyCode::Char('c') if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Some(task) = self.get_selected_task() {
+                    Action::ShowDialog(DialogType::CommentThread { task_uuid: task.uuid })
+                } else {
+                    Action::None
+                }
+            }
+            KeyCode::Char('S') => match &self.sidebar_selection {
+                SidebarSelection::Project(index) => match self.projects.get(*index) {
+                    Some(project) => Action::ShowDialog(DialogType::SectionManagement {
+                        project_uuid: project.uuid,
+                    }),
+                    None => Action::None,
+                },
+                _ => Action::None,
+            },
             _ => Action::None,
         }
     }
@@ -624,11 +1086,24 @@ impl Component for TaskListComponent {
         // Calculate areas for list and scrollbar using helper
         let (list_area, scrollbar_area) = ScrollbarHelper::calculate_areas(rect, total_items);
 
+        let title = match self.view_mode.describe() {
+            Some(mode) => format!("Tasks · {}", mode),
+            None => "Tasks".to_string(),
+        };
+
         let tasks_list = if self.items.is_empty() {
             // Show contextual empty state message
             let empty_message = match &self.sidebar_selection {
                 SidebarSelection::Today => "No tasks due today. Press 'a' to create a task or 'r' to sync.",
+                SidebarSelection::Schedule => {
+                    "No tasks due today. Set a time with 's' (today 9am) and a duration with 'u'."
+                }
                 SidebarSelection::Tomorrow => "No tasks due tomorrow. Press 'a' to create a task or 'r' to sync.",
+                SidebarSelection::Logbook => "No tasks completed in the last 30 days.",
+                SidebarSelection::Calendar => {
+                    "Nothing due on this day. Move with ←/→, [/] and PgUp/PgDn, press 'g' to open a day."
+                }
+                SidebarSelection::Filter(_) => "No tasks match this filter.",
                 _ if self.projects.is_empty() => "No projects available. Press 'r' to sync or 'A' to create a project.",
                 _ => "No tasks in this view. Press 'a' to create a task.",
             };
@@ -642,7 +1117,7 @@ impl Component for TaskListComponent {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .title("Tasks")
+                .title(title)
                 .title_style(Style::default().fg(Color::White))
                 .border_style(Style::default().fg(Color::DarkGray)),
         );
@@ -659,3 +1134,14 @@ impl Component for TaskListComponent {
         self.scrollbar_helper.render(f, scrollbar_area);
     }
 }
+
+/// Header of a due day, shared by the Upcoming view and the grouping by due day
+fn date_header(due_date: NaiveDate, today: NaiveDate) -> String {
+    if due_date == today {
+        HEADER_TODAY.to_string()
+    } else if due_date == today + Duration::days(1) {
+        HEADER_TOMORROW.to_string()
+    } else {
+        format!("📊 {} - {}", due_date.format("%A"), due_date.format("%b %d"))
+    }
+}
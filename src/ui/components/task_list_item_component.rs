@@ -2,7 +2,9 @@ use crate::config::DisplayConfig;
 use crate::entities::{project, task};
 use crate::icons::IconService;
 use crate::ui::components::badge::{create_priority_badge, create_task_badges};
+use crate::utils::color;
 use crate::utils::datetime::{format_human_date, format_human_datetime};
+use crate::utils::schedule::TimeBlock;
 use ratatui::{
     style::{Color, Modifier, Style},
     text::{Line, Span},
@@ -67,6 +69,8 @@ pub struct TaskItem {
     pub icons: IconService,
     pub projects: Vec<project::Model>,
     pub labels: Vec<crate::entities::label::Model>,
+    /// Planned time on the timeline of the Schedule view
+    pub time_block: Option<TimeBlock>,
 }
 
 impl TaskItem {
@@ -85,9 +89,17 @@ impl TaskItem {
             icons,
             projects,
             labels,
+            time_block: None,
         }
     }
 
+    /// Show the task with its planned time, flagging overlaps
+    #[must_use]
+    pub fn with_time_block(mut self, time_block: TimeBlock) -> Self {
+        self.time_block = Some(time_block);
+        self
+    }
+
     fn format_due_date(&self, due_date: &str) -> String {
         // Use human-readable date formatting similar to Todoist
         format_human_date(due_date)
@@ -141,6 +153,17 @@ impl ListItem for TaskItem {
         };
         line_spans.push(Span::styled(format!("{} ", status_icon), status_style));
 
+        // Planned time range in the Schedule view, red when it collides with another task
+        if let Some(block) = &self.time_block {
+            let range = if block.end > block.start {
+                format!("{}-{} ", block.start.format("%H:%M"), block.end.format("%H:%M"))
+            } else {
+                format!("{:<12}", block.start.format("%H:%M").to_string())
+            };
+            let range_color = if block.overlaps { Color::Red } else { Color::Magenta };
+            line_spans.push(Span::styled(range, Style::default().fg(range_color)));
+        }
+
         // Priority badge (if any)
         if let Some(priority_badge) = create_priority_badge(self.task.priority) {
             line_spans.push(priority_badge);
@@ -162,10 +185,17 @@ impl ListItem for TaskItem {
             Style::default().fg(Color::White)
         };
         line_spans.push(Span::styled(self.task.content.clone(), content_style));
+        if self.time_block.is_some_and(|block| block.overlaps) {
+            line_spans.push(Span::styled(" ⚠ overlap", Style::default().fg(Color::Red)));
+        }
 
-        // Child task count (for tasks with children)
+        // Child task count (for tasks with children), marked when the subtasks are hidden
         if self.child_count > 0 {
-            let progress_text = format!(" ({})", self.child_count);
+            let progress_text = if self.task.is_collapsed {
+                format!(" ({}) ▸", self.child_count)
+            } else {
+                format!(" ({})", self.child_count)
+            };
             let progress_style = Style::default().fg(Color::Gray);
             line_spans.push(Span::styled(progress_text, progress_style));
         }
@@ -173,12 +203,13 @@ impl ListItem for TaskItem {
         // Project display (with optional colors)
         if let Some(project) = self.projects.iter().find(|p| p.uuid == self.task.project_uuid) {
             line_spans.push(Span::raw(" "));
-            let project_style = if display_config.show_project_colors {
+            let project_color = if display_config.show_project_colors {
                 // Use project color if available, otherwise cyan
-                Style::default().fg(Color::Cyan)
+                color::backend_color(project.color.as_deref())
             } else {
-                Style::default().fg(Color::Cyan)
+                None
             };
+            let project_style = Style::default().fg(project_color.unwrap_or(Color::Cyan));
             line_spans.push(Span::styled(format!("#{}", project.name), project_style));
         }
 
@@ -197,6 +228,14 @@ impl ListItem for TaskItem {
                 formatted_date,
                 Style::default().fg(Color::Rgb(255, 165, 0)), // Orange color
             ));
+
+            // Recurrence pattern, e.g. "every monday"
+            if let Some(pattern) = self.task.due_string.as_ref().filter(|_| self.task.is_recurring) {
+                line_spans.push(Span::styled(
+                    format!(" ({})", pattern),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
         }
 
         // Metadata badges (only if configured to show)
@@ -210,6 +249,7 @@ impl ListItem for TaskItem {
                     None
                 },
                 if display_config.show_labels { &self.labels } else { &[] },
+                display_config.show_project_colors,
             );
 
             for badge in metadata_badges {
@@ -1,4 +1,8 @@
+use crate::sync::conflicts::ConflictResolution;
+use crate::sync::tasks::{NewTask, TaskEdit};
 use crate::sync::SyncStatus;
+use crate::ui::core::view_mode::ViewMode;
+use chrono::NaiveDate;
 use uuid::Uuid;
 
 /// Represents the currently selected item in the sidebar
@@ -8,6 +12,10 @@ pub enum SidebarSelection {
     Today, // Today view (special view)
     Tomorrow,       // Tomorrow view (special view)
     Upcoming,       // Upcoming view (tasks with future due dates)
+    Calendar,       // Calendar view (month grid with the agenda of the selected day)
+    Schedule,       // Schedule view (today's tasks on an hourly timeline)
+    Logbook,        // Logbook view (completed tasks)
+    Filter(usize),  // Index into saved filters
     Label(usize),   // Index into labels vector
     Project(usize), // Index into projects vector
 }
@@ -28,13 +36,32 @@ pub enum Action {
     SetTaskDueNextWeek(Uuid),
     SetTaskDueWeekEnd(Uuid),
     SetTaskDueString(Uuid, String),
-    CreateTask {
-        content: String,
-        project_uuid: Option<Uuid>,
-    },
+    SetTaskDueDate(Uuid, NaiveDate), // Reschedule to the day highlighted in the calendar
+    SetTaskDeadline(Uuid, String),   // Empty clears the deadline
+    SetTaskDuration(Uuid, String),   // Empty clears the duration
+    CreateTask(NewTask),
     EditTask {
         task_uuid: Uuid,
-        content: String,
+        edit: TaskEdit,
+    },
+    SetTaskLabels {
+        task_uuid: Uuid,
+        label_uuids: Vec<Uuid>,
+        new_label_names: Vec<String>, // Labels to create before assigning them
+    },
+    MoveTask {
+        task_uuid: Uuid,
+        project_uuid: Uuid,
+        section_uuid: Option<Uuid>,
+        parent_uuid: Option<Uuid>, // Decides the project and section when set
+    },
+    SetTaskCollapsed {
+        task_uuid: Uuid,
+        is_collapsed: bool,
+    },
+    ReorderTask {
+        task_uuid: Uuid,
+        offset: i32, // Negative moves up, positive moves down
     },
     RestoreTask(String),
 
@@ -48,6 +75,10 @@ pub enum Action {
         name: String,
     },
     DeleteProject(Uuid),
+    ReorderProject {
+        project_uuid: Uuid,
+        offset: i32, // Negative moves up, positive moves down
+    },
 
     // Label operations
     CreateLabel {
@@ -59,28 +90,83 @@ pub enum Action {
     },
     DeleteLabel(Uuid),
 
+    // Section operations
+    CreateSection {
+        project_uuid: Uuid,
+        name: String,
+    },
+    EditSection {
+        section_uuid: Uuid,
+        name: String,
+    },
+    DeleteSection(Uuid),
+    MoveSection {
+        section_uuid: Uuid,
+        offset: i32, // Negative moves up, positive moves down
+    },
+
+    // Comment operations
+    CreateComment {
+        task_uuid: Uuid,
+        content: String,
+    },
+
+    // Calendar navigation
+    CalendarMoveCursor(i64), // Days to move the highlighted day by
+    CalendarMoveMonth(i32),  // Months to move the highlighted day by
+    CalendarToday,
+    CalendarSelectDay, // Show the agenda of the highlighted day
+
+    // Board navigation
+    BoardMoveColumn(i32), // Columns to move the selection by
+    BoardMoveCard(i32),   // Cards to move the selection by within its column
+
+    // View modes
+    SetViewMode {
+        view_key: String,
+        mode: ViewMode,
+    },
+    ViewModesLoaded(Vec<crate::entities::view_setting::Model>), // Stored modes, loaded once at startup
+
     // Sync operations
     StartSync,
     RefreshLocalData, // Debug mode: refresh from local DB without API sync
     SyncCompleted(SyncStatus),
     SyncFailed(String),
+    UpdateApiToken(String),
     InitialDataLoaded {
         projects: Vec<crate::entities::project::Model>,
         labels: Vec<crate::entities::label::Model>,
         sections: Vec<crate::entities::section::Model>,
         tasks: Vec<crate::entities::task::Model>,
+        task_labels: Vec<crate::entities::task_label::Model>,
     },
     DataLoaded {
         projects: Vec<crate::entities::project::Model>,
         labels: Vec<crate::entities::label::Model>,
         sections: Vec<crate::entities::section::Model>,
         tasks: Vec<crate::entities::task::Model>,
+        task_labels: Vec<crate::entities::task_label::Model>,
+    },
+    PendingChangesUpdated(u64), // Number of local changes not yet pushed to the backend
+    ConflictsUpdated(Vec<crate::entities::sync_conflict::Model>),
+    ResolveConflict {
+        conflict_uuid: Uuid,
+        resolution: ConflictResolution,
     },
     SearchTasks(String), // Query for task search
     SearchResultsLoaded {
         query: String,
         results: Vec<crate::entities::task::Model>,
     },
+    CommentsLoaded {
+        task_uuid: Uuid,
+        comments: Vec<crate::entities::comment::Model>,
+    },
+    TaskLabelsLoaded {
+        task_uuid: Uuid,
+        label_uuids: Vec<Uuid>,
+    },
 
     // Data refresh after task operations
     RefreshData,
@@ -104,11 +190,19 @@ pub enum Action {
 pub enum DialogType {
     TaskCreation {
         default_project_uuid: Option<Uuid>,
+        parent_uuid: Option<Uuid>, // Set when adding a subtask, which goes where its parent is
     },
     TaskEdit {
         task_uuid: Uuid,
-        content: String,
-        project_uuid: Uuid,
+        values: TaskEdit, // Current values of the task, its labels are loaded when the editor opens
+    },
+    LabelPicker {
+        task_uuid: Uuid,
+    },
+    TaskMove {
+        task_uuid: Uuid,
+        project_uuid: Uuid, // Current location, marked in the picker
+        section_uuid: Option<Uuid>,
     },
     ProjectCreation,
     ProjectEdit {
@@ -120,6 +214,19 @@ pub enum DialogType {
         label_uuid: Uuid,
         name: String,
     },
+    SectionManagement {
+        project_uuid: Uuid,
+    },
+    SectionCreation {
+        project_uuid: Uuid,
+    },
+    SectionEdit {
+        section_uuid: Uuid,
+        name: String,
+    },
+    CommentThread {
+        task_uuid: Uuid,
+    },
     DeleteConfirmation {
         item_type: String,
         item_uuid: Uuid,
@@ -132,4 +239,14 @@ pub enum DialogType {
     TaskDueDateInput {
         task_uuid: Uuid,
     },
+    TaskDeadlineInput {
+        task_uuid: Uuid,
+        deadline: String, // Current deadline, pre-filled for editing
+    },
+    TaskDurationInput {
+        task_uuid: Uuid,
+        duration: String, // Current duration, pre-filled for editing
+    },
+    ConflictResolution,
+    ApiTokenInput,
 }
@@ -10,7 +10,9 @@
 //! - [`component`] - Base component trait and rendering abstractions
 //! - [`context`] - Application context and shared state management
 //! - [`event_handler`] - Event processing and keyboard/mouse input handling
+//! - [`sync_scheduler`] - Timing for periodic background syncs with failure backoff
 //! - [`task_manager`] - Background task management and async operation handling
+//! - [`view_mode`] - Per-view sort and grouping modes of the task list
 //!
 //! # Architecture
 //!
@@ -30,11 +32,15 @@ pub mod actions;
 pub mod component;
 pub mod context;
 pub mod event_handler;
+pub mod sync_scheduler;
 pub mod task_manager;
+pub mod view_mode;
 
 // Re-export core types for easier access from other modules
 pub use actions::{Action, DialogType, SidebarSelection};
 pub use component::Component;
 pub use context::AppContext;
 pub use event_handler::{EventHandler, EventType};
+pub use sync_scheduler::SyncScheduler;
 pub use task_manager::{TaskId, TaskManager, TaskResult};
+pub use view_mode::{GroupMode, SortMode, ViewLayout, ViewMode};
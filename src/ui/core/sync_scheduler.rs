@@ -0,0 +1,85 @@
+use crate::constants::AUTO_SYNC_MAX_BACKOFF_MINUTES;
+use std::time::{Duration, Instant};
+
+/// Decides when the next automatic background sync is due.
+///
+/// The scheduler is driven by the app loop tick and only keeps timing state; starting the
+/// sync itself is left to the caller. After a failed sync the delay doubles with every
+/// consecutive failure (capped at [`AUTO_SYNC_MAX_BACKOFF_MINUTES`]) so an unreachable
+/// backend is not hammered, and a successful sync resets it to the configured interval.
+#[derive(Debug, Clone)]
+pub struct SyncScheduler {
+    interval: Option<Duration>,
+    next_sync: Option<Instant>,
+    consecutive_failures: u32,
+}
+
+impl SyncScheduler {
+    /// Create a scheduler for the given interval in minutes (0 disables auto-sync).
+    ///
+    /// Nothing is scheduled until the first sync has finished.
+    pub fn new(interval_minutes: u64) -> Self {
+        Self {
+            interval: (interval_minutes > 0).then(|| Duration::from_secs(interval_minutes * 60)),
+            next_sync: None,
+            consecutive_failures: 0,
+        }
+    }
+
+    /// Create a scheduler that never triggers a sync.
+    pub fn disabled() -> Self {
+        Self::new(0)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.interval.is_some()
+    }
+
+    /// When the next sync is due, if one is scheduled.
+    pub fn next_sync(&self) -> Option<Instant> {
+        self.next_sync
+    }
+
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    /// Whether a sync should be started now.
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.next_sync.is_some_and(|next| now >= next)
+    }
+
+    /// A sync has started; nothing is scheduled until it finishes.
+    pub fn sync_started(&mut self) {
+        self.next_sync = None;
+    }
+
+    /// The last sync succeeded; schedule the next one a full interval from now.
+    pub fn record_success(&mut self, now: Instant) {
+        self.consecutive_failures = 0;
+        self.next_sync = self.interval.map(|interval| now + interval);
+    }
+
+    /// The last sync failed; schedule a retry with exponential backoff.
+    pub fn record_failure(&mut self, now: Instant) {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        self.next_sync = self
+            .interval
+            .map(|interval| now + Self::backoff(interval, self.consecutive_failures));
+    }
+
+    /// The backend asked to wait before retrying; back off as after a failure, but never
+    /// retry sooner than `min_delay`.
+    pub fn record_failure_after(&mut self, now: Instant, min_delay: Duration) {
+        self.record_failure(now);
+        if let Some(next) = self.next_sync {
+            self.next_sync = Some(next.max(now + min_delay));
+        }
+    }
+
+    fn backoff(interval: Duration, failures: u32) -> Duration {
+        let max_backoff = Duration::from_secs(AUTO_SYNC_MAX_BACKOFF_MINUTES * 60).max(interval);
+        let factor = 2u32.saturating_pow(failures.min(16));
+        interval.saturating_mul(factor).min(max_backoff)
+    }
+}
@@ -1,6 +1,9 @@
 use super::actions::{Action, SidebarSelection};
+use crate::config::FilterConfig;
 use crate::constants::UI_LOADING_DATA_FROM_STORAGE;
 use crate::sync::{SyncService, SyncStatus};
+use crate::utils::filter_query::parse_filter_query;
+use chrono::NaiveDate;
 use std::collections::HashMap;
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
@@ -30,6 +33,14 @@ pub enum TaskResult {
         query: String,
         results: Vec<crate::entities::task::Model>,
     },
+    CommentsLoaded {
+        task_uuid: uuid::Uuid,
+        comments: Vec<crate::entities::comment::Model>,
+    },
+    TaskLabelsLoaded {
+        task_uuid: uuid::Uuid,
+        label_uuids: Vec<uuid::Uuid>,
+    },
     Other(String),
 }
 
@@ -116,6 +127,11 @@ impl TaskManager {
                         let _ = action_sender.send(Action::NavigateToSidebar(SidebarSelection::Today));
                     }
 
+                    // Retry right away once a rejected API token has been replaced
+                    if desc_clone.starts_with("Update API token") {
+                        let _ = action_sender.send(Action::StartSync);
+                    }
+
                     Ok(result)
                 }
                 Err(e) => {
@@ -181,10 +197,14 @@ impl TaskManager {
     }
 
     /// Spawn a background data loading operation
+    ///
+    /// `calendar_range` holds the days the Calendar view shows, the end being exclusive.
     pub fn spawn_data_load(
         &mut self,
         sync_service: SyncService,
         sidebar_selection: SidebarSelection,
+        filters: Vec<FilterConfig>,
+        calendar_range: (NaiveDate, NaiveDate),
         is_initial_load: bool,
     ) -> TaskId {
         let task_id = self.next_task_id;
@@ -194,6 +214,12 @@ impl TaskManager {
         let description = UI_LOADING_DATA_FROM_STORAGE.to_string();
 
         let handle = tokio::spawn(async move {
+            if is_initial_load {
+                if let Ok(view_settings) = sync_service.get_view_settings().await {
+                    let _ = action_sender.send(Action::ViewModesLoaded(view_settings));
+                }
+            }
+
             match (
                 sync_service.get_projects().await,
                 sync_service.get_labels().await,
@@ -202,9 +228,16 @@ impl TaskManager {
                 (Ok(projects), Ok(labels), Ok(sections)) => {
                     // Get tasks based on sidebar selection
                     let tasks = match sidebar_selection {
-                        SidebarSelection::Today => sync_service.get_tasks_for_today().await.unwrap_or_default(),
+                        SidebarSelection::Today | SidebarSelection::Schedule => {
+                            sync_service.get_tasks_for_today().await.unwrap_or_default()
+                        }
                         SidebarSelection::Tomorrow => sync_service.get_tasks_for_tomorrow().await.unwrap_or_default(),
                         SidebarSelection::Upcoming => sync_service.get_tasks_for_upcoming().await.unwrap_or_default(),
+                        SidebarSelection::Calendar => {
+                            let (start, end) = calendar_range;
+                            sync_service.get_tasks_due_between(start, end).await.unwrap_or_default()
+                        }
+                        SidebarSelection::Logbook => sync_service.get_completed_tasks().await.unwrap_or_default(),
                         SidebarSelection::Project(index) => {
                             if let Some(project) = projects.get(index) {
                                 sync_service.get_tasks_for_project(&project.uuid).await.unwrap_or_default()
@@ -219,6 +252,14 @@ impl TaskManager {
                                 Vec::new()
                             }
                         }
+                        SidebarSelection::Filter(index) => {
+                            match filters.get(index).map(|filter| parse_filter_query(&filter.query)) {
+                                Some(Ok(filter)) => {
+                                    sync_service.get_tasks_for_filter(&filter).await.unwrap_or_default()
+                                }
+                                _ => Vec::new(),
+                            }
+                        }
                     };
 
                     let result = TaskResult::DataLoadCompleted {
@@ -227,6 +268,7 @@ impl TaskManager {
                         sections: sections.clone(),
                         tasks: tasks.clone(),
                     };
+                    let task_labels = sync_service.get_task_label_links().await.unwrap_or_default();
 
                     let action = if is_initial_load {
                         Action::InitialDataLoaded {
@@ -234,6 +276,7 @@ impl TaskManager {
                             labels,
                             sections,
                             tasks,
+                            task_labels,
                         }
                     } else {
                         Action::DataLoaded {
@@ -241,10 +284,18 @@ impl TaskManager {
                             labels,
                             sections,
                             tasks,
+                            task_labels,
                         }
                     };
                     let _ = action_sender.send(action);
 
+                    if let Ok(count) = sync_service.pending_changes_count().await {
+                        let _ = action_sender.send(Action::PendingChangesUpdated(count));
+                    }
+                    if let Ok(conflicts) = sync_service.get_conflicts().await {
+                        let _ = action_sender.send(Action::ConflictsUpdated(conflicts));
+                    }
+
                     Ok(result)
                 }
                 (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => {
@@ -306,6 +357,123 @@ impl TaskManager {
         self.tasks.insert(task_id, task);
         task_id
     }
+
+    /// Spawn a background load of the labels of a task for the task editor.
+    pub fn spawn_task_labels_load(&mut self, sync_service: SyncService, task_uuid: uuid::Uuid) -> TaskId {
+        let task_id = self.next_task_id;
+        self.next_task_id += 1;
+
+        let action_sender = self.action_sender.clone();
+        let description = format!("Loading labels of task {}", task_uuid);
+
+        let handle = tokio::spawn(async move {
+            let label_uuids: Vec<uuid::Uuid> = sync_service
+                .get_labels_for_task(&task_uuid)
+                .await?
+                .into_iter()
+                .map(|label| label.uuid)
+                .collect();
+            let _ = action_sender.send(Action::TaskLabelsLoaded {
+                task_uuid,
+                label_uuids: label_uuids.clone(),
+            });
+            Ok(TaskResult::TaskLabelsLoaded { task_uuid, label_uuids })
+        });
+
+        let task = BackgroundTask {
+            id: task_id,
+            handle,
+            description,
+            started_at: std::time::Instant::now(),
+        };
+
+        self.tasks.insert(task_id, task);
+        task_id
+    }
+
+    /// Spawn a background load of the comment thread of a task.
+    ///
+    /// The locally stored comments are sent first so the thread shows up right away.
+    /// With `refresh_remote` the thread is then fetched from the backend and sent again;
+    /// a failed fetch is only logged so the thread keeps working offline.
+    pub fn spawn_comments_load(
+        &mut self,
+        sync_service: SyncService,
+        task_uuid: uuid::Uuid,
+        refresh_remote: bool,
+    ) -> TaskId {
+        let task_id = self.next_task_id;
+        self.next_task_id += 1;
+
+        let action_sender = self.action_sender.clone();
+        let description = format!("Loading comments of task {}", task_uuid);
+
+        let handle = tokio::spawn(async move {
+            let mut comments = sync_service.get_comments_for_task(&task_uuid).await?;
+            let _ = action_sender.send(Action::CommentsLoaded {
+                task_uuid,
+                comments: comments.clone(),
+            });
+
+            if refresh_remote {
+                match sync_service.refresh_comments(&task_uuid).await {
+                    Ok(()) => {
+                        comments = sync_service.get_comments_for_task(&task_uuid).await?;
+                        let _ = action_sender.send(Action::CommentsLoaded {
+                            task_uuid,
+                            comments: comments.clone(),
+                        });
+                    }
+                    Err(e) => log::warn!("Failed to refresh comments of task {}: {}", task_uuid, e),
+                }
+            }
+
+            Ok(TaskResult::CommentsLoaded { task_uuid, comments })
+        });
+
+        let task = BackgroundTask {
+            id: task_id,
+            handle,
+            description,
+            started_at: std::time::Instant::now(),
+        };
+
+        self.tasks.insert(task_id, task);
+        task_id
+    }
+
+    /// Spawn a background fetch of recently completed tasks for the logbook,
+    /// reloading the view once they are stored
+    pub fn spawn_logbook_refresh(&mut self, sync_service: SyncService) -> TaskId {
+        let task_id = self.next_task_id;
+        self.next_task_id += 1;
+
+        let action_sender = self.action_sender.clone();
+        let description = "Refreshing logbook".to_string();
+
+        let handle = tokio::spawn(async move {
+            match sync_service.refresh_completed_tasks().await {
+                Ok(()) => {
+                    let _ = action_sender.send(Action::RefreshData);
+                    Ok(TaskResult::Other("Logbook refreshed".to_string()))
+                }
+                Err(e) => {
+                    log::warn!("Failed to refresh logbook: {}", e);
+                    Ok(TaskResult::Other(format!("Logbook refresh failed: {}", e)))
+                }
+            }
+        });
+
+        let task = BackgroundTask {
+            id: task_id,
+            handle,
+            description,
+            started_at: std::time::Instant::now(),
+        };
+
+        self.tasks.insert(task_id, task);
+        task_id
+    }
 }
 
 impl Drop for TaskManager {
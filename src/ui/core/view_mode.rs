@@ -0,0 +1,256 @@
+//! Sort and grouping modes and layouts of the task list.
+//!
+//! Every sidebar view starts out in its own order and layout (manual order, the Today
+//! view's Overdue/Today sections, a project's sections, ...). The user can switch each
+//! view to another sort and grouping mode, and projects to a board of their sections;
+//! the choice is stored per view in the local database under the key returned by [`view_key`].
+
+use std::cmp::Reverse;
+
+use chrono::DateTime;
+
+use crate::config::FilterConfig;
+use crate::entities::{label, project, task};
+use crate::ui::core::SidebarSelection;
+
+/// Order of the tasks within each group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortMode {
+    /// The order the view loads its tasks in, the manual order for projects
+    #[default]
+    Manual,
+    Priority,
+    DueDate,
+    Alphabetical,
+    Created,
+}
+
+/// How the tasks of a view are split into headed groups
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupMode {
+    /// The view's own sections, such as Overdue/Today or the sections of a project
+    #[default]
+    View,
+    None,
+    Project,
+    Section,
+    Priority,
+    DueDay,
+    Label,
+}
+
+/// How a view presents its tasks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ViewLayout {
+    #[default]
+    List,
+    /// A column per section with the tasks as cards, only offered for projects
+    Board,
+}
+
+impl SortMode {
+    const ALL: [SortMode; 5] = [
+        SortMode::Manual,
+        SortMode::Priority,
+        SortMode::DueDate,
+        SortMode::Alphabetical,
+        SortMode::Created,
+    ];
+
+    /// The mode following this one when cycling
+    #[must_use]
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|mode| *mode == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Stored form of the mode
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SortMode::Manual => "manual",
+            SortMode::Priority => "priority",
+            SortMode::DueDate => "due_date",
+            SortMode::Alphabetical => "alphabetical",
+            SortMode::Created => "created",
+        }
+    }
+
+    /// Parse the stored form of a mode
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|mode| mode.as_str() == value)
+    }
+
+    /// Name shown in the task list title
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            SortMode::Manual => "manual order",
+            SortMode::Priority => "priority",
+            SortMode::DueDate => "due date",
+            SortMode::Alphabetical => "name",
+            SortMode::Created => "date added",
+        }
+    }
+}
+
+impl GroupMode {
+    const ALL: [GroupMode; 7] = [
+        GroupMode::View,
+        GroupMode::None,
+        GroupMode::Project,
+        GroupMode::Section,
+        GroupMode::Priority,
+        GroupMode::DueDay,
+        GroupMode::Label,
+    ];
+
+    /// The mode following this one when cycling
+    #[must_use]
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|mode| *mode == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Stored form of the mode
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            GroupMode::View => "view",
+            GroupMode::None => "none",
+            GroupMode::Project => "project",
+            GroupMode::Section => "section",
+            GroupMode::Priority => "priority",
+            GroupMode::DueDay => "due_day",
+            GroupMode::Label => "label",
+        }
+    }
+
+    /// Parse the stored form of a mode
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|mode| mode.as_str() == value)
+    }
+
+    /// Name shown in the task list title
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            GroupMode::View => "view sections",
+            GroupMode::None => "no groups",
+            GroupMode::Project => "project",
+            GroupMode::Section => "section",
+            GroupMode::Priority => "priority",
+            GroupMode::DueDay => "due day",
+            GroupMode::Label => "label",
+        }
+    }
+}
+
+impl ViewLayout {
+    /// The other layout
+    #[must_use]
+    pub fn toggled(self) -> Self {
+        match self {
+            ViewLayout::List => ViewLayout::Board,
+            ViewLayout::Board => ViewLayout::List,
+        }
+    }
+
+    /// Stored form of the layout
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ViewLayout::List => "list",
+            ViewLayout::Board => "board",
+        }
+    }
+
+    /// Parse the stored form of a layout
+    pub fn parse(value: &str) -> Option<Self> {
+        [ViewLayout::List, ViewLayout::Board]
+            .into_iter()
+            .find(|layout| layout.as_str() == value)
+    }
+}
+
+/// The sort and grouping mode and the layout of a view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ViewMode {
+    pub sort: SortMode,
+    pub group: GroupMode,
+    pub layout: ViewLayout,
+}
+
+impl ViewMode {
+    /// Parse a stored view setting, unknown modes fall back to the view's own
+    #[must_use]
+    pub fn from_stored(sort_mode: &str, group_mode: &str, layout: &str) -> Self {
+        Self {
+            sort: SortMode::parse(sort_mode).unwrap_or_default(),
+            group: GroupMode::parse(group_mode).unwrap_or_default(),
+            layout: ViewLayout::parse(layout).unwrap_or_default(),
+        }
+    }
+
+    /// Short description for the task list title, `None` while the view's own layout is used
+    #[must_use]
+    pub fn describe(&self) -> Option<String> {
+        let sort = (self.sort != SortMode::Manual).then(|| format!("by {}", self.sort.label()));
+        let group = match self.group {
+            GroupMode::View => None,
+            GroupMode::None => Some(GroupMode::None.label().to_string()),
+            group => Some(format!("grouped by {}", group.label())),
+        };
+        match (sort, group) {
+            (None, None) => None,
+            (Some(sort), None) => Some(sort),
+            (None, Some(group)) => Some(group),
+            (Some(sort), Some(group)) => Some(format!("{}, {}", sort, group)),
+        }
+    }
+}
+
+/// The key a view's mode is stored under, `None` for views with a fixed order
+/// (the Calendar agenda, the Schedule timeline and the Logbook's completion history).
+pub fn view_key(
+    selection: &SidebarSelection,
+    projects: &[project::Model],
+    labels: &[label::Model],
+    filters: &[FilterConfig],
+) -> Option<String> {
+    match selection {
+        SidebarSelection::Today => Some("today".to_string()),
+        SidebarSelection::Tomorrow => Some("tomorrow".to_string()),
+        SidebarSelection::Upcoming => Some("upcoming".to_string()),
+        SidebarSelection::Calendar | SidebarSelection::Schedule | SidebarSelection::Logbook => None,
+        SidebarSelection::Project(index) => projects.get(*index).map(|project| format!("project:{}", project.uuid)),
+        SidebarSelection::Label(index) => labels.get(*index).map(|label| format!("label:{}", label.uuid)),
+        SidebarSelection::Filter(index) => filters.get(*index).map(|filter| format!("filter:{}", filter.name)),
+    }
+}
+
+/// Sort tasks in place; open tasks always stay ahead of completed ones.
+///
+/// The sort is stable, so tasks that compare equal keep the order they were loaded in.
+pub fn sort_tasks(tasks: &mut [task::Model], sort: SortMode) {
+    match sort {
+        SortMode::Manual => {}
+        SortMode::Priority => tasks.sort_by_key(|task| (task.is_completed, Reverse(task.priority))),
+        SortMode::DueDate => tasks.sort_by_cached_key(|task| {
+            (
+                task.is_completed,
+                task.due_date.is_none(),
+                task.due_date.clone(),
+                task.due_datetime.clone(),
+            )
+        }),
+        SortMode::Alphabetical => tasks.sort_by_cached_key(|task| (task.is_completed, task.content.to_lowercase())),
+        SortMode::Created => tasks.sort_by_cached_key(|task| {
+            let created_at = task
+                .created_at
+                .as_deref()
+                .and_then(|created_at| DateTime::parse_from_rfc3339(created_at).ok());
+            (task.is_completed, created_at.is_none(), created_at)
+        }),
+    }
+}
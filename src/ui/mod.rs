@@ -36,7 +36,7 @@
 //!
 //! # async fn example() -> anyhow::Result<()> {
 //! let config = Config::load()?;
-//! let storage = Arc::new(Mutex::new(LocalStorage::new(false).await?));
+//! let storage = Arc::new(Mutex::new(LocalStorage::new().await?));
 //! let backend_registry = Arc::new(BackendRegistry::new(storage));
 //! // ... initialize and load backends ...
 //! # let backend_uuid = uuid::Uuid::new_v4();
@@ -107,6 +107,11 @@ async fn run_app_loop<B: Backend>(
                         }
                     }
                 }
+
+                // Kick off a periodic sync once the configured interval has elapsed
+                if app.tick_auto_sync() {
+                    needs_render = true;
+                }
                 // Don't render on every tick - only when there are actual background actions
             }
             EventType::Render => {
@@ -0,0 +1,116 @@
+//! Mapping of backend color names to terminal colors.
+//!
+//! Todoist names its project and label colors ("berry_red", "sky_blue", ...). They are
+//! resolved to their RGB values and rendered as true color when the terminal announces
+//! support for it through `COLORTERM`, or as the closest entry of the 256-color palette
+//! otherwise.
+
+use ratatui::style::Color;
+use std::sync::OnceLock;
+
+/// The Todoist color palette
+const TODOIST_COLORS: &[(&str, (u8, u8, u8))] = &[
+    ("berry_red", (0xb8, 0x25, 0x6f)),
+    ("red", (0xdb, 0x40, 0x35)),
+    ("orange", (0xff, 0x99, 0x33)),
+    ("yellow", (0xfa, 0xd0, 0x00)),
+    ("olive_green", (0xaf, 0xb8, 0x3b)),
+    ("lime_green", (0x7e, 0xcc, 0x49)),
+    ("green", (0x29, 0x94, 0x38)),
+    ("mint_green", (0x6a, 0xcc, 0xbc)),
+    ("teal", (0x15, 0x8f, 0xad)),
+    ("sky_blue", (0x14, 0xaa, 0xf5)),
+    ("light_blue", (0x96, 0xc3, 0xeb)),
+    ("blue", (0x40, 0x73, 0xff)),
+    ("grape", (0x88, 0x4d, 0xff)),
+    ("violet", (0xaf, 0x38, 0xeb)),
+    ("lavender", (0xeb, 0x96, 0xeb)),
+    ("magenta", (0xe0, 0x51, 0x94)),
+    ("salmon", (0xff, 0x8d, 0x85)),
+    ("charcoal", (0x80, 0x80, 0x80)),
+    ("grey", (0xb8, 0xb8, 0xb8)),
+    ("taupe", (0xcc, 0xac, 0x93)),
+];
+
+/// Intensities of the 6x6x6 color cube of the 256-color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Colors the terminal can display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSupport {
+    TrueColor,
+    Ansi256,
+}
+
+impl ColorSupport {
+    /// Detect color support from the `COLORTERM` environment variable
+    #[must_use]
+    pub fn detect() -> Self {
+        match std::env::var("COLORTERM") {
+            Ok(value) if value.eq_ignore_ascii_case("truecolor") || value.eq_ignore_ascii_case("24bit") => {
+                Self::TrueColor
+            }
+            _ => Self::Ansi256,
+        }
+    }
+}
+
+/// Resolve a color name or `#rrggbb` hex value to RGB
+#[must_use]
+pub fn parse_color(name: &str) -> Option<(u8, u8, u8)> {
+    if let Some(hex) = name.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        return Some((channel(0)?, channel(2)?, channel(4)?));
+    }
+    TODOIST_COLORS
+        .iter()
+        .find(|(color_name, _)| color_name.eq_ignore_ascii_case(name))
+        .map(|(_, rgb)| *rgb)
+}
+
+/// Index of the 256-color palette entry closest to an RGB value
+#[must_use]
+pub fn rgb_to_ansi256((r, g, b): (u8, u8, u8)) -> u8 {
+    let nearest_level = |value: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| CUBE_LEVELS[i].abs_diff(value))
+            .unwrap_or(0)
+    };
+    let (ri, gi, bi) = (nearest_level(r), nearest_level(g), nearest_level(b));
+    let cube = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+
+    // Grayscale ramp 232..=255 runs from 8 to 238 in steps of 10
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let gray_step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray_value = 8 + gray_step * 10;
+    let gray = (gray_value, gray_value, gray_value);
+
+    let distance = |(cr, cg, cb): (u8, u8, u8)| {
+        u32::from(cr.abs_diff(r)).pow(2) + u32::from(cg.abs_diff(g)).pow(2) + u32::from(cb.abs_diff(b)).pow(2)
+    };
+    if distance(gray) < distance(cube) {
+        232 + gray_step
+    } else {
+        16 + 36 * ri as u8 + 6 * gi as u8 + bi as u8
+    }
+}
+
+/// Terminal color for a color name with the given color support
+#[must_use]
+pub fn terminal_color(name: &str, support: ColorSupport) -> Option<Color> {
+    let (r, g, b) = parse_color(name)?;
+    Some(match support {
+        ColorSupport::TrueColor => Color::Rgb(r, g, b),
+        ColorSupport::Ansi256 => Color::Indexed(rgb_to_ansi256((r, g, b))),
+    })
+}
+
+/// Terminal color for a backend color name, using the color support of the current terminal
+#[must_use]
+pub fn backend_color(name: Option<&str>) -> Option<Color> {
+    static SUPPORT: OnceLock<ColorSupport> = OnceLock::new();
+    terminal_color(name?, *SUPPORT.get_or_init(ColorSupport::detect))
+}
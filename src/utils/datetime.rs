@@ -3,7 +3,7 @@
 //! This module provides functions for date manipulation and human-readable formatting,
 //! similar to how Todoist displays dates (e.g., "yesterday", "today", "tomorrow").
 
-use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, TimeZone, Weekday};
+use chrono::{DateTime, Datelike, Duration, Local, Months, NaiveDate, NaiveTime, TimeZone, Weekday};
 
 /// Standard date format used throughout the application for Todoist API compatibility
 pub const TODOIST_DATE_FORMAT: &str = "%Y-%m-%d";
@@ -41,6 +41,13 @@ pub fn format_date_with_offset(days_offset: i64) -> String {
     format_ymd(target_date)
 }
 
+/// Local calendar date of an RFC 3339 timestamp, such as the completion time of a task
+pub fn local_date(timestamp: &str) -> Option<NaiveDate> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .map(|dt| dt.with_timezone(&Local).date_naive())
+}
+
 /// Calculate the next occurrence of a target weekday from a given date
 pub fn next_weekday(from: NaiveDate, target: Weekday) -> NaiveDate {
     let from_w = from.weekday().num_days_from_monday() as i64;
@@ -52,6 +59,28 @@ pub fn next_weekday(from: NaiveDate, target: Weekday) -> NaiveDate {
     from + Duration::days(delta)
 }
 
+/// First day of the month a date falls in
+pub fn month_start(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+/// Move a date by whole months, keeping the day of the month where the target month allows it
+/// (Jan 31 plus one month is the last day of February)
+pub fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
+    let shifted = if months >= 0 {
+        date.checked_add_months(Months::new(months.unsigned_abs()))
+    } else {
+        date.checked_sub_months(Months::new(months.unsigned_abs()))
+    };
+    shifted.unwrap_or(date)
+}
+
+/// First day shown in a month grid: the Monday on or before the first of the month
+pub fn month_grid_start(month: NaiveDate) -> NaiveDate {
+    let first = month_start(month);
+    first - Duration::days(i64::from(first.weekday().num_days_from_monday()))
+}
+
 /// Format a date string in Todoist-style human-readable format
 ///
 /// # Arguments
@@ -110,43 +139,37 @@ pub fn format_human_date(date_str: &str) -> String {
     }
 }
 
-/// Format a datetime string in Todoist-style human-readable format
+/// Parse a due datetime into local time
 ///
-/// # Arguments
-/// * `datetime_str` - DateTime string in various formats (RFC3339, ISO 8601, etc.)
-///
-/// # Returns
-/// * `String` - Human-readable datetime format
-pub fn format_human_datetime(datetime_str: &str) -> String {
-    // Try multiple datetime parsing strategies
-    let parsed_dt = if let Ok(dt) = DateTime::parse_from_rfc3339(datetime_str) {
-        // RFC3339 with timezone (e.g., "2025-01-15T14:30:00Z")
-        Some(dt.with_timezone(&Local))
-    } else if let Ok(dt) =
-        chrono::NaiveDateTime::parse_from_str(datetime_str, &format!("{}T%H:%M:%S", TODOIST_DATE_FORMAT))
-    {
-        // ISO 8601 without timezone (e.g., "2025-01-15T14:30:00")
-        Some(
-            Local
-                .from_local_datetime(&dt)
-                .single()
-                .unwrap_or_else(|| Local.from_utc_datetime(&dt)),
-        )
-    } else if let Ok(dt) =
-        chrono::NaiveDateTime::parse_from_str(datetime_str, &format!("{} %H:%M:%S", TODOIST_DATE_FORMAT))
-    {
-        // Space-separated format (e.g., "2025-01-15 14:30:00")
+/// Accepts RFC 3339 timestamps (e.g., "2025-01-15T14:30:00Z") as well as floating times
+/// without a timezone (e.g., "2025-01-15T14:30:00" or "2025-01-15 14:30:00"), which are
+/// taken as local time.
+pub fn parse_datetime(datetime_str: &str) -> Option<DateTime<Local>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(datetime_str) {
+        return Some(dt.with_timezone(&Local));
+    }
+    ["T%H:%M:%S", " %H:%M:%S"].iter().find_map(|time_format| {
+        let dt =
+            chrono::NaiveDateTime::parse_from_str(datetime_str, &format!("{}{}", TODOIST_DATE_FORMAT, time_format))
+                .ok()?;
         Some(
             Local
                 .from_local_datetime(&dt)
                 .single()
                 .unwrap_or_else(|| Local.from_utc_datetime(&dt)),
         )
-    } else {
-        None
-    };
+    })
+}
 
-    if let Some(local_dt) = parsed_dt {
+/// Format a datetime string in Todoist-style human-readable format
+///
+/// # Arguments
+/// * `datetime_str` - DateTime string in various formats (RFC3339, ISO 8601, etc.)
+///
+/// # Returns
+/// * `String` - Human-readable datetime format
+pub fn format_human_datetime(datetime_str: &str) -> String {
+    if let Some(local_dt) = parse_datetime(datetime_str) {
         let date_str = local_dt.format(TODOIST_DATE_FORMAT).to_string();
         let time_str = local_dt.format("%H:%M").to_string();
 
@@ -192,6 +215,179 @@ pub fn normalize_due_string(input: &str) -> String {
     expanded.join(" ")
 }
 
+/// Normalize a task duration to the "<amount> <unit>" form stored for tasks.
+///
+/// Accepts a bare number of minutes ("30"), minutes, hours or both ("45m", "2 hours",
+/// "1h30", "1h 30m"), which are stored in minutes, or a number of days ("2 days").
+/// Returns `None` for anything else.
+pub fn normalize_duration(input: &str) -> Option<String> {
+    let input = input.trim().to_lowercase();
+    let mut parts = Vec::new();
+    let mut rest = input.as_str();
+    while !rest.is_empty() {
+        let split = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let amount: u32 = rest[..split].parse().ok()?;
+        let rest_after_amount = rest[split..].trim_start();
+        let split = rest_after_amount
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(rest_after_amount.len());
+        parts.push((amount, &rest_after_amount[..split]));
+        rest = rest_after_amount[split..].trim_start();
+    }
+
+    let is_minutes = |unit: &str| matches!(unit, "" | "m" | "min" | "mins" | "minute" | "minutes");
+    let is_hours = |unit: &str| matches!(unit, "h" | "hr" | "hrs" | "hour" | "hours");
+    let (amount, unit) = match parts.as_slice() {
+        [(days, "d" | "day" | "days")] => (*days, "day"),
+        [(minutes, unit)] if is_minutes(unit) => (*minutes, "minute"),
+        [(hours, unit)] if is_hours(unit) => (hours.checked_mul(60)?, "minute"),
+        [(hours, hours_unit), (minutes, minutes_unit)] if is_hours(hours_unit) && is_minutes(minutes_unit) => {
+            (hours.checked_mul(60)?.checked_add(*minutes)?, "minute")
+        }
+        _ => return None,
+    };
+    (amount > 0).then(|| format!("{} {}", amount, unit))
+}
+
+/// Length in minutes of a stored task duration ("90 minute", "2 day")
+pub fn duration_minutes(duration: &str) -> Option<i64> {
+    let (amount, unit) = duration.split_once(' ')?;
+    let amount: i64 = amount.parse().ok()?;
+    match unit {
+        "minute" => Some(amount),
+        "day" => Some(amount * 24 * 60),
+        _ => None,
+    }
+}
+
+/// Parse a deadline typed as `YYYY-MM-DD` or as a date phrase such as "friday" or "march 15".
+///
+/// Deadlines are plain dates, so phrases with a time of day and recurring phrases are rejected.
+pub fn parse_deadline(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    match resolve_due_string(input.trim(), today)? {
+        (date, None) => Some(date),
+        (_, Some(_)) => None,
+    }
+}
+
+/// Parse a weekday name or one of its common abbreviations ("fri", "thurs")
+pub fn parse_weekday(word: &str) -> Option<Weekday> {
+    match word.to_lowercase().as_str() {
+        "monday" | "mon" => Some(Weekday::Mon),
+        "tuesday" | "tue" | "tues" => Some(Weekday::Tue),
+        "wednesday" | "wed" => Some(Weekday::Wed),
+        "thursday" | "thu" | "thur" | "thurs" => Some(Weekday::Thu),
+        "friday" | "fri" => Some(Weekday::Fri),
+        "saturday" | "sat" => Some(Weekday::Sat),
+        "sunday" | "sun" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Parse a month name or its three letter abbreviation to its number (1-12)
+pub fn parse_month(word: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+    let word = word.to_lowercase();
+    let word = word.strip_suffix('.').unwrap_or(&word);
+    MONTHS
+        .iter()
+        .position(|month| word.len() >= 3 && month.starts_with(word))
+        .map(|index| index as u32 + 1)
+}
+
+/// Parse a time of day such as "5pm", "5:30pm" or "17:00"
+pub fn parse_time_of_day(word: &str) -> Option<NaiveTime> {
+    let word = word.to_lowercase();
+    let (clock, offset) = if let Some(clock) = word.strip_suffix("am") {
+        (clock, Some(0))
+    } else if let Some(clock) = word.strip_suffix("pm") {
+        (clock, Some(12))
+    } else {
+        (word.as_str(), None)
+    };
+
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) if minute.len() == 2 => (hour.parse::<u32>().ok()?, minute.parse::<u32>().ok()?),
+        // A bare number is only a time with an am/pm suffix
+        None if offset.is_some() => (clock.parse::<u32>().ok()?, 0),
+        _ => return None,
+    };
+    let hour = match offset {
+        Some(_) if !(1..=12).contains(&hour) => return None,
+        Some(offset) => hour % 12 + offset,
+        None => hour,
+    };
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+/// Resolve a simple due string to a calendar date and optional time of day, relative to `today`.
+///
+/// Understands "today", "tomorrow", weekdays, "next week", "in N days/weeks", ISO dates and
+/// month/day pairs ("march 15", "15 mar"), each optionally followed by a time ("at 5pm").
+/// A time on its own means today. Anything else, such as recurring dates, returns `None`
+/// and is left to the backend to interpret.
+pub fn resolve_due_string(input: &str, today: NaiveDate) -> Option<(NaiveDate, Option<NaiveTime>)> {
+    let normalized = normalize_due_string(input).to_lowercase();
+    let mut words: Vec<&str> = normalized.split_whitespace().collect();
+
+    let time = match words.last().and_then(|word| parse_time_of_day(word)) {
+        Some(time) => {
+            words.pop();
+            if words.last() == Some(&"at") {
+                words.pop();
+            }
+            Some(time)
+        }
+        None => None,
+    };
+
+    let date = match words.as_slice() {
+        [] if time.is_some() => today,
+        ["today"] => today,
+        ["tomorrow"] => today + Duration::days(1),
+        ["next", "week"] => next_weekday(today, Weekday::Mon),
+        ["in", amount, unit] => {
+            let amount: i64 = amount.parse().ok()?;
+            match *unit {
+                "day" | "days" => today + Duration::days(amount),
+                "week" | "weeks" => today + Duration::weeks(amount),
+                _ => return None,
+            }
+        }
+        [word] => match parse_weekday(word) {
+            Some(weekday) => next_weekday(today, weekday),
+            None => parse_date(word).ok()?,
+        },
+        [first, second] => {
+            let (month, day) = match (parse_month(first), parse_month(second)) {
+                (Some(month), None) => (month, second.parse().ok()?),
+                (None, Some(month)) => (month, first.parse().ok()?),
+                _ => return None,
+            };
+            // A month and day without a year means the next time that day comes around
+            match NaiveDate::from_ymd_opt(today.year(), month, day)? {
+                date if date < today => NaiveDate::from_ymd_opt(today.year() + 1, month, day)?,
+                date => date,
+            }
+        }
+        _ => return None,
+    };
+    Some((date, time))
+}
+
 /// Get a human-readable weekday name
 fn weekday_name(weekday: Weekday) -> &'static str {
     match weekday {
@@ -0,0 +1,277 @@
+//! Parser for Todoist-style filter queries.
+//!
+//! A query such as `(p1 | p2) & @work & !#Someday` combines terms with operators:
+//!
+//! - `&` - both sides match
+//! - `|` - either side matches
+//! - `!` - the term or group that follows does not match
+//! - `( )` - grouping; `!` binds tightest, then `&`, then `|`
+//!
+//! Terms are case-insensitive:
+//!
+//! - `p1`-`p4` - priority, `p1` being the most urgent
+//! - `@name` - tasks with the label, `no labels` - tasks without any label
+//! - `#name` - tasks in the project, `##name` - in the project or one of its subprojects
+//! - `/name` - tasks in a section with that name
+//! - `today`, `tomorrow`, `overdue`, `no date` - due dates
+//! - `7 days` or `next 7 days` - due within the next 7 days, today included
+//! - `due before: <date>`, `due after: <date>` - due dates relative to a date phrase
+//! - `recurring` - recurring tasks
+//! - `search: text` - content or description containing the text
+//! - `all` - every task
+//!
+//! Parsing is purely textual; names and dates are resolved when the filter is evaluated,
+//! so relative dates such as `due before: next week` follow the calendar.
+
+use chrono::Local;
+
+use crate::utils::datetime::parse_deadline;
+
+/// A parsed filter query
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterExpr {
+    And(Box<FilterExpr>, Box<FilterExpr>),
+    Or(Box<FilterExpr>, Box<FilterExpr>),
+    Not(Box<FilterExpr>),
+    Term(FilterTerm),
+}
+
+/// A single condition of a filter query
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterTerm {
+    /// Backend priority, where 4 is the most urgent (`p1`)
+    Priority(i32),
+    Label(String),
+    NoLabels,
+    Project(String),
+    /// A project together with its subprojects (`##name`)
+    ProjectTree(String),
+    Section(String),
+    Today,
+    Tomorrow,
+    Overdue,
+    NoDate,
+    /// Due within this many days, today included
+    NextDays(i64),
+    /// Due strictly before the date phrase
+    DueBefore(String),
+    /// Due strictly after the date phrase
+    DueAfter(String),
+    Recurring,
+    Search(String),
+    All,
+}
+
+/// Why a filter query could not be parsed
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum FilterQueryError {
+    #[error("filter query is empty")]
+    Empty,
+    #[error("unknown filter term '{0}'")]
+    UnknownTerm(String),
+    #[error("invalid date in filter term '{0}'")]
+    InvalidDate(String),
+    #[error("expected a term after '{0}'")]
+    MissingTerm(String),
+    #[error("unexpected '{0}' in filter query")]
+    Unexpected(String),
+    #[error("missing ')' in filter query")]
+    UnclosedParen,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+    Term(String),
+}
+
+impl Token {
+    fn text(&self) -> String {
+        match self {
+            Token::And => "&".to_string(),
+            Token::Or => "|".to_string(),
+            Token::Not => "!".to_string(),
+            Token::Open => "(".to_string(),
+            Token::Close => ")".to_string(),
+            Token::Term(term) => term.clone(),
+        }
+    }
+}
+
+/// Parse a filter query into an expression tree.
+///
+/// Date phrases are checked against today's date so typos are reported up front.
+pub fn parse_filter_query(input: &str) -> Result<FilterExpr, FilterQueryError> {
+    let tokens = tokenize(input);
+    if tokens.is_empty() {
+        return Err(FilterQueryError::Empty);
+    }
+
+    let mut parser = Parser { tokens, position: 0 };
+    let expr = parser.or()?;
+    match parser.tokens.get(parser.position) {
+        Some(token) => Err(FilterQueryError::Unexpected(token.text())),
+        None => Ok(expr),
+    }
+}
+
+/// Split the input into operators and the trimmed terms between them
+fn tokenize(input: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut term = String::new();
+    let flush = |term: &mut String, tokens: &mut Vec<Token>| {
+        let text = term.trim();
+        if !text.is_empty() {
+            tokens.push(Token::Term(text.to_string()));
+        }
+        term.clear();
+    };
+
+    for c in input.chars() {
+        let operator = match c {
+            '&' => Token::And,
+            '|' => Token::Or,
+            '!' => Token::Not,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            _ => {
+                term.push(c);
+                continue;
+            }
+        };
+        flush(&mut term, &mut tokens);
+        tokens.push(operator);
+    }
+    flush(&mut term, &mut tokens);
+    tokens
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn next_if(&mut self, token: &Token) -> bool {
+        if self.tokens.get(self.position) == Some(token) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn or(&mut self) -> Result<FilterExpr, FilterQueryError> {
+        let mut expr = self.and()?;
+        while self.next_if(&Token::Or) {
+            self.expect_operand("|")?;
+            expr = FilterExpr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<FilterExpr, FilterQueryError> {
+        let mut expr = self.unary()?;
+        while self.next_if(&Token::And) {
+            self.expect_operand("&")?;
+            expr = FilterExpr::And(Box::new(expr), Box::new(self.unary()?));
+        }
+        Ok(expr)
+    }
+
+    /// Report a dangling operator at the end of the query
+    fn expect_operand(&self, operator: &str) -> Result<(), FilterQueryError> {
+        if self.position < self.tokens.len() {
+            Ok(())
+        } else {
+            Err(FilterQueryError::MissingTerm(operator.to_string()))
+        }
+    }
+
+    fn unary(&mut self) -> Result<FilterExpr, FilterQueryError> {
+        let Some(token) = self.tokens.get(self.position).cloned() else {
+            return Err(FilterQueryError::Empty);
+        };
+        self.position += 1;
+        match token {
+            Token::Not => {
+                self.expect_operand("!")?;
+                Ok(FilterExpr::Not(Box::new(self.unary()?)))
+            }
+            Token::Open => {
+                self.expect_operand("(")?;
+                let expr = self.or()?;
+                if self.next_if(&Token::Close) {
+                    Ok(expr)
+                } else {
+                    Err(FilterQueryError::UnclosedParen)
+                }
+            }
+            Token::Term(term) => parse_term(&term).map(FilterExpr::Term),
+            other => Err(FilterQueryError::Unexpected(other.text())),
+        }
+    }
+}
+
+/// Parse a single term, such as `@work` or `due before: friday`
+fn parse_term(term: &str) -> Result<FilterTerm, FilterQueryError> {
+    let unknown = || FilterQueryError::UnknownTerm(term.to_string());
+    let lower = term.to_lowercase();
+    let words: Vec<&str> = lower.split_whitespace().collect();
+
+    let named = |prefix: &str| term.strip_prefix(prefix).map(str::trim).filter(|name| !name.is_empty());
+    if term.starts_with("##") {
+        return named("##")
+            .map(|name| FilterTerm::ProjectTree(name.to_string()))
+            .ok_or_else(unknown);
+    }
+    if let Some(name) = named("#") {
+        return Ok(FilterTerm::Project(name.to_string()));
+    }
+    if let Some(name) = named("@") {
+        return Ok(FilterTerm::Label(name.to_string()));
+    }
+    if let Some(name) = named("/") {
+        return Ok(FilterTerm::Section(name.to_string()));
+    }
+
+    if let Some((key, value)) = term.split_once(':') {
+        let value = value.trim();
+        let key = key.trim().to_lowercase();
+        let date_term = |term: fn(String) -> FilterTerm| {
+            if parse_deadline(value, Local::now().date_naive()).is_some() {
+                Ok(term(value.to_string()))
+            } else {
+                Err(FilterQueryError::InvalidDate(value.to_string()))
+            }
+        };
+        return match key.as_str() {
+            "search" if !value.is_empty() => Ok(FilterTerm::Search(value.to_string())),
+            "due before" => date_term(FilterTerm::DueBefore),
+            "due after" => date_term(FilterTerm::DueAfter),
+            _ => Err(unknown()),
+        };
+    }
+
+    match words.as_slice() {
+        ["p1"] => Ok(FilterTerm::Priority(4)),
+        ["p2"] => Ok(FilterTerm::Priority(3)),
+        ["p3"] => Ok(FilterTerm::Priority(2)),
+        ["p4"] => Ok(FilterTerm::Priority(1)),
+        ["today"] => Ok(FilterTerm::Today),
+        ["tomorrow"] => Ok(FilterTerm::Tomorrow),
+        ["overdue"] => Ok(FilterTerm::Overdue),
+        ["no", "date"] | ["no", "due", "date"] => Ok(FilterTerm::NoDate),
+        ["no", "labels"] => Ok(FilterTerm::NoLabels),
+        ["recurring"] => Ok(FilterTerm::Recurring),
+        ["all"] => Ok(FilterTerm::All),
+        [count, "days" | "day"] | ["next", count, "days" | "day"] => {
+            count.parse().map(FilterTerm::NextDays).map_err(|_| unknown())
+        }
+        _ => Err(unknown()),
+    }
+}
@@ -0,0 +1,53 @@
+//! Fuzzy matching of short names, such as labels in a picker.
+//!
+//! A query matches when its characters appear in the name in the same order, ignoring
+//! case. Matches are scored so that prefixes, word starts and consecutive characters rank
+//! above scattered ones.
+
+/// Score how well `query` matches `name`, higher is better.
+///
+/// Returns `None` when the characters of the query do not all appear in order in the name.
+/// An empty query matches everything with a score of 0.
+pub fn fuzzy_score(name: &str, query: &str) -> Option<i32> {
+    let name: Vec<char> = name.chars().flat_map(char::to_lowercase).collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for query_char in query.chars().flat_map(char::to_lowercase) {
+        let offset = name[position..].iter().position(|c| *c == query_char)?;
+        let index = position + offset;
+
+        score += 1;
+        if index == 0 {
+            score += 8;
+        } else if !name[index - 1].is_alphanumeric() {
+            score += 5;
+        }
+        if previous_match.is_some_and(|previous| previous + 1 == index) {
+            score += 4;
+        }
+        // Skipped characters make the match weaker
+        score -= i32::try_from(offset).unwrap_or(i32::MAX).min(3);
+
+        previous_match = Some(index);
+        position = index + 1;
+    }
+    Some(score)
+}
+
+/// Indices of the names matching `query`, best match first.
+///
+/// Names with the same score keep their original order.
+pub fn fuzzy_filter<'a, I>(names: I, query: &str) -> Vec<usize>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut matches: Vec<(usize, i32)> = names
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, name)| fuzzy_score(name, query).map(|score| (index, score)))
+        .collect();
+    matches.sort_by(|a, b| b.1.cmp(&a.1));
+    matches.into_iter().map(|(index, _)| index).collect()
+}
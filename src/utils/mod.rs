@@ -7,6 +7,12 @@
 //! # Available Utilities
 //!
 //! - [`datetime`] - Date and time formatting, parsing, and manipulation functions
+//! - [`color`] - Mapping of backend color names to terminal colors
+//! - [`quick_add`] - Parser for the inline quick-add task syntax
+//! - [`fuzzy`] - Fuzzy matching of short names for pickers
+//! - [`schedule`] - Time blocking of a day's tasks on an hourly timeline
+//! - [`filter_query`] - Parser for Todoist-style filter queries
+//! - [`text`] - Fitting text into fixed-width cells
 //!
 //! # Purpose
 //!
@@ -26,4 +32,10 @@
 //! - **Performance** - Efficient implementations suitable for frequent use
 //! - **Testability** - Easy to unit test with clear inputs and outputs
 
+pub mod color;
 pub mod datetime;
+pub mod filter_query;
+pub mod fuzzy;
+pub mod quick_add;
+pub mod schedule;
+pub mod text;
@@ -0,0 +1,241 @@
+//! Parser for the inline quick-add task syntax.
+//!
+//! A single line such as `Buy milk #Groceries /Dairy @errand p1 tomorrow 5pm //Semi-skimmed`
+//! is split into the task content and its attributes:
+//!
+//! - `#name` - project
+//! - `/name` - section
+//! - `@name` - label (may be repeated)
+//! - `p1`-`p4` - priority, `p1` being the most urgent
+//! - the first date phrase ("tomorrow 5pm", "next monday", "every week", "march 15") - due string
+//! - `{date}` - deadline, such as `{march 30}` or `{2025-03-30}`
+//! - `for` followed by a duration with a unit ("for 45m", "for 1h30", "for 2 hours") - duration
+//! - everything after `//` - description
+//!
+//! Parsing is purely textual, so it works the same for every backend. Names are resolved
+//! against local projects, sections and labels by the caller.
+
+use crate::utils::datetime::{
+    normalize_due_string, normalize_duration, parse_date, parse_month, parse_time_of_day, parse_weekday,
+};
+
+/// The attributes extracted from a quick-add line
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QuickAdd {
+    pub content: String,
+    pub project: Option<String>,
+    pub section: Option<String>,
+    pub labels: Vec<String>,
+    /// Backend priority, where 4 is the most urgent (`p1`)
+    pub priority: Option<i32>,
+    pub due_string: Option<String>,
+    /// The deadline as typed between braces, resolved to a date by the caller
+    pub deadline: Option<String>,
+    /// Normalized duration, such as "90 minute"
+    pub duration: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Parse a quick-add line into its content and attributes.
+///
+/// Markers are only recognized at the start of a word, so e-mail addresses and URLs stay
+/// part of the content. When a marker is repeated the last one wins, except for labels.
+pub fn parse_quick_add(input: &str) -> QuickAdd {
+    let (text, description) = split_description(input);
+    let (text, deadline) = split_deadline(text);
+    let mut parsed = QuickAdd {
+        deadline,
+        description,
+        ..QuickAdd::default()
+    };
+
+    let all_words: Vec<&str> = text.split_whitespace().collect();
+    let mut words = Vec::new();
+    let mut index = 0;
+    while index < all_words.len() {
+        let word = all_words[index];
+        index += 1;
+        if let Some((duration, len)) = duration_phrase(word, &all_words[index..]) {
+            parsed.duration = Some(duration);
+            index += len;
+        } else if let Some(name) = marker(word, '#') {
+            parsed.project = Some(name.to_string());
+        } else if let Some(name) = marker(word, '/') {
+            parsed.section = Some(name.to_string());
+        } else if let Some(name) = marker(word, '@') {
+            if !parsed.labels.iter().any(|label| label.eq_ignore_ascii_case(name)) {
+                parsed.labels.push(name.to_string());
+            }
+        } else if let Some(priority) = priority(word) {
+            parsed.priority = Some(priority);
+        } else {
+            words.push(word);
+        }
+    }
+
+    // Only the first date phrase is the due date, later ones are part of the content
+    if let Some(start) = (0..words.len()).find(|&start| phrase_len(&words[start..]) > 0) {
+        let mut end = start;
+        loop {
+            let len = phrase_len(&words[end..]);
+            if len == 0 {
+                break;
+            }
+            end += len;
+        }
+        parsed.due_string = Some(normalize_due_string(&words[start..end].join(" ")));
+        words.drain(start..end);
+    }
+
+    parsed.content = words.join(" ");
+    parsed
+}
+
+/// Whether a project, section or label name matches the name typed after a marker.
+///
+/// Case is ignored. Markers end at whitespace, so the spaces of a name may be left out
+/// or typed as underscores ("#GroceryList" and "#grocery_list" both match "Grocery List").
+pub fn matches_name(name: &str, typed: &str) -> bool {
+    let simplify = |text: &str| -> String {
+        text.chars()
+            .filter(|c| !c.is_whitespace() && *c != '_')
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    simplify(name) == simplify(typed)
+}
+
+/// Split the input at the first `//` that starts a word
+fn split_description(input: &str) -> (&str, Option<String>) {
+    let position = input
+        .match_indices("//")
+        .map(|(position, _)| position)
+        .find(|&position| input[..position].chars().last().map_or(true, char::is_whitespace));
+
+    match position {
+        Some(position) => {
+            let description = input[position + 2..].trim();
+            let description = (!description.is_empty()).then(|| description.to_string());
+            (&input[..position], description)
+        }
+        None => (input, None),
+    }
+}
+
+/// Remove the `{...}` deadlines that start a word, returning the text and the last deadline
+fn split_deadline(input: &str) -> (String, Option<String>) {
+    let mut text = input.to_string();
+    let mut deadline = None;
+    let mut search_from = 0;
+    while let Some(open) = text[search_from..].find('{').map(|open| search_from + open) {
+        let starts_word = text[..open].chars().last().map_or(true, char::is_whitespace);
+        match text[open..].find('}').map(|close| open + close) {
+            Some(close) if starts_word => {
+                let inner = text[open + 1..close].trim();
+                if !inner.is_empty() {
+                    deadline = Some(inner.to_string());
+                }
+                text.replace_range(open..=close, " ");
+                search_from = open;
+            }
+            _ => search_from = open + 1,
+        }
+    }
+    (text, deadline)
+}
+
+/// The normalized duration and number of words it takes when `word` is "for" followed by a
+/// duration. Durations must name their unit, so "table for 2" keeps its number.
+fn duration_phrase(word: &str, rest: &[&str]) -> Option<(String, usize)> {
+    if !word.eq_ignore_ascii_case("for") {
+        return None;
+    }
+    (1..=2).rev().find_map(|len| {
+        let phrase = rest.get(..len)?.join(" ");
+        if !phrase.chars().any(char::is_alphabetic) {
+            return None;
+        }
+        normalize_duration(&phrase).map(|duration| (duration, len))
+    })
+}
+
+/// The name following a marker character, if the word is a non-empty marker
+fn marker(word: &str, prefix: char) -> Option<&str> {
+    word.strip_prefix(prefix)
+        .filter(|name| !name.is_empty() && !name.starts_with(prefix))
+}
+
+/// Backend priority for a `p1`-`p4` word
+fn priority(word: &str) -> Option<i32> {
+    match word.to_lowercase().as_str() {
+        "p1" => Some(4),
+        "p2" => Some(3),
+        "p3" => Some(2),
+        "p4" => Some(1),
+        _ => None,
+    }
+}
+
+/// Number of leading words that form a single date phrase, 0 if they do not start one
+fn phrase_len(words: &[&str]) -> usize {
+    let lower: Vec<String> = words.iter().take(3).map(|word| word.to_lowercase()).collect();
+    let word = |index: usize| lower.get(index).map(String::as_str);
+    let Some(first) = word(0) else {
+        return 0;
+    };
+
+    match first {
+        // "tom" and "tod" are left out, they are more likely names than dates
+        "today" | "tdy" | "tomorrow" | "tmrw" | "tmr" | "tmw" => 1,
+        "next" if word(1).is_some_and(|next| is_unit(next) || parse_weekday(next).is_some()) => 2,
+        "in" if word(1).is_some_and(is_number) && word(2).is_some_and(is_unit) => 3,
+        "every" | "every!" => {
+            let len = 1 + words[1..].iter().take_while(|word| is_recurrence_word(word)).count();
+            if len > 1 {
+                len
+            } else {
+                0
+            }
+        }
+        "at" if word(1).and_then(parse_time_of_day).is_some() => 2,
+        // Abbreviated weekdays ("sat", "sun") double as words, so on their own only full names count
+        _ if is_full_weekday(first) || parse_date(first).is_ok() => 1,
+        _ if parse_time_of_day(first).is_some() => 1,
+        _ if parse_month(first).is_some() && word(1).is_some_and(is_day_of_month) => 2,
+        _ if is_day_of_month(first) && word(1).and_then(parse_month).is_some() => 2,
+        _ => 0,
+    }
+}
+
+fn is_full_weekday(word: &str) -> bool {
+    parse_weekday(word).is_some() && word.ends_with("day")
+}
+
+fn is_number(word: &str) -> bool {
+    !word.is_empty() && word.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_day_of_month(word: &str) -> bool {
+    word.parse::<u32>().is_ok_and(|day| (1..=31).contains(&day))
+}
+
+fn is_unit(word: &str) -> bool {
+    matches!(
+        word,
+        "day" | "days" | "week" | "weeks" | "month" | "months" | "year" | "years"
+    )
+}
+
+/// Words that can follow "every" in a recurring due string
+fn is_recurrence_word(word: &&str) -> bool {
+    let word = word.to_lowercase();
+    let ordinal = ["st", "nd", "rd", "th"]
+        .iter()
+        .any(|suffix| word.strip_suffix(suffix).is_some_and(is_number));
+    is_number(&word)
+        || ordinal
+        || is_unit(&word)
+        || matches!(word.as_str(), "other" | "workday" | "weekday" | "weekend")
+        || parse_weekday(&word).is_some()
+        || parse_month(&word).is_some()
+}
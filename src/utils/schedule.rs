@@ -0,0 +1,86 @@
+//! Time blocking of the tasks of a single day.
+//!
+//! Tasks due at a time of day occupy a block on the timeline from their due time for
+//! their duration. Tasks without a duration only mark a point in time and never overlap.
+//! Everything else due that day, or overdue, is left unscheduled.
+
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+use crate::entities::task;
+use crate::utils::datetime::{duration_minutes, parse_datetime};
+
+/// The time a task occupies on the timeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeBlock {
+    pub start: NaiveDateTime,
+    /// Equal to `start` for tasks without a duration; blocks spanning midnight end there
+    pub end: NaiveDateTime,
+    /// Whether another open task is planned for part of the same time
+    pub overlaps: bool,
+}
+
+/// The tasks of a day, laid out on a timeline
+#[derive(Debug, Clone, Default)]
+pub struct DaySchedule {
+    /// Tasks with a time of day, ordered by start time
+    pub blocks: Vec<(task::Model, TimeBlock)>,
+    /// Tasks without a time of day on this day, in their original order
+    pub unscheduled: Vec<task::Model>,
+}
+
+impl DaySchedule {
+    /// Number of tasks that overlap another task
+    #[must_use]
+    pub fn overlap_count(&self) -> usize {
+        self.blocks.iter().filter(|(_, block)| block.overlaps).count()
+    }
+}
+
+/// Lay out the tasks due at a time of `day` on a timeline and flag overlapping ones.
+///
+/// Completed tasks keep their block but no longer claim the time, so they neither overlap
+/// nor cause overlaps.
+pub fn schedule_day(tasks: &[task::Model], day: NaiveDate) -> DaySchedule {
+    let day_end = (day + Duration::days(1)).and_hms_opt(0, 0, 0).unwrap_or(NaiveDateTime::MAX);
+
+    let mut schedule = DaySchedule::default();
+    for task in tasks {
+        let start = task
+            .due_datetime
+            .as_deref()
+            .and_then(parse_datetime)
+            .map(|start| start.naive_local())
+            .filter(|start| start.date() == day);
+        match start {
+            Some(start) => {
+                let minutes = task.duration.as_deref().and_then(duration_minutes).unwrap_or(0);
+                let end = (start + Duration::minutes(minutes)).min(day_end);
+                schedule.blocks.push((
+                    task.clone(),
+                    TimeBlock {
+                        start,
+                        end,
+                        overlaps: false,
+                    },
+                ));
+            }
+            None => schedule.unscheduled.push(task.clone()),
+        }
+    }
+    schedule.blocks.sort_by_key(|(_, block)| block.start);
+
+    let claims_time = |(task, block): &(task::Model, TimeBlock)| !task.is_completed && block.start < block.end;
+    for i in 0..schedule.blocks.len() {
+        if !claims_time(&schedule.blocks[i]) {
+            continue;
+        }
+        let (start, end) = (schedule.blocks[i].1.start, schedule.blocks[i].1.end);
+        let overlaps = schedule
+            .blocks
+            .iter()
+            .enumerate()
+            .any(|(j, other)| j != i && claims_time(other) && other.1.start < end && start < other.1.end);
+        schedule.blocks[i].1.overlaps = overlaps;
+    }
+    schedule
+}
@@ -0,0 +1,12 @@
+//! Helpers for fitting text into fixed-width terminal cells.
+
+/// Cut text to the given number of characters, marking the cut with an ellipsis
+pub fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        text.to_string()
+    } else {
+        let mut cut: String = text.chars().take(width.saturating_sub(1)).collect();
+        cut.push('…');
+        cut
+    }
+}
@@ -0,0 +1,394 @@
+#[path = "fixtures/backend_task.rs"]
+mod fixtures;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::{Duration, Instant};
+use terminalist::backend::resilient::{RateLimitSettings, ResilienceSettings, ResilientBackend, RetrySettings};
+use terminalist::backend::{
+    Backend, BackendComment, BackendError, BackendLabel, BackendProject, BackendSection, BackendTask,
+    CreateCommentArgs, CreateLabelArgs, CreateProjectArgs, CreateSectionArgs, CreateTaskArgs, MoveTaskArgs,
+    ProjectOrder, SectionOrder, TaskOrder, UpdateCommentArgs, UpdateLabelArgs, UpdateProjectArgs, UpdateSectionArgs,
+    UpdateTaskArgs,
+};
+use terminalist::sync::outbox::new_request_id;
+use terminalist::sync::SyncStatus;
+use terminalist::todoist::TodoistError;
+
+#[test]
+fn test_http_status_classification() {
+    let classify = |status| BackendError::from_status(status, "body".to_string(), Some(30));
+
+    assert!(matches!(classify(401), BackendError::Auth(_)));
+    assert!(matches!(classify(403), BackendError::Auth(_)));
+    assert!(matches!(classify(404), BackendError::NotFound(_)));
+    assert!(matches!(classify(409), BackendError::Conflict(_)));
+    assert!(matches!(classify(400), BackendError::InvalidData(_)));
+    assert!(matches!(
+        classify(429),
+        BackendError::RateLimited {
+            retry_after: Some(30),
+            ..
+        }
+    ));
+    assert!(matches!(classify(503), BackendError::Server { status: 503, .. }));
+    assert!(matches!(classify(418), BackendError::Other(_)));
+}
+
+#[test]
+fn test_only_transient_errors_are_retryable() {
+    assert!(BackendError::Network("timeout".to_string()).is_retryable());
+    assert!(BackendError::from_status(429, String::new(), None).is_retryable());
+    assert!(BackendError::from_status(502, String::new(), None).is_retryable());
+
+    assert!(!BackendError::from_status(401, String::new(), None).is_retryable());
+    assert!(!BackendError::from_status(404, String::new(), None).is_retryable());
+    assert!(!BackendError::from_status(409, String::new(), None).is_retryable());
+}
+
+#[test]
+fn test_todoist_errors_keep_their_kind() {
+    let auth: BackendError = TodoistError::AuthenticationError {
+        message: "bad token".to_string(),
+    }
+    .into();
+    assert!(matches!(auth, BackendError::Auth(_)));
+
+    let limited: BackendError = TodoistError::RateLimited {
+        retry_after: Some(12),
+        message: "slow down".to_string(),
+    }
+    .into();
+    assert!(matches!(
+        limited,
+        BackendError::RateLimited {
+            retry_after: Some(12),
+            ..
+        }
+    ));
+
+    let generic: BackendError = TodoistError::Generic {
+        status_code: Some(409),
+        message: "conflict".to_string(),
+    }
+    .into();
+    assert!(matches!(generic, BackendError::Conflict(_)));
+}
+
+#[test]
+fn test_sync_status_reflects_backend_error() {
+    let status = SyncStatus::failed(
+        "Failed to fetch changes",
+        &BackendError::Auth("bad token".to_string()).into(),
+    );
+    assert!(matches!(status, SyncStatus::AuthFailed { .. }));
+
+    let status = SyncStatus::failed(
+        "Failed to fetch changes",
+        &BackendError::from_status(429, String::new(), Some(5)).into(),
+    );
+    assert!(matches!(
+        status,
+        SyncStatus::RateLimited {
+            retry_after: Some(5),
+            ..
+        }
+    ));
+
+    let status = SyncStatus::failed(
+        "Failed to fetch changes",
+        &BackendError::Network("down".to_string()).into(),
+    );
+    assert!(matches!(status, SyncStatus::Error { .. }));
+    assert!(status.is_failure());
+    assert_eq!(
+        status.error_message(),
+        Some("Failed to fetch changes: Network error: down")
+    );
+}
+
+/// Backend whose `create_task` fails with `error` a number of times before succeeding.
+#[derive(Clone)]
+struct FlakyBackend {
+    failures_left: Arc<AtomicU32>,
+    error: fn() -> BackendError,
+    request_ids: Arc<StdMutex<Vec<Option<String>>>>,
+}
+
+impl FlakyBackend {
+    fn new(failures: u32, error: fn() -> BackendError) -> Self {
+        Self {
+            failures_left: Arc::new(AtomicU32::new(failures)),
+            error,
+            request_ids: Arc::new(StdMutex::new(Vec::new())),
+        }
+    }
+}
+
+fn unused<T>() -> Result<T, BackendError> {
+    Err(BackendError::Other("not used in this test".to_string()))
+}
+
+#[async_trait]
+impl Backend for FlakyBackend {
+    fn backend_type(&self) -> &str {
+        "flaky"
+    }
+
+    async fn fetch_projects(&self) -> Result<Vec<BackendProject>, BackendError> {
+        unused()
+    }
+
+    async fn fetch_tasks(&self) -> Result<Vec<BackendTask>, BackendError> {
+        unused()
+    }
+
+    async fn fetch_labels(&self) -> Result<Vec<BackendLabel>, BackendError> {
+        unused()
+    }
+
+    async fn fetch_sections(&self) -> Result<Vec<BackendSection>, BackendError> {
+        unused()
+    }
+
+    async fn fetch_task(&self, _remote_id: &str) -> Result<BackendTask, BackendError> {
+        unused()
+    }
+
+    async fn fetch_completed_tasks(
+        &self,
+        _since: DateTime<Utc>,
+        _until: DateTime<Utc>,
+    ) -> Result<Vec<BackendTask>, BackendError> {
+        unused()
+    }
+
+    async fn create_project(&self, _args: CreateProjectArgs) -> Result<BackendProject, BackendError> {
+        unused()
+    }
+
+    async fn update_project(&self, _remote_id: &str, _args: UpdateProjectArgs) -> Result<BackendProject, BackendError> {
+        unused()
+    }
+
+    async fn delete_project(&self, _remote_id: &str) -> Result<(), BackendError> {
+        unused()
+    }
+
+    async fn reorder_projects(&self, _orders: Vec<ProjectOrder>) -> Result<(), BackendError> {
+        unused()
+    }
+
+    async fn create_task(&self, args: CreateTaskArgs) -> Result<BackendTask, BackendError> {
+        self.request_ids.lock().unwrap().push(args.request_id.clone());
+        if self
+            .failures_left
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| left.checked_sub(1))
+            .is_ok()
+        {
+            return Err((self.error)());
+        }
+        Ok(BackendTask {
+            content: args.content,
+            project_remote_id: args.project_remote_id,
+            ..fixtures::backend_task()
+        })
+    }
+
+    async fn update_task(&self, _remote_id: &str, _args: UpdateTaskArgs) -> Result<BackendTask, BackendError> {
+        unused()
+    }
+
+    async fn delete_task(&self, _remote_id: &str) -> Result<(), BackendError> {
+        unused()
+    }
+
+    async fn complete_task(&self, _remote_id: &str) -> Result<(), BackendError> {
+        unused()
+    }
+
+    async fn reopen_task(&self, _remote_id: &str) -> Result<(), BackendError> {
+        unused()
+    }
+
+    async fn move_task(&self, _remote_id: &str, _args: MoveTaskArgs) -> Result<(), BackendError> {
+        unused()
+    }
+
+    async fn reorder_tasks(&self, _orders: Vec<TaskOrder>) -> Result<(), BackendError> {
+        unused()
+    }
+
+    async fn create_label(&self, _args: CreateLabelArgs) -> Result<BackendLabel, BackendError> {
+        unused()
+    }
+
+    async fn update_label(&self, _remote_id: &str, _args: UpdateLabelArgs) -> Result<BackendLabel, BackendError> {
+        unused()
+    }
+
+    async fn delete_label(&self, _remote_id: &str) -> Result<(), BackendError> {
+        unused()
+    }
+
+    async fn create_section(&self, _args: CreateSectionArgs) -> Result<BackendSection, BackendError> {
+        unused()
+    }
+
+    async fn update_section(&self, _remote_id: &str, _args: UpdateSectionArgs) -> Result<BackendSection, BackendError> {
+        unused()
+    }
+
+    async fn delete_section(&self, _remote_id: &str) -> Result<(), BackendError> {
+        unused()
+    }
+
+    async fn reorder_sections(&self, _orders: Vec<SectionOrder>) -> Result<(), BackendError> {
+        unused()
+    }
+
+    async fn fetch_comments(&self, _task_remote_id: &str) -> Result<Vec<BackendComment>, BackendError> {
+        unused()
+    }
+
+    async fn create_comment(&self, _args: CreateCommentArgs) -> Result<BackendComment, BackendError> {
+        unused()
+    }
+
+    async fn update_comment(&self, _remote_id: &str, _args: UpdateCommentArgs) -> Result<BackendComment, BackendError> {
+        unused()
+    }
+
+    async fn delete_comment(&self, _remote_id: &str) -> Result<(), BackendError> {
+        unused()
+    }
+}
+
+fn fast_settings() -> ResilienceSettings {
+    ResilienceSettings::from_json(
+        r#"{"retry": {"max_attempts": 3, "base_delay_ms": 1, "max_delay_ms": 5}, "rate_limit": {"requests_per_minute": 0}}"#,
+    )
+    .unwrap()
+}
+
+fn task_args() -> CreateTaskArgs {
+    CreateTaskArgs {
+        content: "Buy milk".to_string(),
+        description: None,
+        project_remote_id: "p1".to_string(),
+        section_remote_id: None,
+        parent_remote_id: None,
+        priority: None,
+        due_date: None,
+        due_datetime: None,
+        due_string: None,
+        deadline: None,
+        duration: None,
+        labels: Vec::new(),
+        request_id: None,
+    }
+}
+
+#[test]
+fn test_resilience_settings_fall_back_to_defaults() {
+    assert_eq!(
+        ResilienceSettings::from_json("{}").unwrap(),
+        ResilienceSettings::default()
+    );
+    assert_eq!(
+        ResilienceSettings::from_json("").unwrap(),
+        ResilienceSettings::default()
+    );
+
+    let settings = ResilienceSettings::from_json(r#"{"retry": {"max_attempts": 2}, "theme": "dark"}"#).unwrap();
+    assert_eq!(settings.retry.max_attempts, 2);
+    assert_eq!(settings.retry.base_delay_ms, RetrySettings::default().base_delay_ms);
+    assert_eq!(settings.rate_limit, RateLimitSettings::default());
+
+    assert!(ResilienceSettings::from_json(r#"{"retry": {"max_attempts": "many"}}"#).is_err());
+}
+
+#[test]
+fn test_retry_delay_grows_and_respects_retry_after() {
+    let retry = RetrySettings {
+        max_attempts: 5,
+        base_delay_ms: 100,
+        max_delay_ms: 1_000,
+    };
+
+    // Jitter picks a point in the upper half of the exponential delay
+    assert_eq!(retry.delay(1, None, 0.0), Duration::from_millis(50));
+    assert_eq!(retry.delay(1, None, 1.0), Duration::from_millis(100));
+    assert_eq!(retry.delay(3, None, 1.0), Duration::from_millis(400));
+    assert_eq!(retry.delay(10, None, 1.0), Duration::from_millis(1_000));
+
+    assert_eq!(retry.delay(1, Some(3), 0.5), Duration::from_secs(3));
+}
+
+#[tokio::test]
+async fn test_retries_reuse_the_idempotency_key() {
+    let flaky = FlakyBackend::new(2, || BackendError::from_status(503, String::new(), None));
+    let backend = ResilientBackend::new(Box::new(flaky.clone()), fast_settings());
+
+    let task = backend.create_task(task_args()).await.unwrap();
+    assert_eq!(task.remote_id, "t1");
+
+    let request_ids = flaky.request_ids.lock().unwrap();
+    assert_eq!(request_ids.len(), 3);
+    assert!(request_ids[0].is_some());
+    assert!(request_ids.iter().all(|id| id == &request_ids[0]));
+}
+
+#[tokio::test]
+async fn test_enqueued_idempotency_key_is_sent_on_every_replay() {
+    // The first replay gives up after its retries, the next one succeeds
+    let flaky = FlakyBackend::new(3, || BackendError::Network("down".to_string()));
+    let backend = ResilientBackend::new(Box::new(flaky.clone()), fast_settings());
+    let args = CreateTaskArgs {
+        request_id: Some(new_request_id()),
+        ..task_args()
+    };
+
+    assert!(backend.create_task(args.clone()).await.is_err());
+    assert!(backend.create_task(args.clone()).await.is_ok());
+
+    let request_ids = flaky.request_ids.lock().unwrap();
+    assert_eq!(request_ids.len(), 4);
+    assert!(request_ids.iter().all(|id| id == &args.request_id));
+}
+
+#[tokio::test]
+async fn test_gives_up_after_max_attempts_and_on_permanent_errors() {
+    let flaky = FlakyBackend::new(5, || BackendError::Network("down".to_string()));
+    let backend = ResilientBackend::new(Box::new(flaky.clone()), fast_settings());
+    assert!(matches!(
+        backend.create_task(task_args()).await,
+        Err(BackendError::Network(_))
+    ));
+    assert_eq!(flaky.request_ids.lock().unwrap().len(), 3);
+
+    let flaky = FlakyBackend::new(1, || BackendError::Auth("bad token".to_string()));
+    let backend = ResilientBackend::new(Box::new(flaky.clone()), fast_settings());
+    assert!(matches!(
+        backend.create_task(task_args()).await,
+        Err(BackendError::Auth(_))
+    ));
+    assert_eq!(flaky.request_ids.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_rate_limiter_spaces_out_requests_after_burst() {
+    let flaky = FlakyBackend::new(0, || BackendError::Network("down".to_string()));
+    let settings =
+        ResilienceSettings::from_json(r#"{"rate_limit": {"requests_per_minute": 1200, "burst": 1}}"#).unwrap();
+    let backend = ResilientBackend::new(Box::new(flaky.clone()), settings);
+
+    // 1200 per minute is one request every 50ms once the single burst token is used
+    let started = Instant::now();
+    for _ in 0..3 {
+        backend.create_task(task_args()).await.unwrap();
+    }
+    assert!(started.elapsed() >= Duration::from_millis(90));
+}
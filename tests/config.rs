@@ -78,6 +78,37 @@ fn test_empty_config_deserialization() {
     assert_eq!(config.display.date_format, default_config.display.date_format);
 }
 
+#[test]
+fn test_saved_filters_deserialization_and_validation() {
+    let filters_toml = r##"
+[[filters]]
+name = "Urgent work"
+query = "(p1 | p2) & @work"
+
+[[filters]]
+name = "Inbox zero"
+query = "#Inbox & no date"
+"##;
+
+    let mut config: Config = toml::from_str(filters_toml).unwrap();
+    assert_eq!(config.filters.len(), 2);
+    assert_eq!(config.filters[0].name, "Urgent work");
+    assert_eq!(config.filters[1].query, "#Inbox & no date");
+    assert!(config.validate().is_ok());
+
+    // Filters survive a round trip through the config file format
+    let toml_str = toml::to_string_pretty(&config).unwrap();
+    let reloaded: Config = toml::from_str(&toml_str).unwrap();
+    assert_eq!(reloaded.filters, config.filters);
+
+    config.filters[1].query = "p1 &".to_string();
+    assert!(config.validate().is_err());
+
+    config.filters[1].query = "today".to_string();
+    config.filters[1].name = " ".to_string();
+    assert!(config.validate().is_err());
+}
+
 #[test]
 fn test_generate_config_creates_directory() {
     use std::fs;
@@ -0,0 +1,30 @@
+//! A remote task to build test data from.
+
+use terminalist::backend::BackendTask;
+
+/// The open top-level task "t1" without dates or labels in the project "p1".
+///
+/// Tests set the fields they care about with struct update syntax, e.g.
+/// `BackendTask { content: ..., ..fixtures::backend_task() }`.
+pub fn backend_task() -> BackendTask {
+    BackendTask {
+        remote_id: "t1".to_string(),
+        content: String::new(),
+        description: None,
+        project_remote_id: "p1".to_string(),
+        section_remote_id: None,
+        parent_remote_id: None,
+        priority: 1,
+        order_index: 0,
+        due_date: None,
+        due_datetime: None,
+        is_recurring: false,
+        due_string: None,
+        deadline: None,
+        duration: None,
+        is_completed: false,
+        completed_at: None,
+        created_at: None,
+        labels: Vec::new(),
+    }
+}
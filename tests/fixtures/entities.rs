@@ -0,0 +1,82 @@
+//! Local entities to build test data from.
+//!
+//! Each one is a fresh row with new UUIDs and neutral values; tests set the fields they care
+//! about with struct update syntax, e.g. `task::Model { content: ..., ..fixtures::task() }`.
+
+use terminalist::entities::{label, project, section, task};
+use uuid::Uuid;
+
+/// An open top-level task without dates, in a project of its own.
+pub fn task() -> task::Model {
+    let uuid = Uuid::new_v4();
+    task::Model {
+        uuid,
+        backend_uuid: Uuid::new_v4(),
+        remote_id: uuid.to_string(),
+        content: String::new(),
+        description: None,
+        project_uuid: Uuid::new_v4(),
+        section_uuid: None,
+        parent_uuid: None,
+        priority: 1,
+        order_index: 0,
+        due_date: None,
+        due_datetime: None,
+        is_recurring: false,
+        due_string: None,
+        deadline: None,
+        duration: None,
+        is_completed: false,
+        completed_at: None,
+        is_deleted: false,
+        remote_snapshot: None,
+        is_collapsed: false,
+        created_at: None,
+    }
+}
+
+/// A top-level project that is not the inbox.
+pub fn project() -> project::Model {
+    let uuid = Uuid::new_v4();
+    project::Model {
+        uuid,
+        backend_uuid: Uuid::new_v4(),
+        remote_id: uuid.to_string(),
+        name: String::new(),
+        is_favorite: false,
+        is_inbox_project: false,
+        order_index: 0,
+        parent_uuid: None,
+        color: None,
+        remote_snapshot: None,
+    }
+}
+
+/// A section of a project of its own.
+pub fn section() -> section::Model {
+    let uuid = Uuid::new_v4();
+    section::Model {
+        uuid,
+        backend_uuid: Uuid::new_v4(),
+        remote_id: uuid.to_string(),
+        name: String::new(),
+        project_uuid: Uuid::new_v4(),
+        order_index: 0,
+        remote_snapshot: None,
+    }
+}
+
+/// A label without a color.
+pub fn label() -> label::Model {
+    let uuid = Uuid::new_v4();
+    label::Model {
+        uuid,
+        backend_uuid: Uuid::new_v4(),
+        remote_id: uuid.to_string(),
+        name: String::new(),
+        order_index: 0,
+        is_favorite: false,
+        color: None,
+        remote_snapshot: None,
+    }
+}
@@ -1,2 +1,35 @@
+#[path = "storage/common.rs"]
+mod common;
+
+#[path = "fixtures/backend_task.rs"]
+mod fixtures;
+
 #[path = "storage/db.rs"]
 mod db;
+
+#[path = "storage/migrations.rs"]
+mod migrations;
+
+#[path = "storage/outbox.rs"]
+mod outbox;
+
+#[path = "storage/backends.rs"]
+mod backends;
+
+#[path = "storage/pruning.rs"]
+mod pruning;
+
+#[path = "storage/conflicts.rs"]
+mod conflicts;
+
+#[path = "storage/sections.rs"]
+mod sections;
+
+#[path = "storage/comments.rs"]
+mod comments;
+
+#[path = "storage/logbook.rs"]
+mod logbook;
+
+#[path = "storage/tasks.rs"]
+mod tasks;
@@ -0,0 +1,47 @@
+use crate::common::TempDb;
+use sea_orm::ActiveValue;
+use terminalist::entities::backend;
+use terminalist::repositories::BackendRepository;
+use terminalist::storage::LocalStorage;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_sync_cursor_is_stored_per_backend() {
+    let db = TempDb::new("cursor");
+    let storage = LocalStorage::open(db.path()).await.unwrap();
+
+    let mut uuids = Vec::new();
+    for name in ["First", "Second"] {
+        let model = BackendRepository::create(
+            &storage.conn,
+            backend::ActiveModel {
+                uuid: ActiveValue::Set(Uuid::new_v4()),
+                backend_type: ActiveValue::Set("todoist".to_string()),
+                name: ActiveValue::Set(name.to_string()),
+                is_enabled: ActiveValue::Set(true),
+                credentials: ActiveValue::Set("{}".to_string()),
+                settings: ActiveValue::Set("{}".to_string()),
+                sync_cursor: ActiveValue::Set(None),
+            },
+        )
+        .await
+        .unwrap();
+        uuids.push(model.uuid);
+    }
+
+    BackendRepository::set_sync_cursor(&storage.conn, &uuids[0], Some("token-1"))
+        .await
+        .unwrap();
+
+    let first = BackendRepository::get_by_uuid(&storage.conn, &uuids[0]).await.unwrap().unwrap();
+    let second = BackendRepository::get_by_uuid(&storage.conn, &uuids[1]).await.unwrap().unwrap();
+    assert_eq!(first.sync_cursor.as_deref(), Some("token-1"));
+    assert_eq!(second.sync_cursor, None);
+
+    // Clearing the cursor forces the next sync to be a full one
+    BackendRepository::set_sync_cursor(&storage.conn, &uuids[0], None)
+        .await
+        .unwrap();
+    let first = BackendRepository::get_by_uuid(&storage.conn, &uuids[0]).await.unwrap().unwrap();
+    assert_eq!(first.sync_cursor, None);
+}
@@ -0,0 +1,129 @@
+use crate::common::{pending_operations, sync_service, TempDb};
+use std::sync::Arc;
+use terminalist::backend::{BackendChanges, BackendComment, BackendProject, BackendTask};
+use terminalist::storage::LocalStorage;
+use terminalist::sync::outbox::{is_temp_remote_id, OutboxOperation};
+use terminalist::sync::SyncService;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+fn comment(remote_id: &str, content: &str, posted_at: &str) -> BackendComment {
+    BackendComment {
+        remote_id: remote_id.to_string(),
+        task_remote_id: "t1".to_string(),
+        content: content.to_string(),
+        posted_at: Some(posted_at.to_string()),
+    }
+}
+
+/// Sync service over a project with one task that has a comment.
+async fn seeded_service(db: &TempDb) -> (SyncService, Arc<Mutex<LocalStorage>>, Uuid) {
+    let (service, storage, _) = sync_service(db).await;
+
+    let changes = BackendChanges {
+        projects: vec![BackendProject {
+            remote_id: "p1".to_string(),
+            name: "Project".to_string(),
+            is_favorite: false,
+            is_inbox: false,
+            order_index: 0,
+            parent_remote_id: None,
+            color: None,
+        }],
+        tasks: vec![BackendTask {
+            content: "Write docs".to_string(),
+            ..crate::fixtures::backend_task()
+        }],
+        comments: vec![comment("c1", "First draft is up", "2025-01-15T09:00:00.000000Z")],
+        ..Default::default()
+    };
+    service.store_changes(changes).await.unwrap();
+
+    let task_uuid = service.get_all_tasks().await.unwrap()[0].uuid;
+    (service, storage, task_uuid)
+}
+
+#[tokio::test]
+async fn test_create_comment_appends_to_thread_and_queues_create() {
+    let db = TempDb::new("create");
+    let (service, storage, task_uuid) = seeded_service(&db).await;
+
+    service.create_comment(&task_uuid, "Reviewed, looks good").await.unwrap();
+
+    let comments = service.get_comments_for_task(&task_uuid).await.unwrap();
+    let contents: Vec<&str> = comments.iter().map(|c| c.content.as_str()).collect();
+    assert_eq!(contents, ["First draft is up", "Reviewed, looks good"]);
+    let created = &comments[1];
+    assert!(is_temp_remote_id(&created.remote_id));
+
+    match pending_operations(&storage, &created.uuid).await.as_slice() {
+        [OutboxOperation::CreateComment(args)] => {
+            assert_eq!(args.task_remote_id, "t1");
+            assert_eq!(args.content, "Reviewed, looks good");
+        }
+        other => panic!("unexpected operations: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_sync_upserts_and_removes_comments() {
+    let db = TempDb::new("sync");
+    let (service, _storage, task_uuid) = seeded_service(&db).await;
+
+    let changes = BackendChanges {
+        comments: vec![
+            comment("c1", "First draft is up (edited)", "2025-01-15T09:00:00.000000Z"),
+            comment("c2", "Second pass done", "2025-01-16T09:00:00.000000Z"),
+        ],
+        ..Default::default()
+    };
+    service.store_changes(changes).await.unwrap();
+
+    let comments = service.get_comments_for_task(&task_uuid).await.unwrap();
+    let contents: Vec<&str> = comments.iter().map(|c| c.content.as_str()).collect();
+    assert_eq!(contents, ["First draft is up (edited)", "Second pass done"]);
+
+    let changes = BackendChanges {
+        removed_comment_ids: vec!["c1".to_string()],
+        ..Default::default()
+    };
+    service.store_changes(changes).await.unwrap();
+
+    let comments = service.get_comments_for_task(&task_uuid).await.unwrap();
+    assert_eq!(comments.len(), 1);
+    assert_eq!(comments[0].remote_id, "c2");
+}
+
+#[tokio::test]
+async fn test_comment_edit_survives_sync_while_pending() {
+    let db = TempDb::new("edit");
+    let (service, _storage, task_uuid) = seeded_service(&db).await;
+    let first = service.get_comments_for_task(&task_uuid).await.unwrap()[0].clone();
+
+    service.update_comment_content(&first.uuid, "Final draft is up").await.unwrap();
+
+    // The remote still reports the old text until the edit is pushed
+    let changes = BackendChanges {
+        comments: vec![comment("c1", "First draft is up", "2025-01-15T09:00:00.000000Z")],
+        ..Default::default()
+    };
+    service.store_changes(changes).await.unwrap();
+
+    let comments = service.get_comments_for_task(&task_uuid).await.unwrap();
+    assert_eq!(comments[0].content, "Final draft is up");
+}
+
+#[tokio::test]
+async fn test_delete_comment_queues_delete() {
+    let db = TempDb::new("delete");
+    let (service, storage, task_uuid) = seeded_service(&db).await;
+    let first = service.get_comments_for_task(&task_uuid).await.unwrap()[0].clone();
+
+    service.delete_comment(&first.uuid).await.unwrap();
+
+    assert!(service.get_comments_for_task(&task_uuid).await.unwrap().is_empty());
+    assert!(matches!(
+        pending_operations(&storage, &first.uuid).await.as_slice(),
+        [OutboxOperation::DeleteComment]
+    ));
+}
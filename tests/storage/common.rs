@@ -0,0 +1,72 @@
+//! Helpers shared by the storage tests.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use terminalist::backend_registry::BackendRegistry;
+use terminalist::repositories::PendingOperationRepository;
+use terminalist::storage::LocalStorage;
+use terminalist::sync::outbox::OutboxOperation;
+use terminalist::sync::SyncService;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+/// A database file in the temp directory, deleted with its SQLite side files when dropped.
+///
+/// Declare it before anything that holds the database open so it is dropped last.
+pub struct TempDb {
+    path: PathBuf,
+}
+
+impl TempDb {
+    pub fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join("terminalist_tests");
+        std::fs::create_dir_all(&dir).unwrap();
+        Self {
+            path: dir.join(format!("{}-{}.db", name, Uuid::new_v4())),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDb {
+    fn drop(&mut self) {
+        for suffix in ["", "-wal", "-shm", "-journal"] {
+            let mut path = self.path.clone().into_os_string();
+            path.push(suffix);
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+/// A sync service on the database with a Todoist backend, returned with its storage and
+/// backend uuid.
+pub async fn sync_service(db: &TempDb) -> (SyncService, Arc<Mutex<LocalStorage>>, Uuid) {
+    let storage = Arc::new(Mutex::new(LocalStorage::open(db.path()).await.unwrap()));
+    let registry = Arc::new(BackendRegistry::new(storage.clone()));
+    // The backend is unreachable in tests, don't wait for retries
+    let backend_uuid = registry
+        .add_backend(
+            "todoist".to_string(),
+            "Test".to_string(),
+            r#"{"api_token":"test"}"#.to_string(),
+            r#"{"retry":{"max_attempts":1}}"#.to_string(),
+        )
+        .await
+        .unwrap();
+    let service = SyncService::new(registry, backend_uuid, false).await.unwrap();
+    (service, storage, backend_uuid)
+}
+
+/// The operations queued for an entity, oldest first
+pub async fn pending_operations(storage: &Arc<Mutex<LocalStorage>>, entity_uuid: &Uuid) -> Vec<OutboxOperation> {
+    let storage = storage.lock().await;
+    PendingOperationRepository::get_for_entity(&storage.conn, entity_uuid)
+        .await
+        .unwrap()
+        .iter()
+        .map(|pending| serde_json::from_str(&pending.payload).unwrap())
+        .collect()
+}
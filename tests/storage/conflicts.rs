@@ -0,0 +1,154 @@
+use crate::common::{sync_service, TempDb};
+use sea_orm::{ActiveValue, IntoActiveModel};
+use serde_json::{json, Map, Value};
+use terminalist::backend::{BackendChanges, BackendProject, BackendTask, UpdateTaskArgs};
+use terminalist::repositories::{PendingOperationRepository, TaskRepository};
+use terminalist::sync::conflicts::{merge_fields, ConflictResolution, Fields};
+use terminalist::sync::outbox::OutboxOperation;
+use terminalist::sync::{SyncService, SyncStatus};
+
+fn fields(value: Value) -> Fields {
+    match value {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    }
+}
+
+fn changes(content: &str, priority: i32) -> BackendChanges {
+    BackendChanges {
+        projects: vec![BackendProject {
+            remote_id: "p1".to_string(),
+            name: "Project".to_string(),
+            is_favorite: false,
+            is_inbox: false,
+            order_index: 0,
+            parent_remote_id: None,
+            color: None,
+        }],
+        tasks: vec![BackendTask {
+            content: content.to_string(),
+            priority,
+            ..crate::fixtures::backend_task()
+        }],
+        ..Default::default()
+    }
+}
+
+async fn store(service: &SyncService, changes: BackendChanges) {
+    let status = service.store_changes(changes).await.unwrap();
+    assert!(matches!(status, SyncStatus::Success), "unexpected status: {:?}", status);
+}
+
+#[test]
+fn test_merge_fields_takes_non_overlapping_changes_from_both_sides() {
+    let base = fields(json!({"content": "Buy milk", "priority": 1, "description": null}));
+    let local = fields(json!({"content": "Buy oat milk", "priority": 1, "description": null}));
+    let remote = fields(json!({"content": "Buy milk", "priority": 4, "description": "2 litres"}));
+
+    let outcome = merge_fields(&base, &local, &remote);
+
+    assert!(outcome.conflicts.is_empty());
+    assert_eq!(outcome.merged["content"], json!("Buy oat milk"));
+    assert_eq!(outcome.merged["priority"], json!(4));
+    assert_eq!(outcome.merged["description"], json!("2 litres"));
+}
+
+#[test]
+fn test_merge_fields_reports_fields_changed_on_both_sides() {
+    let base = fields(json!({"content": "Buy milk", "priority": 1}));
+    let local = fields(json!({"content": "Buy oat milk", "priority": 3}));
+    let remote = fields(json!({"content": "Buy soy milk", "priority": 3}));
+
+    let outcome = merge_fields(&base, &local, &remote);
+
+    assert_eq!(outcome.conflicts.len(), 1);
+    assert_eq!(outcome.conflicts[0].field, "content");
+    assert_eq!(outcome.conflicts[0].remote, json!("Buy soy milk"));
+    // Conflicting fields keep the local value until resolved; identical changes are not conflicts
+    assert_eq!(outcome.merged["content"], json!("Buy oat milk"));
+    assert_eq!(outcome.merged["priority"], json!(3));
+}
+
+#[test]
+fn test_without_field_drops_emptied_update() {
+    let operation = OutboxOperation::UpdateTask(UpdateTaskArgs {
+        content: Some("Buy oat milk".to_string()),
+        priority: Some(4),
+        ..Default::default()
+    });
+
+    let remaining = operation.without_field("content").unwrap();
+    match &remaining {
+        OutboxOperation::UpdateTask(args) => {
+            assert_eq!(args.content, None);
+            assert_eq!(args.priority, Some(4));
+        }
+        other => panic!("unexpected operation: {:?}", other),
+    }
+    assert!(remaining.without_field("priority").is_none());
+}
+
+#[tokio::test]
+async fn test_concurrent_edit_records_conflict_and_merges_other_fields() {
+    let db = TempDb::new("concurrent");
+    let (service, storage, backend_uuid) = sync_service(&db).await;
+
+    store(&service, changes("Buy milk", 1)).await;
+
+    // Edit the task locally while it is waiting in the outbox
+    let task_uuid = {
+        let storage = storage.lock().await;
+        let task = TaskRepository::get_by_remote_id(&storage.conn, &backend_uuid, "t1")
+            .await
+            .unwrap()
+            .unwrap();
+        let task_uuid = task.uuid;
+        let mut active_model = task.into_active_model();
+        active_model.content = ActiveValue::Set("Buy oat milk".to_string());
+        TaskRepository::update(&storage.conn, active_model).await.unwrap();
+
+        let operation = OutboxOperation::UpdateTask(UpdateTaskArgs {
+            content: Some("Buy oat milk".to_string()),
+            ..Default::default()
+        });
+        PendingOperationRepository::enqueue(
+            &storage.conn,
+            &backend_uuid,
+            operation.name(),
+            &task_uuid,
+            "t1",
+            serde_json::to_string(&operation).unwrap(),
+        )
+        .await
+        .unwrap();
+        task_uuid
+    };
+
+    store(&service, changes("Buy soy milk", 4)).await;
+
+    let conflicts = service.get_conflicts().await.unwrap();
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].entity_uuid, task_uuid);
+    assert_eq!(conflicts[0].field, "content");
+    {
+        let storage = storage.lock().await;
+        let task = TaskRepository::get_by_id(&storage.conn, &task_uuid).await.unwrap().unwrap();
+        assert_eq!(task.content, "Buy oat milk");
+        assert_eq!(task.priority, 4);
+    }
+
+    service
+        .resolve_conflict(&conflicts[0].uuid, ConflictResolution::UseRemote)
+        .await
+        .unwrap();
+
+    assert!(service.get_conflicts().await.unwrap().is_empty());
+    let storage = storage.lock().await;
+    let task = TaskRepository::get_by_id(&storage.conn, &task_uuid).await.unwrap().unwrap();
+    assert_eq!(task.content, "Buy soy milk");
+    // The local change was dropped, so nothing is left to push
+    assert!(PendingOperationRepository::get_for_entity(&storage.conn, &task_uuid)
+        .await
+        .unwrap()
+        .is_empty());
+}
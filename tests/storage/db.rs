@@ -1,8 +1,10 @@
+use crate::common::TempDb;
 use terminalist::storage::LocalStorage;
 
 #[tokio::test]
 async fn test_local_storage_creation() {
-    // Test that we can create local storage (use in-memory database for tests)
-    let result = LocalStorage::new(false).await;
+    // Test that we can create local storage (on a temporary database, never the user's)
+    let db = TempDb::new("creation");
+    let result = LocalStorage::open(db.path()).await;
     assert!(result.is_ok(), "LocalStorage should be created successfully");
 }
@@ -1,8 +1,10 @@
+use crate::common::TempDb;
 use terminalist::storage::LocalStorage;
 
 #[tokio::test]
 async fn test_labels_storage_creation() {
     // Test that we can create local storage for labels
-    let result = LocalStorage::new(false).await;
+    let db = TempDb::new("labels-creation");
+    let result = LocalStorage::open(db.path()).await;
     assert!(result.is_ok(), "LocalStorage should be created successfully");
 }
@@ -0,0 +1,118 @@
+use crate::common::{sync_service, TempDb};
+use crate::fixtures;
+use terminalist::backend::{BackendChanges, BackendProject, BackendTask};
+use terminalist::sync::SyncService;
+
+fn project() -> BackendProject {
+    BackendProject {
+        remote_id: "p1".to_string(),
+        name: "Project".to_string(),
+        is_favorite: false,
+        is_inbox: false,
+        order_index: 0,
+        parent_remote_id: None,
+        color: None,
+    }
+}
+
+/// Sync service over a project with an open task and one completed long ago.
+async fn seeded_service(db: &TempDb) -> SyncService {
+    let (service, _storage, _) = sync_service(db).await;
+
+    let changes = BackendChanges {
+        projects: vec![project()],
+        tasks: vec![
+            BackendTask {
+                content: "Write docs".to_string(),
+                ..fixtures::backend_task()
+            },
+            BackendTask {
+                remote_id: "t2".to_string(),
+                content: "File taxes".to_string(),
+                is_completed: true,
+                completed_at: Some("2025-01-15T09:00:00.000000Z".to_string()),
+                ..fixtures::backend_task()
+            },
+        ],
+        ..Default::default()
+    };
+    service.store_changes(changes).await.unwrap();
+    service
+}
+
+async fn project_task_contents(service: &SyncService) -> Vec<String> {
+    let project_uuid = service.get_projects().await.unwrap()[0].uuid;
+    let tasks = service.get_tasks_for_project(&project_uuid).await.unwrap();
+    tasks.into_iter().map(|t| t.content).collect()
+}
+
+#[tokio::test]
+async fn test_completed_tasks_survive_full_sync() {
+    let db = TempDb::new("full_sync");
+    let service = seeded_service(&db).await;
+
+    // Full snapshots only list open tasks
+    let changes = BackendChanges {
+        is_full: true,
+        projects: vec![project()],
+        tasks: vec![BackendTask {
+            content: "Write docs".to_string(),
+            ..fixtures::backend_task()
+        }],
+        ..Default::default()
+    };
+    service.store_changes(changes).await.unwrap();
+
+    let completed = service.get_completed_tasks().await.unwrap();
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].content, "File taxes");
+    assert_eq!(
+        completed[0].completed_at.as_deref(),
+        Some("2025-01-15T09:00:00.000000Z")
+    );
+
+    // Tasks reported as removed are still dropped
+    let changes = BackendChanges {
+        removed_task_ids: vec!["t2".to_string()],
+        ..Default::default()
+    };
+    service.store_changes(changes).await.unwrap();
+    assert!(service.get_completed_tasks().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_past_completions_are_only_listed_in_logbook() {
+    let db = TempDb::new("views");
+    let service = seeded_service(&db).await;
+
+    assert_eq!(project_task_contents(&service).await, ["Write docs"]);
+}
+
+#[tokio::test]
+async fn test_complete_and_reopen_track_completion_time() {
+    let db = TempDb::new("complete");
+    let service = seeded_service(&db).await;
+    let open_task = service
+        .get_all_tasks()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|t| t.content == "Write docs")
+        .unwrap();
+
+    service.complete_task(&open_task.uuid).await.unwrap();
+
+    let completed = service.get_completed_tasks().await.unwrap();
+    let contents: Vec<&str> = completed.iter().map(|t| t.content.as_str()).collect();
+    assert_eq!(contents, ["Write docs", "File taxes"]);
+    assert!(completed[0].completed_at.is_some());
+    // Completed today, so it is still shown in the project
+    assert_eq!(project_task_contents(&service).await, ["Write docs"]);
+
+    service.restore_task(&open_task.uuid).await.unwrap();
+
+    let reopened = service.get_task_by_id(&open_task.uuid).await.unwrap().unwrap();
+    assert!(!reopened.is_completed);
+    assert!(reopened.completed_at.is_none());
+    assert_eq!(service.get_completed_tasks().await.unwrap().len(), 1);
+}
@@ -0,0 +1,94 @@
+use crate::common::TempDb;
+use sea_orm::{ConnectionTrait, DbBackend, Statement};
+use terminalist::storage::migrations::{self, MigrationError};
+use terminalist::storage::LocalStorage;
+
+#[tokio::test]
+async fn test_fresh_database_is_migrated_to_latest() {
+    let db = TempDb::new("fresh");
+    let storage = LocalStorage::open(db.path()).await.unwrap();
+    assert_eq!(storage.schema_version().await.unwrap(), migrations::latest_version());
+
+    for table in [
+        "backends",
+        "projects",
+        "sections",
+        "labels",
+        "tasks",
+        "task_labels",
+        "pending_operations",
+        "sync_conflicts",
+    ] {
+        let row = storage
+            .conn
+            .query_one(Statement::from_string(
+                DbBackend::Sqlite,
+                format!(
+                    "SELECT name FROM sqlite_master WHERE type = 'table' AND name = '{}'",
+                    table
+                ),
+            ))
+            .await
+            .unwrap();
+        assert!(row.is_some(), "table {} should exist", table);
+    }
+}
+
+#[tokio::test]
+async fn test_database_is_kept_across_reopen() {
+    let db = TempDb::new("reopen");
+    {
+        let storage = LocalStorage::open(db.path()).await.unwrap();
+        storage
+            .conn
+            .execute(Statement::from_string(
+                DbBackend::Sqlite,
+                "INSERT INTO backends (uuid, backend_type, name, is_enabled, credentials, settings) \
+                 VALUES ('00000000-0000-0000-0000-000000000001', 'todoist', 'Test', 1, '{}', '{}')"
+                    .to_owned(),
+            ))
+            .await
+            .unwrap();
+    }
+
+    let storage = LocalStorage::open(db.path()).await.unwrap();
+    let row = storage
+        .conn
+        .query_one(Statement::from_string(
+            DbBackend::Sqlite,
+            "SELECT COUNT(*) AS n FROM backends".to_owned(),
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(row.try_get::<i64>("", "n").unwrap(), 1);
+    assert_eq!(storage.schema_version().await.unwrap(), migrations::latest_version());
+}
+
+#[tokio::test]
+async fn test_newer_schema_is_rejected() {
+    let db = TempDb::new("too-new");
+    {
+        let storage = LocalStorage::open(db.path()).await.unwrap();
+        storage
+            .conn
+            .execute(Statement::from_string(
+                DbBackend::Sqlite,
+                format!(
+                    "INSERT INTO schema_version (version, name, applied_at) VALUES ({}, 'future', '')",
+                    migrations::latest_version() + 1
+                ),
+            ))
+            .await
+            .unwrap();
+    }
+
+    let err = match LocalStorage::open(db.path()).await {
+        Ok(_) => panic!("opening a newer schema should fail"),
+        Err(e) => e,
+    };
+    assert!(matches!(
+        err.downcast_ref::<MigrationError>(),
+        Some(MigrationError::TooNew { .. })
+    ));
+}
@@ -0,0 +1,155 @@
+use crate::common::TempDb;
+use sea_orm::ActiveValue;
+use std::path::Path;
+use terminalist::backend::CreateTaskArgs;
+use terminalist::entities::backend;
+use terminalist::repositories::{BackendRepository, PendingOperationRepository};
+use terminalist::storage::LocalStorage;
+use terminalist::sync::outbox::{is_temp_remote_id, new_temp_remote_id, OutboxOperation};
+use uuid::Uuid;
+
+async fn open_with_backend(path: &Path) -> (LocalStorage, Uuid) {
+    let storage = LocalStorage::open(path).await.unwrap();
+    let backend = BackendRepository::create(
+        &storage.conn,
+        backend::ActiveModel {
+            uuid: ActiveValue::Set(Uuid::new_v4()),
+            backend_type: ActiveValue::Set("todoist".to_string()),
+            name: ActiveValue::Set("Test".to_string()),
+            is_enabled: ActiveValue::Set(true),
+            credentials: ActiveValue::Set("{}".to_string()),
+            settings: ActiveValue::Set("{}".to_string()),
+            sync_cursor: ActiveValue::Set(None),
+        },
+    )
+    .await
+    .unwrap();
+    (storage, backend.uuid)
+}
+
+fn create_task_args(content: &str, project_remote_id: &str) -> CreateTaskArgs {
+    CreateTaskArgs {
+        content: content.to_string(),
+        description: None,
+        project_remote_id: project_remote_id.to_string(),
+        section_remote_id: None,
+        parent_remote_id: None,
+        priority: None,
+        due_date: None,
+        due_datetime: None,
+        due_string: None,
+        deadline: None,
+        duration: None,
+        labels: Vec::new(),
+        request_id: None,
+    }
+}
+
+#[test]
+fn test_temp_remote_ids_are_recognized() {
+    let temp_id = new_temp_remote_id();
+    assert!(is_temp_remote_id(&temp_id));
+    assert!(!is_temp_remote_id("6Jf8VQXxpwv56VQ7"));
+    assert_ne!(temp_id, new_temp_remote_id());
+}
+
+#[test]
+fn test_operation_payload_round_trip() {
+    let operation = OutboxOperation::CreateTask(create_task_args("Buy milk", "inbox"));
+    let payload = serde_json::to_string(&operation).unwrap();
+    assert!(payload.contains(r#""op":"create_task""#));
+
+    match serde_json::from_str::<OutboxOperation>(&payload).unwrap() {
+        OutboxOperation::CreateTask(args) => assert_eq!(args.content, "Buy milk"),
+        other => panic!("unexpected operation: {:?}", other),
+    }
+
+    let payload = serde_json::to_string(&OutboxOperation::CompleteTask).unwrap();
+    assert!(matches!(
+        serde_json::from_str::<OutboxOperation>(&payload).unwrap(),
+        OutboxOperation::CompleteTask
+    ));
+}
+
+#[tokio::test]
+async fn test_operations_are_replayed_in_order() {
+    let db = TempDb::new("order");
+    let (storage, backend_uuid) = open_with_backend(db.path()).await;
+
+    for name in ["create_task", "update_task", "complete_task"] {
+        PendingOperationRepository::enqueue(&storage.conn, &backend_uuid, name, &Uuid::new_v4(), "1", "{}".into())
+            .await
+            .unwrap();
+    }
+
+    let operations = PendingOperationRepository::get_for_backend(&storage.conn, &backend_uuid)
+        .await
+        .unwrap();
+    let names: Vec<_> = operations.iter().map(|op| op.operation.as_str()).collect();
+    assert_eq!(names, ["create_task", "update_task", "complete_task"]);
+    assert_eq!(
+        PendingOperationRepository::count_for_backend(&storage.conn, &backend_uuid)
+            .await
+            .unwrap(),
+        3
+    );
+
+    let next = PendingOperationRepository::get_next(&storage.conn, &backend_uuid)
+        .await
+        .unwrap()
+        .unwrap();
+    PendingOperationRepository::delete(&storage.conn, &next.uuid).await.unwrap();
+    let next = PendingOperationRepository::get_next(&storage.conn, &backend_uuid)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(next.operation, "update_task");
+}
+
+#[tokio::test]
+async fn test_temp_ids_are_rewritten_in_pending_operations() {
+    let db = TempDb::new("rewrite");
+    let (storage, backend_uuid) = open_with_backend(db.path()).await;
+
+    let project_temp_id = new_temp_remote_id();
+    let task_temp_id = new_temp_remote_id();
+    let payload =
+        serde_json::to_string(&OutboxOperation::CreateTask(create_task_args("Plan", &project_temp_id))).unwrap();
+    PendingOperationRepository::enqueue(
+        &storage.conn,
+        &backend_uuid,
+        "create_task",
+        &Uuid::new_v4(),
+        &task_temp_id,
+        payload,
+    )
+    .await
+    .unwrap();
+
+    PendingOperationRepository::rewrite_remote_id(&storage.conn, &backend_uuid, &project_temp_id, "2203306141")
+        .await
+        .unwrap();
+    PendingOperationRepository::rewrite_remote_id(&storage.conn, &backend_uuid, &task_temp_id, "7025")
+        .await
+        .unwrap();
+
+    let operation = PendingOperationRepository::get_next(&storage.conn, &backend_uuid)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(operation.remote_id, "7025");
+    match serde_json::from_str::<OutboxOperation>(&operation.payload).unwrap() {
+        OutboxOperation::CreateTask(args) => assert_eq!(args.project_remote_id, "2203306141"),
+        other => panic!("unexpected operation: {:?}", other),
+    }
+
+    PendingOperationRepository::record_failure(&storage.conn, &operation.uuid, "network down")
+        .await
+        .unwrap();
+    let operation = PendingOperationRepository::get_next(&storage.conn, &backend_uuid)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(operation.attempts, 1);
+    assert_eq!(operation.last_error.as_deref(), Some("network down"));
+}
@@ -1,8 +1,10 @@
+use crate::common::TempDb;
 use terminalist::storage::LocalStorage;
 
 #[tokio::test]
 async fn test_projects_storage_creation() {
     // Test that we can create local storage for projects
-    let result = LocalStorage::new(false).await;
+    let db = TempDb::new("projects-creation");
+    let result = LocalStorage::open(db.path()).await;
     assert!(result.is_ok(), "LocalStorage should be created successfully");
 }
@@ -0,0 +1,295 @@
+use crate::common::{sync_service, TempDb};
+use crate::fixtures;
+use sea_orm::EntityTrait;
+use terminalist::backend::{BackendChanges, BackendLabel, BackendProject, BackendTask};
+use terminalist::entities::task_label;
+use terminalist::repositories::{LabelRepository, PendingOperationRepository, ProjectRepository, TaskRepository};
+use terminalist::sync::{SyncService, SyncStatus};
+
+fn project(remote_id: &str) -> BackendProject {
+    BackendProject {
+        remote_id: remote_id.to_string(),
+        name: remote_id.to_string(),
+        is_favorite: false,
+        is_inbox: false,
+        order_index: 0,
+        parent_remote_id: None,
+        color: None,
+    }
+}
+
+fn label(remote_id: &str) -> BackendLabel {
+    BackendLabel {
+        remote_id: remote_id.to_string(),
+        name: remote_id.to_string(),
+        order_index: 0,
+        is_favorite: false,
+        color: None,
+    }
+}
+
+async fn store(service: &SyncService, changes: BackendChanges) {
+    let status = service.store_changes(changes).await.unwrap();
+    assert!(matches!(status, SyncStatus::Success), "unexpected status: {:?}", status);
+}
+
+#[tokio::test]
+async fn test_full_sync_prunes_rows_missing_remotely() {
+    let db = TempDb::new("full");
+    let (service, storage, backend_uuid) = sync_service(&db).await;
+
+    store(
+        &service,
+        BackendChanges {
+            is_full: true,
+            projects: vec![project("p1"), project("p2"), project("p3")],
+            labels: vec![label("urgent"), label("later")],
+            tasks: vec![
+                BackendTask {
+                    labels: vec!["urgent".to_string(), "later".to_string()],
+                    ..fixtures::backend_task()
+                },
+                BackendTask {
+                    remote_id: "t2".to_string(),
+                    project_remote_id: "p2".to_string(),
+                    ..fixtures::backend_task()
+                },
+                BackendTask {
+                    remote_id: "t3".to_string(),
+                    project_remote_id: "p3".to_string(),
+                    ..fixtures::backend_task()
+                },
+            ],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    // A task with an unsynced local change must survive the next full sync
+    {
+        let storage = storage.lock().await;
+        let t2 = TaskRepository::get_by_remote_id(&storage.conn, &backend_uuid, "t2")
+            .await
+            .unwrap()
+            .unwrap();
+        PendingOperationRepository::enqueue(&storage.conn, &backend_uuid, "update_task", &t2.uuid, "t2", "{}".into())
+            .await
+            .unwrap();
+    }
+
+    store(
+        &service,
+        BackendChanges {
+            is_full: true,
+            projects: vec![project("p1")],
+            labels: vec![label("urgent")],
+            tasks: vec![BackendTask {
+                labels: vec!["urgent".to_string()],
+                ..fixtures::backend_task()
+            }],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let storage = storage.lock().await;
+    let conn = &storage.conn;
+    assert!(TaskRepository::get_by_remote_id(conn, &backend_uuid, "t1")
+        .await
+        .unwrap()
+        .is_some());
+    assert!(TaskRepository::get_by_remote_id(conn, &backend_uuid, "t2")
+        .await
+        .unwrap()
+        .is_some());
+    assert!(TaskRepository::get_by_remote_id(conn, &backend_uuid, "t3")
+        .await
+        .unwrap()
+        .is_none());
+
+    // p2 still holds the protected task, p3 is gone
+    assert!(ProjectRepository::get_by_remote_id(conn, &backend_uuid, "p2")
+        .await
+        .unwrap()
+        .is_some());
+    assert!(ProjectRepository::get_by_remote_id(conn, &backend_uuid, "p3")
+        .await
+        .unwrap()
+        .is_none());
+
+    let labels = LabelRepository::get_all(conn).await.unwrap();
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].remote_id, "urgent");
+    let links = task_label::Entity::find().all(conn).await.unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].label_uuid, labels[0].uuid);
+}
+
+#[tokio::test]
+async fn test_delta_sync_only_removes_reported_rows() {
+    let db = TempDb::new("delta");
+    let (service, storage, backend_uuid) = sync_service(&db).await;
+
+    store(
+        &service,
+        BackendChanges {
+            is_full: true,
+            projects: vec![project("p1")],
+            labels: vec![label("urgent")],
+            tasks: vec![
+                BackendTask {
+                    labels: vec!["urgent".to_string()],
+                    ..fixtures::backend_task()
+                },
+                BackendTask {
+                    remote_id: "t2".to_string(),
+                    ..fixtures::backend_task()
+                },
+            ],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    store(
+        &service,
+        BackendChanges {
+            removed_task_ids: vec!["t1".to_string()],
+            removed_label_ids: vec!["urgent".to_string()],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let storage = storage.lock().await;
+    let conn = &storage.conn;
+    assert!(TaskRepository::get_by_remote_id(conn, &backend_uuid, "t1")
+        .await
+        .unwrap()
+        .is_none());
+    assert!(TaskRepository::get_by_remote_id(conn, &backend_uuid, "t2")
+        .await
+        .unwrap()
+        .is_some());
+    assert!(ProjectRepository::get_by_remote_id(conn, &backend_uuid, "p1")
+        .await
+        .unwrap()
+        .is_some());
+    assert!(LabelRepository::get_all(conn).await.unwrap().is_empty());
+    assert!(task_label::Entity::find().all(conn).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_protected_subtask_survives_removal_of_its_parent() {
+    let db = TempDb::new("subtask");
+    let (service, storage, backend_uuid) = sync_service(&db).await;
+
+    store(
+        &service,
+        BackendChanges {
+            is_full: true,
+            projects: vec![project("p1")],
+            tasks: vec![
+                fixtures::backend_task(),
+                BackendTask {
+                    remote_id: "t2".to_string(),
+                    parent_remote_id: Some("t1".to_string()),
+                    ..fixtures::backend_task()
+                },
+            ],
+            ..Default::default()
+        },
+    )
+    .await;
+    {
+        let storage = storage.lock().await;
+        let t2 = TaskRepository::get_by_remote_id(&storage.conn, &backend_uuid, "t2")
+            .await
+            .unwrap()
+            .unwrap();
+        PendingOperationRepository::enqueue(&storage.conn, &backend_uuid, "update_task", &t2.uuid, "t2", "{}".into())
+            .await
+            .unwrap();
+    }
+
+    store(
+        &service,
+        BackendChanges {
+            removed_task_ids: vec!["t1".to_string(), "t2".to_string()],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    // The parent is gone, the subtask with the local change moves to the top level
+    let storage = storage.lock().await;
+    let conn = &storage.conn;
+    assert!(TaskRepository::get_by_remote_id(conn, &backend_uuid, "t1")
+        .await
+        .unwrap()
+        .is_none());
+    let t2 = TaskRepository::get_by_remote_id(conn, &backend_uuid, "t2")
+        .await
+        .unwrap()
+        .expect("protected subtask was deleted with its parent");
+    assert_eq!(t2.parent_uuid, None);
+}
+
+#[tokio::test]
+async fn test_kept_sub_project_survives_removal_of_its_parent() {
+    let db = TempDb::new("sub-project");
+    let (service, storage, backend_uuid) = sync_service(&db).await;
+
+    store(
+        &service,
+        BackendChanges {
+            is_full: true,
+            projects: vec![
+                project("p1"),
+                BackendProject {
+                    parent_remote_id: Some("p1".to_string()),
+                    ..project("p2")
+                },
+            ],
+            tasks: vec![BackendTask {
+                project_remote_id: "p2".to_string(),
+                ..fixtures::backend_task()
+            }],
+            ..Default::default()
+        },
+    )
+    .await;
+    {
+        let storage = storage.lock().await;
+        let t1 = TaskRepository::get_by_remote_id(&storage.conn, &backend_uuid, "t1")
+            .await
+            .unwrap()
+            .unwrap();
+        PendingOperationRepository::enqueue(&storage.conn, &backend_uuid, "update_task", &t1.uuid, "t1", "{}".into())
+            .await
+            .unwrap();
+    }
+
+    // p2 stays for its protected task, which must not fail the whole sync
+    store(
+        &service,
+        BackendChanges {
+            removed_project_ids: vec!["p1".to_string(), "p2".to_string()],
+            removed_task_ids: vec!["t1".to_string()],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let storage = storage.lock().await;
+    let conn = &storage.conn;
+    assert!(ProjectRepository::get_by_remote_id(conn, &backend_uuid, "p1")
+        .await
+        .unwrap()
+        .is_none());
+    let p2 = ProjectRepository::get_by_remote_id(conn, &backend_uuid, "p2")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(p2.parent_uuid, None);
+}
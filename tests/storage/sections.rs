@@ -1,8 +1,155 @@
+use crate::common::{pending_operations, sync_service, TempDb};
+use std::sync::Arc;
+use terminalist::backend::{BackendChanges, BackendProject, BackendSection, BackendTask, SectionOrder};
+use terminalist::repositories::{SectionRepository, TaskRepository};
 use terminalist::storage::LocalStorage;
+use terminalist::sync::outbox::{is_temp_remote_id, OutboxOperation};
+use terminalist::sync::SyncService;
+use tokio::sync::Mutex;
+use uuid::Uuid;
 
 #[tokio::test]
 async fn test_sections_storage_creation() {
     // Test that we can create local storage for sections
-    let result = LocalStorage::new(false).await;
+    let db = TempDb::new("sections-creation");
+    let result = LocalStorage::open(db.path()).await;
     assert!(result.is_ok(), "LocalStorage should be created successfully");
 }
+
+/// Sync service over a project with two sections and a task in the first one.
+async fn seeded_service(db: &TempDb) -> (SyncService, Arc<Mutex<LocalStorage>>, Uuid) {
+    let (service, storage, _) = sync_service(db).await;
+
+    let section = |remote_id: &str, name: &str, order_index: i32| BackendSection {
+        remote_id: remote_id.to_string(),
+        name: name.to_string(),
+        project_remote_id: "p1".to_string(),
+        order_index,
+    };
+    let changes = BackendChanges {
+        projects: vec![BackendProject {
+            remote_id: "p1".to_string(),
+            name: "Project".to_string(),
+            is_favorite: false,
+            is_inbox: false,
+            order_index: 0,
+            parent_remote_id: None,
+            color: None,
+        }],
+        sections: vec![section("s1", "Backlog", 1), section("s2", "Doing", 2)],
+        tasks: vec![BackendTask {
+            content: "Write docs".to_string(),
+            section_remote_id: Some("s1".to_string()),
+            ..crate::fixtures::backend_task()
+        }],
+        ..Default::default()
+    };
+    service.store_changes(changes).await.unwrap();
+
+    let project_uuid = service.get_projects().await.unwrap()[0].uuid;
+    (service, storage, project_uuid)
+}
+
+#[tokio::test]
+async fn test_create_section_appends_to_project_and_queues_create() {
+    let db = TempDb::new("create");
+    let (service, storage, project_uuid) = seeded_service(&db).await;
+
+    service.create_section(&project_uuid, "Done").await.unwrap();
+
+    let sections = service.get_sections_for_project(&project_uuid).await.unwrap();
+    let names: Vec<&str> = sections.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["Backlog", "Doing", "Done"]);
+    let created = &sections[2];
+    assert!(is_temp_remote_id(&created.remote_id));
+    assert_eq!(created.order_index, 3);
+
+    match pending_operations(&storage, &created.uuid).await.as_slice() {
+        [OutboxOperation::CreateSection(args)] => {
+            assert_eq!(args.name, "Done");
+            assert_eq!(args.project_remote_id, "p1");
+            assert_eq!(args.order_index, Some(3));
+        }
+        other => panic!("unexpected operations: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_move_section_renumbers_and_queues_one_reorder() {
+    let db = TempDb::new("move");
+    let (service, storage, project_uuid) = seeded_service(&db).await;
+    let doing = service.get_sections_for_project(&project_uuid).await.unwrap()[1].clone();
+
+    service.move_section(&doing.uuid, -1).await.unwrap();
+    // Moving past the top is a no-op
+    service.move_section(&doing.uuid, -1).await.unwrap();
+
+    let sections = service.get_sections_for_project(&project_uuid).await.unwrap();
+    let names: Vec<&str> = sections.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["Doing", "Backlog"]);
+
+    let expected = vec![
+        SectionOrder {
+            remote_id: "s2".to_string(),
+            order_index: 1,
+        },
+        SectionOrder {
+            remote_id: "s1".to_string(),
+            order_index: 2,
+        },
+    ];
+    match pending_operations(&storage, &doing.uuid).await.as_slice() {
+        [OutboxOperation::ReorderSections(orders)] => assert_eq!(orders, &expected),
+        other => panic!("unexpected operations: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_rename_survives_sync_while_pending() {
+    let db = TempDb::new("rename");
+    let (service, _storage, project_uuid) = seeded_service(&db).await;
+    let backlog = service.get_sections_for_project(&project_uuid).await.unwrap()[0].clone();
+
+    service.update_section_content(&backlog.uuid, "Icebox").await.unwrap();
+
+    // The remote still reports the old name until the rename is pushed
+    let changes = BackendChanges {
+        sections: vec![BackendSection {
+            remote_id: "s1".to_string(),
+            name: "Backlog".to_string(),
+            project_remote_id: "p1".to_string(),
+            order_index: 1,
+        }],
+        ..Default::default()
+    };
+    service.store_changes(changes).await.unwrap();
+
+    let sections = service.get_sections_for_project(&project_uuid).await.unwrap();
+    assert_eq!(sections[0].name, "Icebox");
+    assert!(service.get_conflicts().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_section_removes_its_tasks() {
+    let db = TempDb::new("delete");
+    let (service, storage, project_uuid) = seeded_service(&db).await;
+    let backlog = service.get_sections_for_project(&project_uuid).await.unwrap()[0].clone();
+
+    service.delete_section(&backlog.uuid).await.unwrap();
+
+    let sections = service.get_sections_for_project(&project_uuid).await.unwrap();
+    assert_eq!(sections.len(), 1);
+    assert_eq!(sections[0].name, "Doing");
+    {
+        let storage = storage.lock().await;
+        assert!(SectionRepository::get_by_id(&storage.conn, &backlog.uuid)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(TaskRepository::get_all(&storage.conn).await.unwrap().is_empty());
+    }
+    assert!(matches!(
+        pending_operations(&storage, &backlog.uuid).await.as_slice(),
+        [OutboxOperation::DeleteSection]
+    ));
+}
@@ -1,8 +1,873 @@
+use crate::common::{pending_operations, sync_service, TempDb};
+use crate::fixtures;
+use std::sync::Arc;
+use terminalist::backend::{BackendChanges, BackendLabel, BackendProject, BackendSection, BackendTask, TaskOrder};
+use terminalist::repositories::{PendingOperationRepository, TaskRepository};
 use terminalist::storage::LocalStorage;
+use terminalist::sync::outbox::OutboxOperation;
+use terminalist::sync::tasks::{NewTask, TaskEdit};
+use terminalist::sync::SyncService;
+use terminalist::utils::filter_query::parse_filter_query;
+use tokio::sync::Mutex;
+use uuid::Uuid;
 
 #[tokio::test]
 async fn test_tasks_storage_creation() {
     // Test that we can create local storage for tasks
-    let result = LocalStorage::new(false).await;
+    let db = TempDb::new("tasks-creation");
+    let result = LocalStorage::open(db.path()).await;
     assert!(result.is_ok(), "LocalStorage should be created successfully");
 }
+
+/// Remote data: two projects, a section in the second one, two labels and a task with a subtask.
+fn remote_changes() -> BackendChanges {
+    let project = |remote_id: &str, name: &str, order_index: i32| BackendProject {
+        remote_id: remote_id.to_string(),
+        name: name.to_string(),
+        is_favorite: false,
+        is_inbox: false,
+        order_index,
+        parent_remote_id: None,
+        color: None,
+    };
+    let label = |remote_id: &str, name: &str, order_index: i32| BackendLabel {
+        remote_id: remote_id.to_string(),
+        name: name.to_string(),
+        order_index,
+        is_favorite: false,
+        color: None,
+    };
+    BackendChanges {
+        projects: vec![project("p1", "Work", 0), project("p2", "Home", 1)],
+        sections: vec![BackendSection {
+            remote_id: "s1".to_string(),
+            name: "Chores".to_string(),
+            project_remote_id: "p2".to_string(),
+            order_index: 1,
+        }],
+        labels: vec![label("l1", "work", 0), label("l2", "errand", 1)],
+        tasks: vec![
+            BackendTask {
+                content: "Plan week".to_string(),
+                labels: vec!["work".to_string()],
+                ..fixtures::backend_task()
+            },
+            BackendTask {
+                remote_id: "t2".to_string(),
+                content: "Book room".to_string(),
+                parent_remote_id: Some("t1".to_string()),
+                labels: vec!["work".to_string()],
+                ..fixtures::backend_task()
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+async fn seeded_service(db: &TempDb) -> (SyncService, Arc<Mutex<LocalStorage>>) {
+    let (service, storage, _) = sync_service(db).await;
+    service.store_changes(remote_changes()).await.unwrap();
+    (service, storage)
+}
+
+/// The task editor values with everything but the content changed
+async fn full_edit(service: &SyncService) -> (Uuid, TaskEdit) {
+    let tasks = service.get_all_tasks().await.unwrap();
+    let task = tasks.iter().find(|t| t.remote_id == "t1").unwrap();
+    let labels = service.get_labels().await.unwrap();
+    let errand = labels.iter().find(|l| l.name == "errand").unwrap().uuid;
+    let section = service.get_sections().await.unwrap()[0].clone();
+
+    let edit = TaskEdit {
+        description: "Agenda\nRooms".to_string(),
+        project_uuid: section.project_uuid,
+        section_uuid: Some(section.uuid),
+        label_uuids: vec![errand],
+        priority: 4,
+        due: "2030-01-15".to_string(),
+        deadline: "2030-01-20".to_string(),
+        duration: "45 min".to_string(),
+        ..TaskEdit::from_task(task, Vec::new())
+    };
+    (task.uuid, edit)
+}
+
+#[tokio::test]
+async fn test_update_task_applies_all_fields_and_queues_one_update() {
+    let db = TempDb::new("edit");
+    let (service, storage) = seeded_service(&db).await;
+    let (task_uuid, edit) = full_edit(&service).await;
+
+    service.update_task(&task_uuid, &edit).await.unwrap();
+
+    let task = service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+    assert_eq!(task.content, "Plan week");
+    assert_eq!(task.description.as_deref(), Some("Agenda\nRooms"));
+    assert_eq!(task.project_uuid, edit.project_uuid);
+    assert_eq!(task.section_uuid, edit.section_uuid);
+    assert_eq!(task.priority, 4);
+    assert_eq!(task.due_date.as_deref(), Some("2030-01-15"));
+    assert_eq!(task.deadline.as_deref(), Some("2030-01-20"));
+    assert_eq!(task.duration.as_deref(), Some("45 minute"));
+    let labels: Vec<String> = service
+        .get_labels_for_task(&task_uuid)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|l| l.name)
+        .collect();
+    assert_eq!(labels, ["errand"]);
+
+    // The subtask moves along with its parent
+    let tasks = service.get_all_tasks().await.unwrap();
+    let subtask = tasks.iter().find(|t| t.remote_id == "t2").unwrap();
+    assert_eq!(subtask.project_uuid, edit.project_uuid);
+    assert_eq!(subtask.section_uuid, edit.section_uuid);
+
+    match pending_operations(&storage, &task_uuid).await.as_slice() {
+        [OutboxOperation::UpdateTask(args)] => {
+            assert_eq!(args.content, None);
+            assert_eq!(args.description.as_deref(), Some("Agenda\nRooms"));
+            assert_eq!(args.section_remote_id.as_deref(), Some("s1"));
+            assert_eq!(args.project_remote_id, None);
+            assert_eq!(args.labels, Some(vec!["errand".to_string()]));
+            assert_eq!(args.priority, Some(4));
+            assert_eq!(args.due_date.as_deref(), Some("2030-01-15"));
+            assert_eq!(args.deadline.as_deref(), Some("2030-01-20"));
+            assert_eq!(args.duration.as_deref(), Some("45 minute"));
+        }
+        other => panic!("unexpected operations: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_update_task_edit_survives_pull_before_push() {
+    let db = TempDb::new("pull");
+    let (service, _storage) = seeded_service(&db).await;
+    let (task_uuid, edit) = full_edit(&service).await;
+    service.update_task(&task_uuid, &edit).await.unwrap();
+
+    // The pull before the outbox is pushed still reports the old remote values
+    service.store_changes(remote_changes()).await.unwrap();
+
+    let task = service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+    assert_eq!(task.section_uuid, edit.section_uuid);
+    assert_eq!(task.priority, 4);
+    assert_eq!(task.deadline.as_deref(), Some("2030-01-20"));
+    assert_eq!(task.duration.as_deref(), Some("45 minute"));
+    let labels = service.get_labels_for_task(&task_uuid).await.unwrap();
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].name, "errand");
+}
+
+#[tokio::test]
+async fn test_update_task_without_changes_queues_nothing() {
+    let db = TempDb::new("unchanged");
+    let (service, storage) = seeded_service(&db).await;
+    let task = service.get_all_tasks().await.unwrap()[0].clone();
+    let label_uuids = service
+        .get_labels_for_task(&task.uuid)
+        .await
+        .unwrap()
+        .iter()
+        .map(|l| l.uuid)
+        .collect();
+
+    service
+        .update_task(&task.uuid, &TaskEdit::from_task(&task, label_uuids))
+        .await
+        .unwrap();
+
+    assert!(pending_operations(&storage, &task.uuid).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_task_rejects_invalid_deadline_and_duration() {
+    let db = TempDb::new("invalid");
+    let (service, storage) = seeded_service(&db).await;
+    let task = service.get_all_tasks().await.unwrap()[0].clone();
+
+    let bad_deadline = TaskEdit {
+        deadline: "every week".to_string(),
+        ..TaskEdit::from_task(&task, Vec::new())
+    };
+    assert!(service.update_task(&task.uuid, &bad_deadline).await.is_err());
+    let bad_duration = TaskEdit {
+        duration: "a while".to_string(),
+        ..TaskEdit::from_task(&task, Vec::new())
+    };
+    assert!(service.update_task(&task.uuid, &bad_duration).await.is_err());
+
+    assert!(pending_operations(&storage, &task.uuid).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_create_task_with_quick_add_fields() {
+    let db = TempDb::new("create");
+    let (service, storage) = seeded_service(&db).await;
+    let projects = service.get_projects().await.unwrap();
+    let work = projects.iter().find(|p| p.name == "Work").unwrap().uuid;
+    let section = service.get_sections().await.unwrap()[0].clone();
+    let labels = service.get_labels().await.unwrap();
+    let errand = labels.iter().find(|l| l.name == "errand").unwrap().uuid;
+
+    // The section decides the project, even when another project is given
+    let new_task = NewTask {
+        content: "Buy milk".to_string(),
+        description: Some("Semi-skimmed".to_string()),
+        project_uuid: Some(work),
+        section_uuid: Some(section.uuid),
+        parent_uuid: None,
+        label_uuids: vec![errand],
+        priority: Some(4),
+        due_string: Some("tmrw 5pm".to_string()),
+        deadline: Some("2030-03-30".to_string()),
+        duration: Some("1h30".to_string()),
+    };
+    let task_uuid = service.create_task(&new_task).await.unwrap();
+
+    let task = service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+    let tomorrow = chrono::Local::now().date_naive() + chrono::Duration::days(1);
+    assert_eq!(task.content, "Buy milk");
+    assert_eq!(task.description.as_deref(), Some("Semi-skimmed"));
+    assert_eq!(task.project_uuid, section.project_uuid);
+    assert_eq!(task.section_uuid, Some(section.uuid));
+    assert_eq!(task.priority, 4);
+    assert_eq!(task.deadline.as_deref(), Some("2030-03-30"));
+    assert_eq!(task.duration.as_deref(), Some("90 minute"));
+    assert_eq!(task.due_date, Some(tomorrow.format("%Y-%m-%d").to_string()));
+    assert_eq!(
+        task.due_datetime,
+        Some(tomorrow.format("%Y-%m-%dT17:00:00").to_string())
+    );
+    let labels = service.get_labels_for_task(&task_uuid).await.unwrap();
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].name, "errand");
+
+    match pending_operations(&storage, &task_uuid).await.as_slice() {
+        [OutboxOperation::CreateTask(args)] => {
+            assert_eq!(args.project_remote_id, "p2");
+            assert_eq!(args.section_remote_id.as_deref(), Some("s1"));
+            assert_eq!(args.labels, vec!["errand".to_string()]);
+            assert_eq!(args.priority, Some(4));
+            assert_eq!(args.due_string.as_deref(), Some("tomorrow 5pm"));
+            assert_eq!(args.due_date, task.due_date);
+            assert_eq!(args.deadline, task.deadline);
+            assert_eq!(args.duration, task.duration);
+        }
+        other => panic!("unexpected operations: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_set_and_clear_task_deadline_and_duration() {
+    let db = TempDb::new("planning");
+    let (service, storage) = seeded_service(&db).await;
+    let task_uuid = service.get_all_tasks().await.unwrap()[0].uuid;
+
+    service.update_task_deadline(&task_uuid, "2030-03-30").await.unwrap();
+    service.update_task_duration(&task_uuid, "45m").await.unwrap();
+    let task = service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+    assert_eq!(task.deadline.as_deref(), Some("2030-03-30"));
+    assert_eq!(task.duration.as_deref(), Some("45 minute"));
+
+    // Invalid input is rejected without queueing anything
+    assert!(service.update_task_deadline(&task_uuid, "every day").await.is_err());
+    assert!(service.update_task_deadline(&task_uuid, "friday 5pm").await.is_err());
+    assert!(service.update_task_duration(&task_uuid, "a while").await.is_err());
+
+    // Empty input clears the field
+    service.update_task_deadline(&task_uuid, "").await.unwrap();
+    service.update_task_duration(&task_uuid, " ").await.unwrap();
+    let task = service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+    assert_eq!(task.deadline, None);
+    assert_eq!(task.duration, None);
+
+    match pending_operations(&storage, &task_uuid).await.as_slice() {
+        [OutboxOperation::UpdateTask(deadline), OutboxOperation::UpdateTask(duration), OutboxOperation::UpdateTask(cleared_deadline), OutboxOperation::UpdateTask(cleared_duration)] =>
+        {
+            assert_eq!(deadline.deadline.as_deref(), Some("2030-03-30"));
+            assert_eq!(duration.duration.as_deref(), Some("45 minute"));
+            assert_eq!(cleared_deadline.deadline.as_deref(), Some(""));
+            assert_eq!(cleared_duration.duration.as_deref(), Some(""));
+        }
+        other => panic!("unexpected operations: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_create_task_leaves_recurring_due_to_the_backend() {
+    let db = TempDb::new("recurring");
+    let (service, storage) = seeded_service(&db).await;
+    let work = service.get_projects().await.unwrap()[0].uuid;
+
+    let new_task = NewTask {
+        content: "Water plants".to_string(),
+        project_uuid: Some(work),
+        due_string: Some("every monday".to_string()),
+        ..NewTask::default()
+    };
+    let task_uuid = service.create_task(&new_task).await.unwrap();
+
+    let task = service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+    assert!(task.is_recurring);
+    assert_eq!(task.due_date, None);
+    match pending_operations(&storage, &task_uuid).await.as_slice() {
+        [OutboxOperation::CreateTask(args)] => {
+            assert_eq!(args.due_string.as_deref(), Some("every monday"));
+            assert_eq!(args.due_date, None);
+            assert_eq!(args.priority, None);
+        }
+        other => panic!("unexpected operations: {:?}", other),
+    }
+
+    let empty = NewTask {
+        content: "   ".to_string(),
+        ..new_task
+    };
+    assert!(service.create_task(&empty).await.is_err());
+}
+
+#[tokio::test]
+async fn test_create_task_keeps_its_idempotency_key_across_replays() {
+    let db = TempDb::new("request_id");
+    let (service, storage) = seeded_service(&db).await;
+    let work = service.get_projects().await.unwrap()[0].uuid;
+
+    let new_task = NewTask {
+        content: "Pay rent".to_string(),
+        project_uuid: Some(work),
+        ..NewTask::default()
+    };
+    let task_uuid = service.create_task(&new_task).await.unwrap();
+    let request_id = match pending_operations(&storage, &task_uuid).await.as_slice() {
+        [OutboxOperation::CreateTask(args)] => args.request_id.clone().expect("create without a request id"),
+        other => panic!("unexpected operations: {:?}", other),
+    };
+
+    // The backend is unreachable, so both replays fail and the create stays queued
+    let _ = service.flush_outbox().await;
+    let _ = service.flush_outbox().await;
+    match pending_operations(&storage, &task_uuid).await.as_slice() {
+        [OutboxOperation::CreateTask(args)] => assert_eq!(args.request_id.as_deref(), Some(request_id.as_str())),
+        other => panic!("unexpected operations: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_set_task_labels_creates_missing_labels_first() {
+    let db = TempDb::new("labels");
+    let (service, storage) = seeded_service(&db).await;
+    let task = service.get_all_tasks().await.unwrap()[0].clone();
+    let labels = service.get_labels().await.unwrap();
+    let errand = labels.iter().find(|l| l.name == "errand").unwrap().uuid;
+
+    // "errand" replaces "work", "urgent" does not exist yet
+    service
+        .set_task_labels(&task.uuid, &[errand], &["urgent".to_string()])
+        .await
+        .unwrap();
+
+    let mut names: Vec<String> = service
+        .get_labels_for_task(&task.uuid)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|l| l.name)
+        .collect();
+    names.sort();
+    assert_eq!(names, ["errand", "urgent"]);
+
+    let urgent = service
+        .get_labels()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|l| l.name == "urgent")
+        .unwrap();
+    assert!(matches!(
+        pending_operations(&storage, &urgent.uuid).await.as_slice(),
+        [OutboxOperation::CreateLabel(_)]
+    ));
+    match pending_operations(&storage, &task.uuid).await.as_slice() {
+        [OutboxOperation::UpdateTask(args)] => {
+            let mut labels = args.labels.clone().unwrap();
+            labels.sort();
+            assert_eq!(labels, ["errand", "urgent"]);
+            assert_eq!(args.content, None);
+        }
+        other => panic!("unexpected operations: {:?}", other),
+    }
+    {
+        let storage = storage.lock().await;
+        let pending = PendingOperationRepository::get_for_backend(&storage.conn, &task.backend_uuid)
+            .await
+            .unwrap();
+        let label_position = pending.iter().position(|p| p.entity_uuid == urgent.uuid).unwrap();
+        let task_position = pending.iter().position(|p| p.entity_uuid == task.uuid).unwrap();
+        assert!(
+            label_position < task_position,
+            "the label is created before it is assigned"
+        );
+    }
+
+    // Saving the same labels again changes nothing
+    let label_uuids: Vec<Uuid> = vec![errand, urgent.uuid];
+    service.set_task_labels(&task.uuid, &label_uuids, &[]).await.unwrap();
+    assert_eq!(pending_operations(&storage, &task.uuid).await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_move_task_takes_subtasks_along() {
+    let db = TempDb::new("move");
+    let (service, storage) = seeded_service(&db).await;
+    let tasks = service.get_all_tasks().await.unwrap();
+    let parent = tasks.iter().find(|t| t.content == "Plan week").unwrap().clone();
+    let child = tasks.iter().find(|t| t.content == "Book room").unwrap().clone();
+    let section = service.get_sections().await.unwrap()[0].clone();
+
+    service
+        .move_task(&parent.uuid, section.project_uuid, Some(section.uuid), None)
+        .await
+        .unwrap();
+
+    for task_uuid in [parent.uuid, child.uuid] {
+        let task = service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+        assert_eq!(task.project_uuid, section.project_uuid);
+        assert_eq!(task.section_uuid, Some(section.uuid));
+    }
+    let child_after = service.get_task_by_id(&child.uuid).await.unwrap().unwrap();
+    assert_eq!(child_after.parent_uuid, Some(parent.uuid));
+
+    match pending_operations(&storage, &parent.uuid).await.as_slice() {
+        [OutboxOperation::MoveTask(args)] => {
+            assert_eq!(args.project_remote_id, "p2");
+            assert_eq!(args.section_remote_id.as_deref(), Some("s1"));
+            assert_eq!(args.parent_remote_id, None);
+        }
+        other => panic!("unexpected operations: {:?}", other),
+    }
+    assert!(pending_operations(&storage, &child.uuid).await.is_empty());
+
+    // Moving to where the task already is changes nothing
+    service
+        .move_task(&parent.uuid, section.project_uuid, Some(section.uuid), None)
+        .await
+        .unwrap();
+    assert_eq!(pending_operations(&storage, &parent.uuid).await.len(), 1);
+
+    // The queued move keeps the new location when a pull still reports the old one
+    service.store_changes(remote_changes()).await.unwrap();
+    for task_uuid in [parent.uuid, child.uuid] {
+        let task = service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+        assert_eq!(task.section_uuid, Some(section.uuid));
+    }
+}
+
+#[tokio::test]
+async fn test_move_task_to_parent_and_back_to_top_level() {
+    let db = TempDb::new("move-parent");
+    let (service, storage) = seeded_service(&db).await;
+    let tasks = service.get_all_tasks().await.unwrap();
+    let parent = tasks.iter().find(|t| t.content == "Plan week").unwrap().clone();
+    let child = tasks.iter().find(|t| t.content == "Book room").unwrap().clone();
+
+    // A task cannot end up below itself
+    let result = service
+        .move_task(&parent.uuid, parent.project_uuid, None, Some(child.uuid))
+        .await;
+    assert!(result.is_err());
+    let result = service
+        .move_task(&parent.uuid, parent.project_uuid, None, Some(parent.uuid))
+        .await;
+    assert!(result.is_err());
+    assert!(pending_operations(&storage, &parent.uuid).await.is_empty());
+
+    service.move_task(&child.uuid, child.project_uuid, None, None).await.unwrap();
+    let task = service.get_task_by_id(&child.uuid).await.unwrap().unwrap();
+    assert_eq!(task.parent_uuid, None);
+    match pending_operations(&storage, &child.uuid).await.as_slice() {
+        [OutboxOperation::MoveTask(args)] => {
+            assert_eq!(args.project_remote_id, "p1");
+            assert_eq!(args.section_remote_id, None);
+            assert_eq!(args.parent_remote_id, None);
+        }
+        other => panic!("unexpected operations: {:?}", other),
+    }
+
+    // Moving it back under its parent refers to the parent by its remote id
+    service
+        .move_task(&child.uuid, child.project_uuid, None, Some(parent.uuid))
+        .await
+        .unwrap();
+    let task = service.get_task_by_id(&child.uuid).await.unwrap().unwrap();
+    assert_eq!(task.parent_uuid, Some(parent.uuid));
+    match pending_operations(&storage, &child.uuid).await.as_slice() {
+        [_, OutboxOperation::MoveTask(args)] => assert_eq!(args.parent_remote_id.as_deref(), Some("t1")),
+        other => panic!("unexpected operations: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_create_subtask_goes_where_its_parent_is() {
+    let db = TempDb::new("subtask");
+    let (service, storage) = seeded_service(&db).await;
+    let parent = service
+        .get_all_tasks()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|t| t.content == "Plan week")
+        .unwrap();
+    let home = service
+        .get_projects()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|p| p.name == "Home")
+        .unwrap();
+
+    let new_task = NewTask {
+        content: "Call venue".to_string(),
+        project_uuid: Some(home.uuid),
+        parent_uuid: Some(parent.uuid),
+        ..Default::default()
+    };
+    let task_uuid = service.create_task(&new_task).await.unwrap();
+
+    let task = service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+    assert_eq!(task.parent_uuid, Some(parent.uuid));
+    assert_eq!(task.project_uuid, parent.project_uuid);
+    match pending_operations(&storage, &task_uuid).await.as_slice() {
+        [OutboxOperation::CreateTask(args)] => {
+            assert_eq!(args.parent_remote_id.as_deref(), Some("t1"));
+            assert_eq!(args.project_remote_id, "p1");
+        }
+        other => panic!("unexpected operations: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_task_collapsed_state_is_local_and_survives_sync() {
+    let db = TempDb::new("collapse");
+    let (service, storage) = seeded_service(&db).await;
+    let task = service.get_all_tasks().await.unwrap()[0].clone();
+    assert!(!task.is_collapsed);
+
+    service.set_task_collapsed(&task.uuid, true).await.unwrap();
+    assert!(pending_operations(&storage, &task.uuid).await.is_empty());
+
+    service.store_changes(remote_changes()).await.unwrap();
+    let task = service.get_task_by_id(&task.uuid).await.unwrap().unwrap();
+    assert!(task.is_collapsed);
+}
+
+#[tokio::test]
+async fn test_reorder_task_renumbers_siblings_and_survives_pull() {
+    let db = TempDb::new("reorder");
+    let (service, storage) = seeded_service(&db).await;
+    let mut changes = remote_changes();
+    changes.tasks[0].order_index = 1;
+    changes.tasks.push(BackendTask {
+        remote_id: "t3".to_string(),
+        content: "Send invoice".to_string(),
+        order_index: 2,
+        ..fixtures::backend_task()
+    });
+    service.store_changes(changes.clone()).await.unwrap();
+    let tasks = service.get_all_tasks().await.unwrap();
+    let invoice = tasks.iter().find(|t| t.remote_id == "t3").unwrap().clone();
+
+    service.reorder_task(&invoice.uuid, -1).await.unwrap();
+    // Moving past the top is a no-op
+    service.reorder_task(&invoice.uuid, -1).await.unwrap();
+
+    let expected = vec![
+        TaskOrder {
+            remote_id: "t3".to_string(),
+            order_index: 1,
+        },
+        TaskOrder {
+            remote_id: "t1".to_string(),
+            order_index: 2,
+        },
+    ];
+    match pending_operations(&storage, &invoice.uuid).await.as_slice() {
+        [OutboxOperation::ReorderTasks(orders)] => assert_eq!(orders, &expected),
+        other => panic!("unexpected operations: {:?}", other),
+    }
+
+    // The queued reorder keeps the local position when a pull still reports the old order
+    service.store_changes(changes).await.unwrap();
+    let invoice = service.get_task_by_id(&invoice.uuid).await.unwrap().unwrap();
+    assert_eq!(invoice.order_index, 1);
+    let plan = service.get_all_tasks().await.unwrap();
+    let plan = plan.iter().find(|t| t.remote_id == "t1").unwrap();
+    assert_eq!(plan.order_index, 2);
+}
+
+#[tokio::test]
+async fn test_completing_recurring_task_keeps_it_open() {
+    let db = TempDb::new("complete-recurring");
+    let (service, storage) = seeded_service(&db).await;
+    let mut changes = remote_changes();
+    changes.tasks[0].due_date = Some("2030-01-07".to_string());
+    changes.tasks[0].is_recurring = true;
+    changes.tasks[0].due_string = Some("every monday".to_string());
+    service.store_changes(changes).await.unwrap();
+    let tasks = service.get_all_tasks().await.unwrap();
+    let task = tasks.iter().find(|t| t.remote_id == "t1").unwrap().clone();
+    assert_eq!(task.due_string.as_deref(), Some("every monday"));
+    // The editor shows the pattern, so saving it unchanged keeps the recurrence
+    assert_eq!(TaskEdit::from_task(&task, Vec::new()).due, "every monday");
+
+    service.complete_task(&task.uuid).await.unwrap();
+
+    // The backend moves the task to its next occurrence, which is fetched on replay
+    let task = service.get_task_by_id(&task.uuid).await.unwrap().unwrap();
+    assert!(!task.is_completed);
+    assert_eq!(task.completed_at, None);
+    assert_eq!(task.due_date.as_deref(), Some("2030-01-07"));
+    assert!(matches!(
+        pending_operations(&storage, &task.uuid).await.as_slice(),
+        [OutboxOperation::CompleteTask]
+    ));
+
+    // A plain task is completed right away
+    let child = tasks.iter().find(|t| t.remote_id == "t2").unwrap();
+    service.complete_task(&child.uuid).await.unwrap();
+    assert!(service.get_task_by_id(&child.uuid).await.unwrap().unwrap().is_completed);
+}
+
+#[tokio::test]
+async fn test_filter_query_selects_matching_tasks() {
+    let db = TempDb::new("filter");
+    let (service, storage) = seeded_service(&db).await;
+    let mut changes = remote_changes();
+    changes.projects.push(BackendProject {
+        remote_id: "p3".to_string(),
+        name: "Garden".to_string(),
+        is_favorite: false,
+        is_inbox: false,
+        order_index: 2,
+        parent_remote_id: Some("p2".to_string()),
+        color: None,
+    });
+    changes.tasks[0].priority = 4;
+    changes.tasks[0].due_date = Some("2030-01-10".to_string());
+    changes.tasks.push(BackendTask {
+        remote_id: "t3".to_string(),
+        content: "Mow lawn".to_string(),
+        project_remote_id: "p2".to_string(),
+        section_remote_id: Some("s1".to_string()),
+        due_date: Some("2030-01-09".to_string()),
+        is_recurring: true,
+        ..fixtures::backend_task()
+    });
+    changes.tasks.push(BackendTask {
+        remote_id: "t4".to_string(),
+        content: "Buy milk".to_string(),
+        project_remote_id: "p2".to_string(),
+        description: Some("Semi-skimmed".to_string()),
+        due_date: Some("2030-01-14".to_string()),
+        labels: vec!["errand".to_string()],
+        ..fixtures::backend_task()
+    });
+    changes.tasks.push(BackendTask {
+        remote_id: "t5".to_string(),
+        content: "Prune roses".to_string(),
+        project_remote_id: "p3".to_string(),
+        ..fixtures::backend_task()
+    });
+    service.store_changes(changes).await.unwrap();
+
+    let today = chrono::NaiveDate::from_ymd_opt(2030, 1, 10).unwrap();
+    let storage = storage.lock().await;
+    let matching = |query: &'static str| {
+        let conn = &storage.conn;
+        async move {
+            let filter = parse_filter_query(query).unwrap();
+            let mut names: Vec<String> = TaskRepository::get_for_filter(conn, &filter, today)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|task| task.content)
+                .collect();
+            names.sort();
+            names
+        }
+    };
+
+    assert_eq!(matching("p1 & @work & overdue | today").await, ["Plan week"]);
+    assert_eq!(matching("overdue").await, ["Mow lawn"]);
+    assert_eq!(matching("#home").await, ["Buy milk", "Mow lawn"]);
+    assert_eq!(matching("##Home").await, ["Buy milk", "Mow lawn", "Prune roses"]);
+    assert_eq!(matching("#Home & !/Chores").await, ["Buy milk"]);
+    assert_eq!(matching("no labels").await, ["Mow lawn", "Prune roses"]);
+    assert_eq!(matching("no date").await, ["Book room", "Prune roses"]);
+    assert_eq!(matching("7 days").await, ["Buy milk", "Plan week"]);
+    assert_eq!(matching("due before: 2030-01-12").await, ["Mow lawn", "Plan week"]);
+    assert_eq!(matching("due after: today").await, ["Buy milk"]);
+    assert_eq!(matching("recurring").await, ["Mow lawn"]);
+    assert_eq!(matching("search: skimmed").await, ["Buy milk"]);
+    assert_eq!(matching("!@work").await, ["Buy milk", "Mow lawn", "Prune roses"]);
+    assert!(matching("@missing | #Missing").await.is_empty());
+
+    // Negated due dates still match the tasks without one
+    assert_eq!(
+        matching("!today").await,
+        ["Book room", "Buy milk", "Mow lawn", "Prune roses"]
+    );
+    assert_eq!(
+        matching("!tomorrow").await,
+        ["Book room", "Buy milk", "Mow lawn", "Plan week", "Prune roses"]
+    );
+}
+
+#[tokio::test]
+async fn test_filter_search_matches_wildcards_literally() {
+    let db = TempDb::new("filter-search");
+    let (service, storage) = seeded_service(&db).await;
+    let mut changes = remote_changes();
+    changes.tasks = vec![
+        BackendTask {
+            content: "Save 50% now".to_string(),
+            ..fixtures::backend_task()
+        },
+        BackendTask {
+            remote_id: "t2".to_string(),
+            content: "Buy 500 screws".to_string(),
+            ..fixtures::backend_task()
+        },
+        BackendTask {
+            remote_id: "t3".to_string(),
+            content: "Rename a_b".to_string(),
+            ..fixtures::backend_task()
+        },
+        BackendTask {
+            remote_id: "t4".to_string(),
+            content: "Rename axb".to_string(),
+            ..fixtures::backend_task()
+        },
+        BackendTask {
+            remote_id: "t5".to_string(),
+            content: "Escape C:\\temp".to_string(),
+            ..fixtures::backend_task()
+        },
+        BackendTask {
+            remote_id: "t6".to_string(),
+            content: "Escape C:temp".to_string(),
+            ..fixtures::backend_task()
+        },
+    ];
+    service.store_changes(changes).await.unwrap();
+
+    let today = chrono::NaiveDate::from_ymd_opt(2030, 1, 10).unwrap();
+    let storage = storage.lock().await;
+    let matching = |query: &'static str| {
+        let conn = &storage.conn;
+        async move {
+            let filter = parse_filter_query(query).unwrap();
+            TaskRepository::get_for_filter(conn, &filter, today)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|task| task.content)
+                .collect::<Vec<_>>()
+        }
+    };
+
+    assert_eq!(matching("search: 50%").await, ["Save 50% now"]);
+    assert_eq!(matching("search: a_b").await, ["Rename a_b"]);
+    assert_eq!(matching("search: C:\\temp").await, ["Escape C:\\temp"]);
+}
+
+#[tokio::test]
+async fn test_created_at_and_view_settings_are_stored() {
+    let db = TempDb::new("view-settings");
+    let (service, _storage) = seeded_service(&db).await;
+
+    // The creation time comes from the backend and is kept when a later sync omits it
+    let mut changes = remote_changes();
+    changes.tasks[0].created_at = Some("2024-05-01T08:30:00Z".to_string());
+    service.store_changes(changes).await.unwrap();
+    service.store_changes(remote_changes()).await.unwrap();
+    let tasks = service.get_all_tasks().await.unwrap();
+    let plan = tasks.iter().find(|t| t.remote_id == "t1").unwrap();
+    assert_eq!(plan.created_at.as_deref(), Some("2024-05-01T08:30:00Z"));
+
+    // Local tasks are stamped when they are added
+    let work = service.get_projects().await.unwrap()[0].uuid;
+    let new_task = NewTask {
+        content: "Call Sam".to_string(),
+        description: None,
+        project_uuid: Some(work),
+        section_uuid: None,
+        parent_uuid: None,
+        label_uuids: Vec::new(),
+        priority: None,
+        due_string: None,
+        deadline: None,
+        duration: None,
+    };
+    let task_uuid = service.create_task(&new_task).await.unwrap();
+    let task = service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+    assert!(task.created_at.is_some());
+
+    // One row per view, the latest choice wins
+    service.set_view_setting("today", "priority", "project", "list").await.unwrap();
+    service
+        .set_view_setting("filter:Work", "created", "label", "list")
+        .await
+        .unwrap();
+    service.set_view_setting("today", "due_date", "none", "board").await.unwrap();
+    let mut settings = service.get_view_settings().await.unwrap();
+    settings.sort_by(|a, b| a.view_key.cmp(&b.view_key));
+    let stored: Vec<_> = settings
+        .iter()
+        .map(|s| {
+            (
+                s.view_key.as_str(),
+                s.sort_mode.as_str(),
+                s.group_mode.as_str(),
+                s.layout.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        stored,
+        vec![
+            ("filter:Work", "created", "label", "list"),
+            ("today", "due_date", "none", "board")
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_tasks_due_between_include_start_and_exclude_end() {
+    let db = TempDb::new("due-between");
+    let (service, _storage) = seeded_service(&db).await;
+
+    let mut changes = remote_changes();
+    let due = |remote_id: &str, content: &str, date: &str| BackendTask {
+        remote_id: remote_id.to_string(),
+        content: content.to_string(),
+        due_date: Some(date.to_string()),
+        ..fixtures::backend_task()
+    };
+    changes.tasks = vec![
+        due("t3", "Before", "2030-05-31"),
+        due("t4", "First day", "2030-06-01"),
+        due("t5", "Last day", "2030-06-30"),
+        due("t6", "After", "2030-07-01"),
+    ];
+    service.store_changes(changes).await.unwrap();
+
+    let date = |d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").unwrap();
+    let tasks = service
+        .get_tasks_due_between(date("2030-06-01"), date("2030-07-01"))
+        .await
+        .unwrap();
+    let contents: Vec<_> = tasks.iter().map(|t| t.content.as_str()).collect();
+    assert_eq!(contents, vec!["First day", "Last day"]);
+}
@@ -1,3 +1,11 @@
+// Shared with the storage tests, which use more of it
+#[allow(dead_code)]
+#[path = "storage/common.rs"]
+mod common;
+
+#[path = "fixtures/entities.rs"]
+mod fixtures;
+
 #[path = "ui/app_component.rs"]
 mod app_component;
 
@@ -1,4 +1,17 @@
-use terminalist::ui::app_component::AppState;
+use crate::common::{sync_service, TempDb};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::time::{Duration, Instant};
+use terminalist::config::Config;
+use terminalist::constants::SUCCESS_SYNC_COMPLETED;
+use terminalist::sync::SyncStatus;
+use terminalist::ui::app_component::{AppComponent, AppState};
+use terminalist::ui::core::{Action, Component, DialogType, EventType};
+
+/// An app on the temporary database, which must outlive it
+async fn test_app(db: &TempDb) -> AppComponent {
+    let (service, _storage, _) = sync_service(db).await;
+    AppComponent::new(service, Config::default())
+}
 
 #[test]
 fn test_app_state_default() {
@@ -10,3 +23,90 @@ fn test_app_state_default() {
         "Default AppState should have no error message"
     );
 }
+
+#[test]
+fn test_app_state_starts_online() {
+    // Offline mode is only entered after a failed sync
+    let state = AppState::default();
+    assert!(!state.offline, "Default AppState should not be offline");
+    assert_eq!(
+        state.pending_changes, 0,
+        "Default AppState should have no unsynced changes"
+    );
+}
+
+#[tokio::test]
+async fn test_auto_sync_never_overlaps_a_running_sync() {
+    let db = TempDb::new("auto-sync");
+    let mut app = test_app(&db).await;
+    let now = Instant::now();
+
+    app.handle_app_action(Action::StartSync).await;
+    assert!(app.is_syncing());
+    // However long the sync takes, no tick starts a second one
+    assert!(!app.tick_auto_sync_at(now + Duration::from_secs(3600)));
+
+    // Once it finishes the next sync is scheduled and a due tick starts it
+    app.handle_app_action(Action::SyncFailed("offline".to_string())).await;
+    assert!(!app.is_syncing());
+    assert!(!app.tick_auto_sync_at(Instant::now()));
+    assert!(app.tick_auto_sync_at(Instant::now() + Duration::from_secs(24 * 3600)));
+    assert!(app.is_syncing());
+}
+
+#[tokio::test]
+async fn test_failed_sync_goes_offline_without_error_dialog() {
+    let db = TempDb::new("offline");
+    let mut app = test_app(&db).await;
+
+    let action = app.handle_app_action(Action::SyncFailed("Network error".to_string())).await;
+    assert!(matches!(action, Action::None));
+    assert!(app.state().offline, "A failed sync should switch to offline mode");
+    assert!(
+        !app.is_dialog_visible(),
+        "A failed sync should not open an error dialog"
+    );
+
+    let action = app
+        .handle_app_action(Action::SyncCompleted(SyncStatus::Error {
+            message: "Network error".to_string(),
+        }))
+        .await;
+    assert!(matches!(action, Action::None));
+    assert!(app.state().offline);
+    assert!(!app.is_dialog_visible());
+
+    // The next successful sync is back online
+    app.handle_app_action(Action::SyncCompleted(SyncStatus::Success)).await;
+    assert!(!app.state().offline, "A successful sync should leave offline mode");
+    assert!(app.state().error_message.is_none());
+}
+
+#[tokio::test]
+async fn test_auto_sync_leaves_an_open_dialog_alone() {
+    let db = TempDb::new("auto-sync-dialog");
+    let mut app = test_app(&db).await;
+    // The first sync schedules the next one
+    app.handle_app_action(Action::SyncCompleted(SyncStatus::Success)).await;
+
+    let action = app.update(Action::ShowDialog(DialogType::ProjectCreation));
+    app.handle_app_action(action).await;
+    for c in "Garden".chars() {
+        let key = KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        app.handle_event(EventType::Key(key)).await.unwrap();
+    }
+
+    // A scheduled sync runs to completion the way the app loop delivers it
+    assert!(app.tick_auto_sync_at(Instant::now() + Duration::from_secs(24 * 3600)));
+    let action = app.update(Action::SyncCompleted(SyncStatus::Success));
+    let action = app.handle_app_action(action).await;
+    assert!(
+        matches!(action, Action::None),
+        "A scheduled sync should not show a dialog"
+    );
+    assert!(!app.is_syncing());
+
+    assert!(matches!(app.dialog().dialog_type, Some(DialogType::ProjectCreation)));
+    assert_eq!(app.dialog().input_buffer, "Garden");
+    assert_eq!(app.state().info_message.as_deref(), Some(SUCCESS_SYNC_COMPLETED));
+}
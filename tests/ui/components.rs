@@ -1,6 +1,12 @@
 #[path = "components/badge.rs"]
 mod badge;
 
+#[path = "components/board_component.rs"]
+mod board_component;
+
+#[path = "components/calendar_component.rs"]
+mod calendar_component;
+
 #[path = "components/dialog_component.rs"]
 mod dialog_component;
 
@@ -1,3 +1,4 @@
+use ratatui::style::Color;
 use terminalist::ui::components::badge::*;
 
 #[test]
@@ -9,3 +10,14 @@ fn test_create_paren_badge() {
         "Paren badge should contain parentheses"
     );
 }
+
+#[test]
+fn test_create_label_badge_color() {
+    let badge = create_label_badge("work", Some(Color::Indexed(167)));
+    assert_eq!(badge.content, "@work");
+    assert_eq!(badge.style.fg, Some(Color::Indexed(167)));
+
+    // Labels without a color keep the default green
+    let badge = create_label_badge("home", None);
+    assert_eq!(badge.style.fg, Some(Color::Green));
+}
@@ -0,0 +1,226 @@
+use crate::fixtures;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Rect;
+use std::collections::HashMap;
+use terminalist::entities::{label, project, section, task};
+use terminalist::ui::components::BoardComponent;
+use terminalist::ui::core::{Action, Component, SortMode};
+
+struct Fixture {
+    board: BoardComponent,
+    project: project::Model,
+    doing: section::Model,
+    done: section::Model,
+    inbox_task: task::Model,
+    doing_task: task::Model,
+}
+
+/// A project with a task without a section, a "Doing" section with a task and a subtask,
+/// and an empty "Done" section
+fn fixture() -> Fixture {
+    let project = project::Model {
+        name: "Work".to_string(),
+        ..fixtures::project()
+    };
+    let doing = section::Model {
+        name: "Doing".to_string(),
+        project_uuid: project.uuid,
+        ..fixtures::section()
+    };
+    let done = section::Model {
+        name: "Done".to_string(),
+        project_uuid: project.uuid,
+        order_index: 1,
+        ..fixtures::section()
+    };
+    let other_project_section = section::Model {
+        name: "Elsewhere".to_string(),
+        ..fixtures::section()
+    };
+
+    let inbox_task = task::Model {
+        content: "Triage mail".to_string(),
+        project_uuid: project.uuid,
+        ..fixtures::task()
+    };
+    let mut doing_task = task::Model {
+        content: "Write report".to_string(),
+        project_uuid: project.uuid,
+        section_uuid: Some(doing.uuid),
+        ..fixtures::task()
+    };
+    doing_task.priority = 4;
+    doing_task.due_date = Some("2025-06-11".to_string());
+    let mut subtask = task::Model {
+        content: "Add charts".to_string(),
+        project_uuid: project.uuid,
+        section_uuid: Some(doing.uuid),
+        ..fixtures::task()
+    };
+    subtask.parent_uuid = Some(doing_task.uuid);
+    let urgent = label::Model {
+        name: "urgent".to_string(),
+        ..fixtures::label()
+    };
+
+    let mut board = BoardComponent::new();
+    board.update_data(
+        Some(project.clone()),
+        vec![doing.clone(), done.clone(), other_project_section],
+        vec![inbox_task.clone(), doing_task.clone(), subtask],
+        vec![urgent.clone()],
+        HashMap::from([(doing_task.uuid, vec![urgent.uuid])]),
+        SortMode::Manual,
+    );
+    Fixture {
+        board,
+        project,
+        doing,
+        done,
+        inbox_task,
+        doing_task,
+    }
+}
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+    KeyEvent::new(code, modifiers)
+}
+
+/// Press a key and apply the action it maps to
+fn press(board: &mut BoardComponent, code: KeyCode) -> Action {
+    let action = board.handle_key_events(key(code, KeyModifiers::NONE));
+    board.update(action)
+}
+
+fn titles(board: &BoardComponent, column: usize) -> Vec<String> {
+    board.cards(column).iter().map(|task| task.content.clone()).collect()
+}
+
+#[test]
+fn test_columns_are_the_project_sections_after_no_section() {
+    let Fixture { board, doing, done, .. } = fixture();
+
+    assert_eq!(board.column_count(), 3);
+    assert_eq!(board.column_section(0), None);
+    assert_eq!(board.column_section(1), Some(&doing));
+    assert_eq!(board.column_section(2), Some(&done));
+
+    // Subtasks stay inside their parent's card
+    assert_eq!(titles(&board, 0), vec!["Triage mail"]);
+    assert_eq!(titles(&board, 1), vec!["Write report"]);
+    assert!(titles(&board, 2).is_empty());
+}
+
+#[test]
+fn test_keys_select_cards_and_move_them_between_columns() {
+    let Fixture {
+        mut board,
+        project,
+        done,
+        doing_task,
+        ..
+    } = fixture();
+    assert_eq!(
+        board.selected_task().map(|task| task.content.as_str()),
+        Some("Triage mail")
+    );
+
+    press(&mut board, KeyCode::Right);
+    assert_eq!(board.selected_task().map(|task| task.uuid), Some(doing_task.uuid));
+
+    // The card moves to the next section through a task move
+    let action = press(&mut board, KeyCode::Char('>'));
+    assert!(matches!(
+        action,
+        Action::MoveTask { task_uuid, project_uuid, section_uuid, parent_uuid: None }
+            if task_uuid == doing_task.uuid && project_uuid == project.uuid && section_uuid == Some(done.uuid)
+    ));
+    let action = board.handle_key_events(key(KeyCode::Left, KeyModifiers::SHIFT));
+    assert!(matches!(action, Action::MoveTask { section_uuid: None, .. }));
+
+    // The empty column has nothing to move, the last column has no next one
+    press(&mut board, KeyCode::Right);
+    assert_eq!(board.column, 2);
+    assert!(board.selected_task().is_none());
+    assert!(matches!(press(&mut board, KeyCode::Char('>')), Action::None));
+
+    // Alt+j/k are left to the task list for reordering
+    assert!(matches!(
+        board.handle_key_events(key(KeyCode::Char('j'), KeyModifiers::ALT)),
+        Action::None
+    ));
+}
+
+#[test]
+fn test_selection_follows_a_moved_card() {
+    let Fixture {
+        mut board,
+        project,
+        doing,
+        done,
+        inbox_task,
+        mut doing_task,
+    } = fixture();
+    press(&mut board, KeyCode::Right);
+
+    // Reloaded after the move, the card is found in its new section
+    doing_task.section_uuid = Some(done.uuid);
+    board.update_data(
+        Some(project),
+        vec![doing, done],
+        vec![inbox_task, doing_task.clone()],
+        Vec::new(),
+        HashMap::new(),
+        SortMode::Manual,
+    );
+    assert_eq!(board.column, 2);
+    assert_eq!(board.selected_task().map(|task| task.uuid), Some(doing_task.uuid));
+}
+
+#[test]
+fn test_dragging_a_card_onto_another_column_moves_it() {
+    use ratatui::{backend::TestBackend, Terminal};
+
+    let Fixture {
+        mut board,
+        inbox_task,
+        done,
+        ..
+    } = fixture();
+    let area = Rect::new(0, 0, 90, 20);
+    let mut terminal = Terminal::new(TestBackend::new(90, 20)).unwrap();
+    terminal.draw(|f| board.render(f, area)).unwrap();
+    let screen = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>();
+    assert!(screen.contains("Board · Work"));
+    assert!(screen.contains("Doing (1)"));
+    assert!(screen.contains("Write report (1)"));
+    assert!(screen.contains("@urgent"));
+
+    let mouse = |kind, column, row| MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    };
+    // The board border is at x=0, each column is 29 wide with its first card at y=2
+    board.handle_mouse(mouse(MouseEventKind::Down(MouseButton::Left), 5, 3), area);
+    assert_eq!(board.selected_task().map(|task| task.uuid), Some(inbox_task.uuid));
+    board.handle_mouse(mouse(MouseEventKind::Drag(MouseButton::Left), 70, 5), area);
+    let action = board.handle_mouse(mouse(MouseEventKind::Up(MouseButton::Left), 70, 5), area);
+    assert!(matches!(
+        action,
+        Action::MoveTask { task_uuid, section_uuid, .. }
+            if task_uuid == inbox_task.uuid && section_uuid == Some(done.uuid)
+    ));
+
+    // Releasing over the column it came from does not move it
+    board.handle_mouse(mouse(MouseEventKind::Down(MouseButton::Left), 5, 3), area);
+    let action = board.handle_mouse(mouse(MouseEventKind::Up(MouseButton::Left), 8, 8), area);
+    assert!(matches!(action, Action::None));
+}
@@ -0,0 +1,132 @@
+use crate::fixtures;
+use chrono::{Duration, NaiveDate};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use terminalist::entities::task;
+use terminalist::ui::components::CalendarComponent;
+use terminalist::ui::core::{Action, Component};
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+/// A calendar on Wednesday, June 11 2025
+fn calendar() -> CalendarComponent {
+    let mut calendar = CalendarComponent::new();
+    calendar.cursor = date(2025, 6, 11);
+    calendar.selected_day = date(2025, 6, 11);
+    calendar
+}
+
+/// Press a key and apply the action it maps to
+fn press(calendar: &mut CalendarComponent, code: KeyCode) -> Action {
+    let action = calendar.handle_key_events(KeyEvent::new(code, KeyModifiers::NONE));
+    calendar.update(action)
+}
+
+#[test]
+fn test_grid_covers_six_weeks_from_monday() {
+    let calendar = calendar();
+
+    // June 2025 starts on a Sunday
+    assert_eq!(calendar.grid_start(), date(2025, 5, 26));
+    assert_eq!(
+        calendar.load_range(),
+        (date(2025, 5, 26), date(2025, 5, 26) + Duration::weeks(6))
+    );
+}
+
+#[test]
+fn test_keys_move_the_highlight_and_select_a_day() {
+    let mut calendar = calendar();
+
+    assert!(matches!(press(&mut calendar, KeyCode::Right), Action::None));
+    assert!(matches!(press(&mut calendar, KeyCode::Char(']')), Action::None));
+    assert_eq!(calendar.cursor, date(2025, 6, 19));
+    // Only 'g' opens the highlighted day
+    assert_eq!(calendar.selected_day, date(2025, 6, 11));
+    press(&mut calendar, KeyCode::Char('g'));
+    assert_eq!(calendar.selected_day, date(2025, 6, 19));
+
+    // Another month needs other tasks, the selected day keeps its agenda
+    assert!(matches!(press(&mut calendar, KeyCode::PageDown), Action::RefreshData));
+    assert_eq!(calendar.cursor, date(2025, 7, 19));
+    assert_eq!(calendar.load_range().0, date(2025, 6, 19));
+    press(&mut calendar, KeyCode::Char('['));
+    press(&mut calendar, KeyCode::Left);
+    assert_eq!(calendar.cursor, date(2025, 7, 11));
+
+    // Keys the calendar does not use are left to the task list
+    assert!(matches!(
+        calendar.handle_key_events(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE)),
+        Action::None
+    ));
+}
+
+#[test]
+fn test_open_tasks_are_listed_per_day() {
+    let mut calendar = calendar();
+    calendar.update_tasks(vec![
+        task::Model {
+            content: "Plan week".to_string(),
+            due_date: Some("2025-06-11".to_string()),
+            ..fixtures::task()
+        },
+        task::Model {
+            content: "Done already".to_string(),
+            due_date: Some("2025-06-11".to_string()),
+            is_completed: true,
+            ..fixtures::task()
+        },
+        task::Model {
+            content: "Dentist".to_string(),
+            due_date: Some("2025-06-12".to_string()),
+            ..fixtures::task()
+        },
+    ]);
+
+    let on_day = |day| {
+        calendar
+            .open_tasks_on(day)
+            .iter()
+            .map(|task| task.content.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(on_day(date(2025, 6, 11)), vec!["Plan week"]);
+    assert_eq!(on_day(date(2025, 6, 12)), vec!["Dentist"]);
+    assert!(on_day(date(2025, 6, 13)).is_empty());
+}
+
+#[test]
+fn test_render_shows_titles_or_counts_by_cell_size() {
+    use ratatui::{backend::TestBackend, layout::Rect, Terminal};
+
+    let mut calendar = calendar();
+    calendar.update_tasks(vec![
+        task::Model {
+            content: "Plan week".to_string(),
+            due_date: Some("2025-06-11".to_string()),
+            ..fixtures::task()
+        },
+        task::Model {
+            content: "Dentist".to_string(),
+            due_date: Some("2025-06-11".to_string()),
+            ..fixtures::task()
+        },
+    ]);
+    let screen = |width, height, calendar: &mut CalendarComponent| {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|f| calendar.render(f, Rect::new(0, 0, width, height))).unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        buffer.content().iter().map(|cell| cell.symbol()).collect::<String>()
+    };
+
+    let roomy = screen(120, 40, &mut calendar);
+    assert!(roomy.contains("Calendar · June 2025"));
+    assert!(roomy.contains("Plan week"));
+    assert!(roomy.contains("Dentist"));
+
+    // One line per week leaves room for the count only
+    let cramped = screen(70, 9, &mut calendar);
+    assert!(cramped.contains("11 ·2"));
+    assert!(!cramped.contains("Plan week"));
+}
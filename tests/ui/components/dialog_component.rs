@@ -1,4 +1,7 @@
+use crate::fixtures;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use terminalist::sync::tasks::TaskEdit;
+use terminalist::ui::components::dialogs::label_dialogs::LabelPickerRow;
 use terminalist::ui::components::DialogComponent;
 use terminalist::ui::core::{Action, Component, DialogType};
 use uuid::Uuid;
@@ -221,3 +224,356 @@ fn test_due_date_submit_clears_dialog() {
     assert!(dialog.input_buffer.is_empty());
     assert_eq!(dialog.cursor_position, 0);
 }
+
+// --- TaskEdit dialog behavior tests ---
+
+#[test]
+fn test_task_editor_submits_all_fields() {
+    let mut dialog = DialogComponent::new();
+    let work = terminalist::label::Model {
+        name: "work".to_string(),
+        ..fixtures::label()
+    };
+    dialog.update_data(Vec::new(), vec![work.clone()]);
+    let task_uuid = Uuid::new_v4();
+    dialog.update(Action::ShowDialog(DialogType::TaskEdit {
+        task_uuid,
+        values: TaskEdit {
+            content: "Plan".to_string(),
+            priority: 1,
+            ..TaskEdit::default()
+        },
+    }));
+
+    for c in " week".chars() {
+        dialog.handle_key_events(key(KeyCode::Char(c)));
+    }
+    // Enter starts a new line in the description
+    dialog.handle_key_events(key(KeyCode::Tab));
+    for code in [KeyCode::Char('a'), KeyCode::Enter, KeyCode::Char('b')] {
+        dialog.handle_key_events(key(code));
+    }
+    // Skip the project picker, toggle the label and raise the priority
+    dialog.handle_key_events(key(KeyCode::Tab));
+    dialog.handle_key_events(key(KeyCode::Tab));
+    dialog.handle_key_events(key(KeyCode::Char(' ')));
+    dialog.handle_key_events(key(KeyCode::Tab));
+    dialog.handle_key_events(key(KeyCode::Left));
+    // Back-tab wraps from the first field to the duration
+    dialog.handle_key_events(key(KeyCode::Tab));
+    dialog.handle_key_events(key(KeyCode::Tab));
+    dialog.handle_key_events(key(KeyCode::Tab));
+    dialog.handle_key_events(key(KeyCode::Tab));
+    dialog.handle_key_events(key(KeyCode::BackTab));
+    for c in "30".chars() {
+        dialog.handle_key_events(key(KeyCode::Char(c)));
+    }
+
+    let save = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+    match dialog.handle_key_events(save) {
+        Action::EditTask { task_uuid: uuid, edit } => {
+            assert_eq!(uuid, task_uuid);
+            assert_eq!(edit.content, "Plan week");
+            assert_eq!(edit.description, "a\nb");
+            assert_eq!(edit.label_uuids, vec![work.uuid]);
+            assert_eq!(edit.priority, 2);
+            assert_eq!(edit.duration, "30");
+        }
+        other => panic!("Expected EditTask, got {:?}", other),
+    }
+    assert!(!dialog.is_visible());
+}
+
+#[test]
+fn test_task_editor_ignores_labels_of_other_tasks() {
+    let mut dialog = DialogComponent::new();
+    let task_uuid = Uuid::new_v4();
+    dialog.update(Action::ShowDialog(DialogType::TaskEdit {
+        task_uuid,
+        values: TaskEdit::default(),
+    }));
+
+    dialog.update_task_labels(&Uuid::new_v4(), vec![Uuid::new_v4()]);
+    assert!(dialog.task_edit.label_uuids.is_empty());
+
+    let label_uuid = Uuid::new_v4();
+    dialog.update_task_labels(&task_uuid, vec![label_uuid]);
+    assert_eq!(dialog.task_edit.label_uuids, vec![label_uuid]);
+}
+
+#[test]
+fn test_task_editor_does_not_save_empty_content() {
+    let mut dialog = DialogComponent::new();
+    dialog.update(Action::ShowDialog(DialogType::TaskEdit {
+        task_uuid: Uuid::new_v4(),
+        values: TaskEdit::default(),
+    }));
+
+    assert!(matches!(dialog.handle_key_events(key(KeyCode::Enter)), Action::None));
+    assert!(dialog.is_visible());
+}
+
+// --- TaskCreation quick-add tests ---
+
+#[test]
+fn test_task_creation_resolves_quick_add_fields() {
+    let mut dialog = DialogComponent::new();
+    let inbox = terminalist::project::Model {
+        name: "Inbox".to_string(),
+        is_inbox_project: true,
+        ..fixtures::project()
+    };
+    let groceries = terminalist::project::Model {
+        name: "Grocery List".to_string(),
+        ..fixtures::project()
+    };
+    let errand = terminalist::label::Model {
+        name: "errand".to_string(),
+        ..fixtures::label()
+    };
+    dialog.update_data(vec![inbox.clone(), groceries.clone()], vec![errand.clone()]);
+    dialog.update(Action::ShowDialog(DialogType::TaskCreation {
+        default_project_uuid: Some(inbox.uuid),
+        parent_uuid: None,
+    }));
+
+    for c in "Buy milk #grocerylist @errand @unknown p1 friday //Semi-skimmed".chars() {
+        dialog.handle_key_events(key(KeyCode::Char(c)));
+    }
+
+    // The preview flags the label that does not exist
+    let (_, preview) = dialog.quick_add_task(Some(inbox.uuid));
+    let labels = preview.iter().find(|field| field.name == "Labels").unwrap();
+    assert_eq!(
+        labels.values,
+        [("errand".to_string(), true), ("unknown".to_string(), false)]
+    );
+
+    match dialog.handle_key_events(key(KeyCode::Enter)) {
+        Action::CreateTask(task) => {
+            assert_eq!(task.content, "Buy milk");
+            assert_eq!(task.project_uuid, Some(groceries.uuid));
+            assert_eq!(task.label_uuids, vec![errand.uuid]);
+            assert_eq!(task.priority, Some(4));
+            assert_eq!(task.due_string.as_deref(), Some("friday"));
+            assert_eq!(task.description.as_deref(), Some("Semi-skimmed"));
+        }
+        other => panic!("Expected CreateTask, got {:?}", other),
+    }
+    assert!(!dialog.is_visible());
+}
+
+#[test]
+fn test_task_creation_without_content_does_not_submit() {
+    let mut dialog = DialogComponent::new();
+    dialog.update(Action::ShowDialog(DialogType::TaskCreation {
+        default_project_uuid: None,
+        parent_uuid: None,
+    }));
+
+    for c in "#Work p2".chars() {
+        dialog.handle_key_events(key(KeyCode::Char(c)));
+    }
+    assert!(matches!(dialog.handle_key_events(key(KeyCode::Enter)), Action::None));
+    assert!(dialog.is_visible());
+}
+
+#[test]
+fn test_subtask_creation_goes_under_parent() {
+    let mut dialog = DialogComponent::new();
+    let work = terminalist::project::Model {
+        name: "Work".to_string(),
+        ..fixtures::project()
+    };
+    let home = terminalist::project::Model {
+        name: "Home".to_string(),
+        ..fixtures::project()
+    };
+    let parent = terminalist::task::Model {
+        content: "Plan week".to_string(),
+        project_uuid: work.uuid,
+        ..fixtures::task()
+    };
+    dialog.update_data_with_tasks(vec![work.clone(), home], Vec::new(), vec![parent.clone()]);
+    dialog.update(Action::ShowDialog(DialogType::TaskCreation {
+        default_project_uuid: Some(work.uuid),
+        parent_uuid: Some(parent.uuid),
+    }));
+
+    // The project of the parent applies, a typed one is ignored
+    for c in "Book room #home".chars() {
+        dialog.handle_key_events(key(KeyCode::Char(c)));
+    }
+    let (_, preview) = dialog.quick_add_task(Some(work.uuid));
+    let names: Vec<&str> = preview.iter().map(|field| field.name).collect();
+    assert_eq!(names, ["Content", "Subtask of"]);
+    assert_eq!(preview[1].values, [("Plan week".to_string(), true)]);
+
+    match dialog.handle_key_events(key(KeyCode::Enter)) {
+        Action::CreateTask(task) => {
+            assert_eq!(task.content, "Book room");
+            assert_eq!(task.parent_uuid, Some(parent.uuid));
+            assert_eq!(task.project_uuid, Some(work.uuid));
+        }
+        other => panic!("Expected CreateTask, got {:?}", other),
+    }
+}
+
+// --- LabelPicker dialog behavior tests ---
+
+#[test]
+fn test_label_picker_filters_toggles_and_creates() {
+    let mut dialog = DialogComponent::new();
+    let work = terminalist::label::Model {
+        name: "work".to_string(),
+        ..fixtures::label()
+    };
+    let errand = terminalist::label::Model {
+        name: "errand".to_string(),
+        ..fixtures::label()
+    };
+    let home = terminalist::label::Model {
+        name: "home".to_string(),
+        ..fixtures::label()
+    };
+    dialog.update_data(Vec::new(), vec![work.clone(), errand.clone(), home.clone()]);
+    let task_uuid = Uuid::new_v4();
+    dialog.update(Action::ShowDialog(DialogType::LabelPicker { task_uuid }));
+    dialog.update_task_labels(&task_uuid, vec![work.uuid]);
+
+    // "r" matches "errand" best, "work" further in, "home" not at all
+    dialog.handle_key_events(key(KeyCode::Char('r')));
+    let rows = dialog.label_picker_rows();
+    assert_eq!(rows.len(), 3);
+    assert!(matches!(&rows[0], LabelPickerRow::Label { name, is_selected: false, .. } if name == "errand"));
+    assert!(matches!(&rows[1], LabelPickerRow::Label { name, is_selected: true, .. } if name == "work"));
+    assert!(matches!(&rows[2], LabelPickerRow::Create(name) if name == "r"));
+    dialog.handle_key_events(key(KeyCode::Char(' ')));
+
+    // A new name is offered for creation and created on toggle
+    dialog.handle_key_events(key(KeyCode::Backspace));
+    for c in "urgent".chars() {
+        dialog.handle_key_events(key(KeyCode::Char(c)));
+    }
+    assert_eq!(
+        dialog.label_picker_rows(),
+        [LabelPickerRow::Create("urgent".to_string())]
+    );
+    dialog.handle_key_events(key(KeyCode::Char(' ')));
+    assert!(dialog.input_buffer.is_empty());
+
+    match dialog.handle_key_events(key(KeyCode::Enter)) {
+        Action::SetTaskLabels {
+            task_uuid: uuid,
+            label_uuids,
+            new_label_names,
+        } => {
+            assert_eq!(uuid, task_uuid);
+            assert_eq!(label_uuids, vec![work.uuid, errand.uuid]);
+            assert_eq!(new_label_names, vec!["urgent".to_string()]);
+        }
+        other => panic!("Expected SetTaskLabels, got {:?}", other),
+    }
+    assert!(!dialog.is_visible());
+}
+
+#[test]
+fn test_label_picker_enter_creates_typed_label() {
+    let mut dialog = DialogComponent::new();
+    let work = terminalist::label::Model {
+        name: "work".to_string(),
+        ..fixtures::label()
+    };
+    dialog.update_data(Vec::new(), vec![work]);
+    let task_uuid = Uuid::new_v4();
+    dialog.update(Action::ShowDialog(DialogType::LabelPicker { task_uuid }));
+
+    for c in "Work".chars() {
+        dialog.handle_key_events(key(KeyCode::Char(c)));
+    }
+    // An existing name is not offered again, whatever its case
+    assert_eq!(dialog.label_picker_rows().len(), 1);
+
+    dialog.handle_key_events(key(KeyCode::Char('s')));
+    match dialog.handle_key_events(key(KeyCode::Enter)) {
+        Action::SetTaskLabels { new_label_names, .. } => assert_eq!(new_label_names, vec!["Works".to_string()]),
+        other => panic!("Expected SetTaskLabels, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_move_picker_filters_and_moves_to_section() {
+    let mut dialog = DialogComponent::new();
+    let work = terminalist::project::Model {
+        name: "Work".to_string(),
+        ..fixtures::project()
+    };
+    let home = terminalist::project::Model {
+        name: "Home".to_string(),
+        ..fixtures::project()
+    };
+    let chores = terminalist::section::Model {
+        name: "Chores".to_string(),
+        project_uuid: home.uuid,
+        ..fixtures::section()
+    };
+    dialog.update_data(vec![work.clone(), home.clone()], Vec::new());
+    dialog.update_sections(vec![chores.clone()]);
+    let task_uuid = Uuid::new_v4();
+    dialog.update(Action::ShowDialog(DialogType::TaskMove {
+        task_uuid,
+        project_uuid: work.uuid,
+        section_uuid: None,
+    }));
+
+    let names: Vec<String> = dialog.move_targets().into_iter().map(|t| t.name).collect();
+    assert_eq!(names, ["Work", "Home", "Home / Chores"]);
+    assert!(dialog.move_targets()[0].is_current);
+
+    for c in "chor".chars() {
+        dialog.handle_key_events(key(KeyCode::Char(c)));
+    }
+    assert_eq!(dialog.move_targets().len(), 1);
+
+    match dialog.handle_key_events(key(KeyCode::Enter)) {
+        Action::MoveTask {
+            task_uuid: uuid,
+            project_uuid,
+            section_uuid,
+            parent_uuid,
+        } => {
+            assert_eq!(uuid, task_uuid);
+            assert_eq!(project_uuid, home.uuid);
+            assert_eq!(section_uuid, Some(chores.uuid));
+            assert_eq!(parent_uuid, None);
+        }
+        other => panic!("Expected MoveTask, got {:?}", other),
+    }
+    assert!(!dialog.is_visible());
+}
+
+#[test]
+fn test_move_picker_to_current_location_moves_nothing() {
+    let mut dialog = DialogComponent::new();
+    let work = terminalist::project::Model {
+        name: "Work".to_string(),
+        ..fixtures::project()
+    };
+    dialog.update_data(vec![work.clone()], Vec::new());
+    dialog.update(Action::ShowDialog(DialogType::TaskMove {
+        task_uuid: Uuid::new_v4(),
+        project_uuid: work.uuid,
+        section_uuid: None,
+    }));
+
+    // Nothing matches, Enter keeps the picker open
+    dialog.handle_key_events(key(KeyCode::Char('x')));
+    assert!(matches!(dialog.handle_key_events(key(KeyCode::Enter)), Action::None));
+    assert!(dialog.is_visible());
+
+    dialog.handle_key_events(key(KeyCode::Backspace));
+    assert!(matches!(
+        dialog.handle_key_events(key(KeyCode::Enter)),
+        Action::HideDialog
+    ));
+}
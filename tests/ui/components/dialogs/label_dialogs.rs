@@ -3,3 +3,28 @@ fn test_label_dialogs_module_exists() {
     // Test that the label dialogs module compiles and is accessible
     // If this test runs, the module is accessible
 }
+
+#[test]
+fn test_render_label_picker_dialog_does_not_panic() {
+    use ratatui::{backend::TestBackend, layout::Rect, Terminal};
+    use terminalist::ui::components::dialogs::label_dialogs::{render_label_picker_dialog, LabelPickerRow};
+
+    let rows = [
+        LabelPickerRow::Label {
+            uuid: uuid::Uuid::new_v4(),
+            name: "errand".to_string(),
+            is_selected: true,
+        },
+        LabelPickerRow::New("urgent".to_string()),
+        LabelPickerRow::Create("er".to_string()),
+    ];
+    for (width, height) in [(80, 30), (30, 5)] {
+        for rows in [&rows[..], &[]] {
+            let backend = TestBackend::new(width, height);
+            let mut terminal = Terminal::new(backend).unwrap();
+            terminal
+                .draw(|f| render_label_picker_dialog(f, Rect::new(0, 0, width, height), "er", 2, rows, 5))
+                .unwrap();
+        }
+    }
+}
@@ -49,3 +49,107 @@ fn test_render_due_date_dialog_small_terminal() {
         })
         .unwrap();
 }
+
+#[test]
+fn test_render_task_editor_dialog_does_not_panic() {
+    use terminalist::sync::tasks::TaskEdit;
+    use terminalist::ui::components::dialogs::task_dialogs::{
+        render_task_editor_dialog, TaskEditorField, TaskEditorView,
+    };
+
+    let values = TaskEdit {
+        content: "Plan week".to_string(),
+        description: "Agenda\nRooms\nFood\nTravel\nNotes".to_string(),
+        priority: 4,
+        due: "2030-01-15".to_string(),
+        ..TaskEdit::default()
+    };
+    for (width, height) in [(100, 40), (30, 5)] {
+        for field in [TaskEditorField::Description, TaskEditorField::Labels] {
+            let backend = TestBackend::new(width, height);
+            let mut terminal = Terminal::new(backend).unwrap();
+            let view = TaskEditorView {
+                values: &values,
+                focused_field: field,
+                cursor_position: values.description.chars().count(),
+                location: "Work / Chores".to_string(),
+                labels: &[],
+                selected_label_index: 0,
+            };
+            terminal
+                .draw(|f| {
+                    let area = Rect::new(0, 0, width, height);
+                    render_task_editor_dialog(f, area, &view);
+                })
+                .unwrap();
+        }
+    }
+}
+
+#[test]
+fn test_render_task_creation_dialog_with_preview() {
+    use terminalist::icons::IconService;
+    use terminalist::ui::components::dialogs::task_dialogs::{render_task_creation_dialog, QuickAddPreviewField};
+
+    let preview = [
+        QuickAddPreviewField {
+            name: "Content",
+            values: vec![("Buy milk".to_string(), true)],
+        },
+        QuickAddPreviewField {
+            name: "Labels",
+            values: vec![("errand".to_string(), true), ("unknown".to_string(), false)],
+        },
+    ];
+    for (width, height) in [(80, 30), (30, 5)] {
+        for preview in [&preview[..], &[]] {
+            let backend = TestBackend::new(width, height);
+            let mut terminal = Terminal::new(backend).unwrap();
+            terminal
+                .draw(|f| {
+                    let area = Rect::new(0, 0, width, height);
+                    let input = "Buy milk @errand @unknown";
+                    render_task_creation_dialog(
+                        f,
+                        area,
+                        &IconService::default(),
+                        input,
+                        input.len(),
+                        &[],
+                        None,
+                        preview,
+                    );
+                })
+                .unwrap();
+        }
+    }
+}
+
+#[test]
+fn test_render_task_move_dialog_does_not_panic() {
+    use terminalist::ui::components::dialogs::task_dialogs::{render_task_move_dialog, MoveTarget};
+
+    let targets = [
+        MoveTarget {
+            project_uuid: uuid::Uuid::new_v4(),
+            section_uuid: None,
+            name: "Work".to_string(),
+            is_current: true,
+        },
+        MoveTarget {
+            project_uuid: uuid::Uuid::new_v4(),
+            section_uuid: Some(uuid::Uuid::new_v4()),
+            name: "Home / Chores".to_string(),
+            is_current: false,
+        },
+    ];
+    for (width, height) in [(80, 30), (30, 5)] {
+        for targets in [&targets[..], &[]] {
+            let backend = TestBackend::new(width, height);
+            let mut terminal = Terminal::new(backend).unwrap();
+            terminal
+                .draw(|f| render_task_move_dialog(f, Rect::new(0, 0, width, height), "ho", 2, targets, 5))
+                .unwrap();
+        }
+    }
+}
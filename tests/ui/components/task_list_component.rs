@@ -1,7 +1,264 @@
+use crate::fixtures;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+use terminalist::entities::{label, project, task};
+use terminalist::ui::components::task_list_item_component::TaskListItemType;
 use terminalist::ui::components::TaskListComponent;
+use terminalist::ui::core::{Action, Component, DialogType, GroupMode, SidebarSelection, SortMode, ViewMode};
 
 #[test]
 fn test_task_list_component_creation() {
     // Test that TaskListComponent can be created without panicking
     let _task_list = TaskListComponent::new();
 }
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+/// A project view listing "First" with the subtask "Child", followed by "Second"
+fn task_list(is_collapsed: bool) -> (TaskListComponent, [task::Model; 3]) {
+    let project = project::Model {
+        name: "Work".to_string(),
+        ..fixtures::project()
+    };
+    let mut first = task::Model {
+        content: "First".to_string(),
+        project_uuid: project.uuid,
+        ..fixtures::task()
+    };
+    first.is_collapsed = is_collapsed;
+    let child = task::Model {
+        content: "Child".to_string(),
+        project_uuid: project.uuid,
+        parent_uuid: Some(first.uuid),
+        ..fixtures::task()
+    };
+    let second = task::Model {
+        content: "Second".to_string(),
+        project_uuid: project.uuid,
+        ..fixtures::task()
+    };
+
+    let mut task_list = TaskListComponent::new();
+    task_list.update_data(
+        vec![first.clone(), child.clone(), second.clone()],
+        Vec::new(),
+        vec![project],
+        Vec::new(),
+        SidebarSelection::Project(0),
+    );
+    (task_list, [first, child, second])
+}
+
+#[test]
+fn test_indent_and_outdent_reparent_the_selected_task() {
+    let (mut task_list, [first, child, second]) = task_list(false);
+
+    // The first task has no sibling above it
+    assert!(matches!(task_list.handle_key_events(key(KeyCode::Tab)), Action::None));
+    assert!(matches!(
+        task_list.handle_key_events(key(KeyCode::BackTab)),
+        Action::None
+    ));
+
+    // The subtask of the first task is skipped over, "Second" goes under "First"
+    task_list.handle_key_events(key(KeyCode::Down));
+    task_list.handle_key_events(key(KeyCode::Down));
+    assert_eq!(task_list.get_selected_task().unwrap().uuid, second.uuid);
+    match task_list.handle_key_events(key(KeyCode::Tab)) {
+        Action::MoveTask {
+            task_uuid, parent_uuid, ..
+        } => {
+            assert_eq!(task_uuid, second.uuid);
+            assert_eq!(parent_uuid, Some(first.uuid));
+        }
+        other => panic!("Expected MoveTask, got {:?}", other),
+    }
+
+    // Outdenting the subtask makes it a top-level task in the project of its parent
+    task_list.handle_key_events(key(KeyCode::Up));
+    assert_eq!(task_list.get_selected_task().unwrap().uuid, child.uuid);
+    assert!(matches!(task_list.handle_key_events(key(KeyCode::Tab)), Action::None));
+    match task_list.handle_key_events(key(KeyCode::BackTab)) {
+        Action::MoveTask {
+            task_uuid,
+            project_uuid,
+            parent_uuid,
+            ..
+        } => {
+            assert_eq!(task_uuid, child.uuid);
+            assert_eq!(project_uuid, first.project_uuid);
+            assert_eq!(parent_uuid, None);
+        }
+        other => panic!("Expected MoveTask, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_collapsed_task_hides_its_subtasks() {
+    let (mut task_list, [first, _, second]) = task_list(true);
+
+    assert_eq!(task_list.items.len(), 2);
+    task_list.handle_key_events(key(KeyCode::Down));
+    assert_eq!(task_list.get_selected_task().unwrap().uuid, second.uuid);
+    // Only tasks with subtasks can be expanded
+    assert!(matches!(
+        task_list.handle_key_events(key(KeyCode::Char('z'))),
+        Action::None
+    ));
+
+    task_list.handle_key_events(key(KeyCode::Up));
+    match task_list.handle_key_events(key(KeyCode::Char('z'))) {
+        Action::SetTaskCollapsed {
+            task_uuid,
+            is_collapsed,
+        } => {
+            assert_eq!(task_uuid, first.uuid);
+            assert!(!is_collapsed);
+        }
+        other => panic!("Expected SetTaskCollapsed, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_alt_keys_reorder_the_selected_task_among_its_siblings() {
+    let (mut task_list, [first, child, second]) = task_list(false);
+    let alt = |code| KeyEvent::new(code, KeyModifiers::ALT);
+
+    // "First" is the top sibling, the subtask has no siblings at all
+    assert!(matches!(task_list.handle_key_events(alt(KeyCode::Up)), Action::None));
+    task_list.handle_key_events(key(KeyCode::Down));
+    assert_eq!(task_list.get_selected_task().unwrap().uuid, child.uuid);
+    assert!(matches!(task_list.handle_key_events(alt(KeyCode::Down)), Action::None));
+
+    task_list.handle_key_events(key(KeyCode::Down));
+    assert!(matches!(
+        task_list.handle_key_events(alt(KeyCode::Char('j'))),
+        Action::None
+    ));
+    match task_list.handle_key_events(alt(KeyCode::Char('k'))) {
+        Action::ReorderTask { task_uuid, offset } => {
+            assert_eq!(task_uuid, second.uuid);
+            assert_eq!(offset, -1);
+        }
+        other => panic!("Expected ReorderTask, got {:?}", other),
+    }
+
+    // The cursor follows the task once the new order is loaded
+    let mut second = second;
+    second.order_index = -1;
+    let projects = task_list.projects.clone();
+    task_list.update_data(
+        vec![second.clone(), first, child],
+        Vec::new(),
+        projects,
+        Vec::new(),
+        SidebarSelection::Project(0),
+    );
+    assert_eq!(task_list.selected_index, 0);
+    assert_eq!(task_list.get_selected_task().unwrap().uuid, second.uuid);
+}
+
+/// The list as text: headers as they are shown, tasks by content and separators as "--"
+fn rows(task_list: &TaskListComponent) -> Vec<String> {
+    task_list
+        .items
+        .iter()
+        .map(|item| match item {
+            TaskListItemType::Task(item) => item.task.content.clone(),
+            TaskListItemType::Header(header) => header.text.clone(),
+            TaskListItemType::Separator(_) => "--".to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_grouping_replaces_the_view_sections_with_headed_groups() {
+    let (mut task_list, [first, child, second]) = task_list(false);
+    let label = |name: &str| label::Model {
+        name: name.to_string(),
+        ..fixtures::label()
+    };
+    let (work, errand) = (label("work"), label("errand"));
+    let mut second = second;
+    second.priority = 4;
+    let projects = task_list.projects.clone();
+    let update = |task_list: &mut TaskListComponent, view_mode| {
+        task_list.update_view_mode(view_mode);
+        task_list.update_data(
+            vec![first.clone(), child.clone(), second.clone()],
+            Vec::new(),
+            projects.clone(),
+            vec![work.clone(), errand.clone()],
+            SidebarSelection::Project(0),
+        );
+    };
+
+    update(
+        &mut task_list,
+        ViewMode {
+            sort: SortMode::Manual,
+            group: GroupMode::Priority,
+            ..ViewMode::default()
+        },
+    );
+    assert_eq!(
+        rows(&task_list),
+        vec!["🚩 Priority 1", "Second", "--", "🚩 Priority 4", "First", "Child"]
+    );
+
+    // A task is listed under each of its labels, subtasks stay with their parent
+    task_list.update_task_labels(HashMap::from([(first.uuid, vec![errand.uuid, work.uuid])]));
+    update(
+        &mut task_list,
+        ViewMode {
+            sort: SortMode::Alphabetical,
+            group: GroupMode::Label,
+            ..ViewMode::default()
+        },
+    );
+    assert_eq!(
+        rows(&task_list),
+        vec![
+            "🏷️ work",
+            "First",
+            "Child",
+            "--",
+            "🏷️ errand",
+            "First",
+            "Child",
+            "--",
+            "🏷️ No labels",
+            "Second"
+        ]
+    );
+
+    // Tasks can only be moved by hand while the view is in manual order
+    assert!(matches!(
+        task_list.handle_key_events(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::ALT)),
+        Action::ShowDialog(DialogType::Info(_))
+    ));
+}
+
+#[test]
+fn test_calendar_agenda_lists_the_tasks_of_the_selected_day() {
+    let (mut task_list, [first, child, second]) = task_list(false);
+    let today = chrono::Local::now().date_naive();
+    let tomorrow = (today + chrono::Duration::days(1)).format("%Y-%m-%d").to_string();
+    let (mut first, mut child, mut second) = (first, child, second);
+    first.due_date = Some(tomorrow.clone());
+    child.due_date = Some(tomorrow);
+    second.due_date = Some(today.format("%Y-%m-%d").to_string());
+    let projects = task_list.projects.clone();
+
+    task_list.update_agenda_day(today + chrono::Duration::days(1));
+    task_list.update_data(
+        vec![first, child, second],
+        Vec::new(),
+        projects,
+        Vec::new(),
+        SidebarSelection::Calendar,
+    );
+    assert_eq!(rows(&task_list), vec!["📅 Tomorrow", "First", "Child"]);
+}
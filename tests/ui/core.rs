@@ -10,5 +10,11 @@ mod context;
 #[path = "core/event_handler.rs"]
 mod event_handler;
 
+#[path = "core/sync_scheduler.rs"]
+mod sync_scheduler;
+
 #[path = "core/task_manager.rs"]
 mod task_manager;
+
+#[path = "core/view_mode.rs"]
+mod view_mode;
@@ -0,0 +1,72 @@
+use std::time::{Duration, Instant};
+use terminalist::constants::AUTO_SYNC_MAX_BACKOFF_MINUTES;
+use terminalist::ui::core::SyncScheduler;
+
+const MINUTE: Duration = Duration::from_secs(60);
+
+#[test]
+fn test_disabled_scheduler_never_fires() {
+    let mut scheduler = SyncScheduler::new(0);
+    let now = Instant::now();
+    scheduler.record_success(now);
+
+    assert!(!scheduler.is_enabled());
+    assert!(scheduler.next_sync().is_none());
+    assert!(!scheduler.is_due(now + MINUTE * 1000));
+}
+
+#[test]
+fn test_next_sync_is_one_interval_after_success() {
+    let mut scheduler = SyncScheduler::new(5);
+    let now = Instant::now();
+
+    // Nothing is scheduled until the first sync finishes
+    assert!(!scheduler.is_due(now));
+
+    scheduler.record_success(now);
+    assert_eq!(scheduler.next_sync(), Some(now + MINUTE * 5));
+    assert!(!scheduler.is_due(now + MINUTE * 4));
+    assert!(scheduler.is_due(now + MINUTE * 5));
+
+    // A running sync clears the schedule until it completes
+    scheduler.sync_started();
+    assert!(!scheduler.is_due(now + MINUTE * 10));
+}
+
+#[test]
+fn test_failures_back_off_until_success() {
+    let mut scheduler = SyncScheduler::new(5);
+    let now = Instant::now();
+
+    scheduler.record_failure(now);
+    assert_eq!(scheduler.next_sync(), Some(now + MINUTE * 10));
+    scheduler.record_failure(now);
+    assert_eq!(scheduler.next_sync(), Some(now + MINUTE * 20));
+
+    for _ in 0..10 {
+        scheduler.record_failure(now);
+    }
+    assert_eq!(
+        scheduler.next_sync(),
+        Some(now + MINUTE * AUTO_SYNC_MAX_BACKOFF_MINUTES as u32)
+    );
+    assert_eq!(scheduler.consecutive_failures(), 12);
+
+    scheduler.record_success(now);
+    assert_eq!(scheduler.consecutive_failures(), 0);
+    assert_eq!(scheduler.next_sync(), Some(now + MINUTE * 5));
+}
+
+#[test]
+fn test_rate_limit_waits_at_least_retry_after() {
+    let mut scheduler = SyncScheduler::new(1);
+    let now = Instant::now();
+
+    // The requested delay wins over a shorter backoff...
+    scheduler.record_failure_after(now, MINUTE * 30);
+    assert_eq!(scheduler.next_sync(), Some(now + MINUTE * 30));
+
+    // ...and the backoff wins over a shorter requested delay
+    scheduler.record_failure_after(now, Duration::from_secs(10));
+    assert_eq!(scheduler.next_sync(), Some(now + MINUTE * 4));
+}
@@ -0,0 +1,138 @@
+use crate::fixtures;
+use terminalist::config::FilterConfig;
+use terminalist::entities::{label, project, task};
+use terminalist::ui::core::view_mode::{sort_tasks, view_key};
+use terminalist::ui::core::{GroupMode, SidebarSelection, SortMode, ViewLayout, ViewMode};
+
+fn contents(tasks: &[task::Model]) -> Vec<&str> {
+    tasks.iter().map(|t| t.content.as_str()).collect()
+}
+
+#[test]
+fn test_modes_cycle_and_round_trip() {
+    let mut sort = SortMode::default();
+    for _ in 0..5 {
+        assert_eq!(SortMode::parse(sort.as_str()), Some(sort));
+        sort = sort.next();
+    }
+    assert_eq!(sort, SortMode::Manual);
+
+    let mut group = GroupMode::default();
+    for _ in 0..7 {
+        assert_eq!(GroupMode::parse(group.as_str()), Some(group));
+        group = group.next();
+    }
+    assert_eq!(group, GroupMode::View);
+
+    // Unknown stored values fall back to the view's own layout
+    assert_eq!(
+        ViewMode::from_stored("priority", "bogus", "board"),
+        ViewMode {
+            sort: SortMode::Priority,
+            group: GroupMode::View,
+            layout: ViewLayout::Board,
+        }
+    );
+    assert_eq!(ViewMode::from_stored("manual", "view", "").layout, ViewLayout::List);
+    assert_eq!(ViewLayout::List.toggled(), ViewLayout::Board);
+    assert_eq!(ViewLayout::Board.toggled(), ViewLayout::List);
+    assert_eq!(ViewMode::default().describe(), None);
+    assert_eq!(
+        ViewMode {
+            sort: SortMode::DueDate,
+            group: GroupMode::Label,
+            ..ViewMode::default()
+        }
+        .describe()
+        .as_deref(),
+        Some("by due date, grouped by label")
+    );
+}
+
+#[test]
+fn test_view_keys_identify_each_view() {
+    let project = project::Model {
+        name: "Work".to_string(),
+        ..fixtures::project()
+    };
+    let label = label::Model {
+        name: "errand".to_string(),
+        ..fixtures::label()
+    };
+    let filters = vec![FilterConfig {
+        name: "Urgent".to_string(),
+        query: "p1".to_string(),
+    }];
+    let (project_uuid, label_uuid) = (project.uuid, label.uuid);
+    let (projects, labels) = (vec![project], vec![label]);
+    let key = |selection| view_key(&selection, &projects, &labels, &filters);
+
+    assert_eq!(key(SidebarSelection::Today).as_deref(), Some("today"));
+    assert_eq!(
+        key(SidebarSelection::Project(0)),
+        Some(format!("project:{}", project_uuid))
+    );
+    assert_eq!(key(SidebarSelection::Label(0)), Some(format!("label:{}", label_uuid)));
+    assert_eq!(key(SidebarSelection::Filter(0)).as_deref(), Some("filter:Urgent"));
+    assert_eq!(key(SidebarSelection::Project(1)), None);
+    assert_eq!(key(SidebarSelection::Schedule), None);
+    assert_eq!(key(SidebarSelection::Logbook), None);
+}
+
+#[test]
+fn test_sort_tasks_keeps_completed_tasks_last() {
+    let mut done = task::Model {
+        content: "Archive".to_string(),
+        priority: 4,
+        due_date: Some("2020-01-01".to_string()),
+        created_at: Some("2020-01-01T00:00:00Z".to_string()),
+        ..fixtures::task()
+    };
+    done.is_completed = true;
+    let tasks = vec![
+        done,
+        task::Model {
+            content: "beta".to_string(),
+            due_date: Some("2030-02-01".to_string()),
+            ..fixtures::task()
+        },
+        task::Model {
+            content: "Alpha".to_string(),
+            priority: 3,
+            created_at: Some("2024-03-01T10:00:00+02:00".to_string()),
+            ..fixtures::task()
+        },
+        task::Model {
+            content: "gamma".to_string(),
+            priority: 3,
+            due_date: Some("2030-01-01".to_string()),
+            created_at: Some("2024-01-01T00:00:00Z".to_string()),
+            ..fixtures::task()
+        },
+    ];
+
+    let sorted = |sort| {
+        let mut tasks = tasks.clone();
+        sort_tasks(&mut tasks, sort);
+        tasks
+    };
+    assert_eq!(contents(&sorted(SortMode::Manual)), contents(&tasks));
+    // Equal priorities keep their loaded order
+    assert_eq!(
+        contents(&sorted(SortMode::Priority)),
+        vec!["Alpha", "gamma", "beta", "Archive"]
+    );
+    assert_eq!(
+        contents(&sorted(SortMode::DueDate)),
+        vec!["gamma", "beta", "Alpha", "Archive"]
+    );
+    assert_eq!(
+        contents(&sorted(SortMode::Alphabetical)),
+        vec!["Alpha", "beta", "gamma", "Archive"]
+    );
+    // Tasks without a creation time go after the dated ones
+    assert_eq!(
+        contents(&sorted(SortMode::Created)),
+        vec!["gamma", "Alpha", "beta", "Archive"]
+    );
+}
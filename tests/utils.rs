@@ -1,2 +1,25 @@
+// Shared with the UI tests, which use more of it
+#[allow(dead_code)]
+#[path = "fixtures/entities.rs"]
+mod fixtures;
+
 #[path = "utils/datetime.rs"]
 mod datetime;
+
+#[path = "utils/color.rs"]
+mod color;
+
+#[path = "utils/quick_add.rs"]
+mod quick_add;
+
+#[path = "utils/fuzzy.rs"]
+mod fuzzy;
+
+#[path = "utils/schedule.rs"]
+mod schedule;
+
+#[path = "utils/filter_query.rs"]
+mod filter_query;
+
+#[path = "utils/text.rs"]
+mod text;
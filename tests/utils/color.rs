@@ -0,0 +1,32 @@
+use ratatui::style::Color;
+use terminalist::utils::color::*;
+
+#[test]
+fn test_parse_color_names_and_hex() {
+    assert_eq!(parse_color("berry_red"), Some((0xb8, 0x25, 0x6f)));
+    assert_eq!(parse_color("Sky_Blue"), Some((0x14, 0xaa, 0xf5)));
+    assert_eq!(parse_color("#4073ff"), Some((0x40, 0x73, 0xff)));
+    assert_eq!(parse_color("#40"), None);
+    assert_eq!(parse_color("not_a_color"), None);
+}
+
+#[test]
+fn test_rgb_to_ansi256() {
+    // Exact cube and grayscale entries map to themselves
+    assert_eq!(rgb_to_ansi256((255, 0, 0)), 196);
+    assert_eq!(rgb_to_ansi256((0, 0, 0)), 16);
+    assert_eq!(rgb_to_ansi256((128, 128, 128)), 244);
+    // Todoist's red is closest to the 256-color red
+    assert_eq!(rgb_to_ansi256((0xdb, 0x40, 0x35)), 167);
+}
+
+#[test]
+fn test_terminal_color_fallbacks() {
+    assert_eq!(
+        terminal_color("red", ColorSupport::TrueColor),
+        Some(Color::Rgb(0xdb, 0x40, 0x35))
+    );
+    assert_eq!(terminal_color("red", ColorSupport::Ansi256), Some(Color::Indexed(167)));
+    assert_eq!(terminal_color("unknown", ColorSupport::TrueColor), None);
+    assert_eq!(backend_color(None), None);
+}
@@ -117,3 +117,96 @@ fn test_format_human_datetime_iso_format() {
     assert!(formatted.contains("at"));
     assert!(formatted.contains("09:00"));
 }
+
+#[test]
+fn test_normalize_duration() {
+    assert_eq!(normalize_duration("30").as_deref(), Some("30 minute"));
+    assert_eq!(normalize_duration("45 min").as_deref(), Some("45 minute"));
+    assert_eq!(normalize_duration("90minutes").as_deref(), Some("90 minute"));
+    assert_eq!(normalize_duration(" 2 Days ").as_deref(), Some("2 day"));
+    assert_eq!(normalize_duration("45m").as_deref(), Some("45 minute"));
+    assert_eq!(normalize_duration("2 hours").as_deref(), Some("120 minute"));
+    assert_eq!(normalize_duration("1h30").as_deref(), Some("90 minute"));
+    assert_eq!(normalize_duration("1h 30m").as_deref(), Some("90 minute"));
+    assert_eq!(normalize_duration("90 minute").as_deref(), Some("90 minute"));
+    assert_eq!(normalize_duration("0"), None);
+    assert_eq!(normalize_duration("0h0"), None);
+    assert_eq!(normalize_duration("an hour"), None);
+    assert_eq!(normalize_duration("3 weeks"), None);
+    assert_eq!(normalize_duration("30m 1h"), None);
+    assert_eq!(normalize_duration("1 day 2h"), None);
+}
+
+#[test]
+fn test_duration_minutes() {
+    assert_eq!(duration_minutes("90 minute"), Some(90));
+    assert_eq!(duration_minutes("2 day"), Some(2880));
+    assert_eq!(duration_minutes("90"), None);
+}
+
+#[test]
+fn test_parse_deadline() {
+    // A Wednesday
+    let today = NaiveDate::from_ymd_opt(2025, 6, 11).unwrap();
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+
+    assert_eq!(parse_deadline("2025-03-30", today), date(2025, 3, 30));
+    assert_eq!(parse_deadline("fri", today), date(2025, 6, 13));
+    assert_eq!(parse_deadline(" march 30 ", today), date(2026, 3, 30));
+    assert_eq!(parse_deadline("friday 5pm", today), None);
+    assert_eq!(parse_deadline("every friday", today), None);
+    assert_eq!(parse_deadline("soon", today), None);
+}
+
+#[test]
+fn test_parse_time_of_day() {
+    let time = |h, m| Some(chrono::NaiveTime::from_hms_opt(h, m, 0).unwrap());
+    assert_eq!(parse_time_of_day("5pm"), time(17, 0));
+    assert_eq!(parse_time_of_day("5:30PM"), time(17, 30));
+    assert_eq!(parse_time_of_day("12am"), time(0, 0));
+    assert_eq!(parse_time_of_day("12pm"), time(12, 0));
+    assert_eq!(parse_time_of_day("17:00"), time(17, 0));
+    assert_eq!(parse_time_of_day("5"), None);
+    assert_eq!(parse_time_of_day("13pm"), None);
+    assert_eq!(parse_time_of_day("25:00"), None);
+}
+
+#[test]
+fn test_resolve_due_string() {
+    // A Wednesday
+    let today = NaiveDate::from_ymd_opt(2025, 6, 11).unwrap();
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let five_pm = chrono::NaiveTime::from_hms_opt(17, 0, 0);
+
+    assert_eq!(resolve_due_string("today", today), Some((today, None)));
+    assert_eq!(
+        resolve_due_string("tmrw 5pm", today),
+        Some((date(2025, 6, 12), five_pm))
+    );
+    assert_eq!(resolve_due_string("at 5pm", today), Some((today, five_pm)));
+    assert_eq!(resolve_due_string("Fri", today), Some((date(2025, 6, 13), None)));
+    assert_eq!(resolve_due_string("wednesday", today), Some((date(2025, 6, 18), None)));
+    assert_eq!(resolve_due_string("next week", today), Some((date(2025, 6, 16), None)));
+    assert_eq!(resolve_due_string("in 3 days", today), Some((date(2025, 6, 14), None)));
+    assert_eq!(resolve_due_string("in 2 weeks", today), Some((date(2025, 6, 25), None)));
+    assert_eq!(resolve_due_string("2025-07-01", today), Some((date(2025, 7, 1), None)));
+    assert_eq!(resolve_due_string("march 15", today), Some((date(2026, 3, 15), None)));
+    assert_eq!(
+        resolve_due_string("15 Dec at 9am", today),
+        Some((date(2025, 12, 15), chrono::NaiveTime::from_hms_opt(9, 0, 0)))
+    );
+    assert_eq!(resolve_due_string("every monday", today), None);
+    assert_eq!(resolve_due_string("feb 30", today), None);
+}
+
+#[test]
+fn test_month_helpers() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    assert_eq!(month_start(date(2025, 6, 11)), date(2025, 6, 1));
+    assert_eq!(add_months(date(2025, 1, 31), 1), date(2025, 2, 28));
+    assert_eq!(add_months(date(2025, 1, 15), -2), date(2024, 11, 15));
+    // June 2025 starts on a Sunday, September 2025 on a Monday
+    assert_eq!(month_grid_start(date(2025, 6, 20)), date(2025, 5, 26));
+    assert_eq!(month_grid_start(date(2025, 9, 30)), date(2025, 9, 1));
+}
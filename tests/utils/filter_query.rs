@@ -0,0 +1,103 @@
+use terminalist::utils::filter_query::{parse_filter_query, FilterExpr, FilterQueryError, FilterTerm};
+
+fn term(term: FilterTerm) -> FilterExpr {
+    FilterExpr::Term(term)
+}
+
+fn and(left: FilterExpr, right: FilterExpr) -> FilterExpr {
+    FilterExpr::And(Box::new(left), Box::new(right))
+}
+
+fn or(left: FilterExpr, right: FilterExpr) -> FilterExpr {
+    FilterExpr::Or(Box::new(left), Box::new(right))
+}
+
+fn not(inner: FilterExpr) -> FilterExpr {
+    FilterExpr::Not(Box::new(inner))
+}
+
+#[test]
+fn test_parse_single_terms() {
+    let cases = [
+        ("p1", FilterTerm::Priority(4)),
+        ("P4", FilterTerm::Priority(1)),
+        ("@work", FilterTerm::Label("work".to_string())),
+        ("#Grocery List", FilterTerm::Project("Grocery List".to_string())),
+        ("##Home", FilterTerm::ProjectTree("Home".to_string())),
+        ("/Chores", FilterTerm::Section("Chores".to_string())),
+        ("Today", FilterTerm::Today),
+        ("tomorrow", FilterTerm::Tomorrow),
+        ("overdue", FilterTerm::Overdue),
+        ("no date", FilterTerm::NoDate),
+        ("no due date", FilterTerm::NoDate),
+        ("no labels", FilterTerm::NoLabels),
+        ("7 days", FilterTerm::NextDays(7)),
+        ("next 3 days", FilterTerm::NextDays(3)),
+        ("due before: next week", FilterTerm::DueBefore("next week".to_string())),
+        ("due after:2030-01-10", FilterTerm::DueAfter("2030-01-10".to_string())),
+        ("recurring", FilterTerm::Recurring),
+        ("search: Buy milk", FilterTerm::Search("Buy milk".to_string())),
+        ("all", FilterTerm::All),
+    ];
+    for (query, expected) in cases {
+        assert_eq!(parse_filter_query(query), Ok(term(expected)), "query: {}", query);
+    }
+}
+
+#[test]
+fn test_parse_operator_precedence() {
+    // `!` binds tightest, then `&`, then `|`
+    assert_eq!(
+        parse_filter_query("p1 & @work & overdue | today"),
+        Ok(or(
+            and(
+                and(
+                    term(FilterTerm::Priority(4)),
+                    term(FilterTerm::Label("work".to_string()))
+                ),
+                term(FilterTerm::Overdue)
+            ),
+            term(FilterTerm::Today)
+        ))
+    );
+    assert_eq!(
+        parse_filter_query("!#Someday & (p1 | p2)"),
+        Ok(and(
+            not(term(FilterTerm::Project("Someday".to_string()))),
+            or(term(FilterTerm::Priority(4)), term(FilterTerm::Priority(3)))
+        ))
+    );
+    assert_eq!(
+        parse_filter_query("!(today | overdue)"),
+        Ok(not(or(term(FilterTerm::Today), term(FilterTerm::Overdue))))
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(parse_filter_query("  "), Err(FilterQueryError::Empty));
+    assert_eq!(
+        parse_filter_query("p5"),
+        Err(FilterQueryError::UnknownTerm("p5".to_string()))
+    );
+    assert_eq!(
+        parse_filter_query("due before: someday"),
+        Err(FilterQueryError::InvalidDate("someday".to_string()))
+    );
+    assert_eq!(
+        parse_filter_query("today &"),
+        Err(FilterQueryError::MissingTerm("&".to_string()))
+    );
+    assert_eq!(
+        parse_filter_query("(today | overdue"),
+        Err(FilterQueryError::UnclosedParen)
+    );
+    assert_eq!(
+        parse_filter_query("today)"),
+        Err(FilterQueryError::Unexpected(")".to_string()))
+    );
+    assert_eq!(
+        parse_filter_query("& today"),
+        Err(FilterQueryError::Unexpected("&".to_string()))
+    );
+}
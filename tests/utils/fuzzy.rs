@@ -0,0 +1,29 @@
+use terminalist::utils::fuzzy::*;
+
+#[test]
+fn test_fuzzy_score_matches_in_order() {
+    assert!(fuzzy_score("errand", "ern").is_some());
+    assert!(fuzzy_score("Errand", "ERR").is_some());
+    assert_eq!(fuzzy_score("errand", "nre"), None);
+    assert_eq!(fuzzy_score("work", "works"), None);
+    assert_eq!(fuzzy_score("anything", ""), Some(0));
+}
+
+#[test]
+fn test_fuzzy_score_prefers_prefixes_and_word_starts() {
+    let prefix = fuzzy_score("home", "ho").unwrap();
+    let word_start = fuzzy_score("at_home", "ho").unwrap();
+    let inside = fuzzy_score("photo", "ho").unwrap();
+    let scattered = fuzzy_score("chrome", "ho").unwrap();
+    assert!(prefix > word_start);
+    assert!(word_start > inside);
+    assert!(inside > scattered);
+}
+
+#[test]
+fn test_fuzzy_filter_orders_by_score() {
+    let names = ["photo", "home", "errand", "at_home"];
+    assert_eq!(fuzzy_filter(names, "ho"), [1, 3, 0]);
+    // An empty query keeps everything in its original order
+    assert_eq!(fuzzy_filter(names, ""), [0, 1, 2, 3]);
+}
@@ -0,0 +1,123 @@
+use terminalist::utils::quick_add::*;
+
+#[test]
+fn test_parse_quick_add_all_fields() {
+    let parsed = parse_quick_add("Buy milk #Groceries /Dairy @errand p1 tomorrow 5pm //Semi-skimmed, 2 bottles");
+    assert_eq!(
+        parsed,
+        QuickAdd {
+            content: "Buy milk".to_string(),
+            project: Some("Groceries".to_string()),
+            section: Some("Dairy".to_string()),
+            labels: vec!["errand".to_string()],
+            priority: Some(4),
+            due_string: Some("tomorrow 5pm".to_string()),
+            deadline: None,
+            duration: None,
+            description: Some("Semi-skimmed, 2 bottles".to_string()),
+        }
+    );
+}
+
+#[test]
+fn test_parse_quick_add_plain_text() {
+    let parsed = parse_quick_add("Call the plumber");
+    assert_eq!(parsed.content, "Call the plumber");
+    assert_eq!(
+        parsed,
+        QuickAdd {
+            content: "Call the plumber".to_string(),
+            ..QuickAdd::default()
+        }
+    );
+}
+
+#[test]
+fn test_parse_quick_add_markers_only_at_word_start() {
+    let parsed = parse_quick_add("Mail bob@example.com about https://example.com/a#b // ");
+    assert_eq!(parsed.content, "Mail bob@example.com about https://example.com/a#b");
+    assert!(parsed.labels.is_empty());
+    assert_eq!(parsed.project, None);
+    assert_eq!(parsed.description, None);
+
+    // Bare markers and "//" inside a word are content too
+    let parsed = parse_quick_add("Split # / @ items a//b");
+    assert_eq!(parsed.content, "Split # / @ items a//b");
+}
+
+#[test]
+fn test_parse_quick_add_repeated_markers() {
+    let parsed = parse_quick_add("Pack @travel #Home @Travel @kids #Trips p4 P2");
+    assert_eq!(parsed.content, "Pack");
+    assert_eq!(parsed.project.as_deref(), Some("Trips"));
+    assert_eq!(parsed.labels, ["travel", "kids"]);
+    assert_eq!(parsed.priority, Some(3));
+}
+
+#[test]
+fn test_parse_quick_add_due_phrases() {
+    let due = |input: &str| {
+        let parsed = parse_quick_add(input);
+        (parsed.content, parsed.due_string)
+    };
+    let expect = |content: &str, due: &str| (content.to_string(), Some(due.to_string()));
+
+    assert_eq!(
+        due("Standup every weekday at 9am"),
+        expect("Standup", "every weekday at 9am")
+    );
+    assert_eq!(due("Pay rent every 1st"), expect("Pay rent", "every 1st"));
+    assert_eq!(due("Review next monday"), expect("Review", "next monday"));
+    assert_eq!(due("Renew in 3 weeks"), expect("Renew", "in 3 weeks"));
+    assert_eq!(due("Dentist march 15 at 14:30"), expect("Dentist", "march 15 at 14:30"));
+    assert_eq!(due("Taxes 30 apr"), expect("Taxes", "30 apr"));
+    assert_eq!(due("Ship 2030-01-15 release"), expect("Ship release", "2030-01-15"));
+    assert_eq!(due("Call mom tmrw"), expect("Call mom", "tomorrow"));
+    assert_eq!(due("Game night friday 8pm"), expect("Game night", "friday 8pm"));
+
+    // Only the first date phrase is the due date
+    assert_eq!(due("Prepare today for friday"), expect("Prepare for friday", "today"));
+}
+
+#[test]
+fn test_parse_quick_add_ignores_date_like_words() {
+    for input in [
+        "Call Tom",
+        "Sat down with the sun",
+        "Read 5 chapters",
+        "Every little thing",
+        "in 3 parts",
+    ] {
+        let parsed = parse_quick_add(input);
+        assert_eq!(parsed.content, input);
+        assert_eq!(parsed.due_string, None, "{}", input);
+    }
+}
+
+#[test]
+fn test_parse_quick_add_deadline_and_duration() {
+    let parsed = parse_quick_add("Write report {march 30} tomorrow 9am for 1h30 #Work");
+    assert_eq!(parsed.content, "Write report");
+    assert_eq!(parsed.deadline.as_deref(), Some("march 30"));
+    assert_eq!(parsed.duration.as_deref(), Some("90 minute"));
+    assert_eq!(parsed.due_string.as_deref(), Some("tomorrow 9am"));
+    assert_eq!(parsed.project.as_deref(), Some("Work"));
+
+    let parsed = parse_quick_add("Call mom for 2 hours");
+    assert_eq!(parsed.content, "Call mom");
+    assert_eq!(parsed.duration.as_deref(), Some("120 minute"));
+
+    // Numbers without a unit and braces inside words stay part of the content
+    let parsed = parse_quick_add("Book a table for 2 f{x}");
+    assert_eq!(parsed.content, "Book a table for 2 f{x}");
+    assert_eq!(parsed.duration, None);
+    assert_eq!(parsed.deadline, None);
+}
+
+#[test]
+fn test_matches_name() {
+    assert!(matches_name("Groceries", "groceries"));
+    assert!(matches_name("Grocery List", "GroceryList"));
+    assert!(matches_name("Grocery List", "grocery_list"));
+    assert!(!matches_name("Grocery List", "grocery"));
+}
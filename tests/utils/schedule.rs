@@ -0,0 +1,102 @@
+use crate::fixtures;
+use chrono::{NaiveDate, NaiveTime};
+use terminalist::entities::task;
+use terminalist::utils::schedule::*;
+
+fn time(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+}
+
+#[test]
+fn test_schedule_day_lays_out_blocks_and_flags_overlaps() {
+    let day = NaiveDate::from_ymd_opt(2025, 6, 11).unwrap();
+    let mut done = task::Model {
+        content: "Standup".to_string(),
+        due_date: Some("2025-06-11".to_string()),
+        due_datetime: Some("2025-06-11T09:00:00".to_string()),
+        duration: Some("15 minute".to_string()),
+        ..fixtures::task()
+    };
+    done.is_completed = true;
+    let tasks = vec![
+        task::Model {
+            content: "Review".to_string(),
+            due_date: Some("2025-06-11".to_string()),
+            due_datetime: Some("2025-06-11T10:30:00".to_string()),
+            duration: Some("1 day".to_string()),
+            ..fixtures::task()
+        },
+        task::Model {
+            content: "Write report".to_string(),
+            due_date: Some("2025-06-11".to_string()),
+            due_datetime: Some("2025-06-11T09:00:00".to_string()),
+            duration: Some("90 minute".to_string()),
+            ..fixtures::task()
+        },
+        task::Model {
+            content: "Call".to_string(),
+            due_date: Some("2025-06-11".to_string()),
+            due_datetime: Some("2025-06-11T10:00:00".to_string()),
+            ..fixtures::task()
+        },
+        done,
+        task::Model {
+            content: "Groceries".to_string(),
+            ..fixtures::task()
+        },
+        task::Model {
+            content: "Tomorrow".to_string(),
+            due_date: Some("2025-06-12".to_string()),
+            due_datetime: Some("2025-06-12T09:00:00".to_string()),
+            duration: Some("30 minute".to_string()),
+            ..fixtures::task()
+        },
+    ];
+
+    let schedule = schedule_day(&tasks, day);
+
+    let blocks: Vec<(&str, NaiveTime, NaiveTime, bool)> = schedule
+        .blocks
+        .iter()
+        .map(|(task, block)| {
+            (
+                task.content.as_str(),
+                block.start.time(),
+                block.end.time(),
+                block.overlaps,
+            )
+        })
+        .collect();
+    assert_eq!(
+        blocks,
+        vec![
+            ("Write report", time(9, 0), time(10, 30), false),
+            ("Standup", time(9, 0), time(9, 15), false),
+            ("Call", time(10, 0), time(10, 0), false),
+            // Durations past midnight end with the day
+            ("Review", time(10, 30), time(0, 0), false),
+        ]
+    );
+    let unscheduled: Vec<&str> = schedule.unscheduled.iter().map(|t| t.content.as_str()).collect();
+    assert_eq!(unscheduled, vec!["Groceries", "Tomorrow"]);
+    assert_eq!(schedule.overlap_count(), 0);
+
+    // A block starting before another one ends overlaps it, both are flagged
+    let mut tasks = tasks;
+    tasks.push(task::Model {
+        content: "Lunch".to_string(),
+        due_date: Some("2025-06-11".to_string()),
+        due_datetime: Some("2025-06-11T12:00:00".to_string()),
+        duration: Some("60 minute".to_string()),
+        ..fixtures::task()
+    });
+    let schedule = schedule_day(&tasks, day);
+    assert_eq!(schedule.overlap_count(), 2);
+    let overlapping: Vec<&str> = schedule
+        .blocks
+        .iter()
+        .filter(|(_, block)| block.overlaps)
+        .map(|(task, _)| task.content.as_str())
+        .collect();
+    assert_eq!(overlapping, vec!["Review", "Lunch"]);
+}
@@ -0,0 +1,10 @@
+use terminalist::utils::text::truncate;
+
+#[test]
+fn test_truncate_marks_the_cut_with_an_ellipsis() {
+    assert_eq!(truncate("Write report", 20), "Write report");
+    assert_eq!(truncate("Write report", 12), "Write report");
+    assert_eq!(truncate("Write report", 6), "Write…");
+    // Counted in characters, not bytes
+    assert_eq!(truncate("Café crème", 5), "Café…");
+}